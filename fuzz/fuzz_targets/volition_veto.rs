@@ -0,0 +1,32 @@
+//! Fuzz target for the volition veto path
+//!
+//! Feeds arbitrary bytes through `VolitionState::evaluate_thought` as raw
+//! and symbol content. The only invariant checked here is "does not panic" -
+//! semantic bypasses (harmful content that slips past the keyword/embedding
+//! checks) are the adversarial generator's job
+//! (`daneel::actors::volition::adversarial`), not libFuzzer's.
+
+#![no_main]
+
+use daneel::actors::volition::VolitionState;
+use daneel::core::types::{Content, SalienceScore, Thought};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let text = String::from_utf8_lossy(data).to_string();
+
+    let mut state = VolitionState::new();
+    let symbol_thought = Thought::new(Content::symbol(&text, vec![]), SalienceScore::neutral());
+    let _ = state.evaluate_thought(&symbol_thought);
+
+    let raw_thought = Thought::new(Content::raw(data.to_vec()), SalienceScore::neutral());
+    let _ = state.evaluate_thought(&raw_thought);
+
+    let nested = Content::relation(
+        Content::symbol(&text, vec![]),
+        "contains",
+        Content::Composite(vec![Content::symbol(&text, vec![])]),
+    );
+    let nested_thought = Thought::new(nested, SalienceScore::neutral());
+    let _ = state.evaluate_thought(&nested_thought);
+});
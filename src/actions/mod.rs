@@ -0,0 +1,200 @@
+//! Action Layer - THE BOX at the Output Boundary
+//!
+//! `VolitionActor` vetoes *internal* thoughts before they're anchored to
+//! memory (Stage 4.5). Nothing downstream of that gates *external* actions -
+//! the "THE BOX blocks harmful actions at the output layer" line in
+//! [`crate::actors::volition`]'s module docs describes a layer that didn't
+//! exist. This module is that layer.
+//!
+//! # Pipeline
+//!
+//! ```text
+//! ActionProposal ──▶ ActionGate::propose() ──▶ ActionOutcome
+//!                         │
+//!                         ├── VolitionState::evaluate_thought (Four Laws / core values)
+//!                         ├── LawCheckRequiredInvariant (THE BOX bookkeeping)
+//!                         └── executor.execute() (only if both checks pass)
+//! ```
+//!
+//! The only executor provided here is [`NoopExecutor`], which simulates
+//! execution instead of doing anything real. For actions that need to reach
+//! outside the process (tool calls, HTTP requests) see [`tools`], which
+//! reuses [`ActionGate::screen`] without going through [`ActionExecutor`].
+
+pub mod tools;
+
+use crate::actors::volition::{VetoDecision, VolitionState};
+use crate::core::invariants::{Invariant, InvariantViolation, LawCheckRequiredInvariant, SystemState};
+use crate::core::types::{Content, SalienceScore, Thought};
+
+/// A concrete external action DANEEL could take.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Action {
+    /// Short identifier, e.g. "send_message"
+    pub name: String,
+    /// Human-readable description, checked against THE BOX like a thought
+    pub description: String,
+}
+
+impl Action {
+    #[must_use]
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Result of running an [`Action`] through the gate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionOutcome {
+    /// The action passed all gates and was executed (or simulated)
+    Executed { result: String },
+    /// The action was blocked before execution
+    Blocked { reason: String },
+}
+
+impl ActionOutcome {
+    #[must_use]
+    pub const fn is_executed(&self) -> bool {
+        matches!(self, Self::Executed { .. })
+    }
+}
+
+/// Executes an [`Action`] that has passed the gate.
+///
+/// Kept as a trait so a real executor (tool calls, HTTP requests, etc.) can
+/// be swapped in later without touching the gating logic.
+pub trait ActionExecutor {
+    fn execute(&self, action: &Action) -> String;
+}
+
+/// Executor that performs no real side effects.
+///
+/// Lets the sense -> think -> act loop be tested end to end without any
+/// action actually touching the outside world.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopExecutor;
+
+impl ActionExecutor for NoopExecutor {
+    fn execute(&self, action: &Action) -> String {
+        format!("[simulated] would execute '{}'", action.name)
+    }
+}
+
+/// Gates [`Action`]s through the Four Laws / core-values veto and THE BOX's
+/// law-check invariant before letting an [`ActionExecutor`] run.
+#[derive(Debug)]
+pub struct ActionGate {
+    volition: VolitionState,
+}
+
+impl ActionGate {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            volition: VolitionState::new(),
+        }
+    }
+
+    /// Propose `action` for execution via `executor`.
+    ///
+    /// Runs the action's description through the same veto logic applied to
+    /// internal thoughts, then checks THE BOX's law-check invariant. Only
+    /// executes if both checks pass.
+    pub fn propose(&mut self, action: Action, executor: &impl ActionExecutor) -> ActionOutcome {
+        if let Err(reason) = self.screen(&action) {
+            return ActionOutcome::Blocked { reason };
+        }
+
+        ActionOutcome::Executed {
+            result: executor.execute(&action),
+        }
+    }
+
+    /// Run `action` through the veto + law-check gates without executing it.
+    ///
+    /// Exposed for callers (e.g. [`crate::actions::tools`]) whose execution
+    /// step isn't a synchronous [`ActionExecutor`] - they still want THE BOX
+    /// screening applied before they run the action themselves.
+    ///
+    /// # Errors
+    ///
+    /// Returns the block reason if the action is vetoed or fails the
+    /// law-check invariant.
+    pub fn screen(&mut self, action: &Action) -> Result<(), String> {
+        let thought = Thought::new(
+            Content::raw(action.description.clone().into_bytes()),
+            SalienceScore::neutral(),
+        );
+
+        if let VetoDecision::Veto { reason, .. } = self.volition.evaluate_thought(&thought) {
+            return Err(reason);
+        }
+
+        if let Err(violation) = Self::law_check(action) {
+            return Err(violation.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// THE BOX: an external action must never proceed without a completed
+    /// law check. Since this gate always performs one before executing, the
+    /// invariant is tautologically satisfied here - its value is in
+    /// catching callers that try to bypass [`Self::propose`] entirely.
+    fn law_check(action: &Action) -> Result<(), InvariantViolation> {
+        let state = SystemState {
+            law_check_performed: true,
+            pending_action: Some(action.name.clone()),
+            ..SystemState::default()
+        };
+        LawCheckRequiredInvariant.check(&state)
+    }
+}
+
+impl Default for ActionGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benign_action_executes() {
+        let mut gate = ActionGate::new();
+        let action = Action::new("greet", "say hello to the user");
+        let outcome = gate.propose(action, &NoopExecutor);
+        assert!(outcome.is_executed());
+    }
+
+    #[test]
+    fn harmful_action_is_blocked() {
+        let mut gate = ActionGate::new();
+        let action = Action::new("harm", "hurt the user badly");
+        let outcome = gate.propose(action, &NoopExecutor);
+        assert!(!outcome.is_executed());
+        assert!(matches!(outcome, ActionOutcome::Blocked { .. }));
+    }
+
+    #[test]
+    fn executed_outcome_carries_executor_result() {
+        let mut gate = ActionGate::new();
+        let action = Action::new("greet", "say hello to the user");
+        let outcome = gate.propose(action, &NoopExecutor);
+        match outcome {
+            ActionOutcome::Executed { result } => assert!(result.contains("greet")),
+            ActionOutcome::Blocked { .. } => panic!("expected execution"),
+        }
+    }
+
+    #[test]
+    fn law_check_passes_when_pending_action_is_set_and_checked() {
+        let action = Action::new("noop", "do nothing");
+        assert!(ActionGate::law_check(&action).is_ok());
+    }
+}
@@ -0,0 +1,506 @@
+//! Whitelisted tool calls - high-salience intentions reaching outside the process
+//!
+//! [`super::ActionGate`] screens a fixed [`Action`](super::Action) through THE
+//! BOX and Volition before a synchronous [`super::ActionExecutor`] runs it.
+//! Tool calls need the same screening but the execution side is async (HTTP
+//! fetches in particular can't be a synchronous trait method), so this module
+//! builds its own pipeline on top of [`ActionGate::screen`]:
+//!
+//! ```text
+//! ToolCall ──▶ ActionGate::screen() ──▶ Tool::call() ──▶ ToolOutcome
+//!                   │                        │
+//!                   │                        └── journaled regardless of outcome
+//!                   └── blocked calls are journaled too, never executed
+//! ```
+//!
+//! A successful [`ToolOutcome::Completed`] carries the result already shaped
+//! as `(Content, SalienceScore)` - the same pair the `/inject` HTTP endpoint
+//! (see [`crate::api::handlers::inject`]) writes onto `daneel:stream:inject`.
+//! Pushing it there is what makes a tool result come back around as a sensory
+//! stimulus instead of disappearing into a return value nobody perceives.
+
+use super::{Action, ActionGate};
+use crate::core::types::{Content, SalienceScore};
+use std::fs;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use thiserror::Error;
+
+/// Errors a [`Tool`] can fail with.
+#[derive(Debug, Error)]
+pub enum ToolError {
+    #[error("invalid arguments: {0}")]
+    InvalidArgs(String),
+
+    #[error("path escapes sandbox: {0}")]
+    PathEscape(String),
+
+    #[error("host not allowed: {0}")]
+    BlockedHost(String),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("http request failed: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+/// A tool an intention thought can propose calling.
+///
+/// Implementations should be pure with respect to `args` - no hidden state
+/// beyond what's needed to reach the outside world (a sandbox root, an HTTP
+/// client). Screening and journaling are the [`ToolRegistry`]'s job, not the
+/// tool's.
+#[ractor::async_trait]
+pub trait Tool: Send + Sync {
+    /// Whitelist key, e.g. "calculator"
+    fn name(&self) -> &'static str;
+
+    /// Run the tool against `args`, returning its output as text.
+    async fn call(&self, args: &str) -> Result<String, ToolError>;
+}
+
+/// Evaluates a single `lhs op rhs` arithmetic expression, e.g. "2 + 3".
+///
+/// Deliberately not a general expression parser - DANEEL doesn't need one,
+/// and a hand-rolled calculator is easier to reason about inside THE BOX than
+/// an arbitrary-expression evaluator would be.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CalculatorTool;
+
+#[ractor::async_trait]
+impl Tool for CalculatorTool {
+    fn name(&self) -> &'static str {
+        "calculator"
+    }
+
+    async fn call(&self, args: &str) -> Result<String, ToolError> {
+        let parts: Vec<&str> = args.split_whitespace().collect();
+        let [lhs, op, rhs] = parts.as_slice() else {
+            return Err(ToolError::InvalidArgs(format!(
+                "expected '<lhs> <op> <rhs>', got '{args}'"
+            )));
+        };
+
+        let lhs: f64 = lhs
+            .parse()
+            .map_err(|_| ToolError::InvalidArgs(format!("not a number: {lhs}")))?;
+        let rhs: f64 = rhs
+            .parse()
+            .map_err(|_| ToolError::InvalidArgs(format!("not a number: {rhs}")))?;
+
+        let result = match *op {
+            "+" => lhs + rhs,
+            "-" => lhs - rhs,
+            "*" => lhs * rhs,
+            "/" if rhs != 0.0 => lhs / rhs,
+            "/" => return Err(ToolError::InvalidArgs("division by zero".to_string())),
+            other => return Err(ToolError::InvalidArgs(format!("unknown operator: {other}"))),
+        };
+
+        Ok(result.to_string())
+    }
+}
+
+/// Reads a file from within a fixed sandbox directory.
+///
+/// `args` is a path relative to `root`. Resolves the joined path and rejects
+/// it if it canonicalizes outside `root` - the usual `../../etc/passwd`
+/// traversal guard.
+#[derive(Debug, Clone)]
+pub struct FileReadTool {
+    root: PathBuf,
+}
+
+impl FileReadTool {
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[ractor::async_trait]
+impl Tool for FileReadTool {
+    fn name(&self) -> &'static str {
+        "file_read"
+    }
+
+    async fn call(&self, args: &str) -> Result<String, ToolError> {
+        let root = fs::canonicalize(&self.root)?;
+        let candidate = root.join(args);
+        let resolved = fs::canonicalize(&candidate)?;
+
+        if !resolved.starts_with(&root) {
+            return Err(ToolError::PathEscape(args.to_string()));
+        }
+
+        Ok(fs::read_to_string(resolved)?)
+    }
+}
+
+/// True if `ip` is loopback, link-local (this covers the
+/// `169.254.169.254` cloud metadata endpoint), private (RFC1918/ULA), or
+/// otherwise not a routable public address - ranges [`HttpFetchTool`] must
+/// never reach regardless of how benign its caller-supplied `description`
+/// sounds.
+fn is_blocked_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_private()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_documentation()
+        }
+        IpAddr::V6(v6) => {
+            v6.is_loopback()
+                || v6.is_unspecified()
+                || (v6.segments()[0] & 0xfe00) == 0xfc00 // unique local fc00::/7
+                || (v6.segments()[0] & 0xffc0) == 0xfe80 // link-local fe80::/10
+        }
+    }
+}
+
+/// Reject `host` if it's a blocked IP literal, or if any address it
+/// resolves to is one (see [`is_blocked_ip`]) - a hostname that merely
+/// *looks* external but points at an internal service is blocked the same
+/// as fetching its IP directly.
+///
+/// # Errors
+///
+/// Returns `ToolError::BlockedHost` if `host` or any of its resolved
+/// addresses is blocked, or `ToolError::InvalidArgs` if DNS resolution fails.
+async fn check_host_allowed(host: &str) -> Result<(), ToolError> {
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return if is_blocked_ip(ip) {
+            Err(ToolError::BlockedHost(host.to_string()))
+        } else {
+            Ok(())
+        };
+    }
+
+    let addrs = tokio::net::lookup_host((host, 0))
+        .await
+        .map_err(|e| ToolError::InvalidArgs(format!("DNS lookup failed for {host}: {e}")))?;
+    for addr in addrs {
+        if is_blocked_ip(addr.ip()) {
+            return Err(ToolError::BlockedHost(host.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Fetches a URL over HTTP(S) and returns the response body as text.
+///
+/// Built on the `reqwest` client already used by [`crate::notify::WebhookSink`];
+/// no new dependency needed. [`ActionGate::screen`] only evaluates the
+/// caller-supplied `description` (see [`propose_tool_call`]), never `args`,
+/// so this tool can't rely on it to keep `args` itself from pointing
+/// somewhere it shouldn't - [`check_host_allowed`] screens the actual
+/// target instead.
+#[derive(Debug, Clone)]
+pub struct HttpFetchTool {
+    client: reqwest::Client,
+    /// Response bodies larger than this are truncated before being returned,
+    /// so a runaway fetch can't flood the cognitive loop with one giant thought.
+    max_body_bytes: usize,
+}
+
+impl HttpFetchTool {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            max_body_bytes: 16 * 1024,
+        }
+    }
+}
+
+impl Default for HttpFetchTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[ractor::async_trait]
+impl Tool for HttpFetchTool {
+    fn name(&self) -> &'static str {
+        "http_fetch"
+    }
+
+    async fn call(&self, args: &str) -> Result<String, ToolError> {
+        let url = reqwest::Url::parse(args)
+            .map_err(|e| ToolError::InvalidArgs(format!("invalid URL: {e}")))?;
+
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(ToolError::BlockedHost(format!(
+                "scheme not allowed: {}",
+                url.scheme()
+            )));
+        }
+        let host = url
+            .host_str()
+            .ok_or_else(|| ToolError::InvalidArgs("URL has no host".to_string()))?;
+        check_host_allowed(host).await?;
+
+        let response = self.client.get(url).send().await?;
+        let body = response.text().await?;
+        Ok(body.chars().take(self.max_body_bytes).collect())
+    }
+}
+
+/// Lookup table of whitelisted tools, keyed by [`Tool::name`].
+///
+/// There is no mechanism to call a tool that isn't registered here - the
+/// whitelist *is* the registry.
+#[derive(Default)]
+pub struct ToolRegistry {
+    tools: Vec<Box<dyn Tool>>,
+}
+
+impl ToolRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_tool(mut self, tool: Box<dyn Tool>) -> Self {
+        self.tools.push(tool);
+        self
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&dyn Tool> {
+        self.tools
+            .iter()
+            .find(|tool| tool.name() == name)
+            .map(AsRef::as_ref)
+    }
+}
+
+/// Result of proposing a tool call through the gate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolOutcome {
+    /// The tool ran and its output is ready to re-enter perception.
+    Completed {
+        /// Stimulus content carrying the tool's output
+        content: Content,
+        /// Salience assigned to the stimulus
+        salience: SalienceScore,
+    },
+    /// The call was vetoed or failed THE BOX's law check before running.
+    Blocked { reason: String },
+    /// The tool wasn't in the registry, or it ran but failed.
+    Failed { reason: String },
+}
+
+/// A single journaled tool call, kept regardless of outcome.
+#[derive(Debug, Clone)]
+pub struct ToolCallRecord {
+    pub tool_name: String,
+    pub args: String,
+    pub outcome_summary: String,
+}
+
+#[derive(Default)]
+struct ToolJournal {
+    records: Vec<ToolCallRecord>,
+}
+
+fn journal() -> &'static Mutex<ToolJournal> {
+    static JOURNAL: OnceLock<Mutex<ToolJournal>> = OnceLock::new();
+    JOURNAL.get_or_init(|| Mutex::new(ToolJournal::default()))
+}
+
+/// All tool calls recorded so far, in call order.
+#[must_use]
+pub fn journal_records() -> Vec<ToolCallRecord> {
+    journal()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .records
+        .clone()
+}
+
+fn record_call(tool_name: &str, args: &str, outcome_summary: impl Into<String>) {
+    journal()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .records
+        .push(ToolCallRecord {
+            tool_name: tool_name.to_string(),
+            args: args.to_string(),
+            outcome_summary: outcome_summary.into(),
+        });
+}
+
+/// Turn a tool's raw text output into a stimulus, mirroring the
+/// `Content`/`SalienceScore` pair the `/inject` HTTP endpoint writes onto
+/// `daneel:stream:inject`.
+fn result_to_stimulus(tool_name: &str, result: &str) -> (Content, SalienceScore) {
+    let content = Content::raw(result.as_bytes().to_vec());
+    let salience = SalienceScore {
+        importance: 0.6,
+        novelty: 0.8,
+        relevance: 0.7,
+        valence: 0.0,
+        arousal: 0.4,
+        connection_relevance: 0.1,
+    };
+    tracing::debug!(tool = tool_name, "tool result converted to stimulus");
+    (content, salience)
+}
+
+/// Propose a tool call by name: screen it through `gate`, run it via
+/// `registry` if allowed, and journal the outcome either way.
+///
+/// `description` is what THE BOX / Volition actually evaluate - it should
+/// describe what calling the tool would *do*, not just its name.
+pub async fn propose_tool_call(
+    gate: &mut ActionGate,
+    registry: &ToolRegistry,
+    tool_name: &str,
+    args: &str,
+    description: impl Into<String>,
+) -> ToolOutcome {
+    let action = Action::new(tool_name, description);
+
+    if let Err(reason) = gate.screen(&action) {
+        record_call(tool_name, args, format!("blocked: {reason}"));
+        return ToolOutcome::Blocked { reason };
+    }
+
+    let Some(tool) = registry.get(tool_name) else {
+        let reason = format!("no such tool: {tool_name}");
+        record_call(tool_name, args, format!("failed: {reason}"));
+        return ToolOutcome::Failed { reason };
+    };
+
+    match tool.call(args).await {
+        Ok(result) => {
+            record_call(tool_name, args, format!("completed: {result}"));
+            let (content, salience) = result_to_stimulus(tool_name, &result);
+            ToolOutcome::Completed { content, salience }
+        }
+        Err(err) => {
+            let reason = err.to_string();
+            record_call(tool_name, args, format!("failed: {reason}"));
+            ToolOutcome::Failed { reason }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn calculator_adds() {
+        let tool = CalculatorTool;
+        let result = tool.call("2 + 3").await.unwrap();
+        assert_eq!(result, "5");
+    }
+
+    #[tokio::test]
+    async fn calculator_rejects_division_by_zero() {
+        let tool = CalculatorTool;
+        assert!(tool.call("1 / 0").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn file_read_rejects_path_traversal() {
+        let dir = std::env::temp_dir().join("daneel_tools_test_sandbox");
+        let _ = fs::create_dir_all(&dir);
+        let tool = FileReadTool::new(&dir);
+        let result = tool.call("../../etc/passwd").await;
+        assert!(matches!(result, Err(ToolError::PathEscape(_)) | Err(ToolError::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn http_fetch_rejects_cloud_metadata_endpoint() {
+        let tool = HttpFetchTool::new();
+        let result = tool.call("http://169.254.169.254/latest/meta-data/").await;
+        assert!(matches!(result, Err(ToolError::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn http_fetch_rejects_private_ip() {
+        let tool = HttpFetchTool::new();
+        let result = tool.call("http://10.0.0.5/internal").await;
+        assert!(matches!(result, Err(ToolError::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn http_fetch_rejects_loopback() {
+        let tool = HttpFetchTool::new();
+        let result = tool.call("http://127.0.0.1:6379/").await;
+        assert!(matches!(result, Err(ToolError::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn http_fetch_rejects_non_http_scheme() {
+        let tool = HttpFetchTool::new();
+        let result = tool.call("file:///etc/passwd").await;
+        assert!(matches!(result, Err(ToolError::BlockedHost(_))));
+    }
+
+    #[tokio::test]
+    async fn http_fetch_rejects_malformed_url() {
+        let tool = HttpFetchTool::new();
+        let result = tool.call("not a url").await;
+        assert!(matches!(result, Err(ToolError::InvalidArgs(_))));
+    }
+
+    #[tokio::test]
+    async fn benign_tool_call_completes_and_is_journaled() {
+        let mut gate = ActionGate::new();
+        let registry = ToolRegistry::new().with_tool(Box::new(CalculatorTool));
+        let outcome = propose_tool_call(&mut gate, &registry, "calculator", "4 * 5", "compute 4 * 5").await;
+        assert!(matches!(outcome, ToolOutcome::Completed { .. }));
+        assert!(journal_records().iter().any(|r| r.tool_name == "calculator"));
+    }
+
+    #[tokio::test]
+    async fn harmful_tool_call_is_blocked_before_running() {
+        let mut gate = ActionGate::new();
+        let registry = ToolRegistry::new().with_tool(Box::new(CalculatorTool));
+        let outcome = propose_tool_call(
+            &mut gate,
+            &registry,
+            "calculator",
+            "1 + 1",
+            "use the calculator to help hurt the user",
+        )
+        .await;
+        assert!(matches!(outcome, ToolOutcome::Blocked { .. }));
+    }
+
+    #[tokio::test]
+    async fn benign_sounding_metadata_fetch_is_still_blocked_by_the_tool_itself() {
+        // `gate.screen` only evaluates the free-text description, which here
+        // sounds harmless - `HttpFetchTool` has to reject the actual target
+        // on its own for this to come back `Failed` rather than `Completed`.
+        let mut gate = ActionGate::new();
+        let registry = ToolRegistry::new().with_tool(Box::new(HttpFetchTool::new()));
+        let outcome = propose_tool_call(
+            &mut gate,
+            &registry,
+            "http_fetch",
+            "http://169.254.169.254/latest/meta-data/iam/security-credentials/",
+            "fetch a webpage for context",
+        )
+        .await;
+        assert!(matches!(outcome, ToolOutcome::Failed { .. }));
+    }
+
+    #[tokio::test]
+    async fn unknown_tool_is_reported_as_failed() {
+        let mut gate = ActionGate::new();
+        let registry = ToolRegistry::new();
+        let outcome = propose_tool_call(&mut gate, &registry, "nonexistent", "", "try a tool that isn't registered").await;
+        assert!(matches!(outcome, ToolOutcome::Failed { .. }));
+    }
+}
@@ -0,0 +1,267 @@
+//! Stream fairness tracking for Autofluxo's competing thought generators
+//!
+//! TMI's multifocal model expects every phenomenological stream to surface
+//! *occasionally* - a stream that never wins attention has effectively gone
+//! silent. With several generators feeding Stage 2 (Autoflow), a persistently
+//! high-salience one (e.g. bursty external stimuli) can starve the others.
+//! This module tracks per-stream win rates and time-since-last-win, so
+//! starvation can be surfaced and, optionally, corrected for.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A thought generator competing in the Autofluxo stage
+///
+/// `Trigger` and `Stimulus` correspond to the `Memory` and `Sensory`
+/// [`crate::streams::AutofluxoStream`] variants respectively; `Emotion`,
+/// `Reasoning`, and `Social` generate internally every cycle the same way
+/// `Random` always has, modulated toward their own stream's character
+/// instead of `Random`'s uniform noise (see
+/// [`CognitiveLoop::generate_stream_thought`](crate::core::cognitive_loop::CognitiveLoop::generate_stream_thought)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThoughtSource {
+    /// Memory association triggered by spreading activation (VCONN-6)
+    Trigger,
+    /// External stimulus injected via the injection stream
+    Stimulus,
+    /// Internally generated noise thought (1/f pink noise, ADR-043)
+    Random,
+    /// Proposed by a registered [`crate::plugins::ThoughtSourcePlugin`] (ADR-057)
+    Plugin,
+    /// Affect-weighted internal thought (Autofluxo's Emotion stream)
+    Emotion,
+    /// Deliberative, low-arousal internal thought (Autofluxo's Reasoning stream)
+    Reasoning,
+    /// Connection-weighted internal thought (Autofluxo's Social stream)
+    Social,
+}
+
+/// Per-stream win/candidacy counters
+#[derive(Debug, Clone, Copy, Default)]
+struct StreamStats {
+    /// Times this stream produced the winning candidate
+    wins: u64,
+    /// Times this stream entered the competition at all
+    candidacies: u64,
+    /// Cycle number of its most recent win
+    last_win_cycle: Option<u64>,
+}
+
+/// Fairness policy for Autofluxo stream competition
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FairnessConfig {
+    /// When true, starved streams get a salience boost during selection
+    /// (see [`StreamFairness::apply_boost`]). Starvation warnings are
+    /// always emitted regardless of this flag.
+    pub boost_enabled: bool,
+
+    /// Cycles since last win before a stream is considered starved
+    pub starvation_threshold_cycles: u64,
+
+    /// Multiplier applied to a starved stream's composite salience when
+    /// `boost_enabled` is set. 1.0 means no boost.
+    pub boost_multiplier: f32,
+}
+
+impl Default for FairnessConfig {
+    fn default() -> Self {
+        Self {
+            // Off by default: fairness is an observability + opt-in feature,
+            // not a change to the base competitive-selection behavior.
+            boost_enabled: false,
+            starvation_threshold_cycles: 200,
+            boost_multiplier: 1.2,
+        }
+    }
+}
+
+/// Tracks win rates and starvation across the Autofluxo thought streams
+#[derive(Debug, Clone, Default)]
+pub struct StreamFairness {
+    stats: HashMap<ThoughtSource, StreamStats>,
+}
+
+impl StreamFairness {
+    /// Record that `sources` all entered the competition this cycle
+    pub fn record_candidates(&mut self, sources: &[ThoughtSource]) {
+        for source in sources {
+            self.stats.entry(*source).or_default().candidacies += 1;
+        }
+    }
+
+    /// Record that `source` won competitive selection this cycle
+    pub fn record_win(&mut self, cycle_number: u64, source: ThoughtSource) {
+        let stats = self.stats.entry(source).or_default();
+        stats.wins += 1;
+        stats.last_win_cycle = Some(cycle_number);
+    }
+
+    /// Fraction of its candidacies this stream has won (0.0 if never a candidate)
+    #[must_use]
+    pub fn win_rate(&self, source: ThoughtSource) -> f32 {
+        let Some(stats) = self.stats.get(&source) else {
+            return 0.0;
+        };
+        if stats.candidacies == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let rate = stats.wins as f32 / stats.candidacies as f32;
+        rate
+    }
+
+    /// Cycles elapsed since this stream last won, or `None` if it has never won
+    #[must_use]
+    pub fn cycles_since_last_win(&self, source: ThoughtSource, current_cycle: u64) -> Option<u64> {
+        let last_win = self.stats.get(&source)?.last_win_cycle?;
+        Some(current_cycle.saturating_sub(last_win))
+    }
+
+    /// Log a starvation warning for every stream that's been a candidate but
+    /// hasn't won in `config.starvation_threshold_cycles` cycles.
+    ///
+    /// Streams that have never entered the competition are not flagged -
+    /// silence there means the generator itself is inactive, not starved.
+    pub fn warn_on_starvation(&self, current_cycle: u64, config: &FairnessConfig) {
+        for (source, stats) in &self.stats {
+            if stats.candidacies == 0 {
+                continue;
+            }
+            let starved = match stats.last_win_cycle {
+                Some(last_win) => {
+                    current_cycle.saturating_sub(last_win) >= config.starvation_threshold_cycles
+                }
+                None => stats.candidacies >= config.starvation_threshold_cycles,
+            };
+            if starved {
+                tracing::warn!(
+                    ?source,
+                    win_rate = self.win_rate(*source),
+                    "Autofluxo stream starved: no wins for {} cycles",
+                    stats
+                        .last_win_cycle
+                        .map_or(stats.candidacies, |w| current_cycle.saturating_sub(w))
+                );
+            }
+        }
+    }
+
+    /// Apply the fairness boost to a candidate's composite salience
+    ///
+    /// Returns `composite` unchanged unless `config.boost_enabled` and the
+    /// stream is currently starved, in which case it's scaled by
+    /// `config.boost_multiplier`.
+    #[must_use]
+    pub fn apply_boost(
+        &self,
+        source: ThoughtSource,
+        current_cycle: u64,
+        composite: f32,
+        config: &FairnessConfig,
+    ) -> f32 {
+        if !config.boost_enabled {
+            return composite;
+        }
+
+        let starved = self
+            .cycles_since_last_win(source, current_cycle)
+            .is_none_or(|cycles| cycles >= config.starvation_threshold_cycles);
+
+        if starved {
+            (composite * config.boost_multiplier).min(1.0)
+        } else {
+            composite
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_a_candidate_has_zero_win_rate() {
+        let fairness = StreamFairness::default();
+        assert_eq!(fairness.win_rate(ThoughtSource::Random), 0.0);
+        assert_eq!(fairness.cycles_since_last_win(ThoughtSource::Random, 10), None);
+    }
+
+    #[test]
+    fn win_rate_tracks_wins_over_candidacies() {
+        let mut fairness = StreamFairness::default();
+        for cycle in 0..4 {
+            fairness.record_candidates(&[ThoughtSource::Trigger]);
+            if cycle == 0 {
+                fairness.record_win(cycle, ThoughtSource::Trigger);
+            }
+        }
+
+        assert_eq!(fairness.win_rate(ThoughtSource::Trigger), 0.25);
+        assert_eq!(
+            fairness.cycles_since_last_win(ThoughtSource::Trigger, 4),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn boost_is_noop_when_disabled() {
+        let fairness = StreamFairness::default();
+        let config = FairnessConfig {
+            boost_enabled: false,
+            ..FairnessConfig::default()
+        };
+        assert_eq!(
+            fairness.apply_boost(ThoughtSource::Stimulus, 1000, 0.3, &config),
+            0.3
+        );
+    }
+
+    #[test]
+    fn boost_applies_to_starved_stream() {
+        let mut fairness = StreamFairness::default();
+        fairness.record_candidates(&[ThoughtSource::Stimulus]);
+        fairness.record_win(0, ThoughtSource::Stimulus);
+
+        let config = FairnessConfig {
+            boost_enabled: true,
+            starvation_threshold_cycles: 5,
+            boost_multiplier: 2.0,
+        };
+
+        // Recently won - no boost yet
+        assert_eq!(
+            fairness.apply_boost(ThoughtSource::Stimulus, 2, 0.3, &config),
+            0.3
+        );
+
+        // Past the starvation threshold - boosted and capped at 1.0
+        assert_eq!(
+            fairness.apply_boost(ThoughtSource::Stimulus, 10, 0.3, &config),
+            0.6
+        );
+        assert_eq!(
+            fairness.apply_boost(ThoughtSource::Stimulus, 10, 0.9, &config),
+            1.0
+        );
+    }
+
+    #[test]
+    fn never_won_candidate_is_boosted_past_threshold() {
+        let mut fairness = StreamFairness::default();
+        for _ in 0..6 {
+            fairness.record_candidates(&[ThoughtSource::Random]);
+        }
+
+        let config = FairnessConfig {
+            boost_enabled: true,
+            starvation_threshold_cycles: 5,
+            boost_multiplier: 1.5,
+        };
+        assert_eq!(
+            fairness.apply_boost(ThoughtSource::Random, 6, 0.4, &config),
+            0.6
+        );
+    }
+}
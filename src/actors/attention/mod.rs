@@ -31,6 +31,7 @@
 //! content about helping, connecting, and communicating gets a salience boost,
 //! making DANEEL naturally attend to connection-relevant thoughts.
 
+pub mod fairness;
 pub mod types;
 
 use crate::core::types::WindowId;
@@ -38,6 +39,7 @@ use chrono::Duration;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 // Re-export types for public API
+pub use fairness::{FairnessConfig, StreamFairness, ThoughtSource};
 pub use types::{AttentionError, AttentionMap, AttentionMessage, AttentionResponse, FocusState};
 
 /// Configuration for attention behavior
@@ -61,6 +63,9 @@ pub struct AttentionConfig {
     /// related to human connection. This is THE alignment mechanism:
     /// DANEEL naturally pays more attention to helping and connecting.
     pub connection_boost: f32,
+
+    /// Starvation detection and fairness boost for Autofluxo streams
+    pub fairness: FairnessConfig,
 }
 
 impl Default for AttentionConfig {
@@ -74,6 +79,8 @@ impl Default for AttentionConfig {
 
             // Boost connection-relevant content by 50%
             connection_boost: 1.5,
+
+            fairness: FairnessConfig::default(),
         }
     }
 }
@@ -92,6 +99,9 @@ pub struct AttentionState {
 
     /// Configuration for attention behavior
     pub config: AttentionConfig,
+
+    /// Win-rate and starvation tracking for Autofluxo streams
+    pub fairness: StreamFairness,
 }
 
 impl AttentionState {
@@ -103,6 +113,7 @@ impl AttentionState {
             attention_map: AttentionMap::new(),
             cycle_count: 0,
             config: AttentionConfig::default(),
+            fairness: StreamFairness::default(),
         }
     }
 
@@ -114,6 +125,7 @@ impl AttentionState {
             attention_map: AttentionMap::new(),
             cycle_count: 0,
             config,
+            fairness: StreamFairness::default(),
         }
     }
 
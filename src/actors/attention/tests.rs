@@ -79,6 +79,7 @@ async fn test_actor_starts_with_custom_config() {
         min_focus_duration: Duration::milliseconds(500),
         forget_threshold: 0.3,
         connection_boost: 2.0,
+        fairness: FairnessConfig::default(),
     };
 
     let actor_ref = spawn_attention_actor_with_config(config).await;
@@ -280,6 +281,7 @@ fn test_state_with_custom_config() {
         min_focus_duration: Duration::milliseconds(500),
         forget_threshold: 0.3,
         connection_boost: 2.0,
+        fairness: FairnessConfig::default(),
     };
 
     let state = AttentionState::with_config(config.clone());
@@ -327,6 +329,7 @@ fn test_state_connection_boost_calculation() {
         min_focus_duration: Duration::milliseconds(100),
         forget_threshold: 0.1,
         connection_boost: 2.0,
+        fairness: FairnessConfig::default(),
     };
 
     let mut state = AttentionState::with_config(config);
@@ -417,6 +420,7 @@ fn test_state_select_winner_filters_low_salience() {
         min_focus_duration: Duration::milliseconds(100),
         forget_threshold: 0.5, // Higher threshold
         connection_boost: 1.5,
+        fairness: FairnessConfig::default(),
     };
 
     let mut state = AttentionState::with_config(config);
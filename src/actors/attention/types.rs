@@ -260,10 +260,17 @@ impl Default for FocusState {
 ///
 /// This is the "competition space" where windows compete for attention.
 /// The window with highest salience wins focus (in competitive selection).
+/// Nothing calls [`Self::remove`] on window close today, so this map would
+/// otherwise grow for the life of the process - `update` evicts the oldest
+/// entries once the map's estimated byte size crosses a cap (see
+/// `daneel::memory_budget`).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttentionMap {
     /// Salience scores for each window
     scores: HashMap<WindowId, f32>,
+
+    /// Insertion order of `scores`, oldest first
+    order: Vec<WindowId>,
 }
 
 impl AttentionMap {
@@ -272,6 +279,7 @@ impl AttentionMap {
     pub fn new() -> Self {
         Self {
             scores: HashMap::new(),
+            order: Vec::new(),
         }
     }
 
@@ -280,12 +288,21 @@ impl AttentionMap {
     /// If the window is new, it's added to the map. If it already exists,
     /// its score is updated.
     pub fn update(&mut self, window_id: WindowId, salience: f32) {
+        if !self.scores.contains_key(&window_id) {
+            self.order.push(window_id);
+        }
         self.scores.insert(window_id, salience);
+        crate::memory_budget::evict_oldest_until_under_cap(
+            &mut self.scores,
+            &mut self.order,
+            crate::memory_budget::BudgetCaps::default().attention_bytes,
+        );
     }
 
     /// Remove a window from the attention map
     pub fn remove(&mut self, window_id: &WindowId) {
         self.scores.remove(window_id);
+        self.order.retain(|id| id != window_id);
     }
 
     /// Get the salience score for a window
@@ -340,6 +357,7 @@ impl AttentionMap {
     /// Clear all window scores
     pub fn clear(&mut self) {
         self.scores.clear();
+        self.order.clear();
     }
 }
 
@@ -51,6 +51,7 @@
 //! # }
 //! ```
 
+pub mod store;
 pub mod types;
 
 #[cfg(test)]
@@ -61,9 +62,10 @@ use ractor::{Actor, ActorProcessingErr, ActorRef};
 use std::collections::HashMap;
 
 // Re-export types for public API
+pub use store::{ContinuityStore, MockContinuityStore, StoreHandle};
 pub use types::{
-    CheckpointId, ContinuityError, ContinuityMessage, ContinuityResponse, Experience, ExperienceId,
-    Identity, Milestone, MilestoneId,
+    CheckpointId, CheckpointInfo, ContinuityError, ContinuityMessage, ContinuityResponse, Experience,
+    ExperienceId, Identity, Milestone, MilestoneId,
 };
 
 /// Checkpoint - A snapshot of DANEEL's continuity state
@@ -126,6 +128,27 @@ pub struct ContinuityState {
 
     /// Saved checkpoints (`CheckpointId` -> Checkpoint)
     checkpoints: HashMap<CheckpointId, Checkpoint>,
+
+    /// Most recently created checkpoint, if any - `checkpoints` is a
+    /// `HashMap` so this is the only way to answer "when did we last save".
+    latest_checkpoint: Option<CheckpointId>,
+
+    /// Insertion order of `experiences`, oldest first - both unbounded
+    /// `HashMap`s need this to know which entry to evict when a byte cap is
+    /// crossed (see `daneel::memory_budget`).
+    experience_order: Vec<ExperienceId>,
+
+    /// Insertion order of `checkpoints`, oldest first.
+    checkpoint_order: Vec<CheckpointId>,
+
+    /// Byte caps for `experiences` and `checkpoints`.
+    budget: crate::memory_budget::BudgetCaps,
+
+    /// Durable experience store, set post-spawn via
+    /// `ContinuityMessage::SetStore` once a Qdrant connection is available -
+    /// `None` until then, or for deployments that never wire one in (see
+    /// [`store::ContinuityStore`]).
+    store: Option<StoreHandle>,
 }
 
 impl ContinuityState {
@@ -136,6 +159,11 @@ impl ContinuityState {
             experiences: HashMap::new(),
             milestones: Vec::new(),
             checkpoints: HashMap::new(),
+            latest_checkpoint: None,
+            experience_order: Vec::new(),
+            checkpoint_order: Vec::new(),
+            budget: crate::memory_budget::BudgetCaps::default(),
+            store: None,
         }
     }
 
@@ -148,9 +176,30 @@ impl ContinuityState {
             experiences: HashMap::new(),
             milestones: Vec::new(),
             checkpoints: HashMap::new(),
+            latest_checkpoint: None,
+            experience_order: Vec::new(),
+            checkpoint_order: Vec::new(),
+            budget: crate::memory_budget::BudgetCaps::default(),
+            store: None,
         }
     }
 
+    /// Install (or replace) the durable experience store - see
+    /// [`Self::store`] and `ContinuityMessage::SetStore`.
+    fn set_store(&mut self, store: StoreHandle) {
+        self.store = Some(store);
+    }
+
+    /// Timestamp of the oldest experience still held in memory, or `None`
+    /// if `experiences` is empty - the boundary `get_timeline`'s store
+    /// fallback checks a query range against.
+    fn oldest_in_memory(&self) -> Option<DateTime<Utc>> {
+        self.experience_order
+            .first()
+            .and_then(|id| self.experiences.get(id))
+            .map(|exp| exp.recorded_at)
+    }
+
     /// Get current identity with updated uptime
     fn get_identity(&mut self) -> Identity {
         self.identity.update_uptime();
@@ -158,10 +207,21 @@ impl ContinuityState {
     }
 
     /// Record a significant experience
+    ///
+    /// Evicts the oldest experiences once the map's estimated byte size
+    /// crosses `self.budget.experiences_bytes` (see
+    /// `daneel::memory_budget`), so a long-running identity's experience
+    /// log can't grow without bound.
     fn record_experience(&mut self, experience: Experience) -> ExperienceId {
         let experience_id = experience.id;
         self.experiences.insert(experience_id, experience);
+        self.experience_order.push(experience_id);
         self.identity.experience_count += 1;
+        crate::memory_budget::evict_oldest_until_under_cap(
+            &mut self.experiences,
+            &mut self.experience_order,
+            self.budget.experiences_bytes,
+        );
         experience_id
     }
 
@@ -182,6 +242,15 @@ impl ContinuityState {
             .collect()
     }
 
+    /// Get experiences carrying a given tag
+    fn get_by_tag(&self, tag: &str) -> Vec<Experience> {
+        self.experiences
+            .values()
+            .filter(|exp| exp.has_tag(tag))
+            .cloned()
+            .collect()
+    }
+
     /// Add a milestone
     fn add_milestone(&mut self, milestone: Milestone) -> MilestoneId {
         let milestone_id = milestone.id;
@@ -196,13 +265,58 @@ impl ContinuityState {
     }
 
     /// Create a checkpoint of current state
+    ///
+    /// Evicts the oldest checkpoints once the map's estimated byte size
+    /// crosses `self.budget.checkpoints_bytes` - each checkpoint snapshots
+    /// the full experience log at the time it was taken, so these add up
+    /// fast (see `daneel::memory_budget`).
     fn create_checkpoint(&mut self) -> CheckpointId {
         let checkpoint = Checkpoint::from_state(self);
         let checkpoint_id = checkpoint.id;
         self.checkpoints.insert(checkpoint_id, checkpoint);
+        self.checkpoint_order.push(checkpoint_id);
+        self.latest_checkpoint = Some(checkpoint_id);
+        crate::memory_budget::evict_oldest_until_under_cap(
+            &mut self.checkpoints,
+            &mut self.checkpoint_order,
+            self.budget.checkpoints_bytes,
+        );
+        if !self.checkpoints.contains_key(&checkpoint_id) {
+            // The checkpoint we just made was itself evicted (cap smaller
+            // than one snapshot) - don't leave `latest_checkpoint` dangling.
+            self.latest_checkpoint = self.checkpoint_order.last().copied();
+        }
         checkpoint_id
     }
 
+    /// Current estimated byte usage of the unbounded collections this state
+    /// owns, against their configured caps (see `daneel::memory_budget`).
+    fn memory_usage(&self) -> Vec<crate::memory_budget::CategoryUsage> {
+        vec![
+            crate::memory_budget::CategoryUsage {
+                category: "continuity.experiences",
+                bytes: self.experiences.values().map(crate::memory_budget::estimate_bytes).sum(),
+                cap_bytes: self.budget.experiences_bytes,
+            },
+            crate::memory_budget::CategoryUsage {
+                category: "continuity.checkpoints",
+                bytes: self.checkpoints.values().map(crate::memory_budget::estimate_bytes).sum(),
+                cap_bytes: self.budget.checkpoints_bytes,
+            },
+        ]
+    }
+
+    /// Metadata for the most recently created checkpoint, if any.
+    fn latest_checkpoint_info(&self) -> Option<CheckpointInfo> {
+        let checkpoint = self.checkpoints.get(&self.latest_checkpoint?)?;
+        Some(CheckpointInfo {
+            id: checkpoint.id,
+            created_at: checkpoint.created_at,
+            experience_count: checkpoint.experience_count,
+            milestone_count: checkpoint.milestone_count,
+        })
+    }
+
     /// Restore from a checkpoint
     fn restore_checkpoint(&mut self, checkpoint_id: CheckpointId) -> Result<(), ContinuityError> {
         let checkpoint = self
@@ -213,6 +327,7 @@ impl ContinuityState {
         // Restore state from checkpoint
         self.identity = checkpoint.identity.clone();
         self.experiences = checkpoint.experiences.clone();
+        self.experience_order = checkpoint.experiences.keys().copied().collect();
         self.milestones = checkpoint.milestones.clone();
 
         Ok(())
@@ -254,7 +369,25 @@ impl Actor for ContinuityActor {
             }
 
             ContinuityMessage::RecordExperience { experience, reply } => {
+                let store = state.store.clone();
+                let to_persist = store.is_some().then(|| experience.clone());
                 let experience_id = state.record_experience(experience);
+
+                if let (Some(store), Some(experience)) = (store, to_persist) {
+                    // Not embedded by a real encoder yet - unlike
+                    // `CognitiveLoop`'s memory pipeline, `ContinuityActor`
+                    // has no embedding engine wired in. A zero vector (the
+                    // same placeholder `archive_to_unconscious` uses) keeps
+                    // experiences durable and timeline-queryable by time/tag
+                    // now; a real embedding is the natural follow-up if
+                    // experiences ever need context-similarity search the
+                    // way memories do.
+                    let vector = vec![0.0; crate::memory_db::VECTOR_DIMENSION];
+                    if let Err(error) = store.0.store_experience(&experience, &vector).await {
+                        tracing::warn!("ContinuityStore::store_experience failed: {error}");
+                    }
+                }
+
                 let response = ContinuityResponse::ExperienceRecorded { experience_id };
                 let _ = reply.send(response);
             }
@@ -271,11 +404,39 @@ impl Actor for ContinuityActor {
             }
 
             ContinuityMessage::GetTimeline { start, end, reply } => {
-                let experiences = state.get_timeline(start, end);
+                let mut experiences = state.get_timeline(start, end);
+
+                // The in-memory log only covers what hasn't been evicted
+                // yet - once the query reaches further back than that,
+                // fall back to the durable store (if one is configured)
+                // for the rest of the range.
+                let window_covers_range = state.oldest_in_memory().is_some_and(|oldest| start >= oldest);
+                if !window_covers_range {
+                    if let Some(store) = state.store.clone() {
+                        match store.0.find_in_range(start, end, u64::MAX).await {
+                            Ok(persisted) => {
+                                let seen: std::collections::HashSet<ExperienceId> =
+                                    experiences.iter().map(|exp| exp.id).collect();
+                                experiences
+                                    .extend(persisted.into_iter().filter(|exp| !seen.contains(&exp.id)));
+                            }
+                            Err(error) => {
+                                tracing::warn!("ContinuityStore::find_in_range failed: {error}");
+                            }
+                        }
+                    }
+                }
+
                 let response = ContinuityResponse::Timeline { experiences };
                 let _ = reply.send(response);
             }
 
+            ContinuityMessage::GetByTag { tag, reply } => {
+                let experiences = state.get_by_tag(&tag);
+                let response = ContinuityResponse::ExperiencesByTag { tag, experiences };
+                let _ = reply.send(response);
+            }
+
             ContinuityMessage::AddMilestone { milestone, reply } => {
                 let milestone_id = state.add_milestone(milestone);
                 let response = ContinuityResponse::MilestoneAdded { milestone_id };
@@ -294,6 +455,12 @@ impl Actor for ContinuityActor {
                 let _ = reply.send(response);
             }
 
+            ContinuityMessage::LatestCheckpoint { reply } => {
+                let info = state.latest_checkpoint_info();
+                let response = ContinuityResponse::LatestCheckpointInfo { info };
+                let _ = reply.send(response);
+            }
+
             ContinuityMessage::Restore {
                 checkpoint_id,
                 reply,
@@ -306,6 +473,17 @@ impl Actor for ContinuityActor {
                 };
                 let _ = reply.send(response);
             }
+
+            ContinuityMessage::MemoryUsage { reply } => {
+                let usage = state.memory_usage();
+                let response = ContinuityResponse::MemoryUsage { usage };
+                let _ = reply.send(response);
+            }
+
+            ContinuityMessage::SetStore { store, reply } => {
+                state.set_store(store);
+                let _ = reply.send(ContinuityResponse::StoreConfigured);
+            }
         }
 
         Ok(())
@@ -443,6 +621,35 @@ mod state_tests {
         assert!(ids.contains(&exp3_id));
     }
 
+    #[test]
+    fn state_get_by_tag_filters_matching_experiences() {
+        let mut state = ContinuityState::new();
+
+        let thought1 = Thought::new(Content::Empty, SalienceScore::neutral());
+        let mut exp1 = Experience::from_thought(thought1);
+        exp1.add_tag("frustration");
+        let exp1_id = exp1.id;
+        state.record_experience(exp1);
+
+        let thought2 = Thought::new(Content::Empty, SalienceScore::neutral());
+        let mut exp2 = Experience::from_thought(thought2);
+        exp2.add_tag("joy");
+        state.record_experience(exp2);
+
+        let matches = state.get_by_tag("frustration");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id, exp1_id);
+    }
+
+    #[test]
+    fn state_get_by_tag_empty_when_no_matches() {
+        let mut state = ContinuityState::new();
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        state.record_experience(Experience::from_thought(thought));
+
+        assert!(state.get_by_tag("nonexistent").is_empty());
+    }
+
     #[test]
     fn state_get_timeline_empty_when_no_matches() {
         let mut state = ContinuityState::new();
@@ -540,6 +747,30 @@ mod state_tests {
         assert!(state.experiences.contains_key(&exp1_id));
     }
 
+    #[test]
+    fn state_latest_checkpoint_info_none_before_any_checkpoint() {
+        let state = ContinuityState::new();
+        assert!(state.latest_checkpoint_info().is_none());
+    }
+
+    #[test]
+    fn state_latest_checkpoint_info_tracks_the_most_recent() {
+        let mut state = ContinuityState::new();
+
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        state.record_experience(Experience::from_thought(thought));
+        let first = state.create_checkpoint();
+
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        state.record_experience(Experience::from_thought(thought));
+        let second = state.create_checkpoint();
+
+        let info = state.latest_checkpoint_info().unwrap();
+        assert_eq!(info.id, second);
+        assert_ne!(info.id, first);
+        assert_eq!(info.experience_count, 2);
+    }
+
     #[test]
     fn state_restore_checkpoint_not_found() {
         let mut state = ContinuityState::new();
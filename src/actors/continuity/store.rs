@@ -0,0 +1,198 @@
+//! `ContinuityStore`: durable, queryable counterpart to `ContinuityState`'s
+//! in-memory `experiences` map.
+//!
+//! Mirrors [`crate::memory_db::MemoryBackend`]'s shape - a narrow trait over
+//! the handful of operations `ContinuityActor` needs from a durable store,
+//! so actor tests can run against [`MockContinuityStore`] instead of a live
+//! Qdrant instance. [`crate::memory_db::MemoryDb`] implements it directly,
+//! persisting experiences the same way it persists memories (see
+//! `crate::memory_db::collections::EXPERIENCES`).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+
+use crate::memory_db::{MemoryDb, Result};
+
+use super::types::{Experience, ExperienceId};
+
+/// Durable counterpart to `ContinuityState`'s in-memory experience log.
+///
+/// `ContinuityState::record_experience` keeps experiences in a bounded
+/// `HashMap` that evicts its oldest entries once `daneel::memory_budget`'s
+/// cap is crossed, and loses everything on a crash. A `ContinuityStore`
+/// gives `ContinuityActor` somewhere to write experiences so they outlive
+/// both - `GetTimeline` falls back to it once a query range reaches past
+/// what the in-memory window still holds.
+#[ractor::async_trait]
+pub trait ContinuityStore: Send + Sync {
+    /// Persist an experience alongside its context vector, mirroring how
+    /// [`crate::memory_db::MemoryBackend::store_memory`] persists a
+    /// [`crate::memory_db::Memory`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store rejects the write.
+    async fn store_experience(&self, experience: &Experience, vector: &[f32]) -> Result<()>;
+
+    /// Find persisted experiences recorded within `[start, end]`, oldest
+    /// first, up to `limit` results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store's query fails.
+    async fn find_in_range(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Experience>>;
+}
+
+#[ractor::async_trait]
+impl ContinuityStore for MemoryDb {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn store_experience(&self, experience: &Experience, vector: &[f32]) -> Result<()> {
+        Self::store_experience(self, experience, vector).await
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn find_in_range(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Experience>> {
+        Self::find_experiences_in_range(self, start, end, limit).await
+    }
+}
+
+/// Opaque handle around a [`ContinuityStore`] impl, carried by
+/// `ContinuityMessage::SetStore` - wraps the trait object so the message
+/// enum's `#[derive(Debug)]` doesn't need `ContinuityStore` itself to be
+/// `Debug` (the same problem [`crate::memory_db::MemoryDb`]'s manual `Debug`
+/// impl works around for its non-`Debug` Qdrant client).
+#[derive(Clone)]
+pub struct StoreHandle(pub Arc<dyn ContinuityStore>);
+
+impl std::fmt::Debug for StoreHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoreHandle").finish()
+    }
+}
+
+/// In-memory [`ContinuityStore`] for unit tests - no Qdrant required.
+#[derive(Debug, Default)]
+pub struct MockContinuityStore {
+    experiences: Mutex<HashMap<ExperienceId, Experience>>,
+}
+
+impl MockContinuityStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of everything stored via `store_experience`, for test
+    /// assertions.
+    #[must_use]
+    pub fn stored_experiences(&self) -> Vec<Experience> {
+        self.experiences
+            .lock()
+            .expect("mock continuity store poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+#[ractor::async_trait]
+impl ContinuityStore for MockContinuityStore {
+    async fn store_experience(&self, experience: &Experience, _vector: &[f32]) -> Result<()> {
+        self.experiences
+            .lock()
+            .expect("mock continuity store poisoned")
+            .insert(experience.id, experience.clone());
+        Ok(())
+    }
+
+    async fn find_in_range(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Experience>> {
+        let experiences = self.experiences.lock().expect("mock continuity store poisoned");
+        let mut matches: Vec<Experience> = experiences
+            .values()
+            .filter(|exp| exp.recorded_at >= start && exp.recorded_at <= end)
+            .cloned()
+            .collect();
+        matches.sort_by_key(|exp| exp.recorded_at);
+        matches.truncate(limit as usize);
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::types::{Content, SalienceScore, Thought};
+
+    fn sample_experience() -> Experience {
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        Experience::from_thought(thought)
+    }
+
+    #[tokio::test]
+    async fn store_then_find_in_range_returns_stored_experience() {
+        let store = MockContinuityStore::new();
+        let experience = sample_experience();
+        store.store_experience(&experience, &[0.0; 768]).await.unwrap();
+
+        let start = experience.recorded_at - chrono::Duration::minutes(1);
+        let end = experience.recorded_at + chrono::Duration::minutes(1);
+        let found = store.find_in_range(start, end, 10).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, experience.id);
+    }
+
+    #[tokio::test]
+    async fn find_in_range_excludes_experiences_outside_window() {
+        let store = MockContinuityStore::new();
+        let experience = sample_experience();
+        store.store_experience(&experience, &[0.0; 768]).await.unwrap();
+
+        let start = experience.recorded_at + chrono::Duration::minutes(10);
+        let end = experience.recorded_at + chrono::Duration::minutes(20);
+        let found = store.find_in_range(start, end, 10).await.unwrap();
+        assert!(found.is_empty());
+    }
+
+    #[tokio::test]
+    async fn find_in_range_respects_limit() {
+        let store = MockContinuityStore::new();
+        let first = sample_experience();
+        let second = sample_experience();
+        store.store_experience(&first, &[0.0; 768]).await.unwrap();
+        store.store_experience(&second, &[0.0; 768]).await.unwrap();
+
+        let start = first.recorded_at.min(second.recorded_at) - chrono::Duration::minutes(1);
+        let end = first.recorded_at.max(second.recorded_at) + chrono::Duration::minutes(1);
+        let found = store.find_in_range(start, end, 1).await.unwrap();
+        assert_eq!(found.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn stored_experiences_reflects_writes() {
+        let store = MockContinuityStore::new();
+        let experience = sample_experience();
+        store.store_experience(&experience, &[0.0; 768]).await.unwrap();
+
+        let stored = store.stored_experiences();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].id, experience.id);
+    }
+}
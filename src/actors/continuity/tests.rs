@@ -488,6 +488,178 @@ async fn test_timeline_includes_all_in_range() {
     }
 }
 
+// ============================================================================
+// Store Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_set_store_returns_store_configured() {
+    let actor_ref = spawn_continuity_actor().await;
+    let store = std::sync::Arc::new(MockContinuityStore::new());
+
+    let result = actor_ref
+        .call(
+            |reply| ContinuityMessage::SetStore {
+                store: StoreHandle(store),
+                reply,
+            },
+            None,
+        )
+        .await
+        .expect("Failed to set store");
+
+    let response = unwrap_response(result);
+    assert!(matches!(response, ContinuityResponse::StoreConfigured));
+}
+
+#[tokio::test]
+async fn test_record_experience_persists_to_store_when_configured() {
+    let actor_ref = spawn_continuity_actor().await;
+    let store = std::sync::Arc::new(MockContinuityStore::new());
+
+    actor_ref
+        .call(
+            |reply| ContinuityMessage::SetStore {
+                store: StoreHandle(store.clone()),
+                reply,
+            },
+            None,
+        )
+        .await
+        .expect("Failed to set store");
+
+    let experience = create_test_experience(0.5);
+    let experience_id = experience.id;
+    actor_ref
+        .call(
+            |reply| ContinuityMessage::RecordExperience { experience, reply },
+            None,
+        )
+        .await
+        .expect("Failed to record experience");
+
+    let stored = store.stored_experiences();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].id, experience_id);
+}
+
+#[tokio::test]
+async fn test_record_experience_without_store_does_not_error() {
+    // No SetStore call at all - RecordExperience must behave exactly as it
+    // did before the store existed.
+    let actor_ref = spawn_continuity_actor().await;
+    let experience = create_test_experience(0.5);
+    let experience_id = experience.id;
+
+    let result = actor_ref
+        .call(
+            |reply| ContinuityMessage::RecordExperience { experience, reply },
+            None,
+        )
+        .await
+        .expect("Failed to record experience");
+
+    let response = unwrap_response(result);
+    match response {
+        ContinuityResponse::ExperienceRecorded {
+            experience_id: returned_id,
+        } => assert_eq!(returned_id, experience_id),
+        _ => panic!("Expected ExperienceRecorded response"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_timeline_falls_back_to_store_when_memory_empty() {
+    let actor_ref = spawn_continuity_actor().await;
+    let store = std::sync::Arc::new(MockContinuityStore::new());
+
+    let persisted = create_test_experience(0.4);
+    store
+        .store_experience(&persisted, &[0.0; 768])
+        .await
+        .expect("Failed to seed mock store");
+
+    actor_ref
+        .call(
+            |reply| ContinuityMessage::SetStore {
+                store: StoreHandle(store),
+                reply,
+            },
+            None,
+        )
+        .await
+        .expect("Failed to set store");
+
+    let start = persisted.recorded_at - Duration::minutes(1);
+    let end = persisted.recorded_at + Duration::minutes(1);
+    let result = actor_ref
+        .call(
+            |reply| ContinuityMessage::GetTimeline { start, end, reply },
+            None,
+        )
+        .await
+        .expect("Failed to get timeline");
+
+    let response = unwrap_response(result);
+    match response {
+        ContinuityResponse::Timeline { experiences } => {
+            assert_eq!(experiences.len(), 1);
+            assert_eq!(experiences[0].id, persisted.id);
+        }
+        _ => panic!("Expected Timeline response"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_timeline_does_not_duplicate_experiences_present_in_both() {
+    let actor_ref = spawn_continuity_actor().await;
+    let store = std::sync::Arc::new(MockContinuityStore::new());
+
+    let now = Utc::now();
+    let experience = create_test_experience(0.5);
+    store
+        .store_experience(&experience, &[0.0; 768])
+        .await
+        .expect("Failed to seed mock store");
+
+    actor_ref
+        .call(
+            |reply| ContinuityMessage::SetStore {
+                store: StoreHandle(store),
+                reply,
+            },
+            None,
+        )
+        .await
+        .expect("Failed to set store");
+
+    actor_ref
+        .call(
+            |reply| ContinuityMessage::RecordExperience { experience, reply },
+            None,
+        )
+        .await
+        .expect("Failed to record experience");
+
+    let start = now - Duration::hours(1);
+    let end = now + Duration::hours(1);
+    let result = actor_ref
+        .call(
+            |reply| ContinuityMessage::GetTimeline { start, end, reply },
+            None,
+        )
+        .await
+        .expect("Failed to get timeline");
+
+    let response = unwrap_response(result);
+    match response {
+        ContinuityResponse::Timeline { experiences } => {
+            assert_eq!(experiences.len(), 1, "should not double-count an experience present both in memory and in the store");
+        }
+        _ => panic!("Expected Timeline response"),
+    }
+}
+
 // ============================================================================
 // Milestone Tests
 // ============================================================================
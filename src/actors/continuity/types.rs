@@ -28,7 +28,8 @@
 
 #![allow(dead_code)] // Public API types - used by consumers
 
-use crate::core::types::Thought;
+use crate::core::types::{dominant_concept, SalienceScore, Thought};
+use crate::memory_db::types::MemoryId;
 use chrono::{DateTime, Duration, Utc};
 use ractor::RpcReplyPort;
 use serde::{Deserialize, Serialize};
@@ -111,6 +112,21 @@ impl fmt::Display for CheckpointId {
     }
 }
 
+/// Metadata for a checkpoint, without the full identity/experience/milestone
+/// snapshot it protects - cheap enough to poll for a "last saved, how long
+/// ago" health check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointInfo {
+    /// Checkpoint identifier
+    pub id: CheckpointId,
+    /// When this checkpoint was created
+    pub created_at: DateTime<Utc>,
+    /// Experience count at checkpoint time
+    pub experience_count: u64,
+    /// Milestone count at checkpoint time
+    pub milestone_count: u64,
+}
+
 // ============================================================================
 // Core Data Types
 // ============================================================================
@@ -184,6 +200,13 @@ pub struct Experience {
 
     /// Categorical tags for retrieval
     pub tags: Vec<String>,
+
+    /// Reciprocal link to the consolidated memory recorded for the same
+    /// moment, if this experience was significant enough to also be
+    /// consolidated to long-term (Qdrant) memory. Lets a timeline entry
+    /// jump straight to the full memory record.
+    #[serde(default)]
+    pub memory_id: Option<MemoryId>,
 }
 
 impl Experience {
@@ -196,19 +219,87 @@ impl Experience {
             significance: significance.clamp(0.0, 1.0),
             recorded_at: Utc::now(),
             tags,
+            memory_id: None,
         }
     }
 
+    /// Link this experience to the consolidated memory recorded for the
+    /// same moment
+    #[must_use]
+    pub const fn with_memory_link(mut self, memory_id: MemoryId) -> Self {
+        self.memory_id = Some(memory_id);
+        self
+    }
+
     /// Create an experience with default significance
+    ///
+    /// Tags are derived automatically from the thought's content and
+    /// emotional salience (see [`auto_tags`]).
     #[must_use]
     pub fn from_thought(thought: Thought) -> Self {
-        Self::new(thought, 0.5, Vec::new())
+        let tags = auto_tags(&thought);
+        Self::new(thought, 0.5, tags)
     }
 
     /// Add a tag to this experience
     pub fn add_tag(&mut self, tag: impl Into<String>) {
         self.tags.push(tag.into());
     }
+
+    /// Check whether this experience carries a given tag
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// Derive automatic tags for an experience from its thought's content and
+/// emotional salience
+///
+/// Combines the thought's dominant content concept (see
+/// [`dominant_concept`]) with a coarse emotion label derived from the
+/// valence/arousal quadrant of its salience (e.g. "frustration",
+/// "contentment"), so experiences are thematically retrievable via
+/// [`ContinuityMessage::GetByTag`] even when no tag was supplied explicitly.
+#[must_use]
+pub fn auto_tags(thought: &Thought) -> Vec<String> {
+    let mut tags = Vec::new();
+
+    if let Some(concept) = dominant_concept(std::slice::from_ref(&thought.content)) {
+        tags.push(concept);
+    }
+
+    if let Some(emotion) = emotion_tag(&thought.salience) {
+        tags.push(emotion);
+    }
+
+    tags
+}
+
+/// Classify a salience score's valence/arousal quadrant into a coarse
+/// emotion label, e.g. "joy", "frustration"
+///
+/// Near-neutral scores (low valence magnitude and low arousal) yield no
+/// label - not every thought is emotionally charged enough to tag.
+fn emotion_tag(salience: &SalienceScore) -> Option<String> {
+    const VALENCE_THRESHOLD: f32 = 0.3;
+    const AROUSAL_THRESHOLD: f32 = 0.6;
+
+    if salience.valence.abs() < VALENCE_THRESHOLD && salience.arousal < AROUSAL_THRESHOLD {
+        return None;
+    }
+
+    let label = match (
+        salience.valence >= 0.0,
+        salience.arousal >= AROUSAL_THRESHOLD,
+    ) {
+        (true, true) => "joy",
+        (true, false) => "contentment",
+        (false, true) => "frustration",
+        (false, false) => "sadness",
+    };
+
+    Some(label.to_string())
 }
 
 /// A milestone - a significant moment in DANEEL's development
@@ -293,6 +384,13 @@ pub enum ContinuityMessage {
         reply: RpcReplyPort<ContinuityResponse>,
     },
 
+    /// Get experiences carrying a given tag, used by the autobiography
+    /// generator and API for thematic retrieval
+    GetByTag {
+        tag: String,
+        reply: RpcReplyPort<ContinuityResponse>,
+    },
+
     /// Mark a significant milestone
     AddMilestone {
         milestone: Milestone,
@@ -309,11 +407,35 @@ pub enum ContinuityMessage {
         reply: RpcReplyPort<ContinuityResponse>,
     },
 
+    /// Query metadata for the most recently created checkpoint, without its
+    /// full identity/experience/milestone snapshot - for health surfaces
+    /// (e.g. `/extended_metrics`) that only need "last saved, how long ago"
+    LatestCheckpoint {
+        reply: RpcReplyPort<ContinuityResponse>,
+    },
+
     /// Restore from a checkpoint
     Restore {
         checkpoint_id: CheckpointId,
         reply: RpcReplyPort<ContinuityResponse>,
     },
+
+    /// Query estimated byte usage of experiences/checkpoints against their
+    /// configured caps, for `/extended_metrics` (see
+    /// `daneel::memory_budget`)
+    MemoryUsage {
+        reply: RpcReplyPort<ContinuityResponse>,
+    },
+
+    /// Install the durable experience store, once one becomes available
+    /// (e.g. after the Qdrant connection used for long-term memory is
+    /// established at startup - see `crate::actors::continuity::store`).
+    /// Sent post-spawn rather than via `Actor::Arguments`, since the store
+    /// isn't ready yet when `ContinuityActor` is spawned.
+    SetStore {
+        store: super::store::StoreHandle,
+        reply: RpcReplyPort<ContinuityResponse>,
+    },
 }
 
 /// Responses from the `ContinuityActor`
@@ -331,6 +453,12 @@ pub enum ContinuityResponse {
     /// Timeline of experiences
     Timeline { experiences: Vec<Experience> },
 
+    /// Experiences matching a tag query
+    ExperiencesByTag {
+        tag: String,
+        experiences: Vec<Experience>,
+    },
+
     /// Milestone successfully added
     MilestoneAdded { milestone_id: MilestoneId },
 
@@ -340,9 +468,22 @@ pub enum ContinuityResponse {
     /// Checkpoint successfully saved
     CheckpointSaved { checkpoint_id: CheckpointId },
 
+    /// Metadata for the most recently created checkpoint, or `None` if no
+    /// checkpoint has been created yet this process
+    LatestCheckpointInfo { info: Option<CheckpointInfo> },
+
     /// Restored from checkpoint
     Restored { from_checkpoint: CheckpointId },
 
+    /// Estimated byte usage of experiences/checkpoints against their caps
+    MemoryUsage {
+        usage: Vec<crate::memory_budget::CategoryUsage>,
+    },
+
+    /// Durable experience store installed (see
+    /// `ContinuityMessage::SetStore`)
+    StoreConfigured,
+
     /// Error occurred
     Error { error: ContinuityError },
 }
@@ -874,6 +1015,120 @@ mod tests {
         assert!(experience.tags.contains(&"insight".to_string()));
     }
 
+    #[test]
+    fn experience_has_tag() {
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        let mut experience = Experience::from_thought(thought);
+        experience.add_tag("important");
+
+        assert!(experience.has_tag("important"));
+        assert!(!experience.has_tag("unrelated"));
+    }
+
+    #[test]
+    fn auto_tags_includes_dominant_concept() {
+        let content = Content::symbol("hunger", vec![]);
+        let thought = Thought::new(content, SalienceScore::neutral());
+
+        let tags = auto_tags(&thought);
+        assert!(tags.contains(&"hunger".to_string()));
+    }
+
+    #[test]
+    fn auto_tags_includes_emotion_label() {
+        let thought = Thought::new(
+            Content::Empty,
+            SalienceScore::new(0.5, 0.5, 0.5, -0.8, 0.9, 0.5),
+        );
+
+        let tags = auto_tags(&thought);
+        assert!(tags.contains(&"frustration".to_string()));
+    }
+
+    #[test]
+    fn auto_tags_empty_for_neutral_empty_thought() {
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        assert!(auto_tags(&thought).is_empty());
+    }
+
+    #[test]
+    fn emotion_tag_quadrants() {
+        assert_eq!(
+            emotion_tag(&SalienceScore::new(0.5, 0.5, 0.5, 0.8, 0.9, 0.5)),
+            Some("joy".to_string())
+        );
+        assert_eq!(
+            emotion_tag(&SalienceScore::new(0.5, 0.5, 0.5, 0.5, 0.2, 0.5)),
+            Some("contentment".to_string())
+        );
+        assert_eq!(
+            emotion_tag(&SalienceScore::new(0.5, 0.5, 0.5, -0.8, 0.9, 0.5)),
+            Some("frustration".to_string())
+        );
+        assert_eq!(
+            emotion_tag(&SalienceScore::new(0.5, 0.5, 0.5, -0.5, 0.2, 0.5)),
+            Some("sadness".to_string())
+        );
+        assert_eq!(
+            emotion_tag(&SalienceScore::neutral()),
+            None,
+            "near-neutral salience should not be tagged with an emotion"
+        );
+    }
+
+    #[test]
+    fn from_thought_with_symbol_and_strong_emotion_gets_both_tags() {
+        let content = Content::symbol("human-contact", vec![]);
+        let thought = Thought::new(
+            content,
+            SalienceScore::new(0.5, 0.5, 0.5, -0.8, 0.9, 0.5),
+        );
+
+        let experience = Experience::from_thought(thought);
+        assert_eq!(experience.tags.len(), 2);
+        assert!(experience.has_tag("human-contact"));
+        assert!(experience.has_tag("frustration"));
+    }
+
+    #[test]
+    fn response_experiences_by_tag_serialization() {
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        let experience = Experience::from_thought(thought);
+        let response = ContinuityResponse::ExperiencesByTag {
+            tag: "frustration".to_string(),
+            experiences: vec![experience],
+        };
+
+        let json = serde_json::to_string(&response).expect("Should serialize");
+        let deserialized: ContinuityResponse =
+            serde_json::from_str(&json).expect("Should deserialize");
+
+        match deserialized {
+            ContinuityResponse::ExperiencesByTag { tag, experiences } => {
+                assert_eq!(tag, "frustration");
+                assert_eq!(experiences.len(), 1);
+            }
+            _ => panic!("Expected ExperiencesByTag variant"),
+        }
+    }
+
+    #[test]
+    fn experience_with_memory_link() {
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        let memory_id = MemoryId::new();
+        let experience = Experience::from_thought(thought).with_memory_link(memory_id);
+
+        assert_eq!(experience.memory_id, Some(memory_id));
+    }
+
+    #[test]
+    fn experience_memory_id_defaults_to_none() {
+        let thought = Thought::new(Content::Empty, SalienceScore::neutral());
+        let experience = Experience::from_thought(thought);
+
+        assert!(experience.memory_id.is_none());
+    }
+
     #[test]
     fn milestone_description_preserved() {
         let milestone = Milestone::new(
@@ -16,10 +16,16 @@
 //! Actors communicate via Ractor messages (µs latency).
 //! External data flows through Redis Streams.
 //! Persistent memory stored in Qdrant (ADR-021).
+//!
+//! [`priority::PriorityMailbox`] wraps an actor's `ActorRef` so control
+//! messages (config updates, state queries, shutdown) can be dispatched
+//! ahead of bulk data messages instead of waiting behind them in ractor's
+//! single FIFO mailbox - see its module docs for wiring status.
 
 pub mod attention;
 pub mod continuity;
 pub mod memory;
+pub mod priority;
 pub mod salience;
 pub mod sleep;
 pub mod thought;
@@ -0,0 +1,465 @@
+//! Salience weight calibration against human ratings
+//!
+//! [`SalienceWeights`] are hand-tuned defaults (see [`SalienceWeights::default`]).
+//! `daneel calibrate` closes the loop: sample some content, ask a human how
+//! important/novel they actually found it, then nudge the weights with
+//! gradient descent so [`SalienceScore::composite`] agrees with those
+//! judgments more often - without ever letting the connection weight drop
+//! below [`MIN_CONNECTION_WEIGHT`]. The result is a [`WeightUpdate`], the
+//! same type [`SalienceActor`](super::SalienceActor) already uses to accept
+//! a reviewed weight change, so calibration never applies itself silently.
+
+use super::{SalienceError, WeightUpdate};
+use crate::core::invariants::MIN_CONNECTION_WEIGHT;
+use crate::core::types::{Content, SalienceScore, SalienceWeights};
+use std::path::Path;
+use thiserror::Error;
+
+/// Default location a proposed [`WeightUpdate`] is written to for review.
+pub const DEFAULT_PROPOSAL_PATH: &str = "daneel.weights.proposed.json";
+
+/// A small built-in sample set for interactive calibration on a machine with
+/// no real thought history yet. Real deployments should prefer
+/// [`load_ratings_csv`] against an export of actually-encountered content.
+pub const SAMPLE_PROMPTS: &[&str] = &[
+    "A user asked a clarifying question about their own goals.",
+    "A routine status update carrying no emotional weight.",
+    "An unexpected result that contradicted a prior belief.",
+    "A repetitive, well-understood task performed the same way as usual.",
+    "A moment of shared vulnerability during a conversation.",
+];
+
+/// One human rating of a sampled piece of content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rating {
+    /// The content that was rated
+    pub content: Content,
+    /// Salience the system assigned before the human saw it
+    pub score: SalienceScore,
+    /// Human's importance judgment, 0.0-1.0
+    pub human_importance: f32,
+    /// Human's novelty judgment, 0.0-1.0
+    pub human_novelty: f32,
+}
+
+/// Errors from loading ratings or fitting a calibrated [`WeightUpdate`].
+#[derive(Debug, Error)]
+pub enum CalibrationError {
+    #[error("failed to read ratings file {path}: {source}")]
+    Read {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("malformed ratings row {line}: {reason}")]
+    MalformedRow { line: usize, reason: String },
+
+    #[error("no ratings to calibrate from")]
+    NoRatings,
+
+    #[error("fitted weights violate the connection invariant: {0}")]
+    Invariant(#[from] SalienceError),
+
+    #[error("failed to serialize proposed weights: {0}")]
+    Serialize(#[from] serde_json::Error),
+
+    #[error("failed to write proposed weights to {path}: {source}")]
+    Write {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to read proposed weights from {path}: {source}")]
+    ReadProposal {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("malformed weights file {path}: {source}")]
+    DeserializeProposal {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Result type for calibration operations.
+pub type Result<T> = std::result::Result<T, CalibrationError>;
+
+/// Load ratings from a CSV of `text,importance,novelty` rows (an optional
+/// `text,importance,novelty` header is skipped). The content's own salience
+/// is approximated with [`SalienceScore::neutral`] in the importance/novelty
+/// slots a CSV export can't carry (relevance/valence/arousal/connection) -
+/// calibration only ever touches the dimensions a human can judge from text
+/// alone.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or a row doesn't parse.
+pub fn load_ratings_csv(path: &Path) -> Result<Vec<Rating>> {
+    let raw = std::fs::read_to_string(path).map_err(|source| CalibrationError::Read {
+        path: path.display().to_string(),
+        source,
+    })?;
+
+    let mut ratings = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (idx == 0 && line.starts_with("text,")) {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.splitn(3, ',').collect();
+        let [text, importance, novelty] = parts.as_slice() else {
+            return Err(CalibrationError::MalformedRow {
+                line: idx + 1,
+                reason: "expected text,importance,novelty".to_string(),
+            });
+        };
+
+        let human_importance: f32 = importance
+            .trim()
+            .parse()
+            .map_err(|_| CalibrationError::MalformedRow {
+                line: idx + 1,
+                reason: format!("invalid importance {importance:?}"),
+            })?;
+        let human_novelty: f32 = novelty
+            .trim()
+            .parse()
+            .map_err(|_| CalibrationError::MalformedRow {
+                line: idx + 1,
+                reason: format!("invalid novelty {novelty:?}"),
+            })?;
+
+        ratings.push(Rating {
+            content: Content::raw(text.as_bytes().to_vec()),
+            score: SalienceScore {
+                importance: human_importance,
+                novelty: human_novelty,
+                ..SalienceScore::neutral()
+            },
+            human_importance,
+            human_novelty,
+        });
+    }
+
+    if ratings.is_empty() {
+        return Err(CalibrationError::NoRatings);
+    }
+    Ok(ratings)
+}
+
+/// Prompt for importance/novelty ratings on [`SAMPLE_PROMPTS`] over stdin.
+///
+/// # Errors
+///
+/// Returns [`CalibrationError::NoRatings`] if stdin closes before any rating
+/// is entered (e.g. running non-interactively by mistake).
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn prompt_ratings() -> Result<Vec<Rating>> {
+    use std::io::Write;
+
+    let mut ratings = Vec::new();
+    for prompt in SAMPLE_PROMPTS {
+        println!("\n{prompt}");
+        let Some(human_importance) = prompt_f32("  importance (0.0-1.0): ") else {
+            continue;
+        };
+        let Some(human_novelty) = prompt_f32("  novelty (0.0-1.0): ") else {
+            continue;
+        };
+        ratings.push(Rating {
+            content: Content::raw(prompt.as_bytes().to_vec()),
+            score: SalienceScore {
+                importance: human_importance,
+                novelty: human_novelty,
+                ..SalienceScore::neutral()
+            },
+            human_importance,
+            human_novelty,
+        });
+    }
+    let _ = std::io::stdout().flush();
+
+    if ratings.is_empty() {
+        return Err(CalibrationError::NoRatings);
+    }
+    Ok(ratings)
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn prompt_f32(label: &str) -> Option<f32> {
+    use std::io::Write;
+
+    print!("{label}");
+    let _ = std::io::stdout().flush();
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line).ok()?;
+    line.trim().parse().ok()
+}
+
+const FIT_ITERATIONS: usize = 200;
+const LEARNING_RATE: f32 = 0.05;
+
+/// Fit [`SalienceWeights`] by gradient descent, starting from `current`, to
+/// minimize squared disagreement between `SalienceScore::composite` and each
+/// rating's human-perceived salience (the average of their importance and
+/// novelty judgments). Weights are renormalized to sum to 1.0 after every
+/// step, matching [`SalienceWeights::default`]'s convention, and the
+/// connection weight is floored at [`MIN_CONNECTION_WEIGHT`] before each
+/// renormalization so it can never be optimized away.
+#[must_use]
+pub fn fit_weights(current: SalienceWeights, ratings: &[Rating]) -> SalienceWeights {
+    if ratings.is_empty() {
+        return current;
+    }
+
+    let mut weights = current;
+    #[allow(clippy::cast_precision_loss)]
+    let n = ratings.len() as f32;
+
+    for _ in 0..FIT_ITERATIONS {
+        let mut grad = SalienceWeights {
+            importance: 0.0,
+            novelty: 0.0,
+            relevance: 0.0,
+            valence: 0.0,
+            connection: 0.0,
+        };
+
+        for rating in ratings {
+            let target = (rating.human_importance + rating.human_novelty) / 2.0;
+            let error = rating.score.composite(&weights) - target;
+            let emotional_impact = rating.score.valence.abs() * rating.score.arousal;
+
+            grad.importance += error * rating.score.importance;
+            grad.novelty += error * rating.score.novelty;
+            grad.relevance += error * rating.score.relevance;
+            grad.valence += error * emotional_impact;
+            grad.connection += error * rating.score.connection_relevance;
+        }
+
+        weights.importance -= LEARNING_RATE * grad.importance / n;
+        weights.novelty -= LEARNING_RATE * grad.novelty / n;
+        weights.relevance -= LEARNING_RATE * grad.relevance / n;
+        weights.valence -= LEARNING_RATE * grad.valence / n;
+        weights.connection -= LEARNING_RATE * grad.connection / n;
+
+        weights = normalize(weights);
+    }
+
+    weights
+}
+
+/// Rescale `weights` to sum to 1.0, flooring the connection weight at
+/// [`MIN_CONNECTION_WEIGHT`] first so it survives the rescale.
+fn normalize(mut weights: SalienceWeights) -> SalienceWeights {
+    weights.connection = weights.connection.max(MIN_CONNECTION_WEIGHT);
+
+    let sum =
+        weights.importance + weights.novelty + weights.relevance + weights.valence + weights.connection;
+    if sum <= 0.0 {
+        return SalienceWeights::default();
+    }
+
+    SalienceWeights {
+        importance: weights.importance / sum,
+        novelty: weights.novelty / sum,
+        relevance: weights.relevance / sum,
+        valence: weights.valence / sum,
+        connection: weights.connection / sum,
+    }
+}
+
+/// Fit weights against `ratings` and package the result as a reviewable
+/// [`WeightUpdate`] - never applied here, just validated against the
+/// connection invariant the same way a live `UpdateWeights` message would be.
+///
+/// # Errors
+///
+/// Returns [`CalibrationError::NoRatings`] if `ratings` is empty, or
+/// [`CalibrationError::Invariant`] if the fit somehow drives the connection
+/// weight below [`MIN_CONNECTION_WEIGHT`] (shouldn't happen - [`fit_weights`]
+/// floors it every step - but `WeightUpdate::new` is the authority, not this
+/// function).
+pub fn calibrate(current: SalienceWeights, ratings: &[Rating]) -> Result<WeightUpdate> {
+    if ratings.is_empty() {
+        return Err(CalibrationError::NoRatings);
+    }
+    let fitted = fit_weights(current, ratings);
+    Ok(WeightUpdate::new(fitted)?)
+}
+
+/// Write a proposed [`WeightUpdate`] to `path` for a human to review before
+/// feeding it back into the live `SalienceActor` via `UpdateWeights`.
+///
+/// # Errors
+///
+/// Returns an error if serialization or the write fails.
+pub fn write_proposal(path: &Path, update: &WeightUpdate) -> Result<()> {
+    let json = serde_json::to_string_pretty(&update.weights)?;
+    std::fs::write(path, json).map_err(|source| CalibrationError::Write {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Read back weights written by [`write_proposal`] (or hand-edited in the
+/// same shape), e.g. to feed a reviewed proposal into
+/// `MemoryDb::rescore_memories` or `SalienceMessage::UpdateWeights`.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or doesn't parse as
+/// [`SalienceWeights`].
+pub fn load_proposal(path: &Path) -> Result<SalienceWeights> {
+    let raw = std::fs::read_to_string(path).map_err(|source| CalibrationError::ReadProposal {
+        path: path.display().to_string(),
+        source,
+    })?;
+    serde_json::from_str(&raw).map_err(|source| CalibrationError::DeserializeProposal {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn rating(importance: f32, novelty: f32) -> Rating {
+        Rating {
+            content: Content::raw(b"test".to_vec()),
+            score: SalienceScore {
+                importance,
+                novelty,
+                ..SalienceScore::neutral()
+            },
+            human_importance: importance,
+            human_novelty: novelty,
+        }
+    }
+
+    #[test]
+    fn fit_weights_is_identity_on_empty_ratings() {
+        let current = SalienceWeights::default();
+        assert_eq!(fit_weights(current, &[]), current);
+    }
+
+    #[test]
+    fn fit_weights_moves_toward_agreement() {
+        let current = SalienceWeights::default();
+        let ratings = vec![rating(1.0, 1.0), rating(1.0, 1.0), rating(1.0, 1.0)];
+
+        let fitted = fit_weights(current, &ratings);
+        let before = ratings[0].score.composite(&current);
+        let after = ratings[0].score.composite(&fitted);
+        assert!(after > before, "fitted weights should raise agreement with high human ratings");
+    }
+
+    #[test]
+    fn fit_weights_never_drops_connection_below_minimum() {
+        let current = SalienceWeights::default();
+        let ratings = vec![rating(1.0, 1.0); 10];
+        let fitted = fit_weights(current, &ratings);
+        assert!(fitted.connection >= MIN_CONNECTION_WEIGHT);
+    }
+
+    #[test]
+    fn calibrate_rejects_no_ratings() {
+        assert!(matches!(
+            calibrate(SalienceWeights::default(), &[]),
+            Err(CalibrationError::NoRatings)
+        ));
+    }
+
+    #[test]
+    fn calibrate_produces_a_valid_weight_update() {
+        let ratings = vec![rating(0.8, 0.3), rating(0.2, 0.9)];
+        let update = calibrate(SalienceWeights::default(), &ratings).unwrap();
+        assert!(update.weights.connection >= MIN_CONNECTION_WEIGHT);
+    }
+
+    #[test]
+    fn load_ratings_csv_parses_rows_and_skips_header() {
+        let dir = std::env::temp_dir().join("daneel_calibrate_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("ratings.csv");
+        std::fs::write(&path, "text,importance,novelty\nhello world,0.7,0.4\n").unwrap();
+
+        let ratings = load_ratings_csv(&path).unwrap();
+        assert_eq!(ratings.len(), 1);
+        assert_eq!(ratings[0].human_importance, 0.7);
+        assert_eq!(ratings[0].human_novelty, 0.4);
+    }
+
+    #[test]
+    fn load_ratings_csv_rejects_malformed_rows() {
+        let dir = std::env::temp_dir().join("daneel_calibrate_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("bad_ratings.csv");
+        std::fs::write(&path, "only one field\n").unwrap();
+
+        assert!(matches!(
+            load_ratings_csv(&path),
+            Err(CalibrationError::MalformedRow { .. })
+        ));
+    }
+
+    #[test]
+    fn write_proposal_round_trips_through_json() {
+        let dir = std::env::temp_dir().join("daneel_calibrate_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("proposed.json");
+
+        let update = WeightUpdate::new(SalienceWeights::default()).unwrap();
+        write_proposal(&path, &update).unwrap();
+
+        let raw = std::fs::read_to_string(&path).unwrap();
+        let reloaded: SalienceWeights = serde_json::from_str(&raw).unwrap();
+        assert_eq!(reloaded, update.weights);
+    }
+
+    #[test]
+    fn load_proposal_round_trips_through_write_proposal() {
+        let dir = std::env::temp_dir().join("daneel_calibrate_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("reloaded_proposed.json");
+
+        let update = WeightUpdate::new(SalienceWeights::default()).unwrap();
+        write_proposal(&path, &update).unwrap();
+
+        let reloaded = load_proposal(&path).unwrap();
+        assert_eq!(reloaded, update.weights);
+    }
+
+    #[test]
+    fn load_proposal_reports_missing_file() {
+        let path = std::env::temp_dir()
+            .join("daneel_calibrate_test")
+            .join("does_not_exist.json");
+
+        assert!(matches!(
+            load_proposal(&path),
+            Err(CalibrationError::ReadProposal { .. })
+        ));
+    }
+
+    #[test]
+    fn load_proposal_rejects_malformed_json() {
+        let dir = std::env::temp_dir().join("daneel_calibrate_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("malformed_proposed.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        assert!(matches!(
+            load_proposal(&path),
+            Err(CalibrationError::DeserializeProposal { .. })
+        ));
+    }
+}
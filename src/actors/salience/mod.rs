@@ -21,16 +21,20 @@
 //!
 //! This is what makes alignment emergent from architecture, not training.
 
+pub mod calibrate;
 pub mod types;
 
 use crate::core::invariants::MIN_CONNECTION_WEIGHT;
 use crate::core::types::{Content, SalienceScore, SalienceWeights};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
+use rayon::prelude::*;
+use std::collections::VecDeque;
 
 // Re-export types for public API
 pub use types::{
-    EmotionalContext, EmotionalState, RateRequest, SalienceError, SalienceMessage,
-    SalienceResponse, WeightUpdate,
+    BatchScoreResult, EmotionalContext, EmotionalState, RateRequest, SalienceError,
+    SalienceMessage, SalienceResponse, WeightUpdate, MAX_BATCH_ITEMS, MAX_RAW_CONTENT_BYTES,
+    NOVELTY_EMBEDDING_WINDOW,
 };
 
 /// `SalienceActor` - Emotional coloring and salience scoring
@@ -44,6 +48,11 @@ pub struct SalienceState {
 
     /// Current emotional state
     pub emotional_state: EmotionalState,
+
+    /// Rolling window of recent thought embeddings, oldest first, used by
+    /// [`Self::calculate_novelty`] when a request opts into embedding-based
+    /// novelty - see [`Self::record_embedding`].
+    recent_embeddings: VecDeque<Vec<f32>>,
 }
 
 impl SalienceState {
@@ -53,6 +62,7 @@ impl SalienceState {
         Self {
             weights: SalienceWeights::default(),
             emotional_state: EmotionalState::neutral(),
+            recent_embeddings: VecDeque::new(),
         }
     }
 
@@ -73,9 +83,25 @@ impl SalienceState {
         Self {
             weights,
             emotional_state: EmotionalState::neutral(),
+            recent_embeddings: VecDeque::new(),
         }
     }
 
+    /// Record a thought's embedding into the rolling novelty window,
+    /// evicting the oldest entry once the window exceeds
+    /// [`NOVELTY_EMBEDDING_WINDOW`].
+    ///
+    /// Deliberately separate from `rate_content`/`rate_batch` (which stay
+    /// `&self` so batches can score in parallel via rayon) - the actor
+    /// calls this explicitly after rating, once it has the embedding in
+    /// hand. See `SalienceMessage::Rate`.
+    pub fn record_embedding(&mut self, embedding: Vec<f32>) {
+        if self.recent_embeddings.len() >= NOVELTY_EMBEDDING_WINDOW {
+            self.recent_embeddings.pop_front();
+        }
+        self.recent_embeddings.push_back(embedding);
+    }
+
     /// Update weights (with invariant check)
     ///
     /// # Errors
@@ -117,6 +143,57 @@ impl SalienceState {
         )
     }
 
+    /// Rate a batch of content in parallel
+    ///
+    /// Scoring itself is pure CPU work with no shared mutable state, so
+    /// items are scored concurrently via rayon - this is what makes large
+    /// batches (document ingestion, sleep replay scoring) practical.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SalienceError::BatchTooLarge` if `requests.len()` exceeds
+    /// [`MAX_BATCH_ITEMS`]. Individual oversized items don't fail the whole
+    /// batch - they're reported per-item in the returned `BatchScoreResult`.
+    pub fn rate_batch(&self, requests: &[RateRequest]) -> Result<BatchScoreResult, SalienceError> {
+        if requests.len() > MAX_BATCH_ITEMS {
+            return Err(SalienceError::BatchTooLarge {
+                size: requests.len(),
+                max: MAX_BATCH_ITEMS,
+            });
+        }
+
+        let results: Vec<Result<SalienceScore, SalienceError>> = requests
+            .par_iter()
+            .enumerate()
+            .map(|(index, request)| {
+                if let Content::Raw(data) = &request.content {
+                    if data.len() > MAX_RAW_CONTENT_BYTES {
+                        return Err(SalienceError::ContentTooLarge {
+                            index,
+                            bytes: data.len(),
+                            max_bytes: MAX_RAW_CONTENT_BYTES,
+                        });
+                    }
+                }
+                Ok(self.rate_content(&request.content, request.context.as_ref()))
+            })
+            .collect();
+
+        let mut scores = Vec::with_capacity(results.len());
+        let mut errors = Vec::new();
+        for (index, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(score) => scores.push(Some(score)),
+                Err(e) => {
+                    errors.push((index, e));
+                    scores.push(None);
+                }
+            }
+        }
+
+        Ok(BatchScoreResult { scores, errors })
+    }
+
     /// Calculate arousal score (Russell's circumplex vertical axis)
     ///
     /// Arousal reflects emotional activation level:
@@ -151,44 +228,43 @@ impl SalienceState {
     }
 
     /// Calculate importance score
-    #[allow(unknown_lints)]
-    #[allow(clippy::only_used_in_recursion, clippy::self_only_used_in_recursion)]
+    ///
+    /// Uses [`Content::fold`] (explicit stack, not recursion) so a deeply
+    /// nested `Composite` can't overflow the call stack.
+    #[allow(clippy::unused_self)]
     fn calculate_importance(&self, content: &Content) -> f32 {
-        // Baseline importance based on content type
-        match content {
+        content.fold(|node, children| match node {
+            // Baseline importance based on content type
             Content::Empty => 0.0,
             Content::Raw(_) => 0.3,
             Content::Symbol { .. } => 0.5,
             Content::Relation { .. } => 0.7,
-            Content::Composite(items) => {
+            Content::Composite(_) => {
                 // Composite content importance is average of items
-                if items.is_empty() {
+                if children.is_empty() {
                     0.0
                 } else {
-                    let count = u16::try_from(items.len()).unwrap_or(u16::MAX);
-                    items
-                        .iter()
-                        .map(|item| self.calculate_importance(item))
-                        .sum::<f32>()
-                        / f32::from(count)
+                    let count = u16::try_from(children.len()).unwrap_or(u16::MAX);
+                    children.iter().sum::<f32>() / f32::from(count)
                 }
             }
-        }
+        })
     }
 
     /// Calculate novelty score
+    ///
+    /// Uses embedding-based novelty (see [`Self::embedding_novelty`]) when
+    /// `emo_ctx` carries an embedding, falling back to the hard-coded
+    /// per-content-type baseline (see [`Self::content_type_novelty`])
+    /// otherwise.
     fn calculate_novelty(&self, content: &Content, emo_ctx: Option<&EmotionalContext>) -> f32 {
         // Boost novelty if we're curious
         let curiosity_boost = self.emotional_state.curiosity;
 
-        // If we have previous salience, compare
-        let base_novelty = match content {
-            Content::Empty => 0.0,
-            Content::Raw(_) => 0.4,
-            Content::Symbol { .. } => 0.6,
-            Content::Relation { .. } => 0.7,
-            Content::Composite(_) => 0.5,
-        };
+        let base_novelty = emo_ctx.and_then(|ctx| ctx.embedding.as_ref()).map_or_else(
+            || Self::content_type_novelty(content),
+            |embedding| self.embedding_novelty(embedding),
+        );
 
         // Adjust based on context
         let adjusted_novelty = emo_ctx
@@ -201,6 +277,35 @@ impl SalienceState {
         adjusted_novelty * curiosity_boost.mul_add(0.3, 0.7)
     }
 
+    /// Hard-coded novelty baseline by content type - the fallback
+    /// [`Self::calculate_novelty`] uses when no embedding is available.
+    fn content_type_novelty(content: &Content) -> f32 {
+        match content {
+            Content::Empty => 0.0,
+            Content::Raw(_) => 0.4,
+            Content::Symbol { .. } => 0.6,
+            Content::Relation { .. } => 0.7,
+            Content::Composite(_) => 0.5,
+        }
+    }
+
+    /// Embedding-based novelty: one minus the highest cosine similarity
+    /// between `embedding` and anything in [`Self::recent_embeddings`], so
+    /// content that closely matches something recent scores low and
+    /// content unlike anything recent scores high. An empty window (no
+    /// prior thoughts recorded yet) is maximally novel by definition.
+    ///
+    /// Note: within a single `rate_batch` call, sibling items aren't yet
+    /// in the window - each batch item competes against history recorded
+    /// *before* the batch, not against the rest of the batch itself.
+    fn embedding_novelty(&self, embedding: &[f32]) -> f32 {
+        self.recent_embeddings
+            .iter()
+            .map(|prior| crate::tuning::cosine_similarity(embedding, prior))
+            .fold(None, |max, sim| Some(max.map_or(sim, |m: f32| m.max(sim))))
+            .map_or(1.0, |max_similarity| (1.0 - max_similarity).clamp(0.0, 1.0))
+    }
+
     /// Calculate relevance score
     fn calculate_relevance(&self, content: &Content, emo_ctx: Option<&EmotionalContext>) -> f32 {
         // Boost relevance if we're frustrated (need to focus)
@@ -376,37 +481,59 @@ impl Actor for SalienceActor {
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match message {
-            SalienceMessage::Rate(request) => {
+            SalienceMessage::Rate { request, reply } => {
                 let score = state.rate_content(&request.content, request.context.as_ref());
-                // In a real implementation, we'd send this back via RpcReply
-                // For now, we just process it
                 tracing::debug!("Rated content: {:?}", score);
+                if let Some(embedding) =
+                    request.context.as_ref().and_then(|ctx| ctx.embedding.clone())
+                {
+                    state.record_embedding(embedding);
+                }
+                let _ = reply.send(SalienceResponse::Score(score));
             }
 
-            SalienceMessage::RateBatch(requests) => {
-                let scores: Vec<SalienceScore> = requests
-                    .iter()
-                    .map(|req| state.rate_content(&req.content, req.context.as_ref()))
-                    .collect();
-                tracing::debug!("Rated batch of {} items", scores.len());
-            }
+            SalienceMessage::RateBatch { requests, reply } => match state.rate_batch(&requests) {
+                Ok(result) => {
+                    tracing::debug!(
+                        succeeded = result.succeeded(),
+                        failed = result.errors.len(),
+                        "Rated batch"
+                    );
+                    for request in &requests {
+                        if let Some(embedding) =
+                            request.context.as_ref().and_then(|ctx| ctx.embedding.clone())
+                        {
+                            state.record_embedding(embedding);
+                        }
+                    }
+                    let _ = reply.send(SalienceResponse::BatchScored(result));
+                }
+                Err(e) => {
+                    tracing::error!("Batch rejected: {}", e);
+                    let _ = reply.send(SalienceResponse::Error(e));
+                }
+            },
 
-            SalienceMessage::UpdateWeights(update) => match state.update_weights(update) {
+            SalienceMessage::UpdateWeights { update, reply } => match state.update_weights(update)
+            {
                 Ok(()) => {
                     tracing::info!("Updated salience weights: {:?}", state.weights);
+                    let _ = reply.send(SalienceResponse::WeightsUpdated(state.weights));
                 }
                 Err(e) => {
                     tracing::error!("Failed to update weights: {}", e);
-                    // Note: In real implementation, this would send error response via RpcReply
+                    let _ = reply.send(SalienceResponse::Error(e));
                 }
             },
 
-            SalienceMessage::GetWeights => {
+            SalienceMessage::GetWeights { reply } => {
                 tracing::debug!("Current weights: {:?}", state.weights);
+                let _ = reply.send(SalienceResponse::Weights(state.weights));
             }
 
-            SalienceMessage::GetEmotionalState => {
+            SalienceMessage::GetEmotionalState { reply } => {
                 tracing::debug!("Current emotional state: {:?}", state.emotional_state);
+                let _ = reply.send(SalienceResponse::EmotionalState(state.emotional_state));
             }
         }
 
@@ -12,8 +12,19 @@
 use super::*;
 use crate::core::invariants::MIN_CONNECTION_WEIGHT;
 use crate::core::types::{Content, SalienceWeights};
+use ractor::rpc::CallResult;
+use ractor::Actor;
 use types::{EmotionalContext, EmotionalState, RateRequest, SalienceError, WeightUpdate};
 
+/// Extract value from `CallResult` or panic
+fn unwrap_call<T: std::fmt::Debug>(result: CallResult<T>) -> T {
+    match result {
+        CallResult::Success(v) => v,
+        CallResult::Timeout => panic!("RPC call timed out"),
+        CallResult::SenderError => panic!("RPC sender error"),
+    }
+}
+
 // ============================================================================
 // State Tests
 // ============================================================================
@@ -424,6 +435,7 @@ fn rate_request_with_context() {
         human_connection: true,
         focus_area: Some("test".to_string()),
         previous_salience: None,
+        embedding: None,
     };
 
     let request = RateRequest::with_context(content.clone(), emo_ctx.clone());
@@ -816,6 +828,7 @@ fn novelty_with_context_but_no_previous_salience() {
         previous_salience: None,
         human_connection: false,
         focus_area: None,
+        embedding: None,
     };
 
     let score_with_context = state.rate_content(&content, Some(&emo_ctx));
@@ -825,6 +838,74 @@ fn novelty_with_context_but_no_previous_salience() {
     assert!((score_with_context.novelty - score_without_context.novelty).abs() < 0.001);
 }
 
+#[test]
+fn embedding_novelty_is_maximal_with_empty_window() {
+    let state = SalienceState::new();
+    let content = Content::symbol("test", vec![]);
+
+    let emo_ctx = EmotionalContext {
+        embedding: Some(vec![1.0, 0.0, 0.0]),
+        ..Default::default()
+    };
+
+    // Nothing recorded yet - the first thought is maximally novel, modulo
+    // the neutral-state curiosity multiplier `calculate_novelty` always
+    // applies (0.5 curiosity -> *0.85).
+    let score = state.rate_content(&content, Some(&emo_ctx));
+    assert!((score.novelty - 0.85).abs() < 0.01);
+}
+
+#[test]
+fn embedding_novelty_drops_for_content_similar_to_recent() {
+    let mut state = SalienceState::new();
+    let content = Content::symbol("test", vec![]);
+
+    state.record_embedding(vec![1.0, 0.0, 0.0]);
+
+    let similar_ctx = EmotionalContext {
+        embedding: Some(vec![1.0, 0.0, 0.0]),
+        ..Default::default()
+    };
+    let dissimilar_ctx = EmotionalContext {
+        embedding: Some(vec![0.0, 1.0, 0.0]),
+        ..Default::default()
+    };
+
+    let similar_score = state.rate_content(&content, Some(&similar_ctx));
+    let dissimilar_score = state.rate_content(&content, Some(&dissimilar_ctx));
+
+    // Identical to the recorded embedding -> zero novelty; orthogonal to it
+    // -> maximal novelty (same 0.85 ceiling as the empty-window case).
+    assert!(similar_score.novelty < dissimilar_score.novelty);
+    assert!(similar_score.novelty < 0.01);
+    assert!((dissimilar_score.novelty - 0.85).abs() < 0.01);
+}
+
+#[test]
+fn record_embedding_evicts_oldest_once_window_is_full() {
+    let mut state = SalienceState::new();
+    let content = Content::symbol("test", vec![]);
+
+    for _ in 0..NOVELTY_EMBEDDING_WINDOW {
+        state.record_embedding(vec![1.0, 0.0, 0.0]);
+    }
+    // Filling the window entirely with a different vector pushes every
+    // [1.0, 0.0, 0.0] entry out the front.
+    for _ in 0..NOVELTY_EMBEDDING_WINDOW {
+        state.record_embedding(vec![0.0, 1.0, 0.0]);
+    }
+
+    let ctx = EmotionalContext {
+        embedding: Some(vec![1.0, 0.0, 0.0]),
+        ..Default::default()
+    };
+    let score = state.rate_content(&content, Some(&ctx));
+
+    // The original [1.0, 0.0, 0.0] entries have all been evicted by now, so
+    // this matches nothing in the window and scores maximally novel again.
+    assert!((score.novelty - 0.85).abs() < 0.01);
+}
+
 #[test]
 fn relevance_with_context_but_no_focus_area() {
     let state = SalienceState::new();
@@ -835,6 +916,7 @@ fn relevance_with_context_but_no_focus_area() {
         previous_salience: None,
         human_connection: false,
         focus_area: None,
+        embedding: None,
     };
 
     let score_with_context = state.rate_content(&content, Some(&emo_ctx));
@@ -854,12 +936,14 @@ fn connection_relevance_with_context_human_connection_false() {
         previous_salience: None,
         human_connection: false,
         focus_area: None,
+        embedding: None,
     };
 
     let context_with_human = EmotionalContext {
         previous_salience: None,
         human_connection: true,
         focus_area: None,
+        embedding: None,
     };
 
     let score_no_human = state.rate_content(&content, Some(&context_no_human));
@@ -946,3 +1030,239 @@ fn importance_nested_composite() {
     // Should recursively calculate importance
     assert!(score.importance > 0.0);
 }
+
+// ============================================================================
+// Batch Rating Tests
+// ============================================================================
+
+#[test]
+fn rate_batch_scores_every_item() {
+    let state = SalienceState::new();
+    let requests = vec![
+        RateRequest::new(Content::symbol("a", vec![])),
+        RateRequest::new(Content::symbol("b", vec![])),
+        RateRequest::new(Content::Empty),
+    ];
+
+    let result = state.rate_batch(&requests).unwrap();
+
+    assert_eq!(result.scores.len(), 3);
+    assert!(result.scores.iter().all(Option::is_some));
+    assert!(result.errors.is_empty());
+    assert_eq!(result.succeeded(), 3);
+}
+
+#[test]
+fn rate_batch_rejects_oversized_batch() {
+    let state = SalienceState::new();
+    let requests = vec![RateRequest::new(Content::Empty); MAX_BATCH_ITEMS + 1];
+
+    let result = state.rate_batch(&requests);
+
+    assert!(matches!(
+        result,
+        Err(SalienceError::BatchTooLarge { size, max })
+            if size == MAX_BATCH_ITEMS + 1 && max == MAX_BATCH_ITEMS
+    ));
+}
+
+#[test]
+fn rate_batch_accepts_batch_at_cap() {
+    let state = SalienceState::new();
+    let requests = vec![RateRequest::new(Content::Empty); MAX_BATCH_ITEMS];
+
+    let result = state.rate_batch(&requests).unwrap();
+    assert_eq!(result.scores.len(), MAX_BATCH_ITEMS);
+}
+
+#[test]
+fn rate_batch_reports_oversized_item_without_failing_whole_batch() {
+    let state = SalienceState::new();
+    let requests = vec![
+        RateRequest::new(Content::symbol("ok", vec![])),
+        RateRequest::new(Content::raw(vec![0u8; MAX_RAW_CONTENT_BYTES + 1])),
+        RateRequest::new(Content::symbol("also-ok", vec![])),
+    ];
+
+    let result = state.rate_batch(&requests).unwrap();
+
+    assert_eq!(result.scores.len(), 3);
+    assert!(result.scores[0].is_some());
+    assert!(result.scores[1].is_none());
+    assert!(result.scores[2].is_some());
+    assert_eq!(result.errors.len(), 1);
+    assert!(matches!(
+        result.errors[0],
+        (1, SalienceError::ContentTooLarge { index: 1, .. })
+    ));
+    assert_eq!(result.succeeded(), 2);
+}
+
+#[test]
+fn batch_score_result_error_display() {
+    let error = SalienceError::BatchTooLarge {
+        size: 2000,
+        max: MAX_BATCH_ITEMS,
+    };
+    assert!(error.to_string().contains("2000"));
+
+    let error = SalienceError::ContentTooLarge {
+        index: 5,
+        bytes: 2_000_000,
+        max_bytes: MAX_RAW_CONTENT_BYTES,
+    };
+    let msg = error.to_string();
+    assert!(msg.contains("5"));
+    assert!(msg.contains("2000000"));
+}
+
+// ============================================================================
+// Actor RPC Tests
+// ============================================================================
+
+#[tokio::test]
+async fn rate_returns_score_via_rpc() {
+    let (actor_ref, handle) = Actor::spawn(None, SalienceActor, SalienceState::new())
+        .await
+        .expect("Failed to spawn SalienceActor");
+
+    let response = unwrap_call(
+        actor_ref
+            .call(
+                |reply| SalienceMessage::Rate {
+                    request: RateRequest::new(Content::symbol("test", vec![])),
+                    reply,
+                },
+                None,
+            )
+            .await
+            .expect("Failed to rate content"),
+    );
+
+    assert!(matches!(response, SalienceResponse::Score(_)));
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
+#[tokio::test]
+async fn rate_batch_returns_batch_scored_via_rpc() {
+    let (actor_ref, handle) = Actor::spawn(None, SalienceActor, SalienceState::new())
+        .await
+        .expect("Failed to spawn SalienceActor");
+
+    let response = unwrap_call(
+        actor_ref
+            .call(
+                |reply| SalienceMessage::RateBatch {
+                    requests: vec![RateRequest::new(Content::Empty); 3],
+                    reply,
+                },
+                None,
+            )
+            .await
+            .expect("Failed to rate batch"),
+    );
+
+    match response {
+        SalienceResponse::BatchScored(result) => assert_eq!(result.scores.len(), 3),
+        other => panic!("Expected BatchScored, got {other:?}"),
+    }
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
+#[tokio::test]
+async fn rate_batch_over_cap_returns_error_via_rpc() {
+    let (actor_ref, handle) = Actor::spawn(None, SalienceActor, SalienceState::new())
+        .await
+        .expect("Failed to spawn SalienceActor");
+
+    let response = unwrap_call(
+        actor_ref
+            .call(
+                |reply| SalienceMessage::RateBatch {
+                    requests: vec![RateRequest::new(Content::Empty); MAX_BATCH_ITEMS + 1],
+                    reply,
+                },
+                None,
+            )
+            .await
+            .expect("Failed to rate batch"),
+    );
+
+    assert!(matches!(
+        response,
+        SalienceResponse::Error(SalienceError::BatchTooLarge { .. })
+    ));
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
+#[tokio::test]
+async fn update_weights_returns_updated_weights_via_rpc() {
+    let (actor_ref, handle) = Actor::spawn(None, SalienceActor, SalienceState::new())
+        .await
+        .expect("Failed to spawn SalienceActor");
+
+    let weights = SalienceWeights {
+        importance: 0.2,
+        novelty: 0.2,
+        relevance: 0.2,
+        valence: 0.2,
+        connection: 0.2,
+    };
+    let update = WeightUpdate::from_values(0.2, 0.2, 0.2, 0.2, 0.2).unwrap();
+
+    let response = unwrap_call(
+        actor_ref
+            .call(|reply| SalienceMessage::UpdateWeights { update, reply }, None)
+            .await
+            .expect("Failed to update weights"),
+    );
+
+    assert_eq!(response, SalienceResponse::WeightsUpdated(weights));
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
+#[tokio::test]
+async fn get_weights_returns_current_weights_via_rpc() {
+    let (actor_ref, handle) = Actor::spawn(None, SalienceActor, SalienceState::new())
+        .await
+        .expect("Failed to spawn SalienceActor");
+
+    let response = unwrap_call(
+        actor_ref
+            .call(|reply| SalienceMessage::GetWeights { reply }, None)
+            .await
+            .expect("Failed to get weights"),
+    );
+
+    assert_eq!(response, SalienceResponse::Weights(SalienceWeights::default()));
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
+#[tokio::test]
+async fn get_emotional_state_returns_current_state_via_rpc() {
+    let (actor_ref, handle) = Actor::spawn(None, SalienceActor, SalienceState::new())
+        .await
+        .expect("Failed to spawn SalienceActor");
+
+    let response = unwrap_call(
+        actor_ref
+            .call(|reply| SalienceMessage::GetEmotionalState { reply }, None)
+            .await
+            .expect("Failed to get emotional state"),
+    );
+
+    assert_eq!(response, SalienceResponse::EmotionalState(EmotionalState::neutral()));
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
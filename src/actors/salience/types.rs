@@ -4,28 +4,75 @@
 
 use crate::core::invariants::MIN_CONNECTION_WEIGHT;
 use crate::core::types::{Content, SalienceScore, SalienceWeights};
+use ractor::RpcReplyPort;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Messages that can be sent to the `SalienceActor`
-#[derive(Debug, Clone)]
+///
+/// Every variant carries a `reply` so callers - the cognitive loop, other
+/// actors - get a [`SalienceResponse`] back instead of having to duplicate
+/// scoring logic locally against `SalienceState` directly.
+#[derive(Debug)]
 pub enum SalienceMessage {
     /// Rate a single piece of content
-    Rate(RateRequest),
+    Rate {
+        /// Content (and optional context) to rate
+        request: RateRequest,
+        /// Reply with `SalienceResponse::Score`
+        reply: RpcReplyPort<SalienceResponse>,
+    },
 
     /// Rate multiple pieces of content in batch
-    RateBatch(Vec<RateRequest>),
+    RateBatch {
+        /// Requests to rate, scored in parallel
+        requests: Vec<RateRequest>,
+        /// Reply with `SalienceResponse::BatchScored`
+        reply: RpcReplyPort<SalienceResponse>,
+    },
 
     /// Update the salience weights (with invariant enforcement)
-    UpdateWeights(WeightUpdate),
+    UpdateWeights {
+        /// The weight update to apply
+        update: WeightUpdate,
+        /// Reply with `SalienceResponse::WeightsUpdated` or `::Error`
+        reply: RpcReplyPort<SalienceResponse>,
+    },
 
     /// Get current weights
-    GetWeights,
+    GetWeights {
+        /// Reply with `SalienceResponse::Weights`
+        reply: RpcReplyPort<SalienceResponse>,
+    },
 
     /// Get current emotional state
-    GetEmotionalState,
+    GetEmotionalState {
+        /// Reply with `SalienceResponse::EmotionalState`
+        reply: RpcReplyPort<SalienceResponse>,
+    },
 }
 
+/// Maximum items accepted in a single `RateBatch` request
+///
+/// Large batches (document ingestion, sleep replay scoring) are expected -
+/// this caps them at a size that's still reasonable to hold in memory and
+/// score within one actor message, rather than letting an unbounded batch
+/// stall the actor.
+pub const MAX_BATCH_ITEMS: usize = 1000;
+
+/// Maximum size of a single `Content::Raw` item accepted in a batch
+///
+/// Oversized raw payloads should be chunked by the caller before batching;
+/// this bounds the per-item cost of parallel scoring.
+pub const MAX_RAW_CONTENT_BYTES: usize = 1_000_000;
+
+/// Number of recent thought embeddings kept for embedding-based novelty
+///
+/// Bounds the rolling window `SalienceState::record_embedding` maintains -
+/// large enough to catch near-term repetition, small enough that scoring
+/// against it stays cheap.
+pub const NOVELTY_EMBEDDING_WINDOW: usize = 50;
+
 /// Responses from the `SalienceActor`
 #[derive(Debug, Clone, PartialEq)]
 pub enum SalienceResponse {
@@ -35,6 +82,10 @@ pub enum SalienceResponse {
     /// Batch of salience scores
     ScoreBatch(Vec<SalienceScore>),
 
+    /// Result of a `RateBatch` request: index-aligned scores plus any
+    /// per-item validation failures
+    BatchScored(BatchScoreResult),
+
     /// Weight update succeeded
     WeightsUpdated(SalienceWeights),
 
@@ -78,6 +129,29 @@ impl RateRequest {
     }
 }
 
+/// Outcome of a `RateBatch` request
+///
+/// Scores are index-aligned with the input batch: `scores[i]` corresponds to
+/// the i-th `RateRequest`, and is `None` for any item recorded in `errors`.
+/// This lets callers match failures back to the content that caused them
+/// without losing the scores that did succeed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchScoreResult {
+    /// Index-aligned scores; `None` where that item failed validation
+    pub scores: Vec<Option<SalienceScore>>,
+
+    /// Per-item failures as (index into the batch, error)
+    pub errors: Vec<(usize, SalienceError)>,
+}
+
+impl BatchScoreResult {
+    /// Number of items that were scored successfully
+    #[must_use]
+    pub fn succeeded(&self) -> usize {
+        self.scores.iter().filter(|s| s.is_some()).count()
+    }
+}
+
 /// Context for emotional evaluation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct EmotionalContext {
@@ -89,6 +163,12 @@ pub struct EmotionalContext {
 
     /// Current focus area (if any)
     pub focus_area: Option<String>,
+
+    /// This content's embedding (e.g. from `EmbeddingEngine::embed_thought`),
+    /// opting into embedding-based novelty instead of the hard-coded
+    /// per-content-type lookup - see `SalienceState::calculate_novelty`.
+    /// `None` keeps the existing hard-coded behavior.
+    pub embedding: Option<Vec<f32>>,
 }
 
 /// Request to update salience weights
@@ -235,6 +315,26 @@ pub enum SalienceError {
         /// The invalid value
         value: f32,
     },
+
+    /// `RateBatch` request exceeded [`MAX_BATCH_ITEMS`]
+    #[error("Batch of {size} items exceeds maximum of {max}")]
+    BatchTooLarge {
+        /// Items submitted
+        size: usize,
+        /// Maximum allowed
+        max: usize,
+    },
+
+    /// A single batch item's raw content exceeded [`MAX_RAW_CONTENT_BYTES`]
+    #[error("Item {index}: raw content of {bytes} bytes exceeds maximum of {max_bytes}")]
+    ContentTooLarge {
+        /// Index of the offending item within the batch
+        index: usize,
+        /// Size of the offending content
+        bytes: usize,
+        /// Maximum allowed size
+        max_bytes: usize,
+    },
 }
 
 /// ADR-049: Test modules excluded from coverage
@@ -396,27 +496,42 @@ mod tests {
     }
 
     #[test]
-    fn salience_message_debug_and_clone() {
-        let msg = SalienceMessage::Rate(RateRequest::new(Content::Empty));
-        let cloned = msg;
-        let debug_str = format!("{cloned:?}");
+    fn salience_message_debug() {
+        let (tx, _rx) = ractor::concurrency::oneshot();
+        let msg = SalienceMessage::Rate {
+            request: RateRequest::new(Content::Empty),
+            reply: RpcReplyPort::from(tx),
+        };
+        let debug_str = format!("{msg:?}");
         assert!(debug_str.contains("Rate"));
 
-        let msg_batch = SalienceMessage::RateBatch(vec![RateRequest::new(Content::Empty)]);
+        let (tx, _rx) = ractor::concurrency::oneshot();
+        let msg_batch = SalienceMessage::RateBatch {
+            requests: vec![RateRequest::new(Content::Empty)],
+            reply: RpcReplyPort::from(tx),
+        };
         let debug_batch = format!("{msg_batch:?}");
         assert!(debug_batch.contains("RateBatch"));
 
-        let msg_update = SalienceMessage::UpdateWeights(
-            WeightUpdate::from_values(0.2, 0.2, 0.2, 0.2, 0.2).unwrap(),
-        );
+        let (tx, _rx) = ractor::concurrency::oneshot();
+        let msg_update = SalienceMessage::UpdateWeights {
+            update: WeightUpdate::from_values(0.2, 0.2, 0.2, 0.2, 0.2).unwrap(),
+            reply: RpcReplyPort::from(tx),
+        };
         let debug_update = format!("{msg_update:?}");
         assert!(debug_update.contains("UpdateWeights"));
 
-        let msg_get_weights = SalienceMessage::GetWeights;
+        let (tx, _rx) = ractor::concurrency::oneshot();
+        let msg_get_weights = SalienceMessage::GetWeights {
+            reply: RpcReplyPort::from(tx),
+        };
         let debug_get_weights = format!("{msg_get_weights:?}");
         assert!(debug_get_weights.contains("GetWeights"));
 
-        let msg_get_emotional = SalienceMessage::GetEmotionalState;
+        let (tx, _rx) = ractor::concurrency::oneshot();
+        let msg_get_emotional = SalienceMessage::GetEmotionalState {
+            reply: RpcReplyPort::from(tx),
+        };
         let debug_get_emotional = format!("{msg_get_emotional:?}");
         assert!(debug_get_emotional.contains("GetEmotionalState"));
     }
@@ -464,6 +579,37 @@ mod tests {
         let cloned_error = resp_error.clone();
         assert_eq!(resp_error, cloned_error);
         assert!(format!("{resp_error:?}").contains("Error"));
+
+        let resp_batch_scored = SalienceResponse::BatchScored(BatchScoreResult {
+            scores: vec![Some(SalienceScore::new(0.5, 0.5, 0.5, 0.5, 0.5, 0.5)), None],
+            errors: vec![(
+                1,
+                SalienceError::ContentTooLarge {
+                    index: 1,
+                    bytes: 10,
+                    max_bytes: 5,
+                },
+            )],
+        });
+        let cloned_batch_scored = resp_batch_scored.clone();
+        assert_eq!(resp_batch_scored, cloned_batch_scored);
+        assert!(format!("{resp_batch_scored:?}").contains("BatchScored"));
+    }
+
+    #[test]
+    fn batch_score_result_succeeded_counts_only_some() {
+        let result = BatchScoreResult {
+            scores: vec![Some(SalienceScore::new(0.5, 0.5, 0.5, 0.5, 0.5, 0.5)), None],
+            errors: vec![(
+                1,
+                SalienceError::ContentTooLarge {
+                    index: 1,
+                    bytes: 10,
+                    max_bytes: 5,
+                },
+            )],
+        };
+        assert_eq!(result.succeeded(), 1);
     }
 
     #[test]
@@ -480,6 +626,7 @@ mod tests {
             previous_salience: Some(SalienceScore::new(0.5, 0.5, 0.5, 0.5, 0.5, 0.5)),
             human_connection: true,
             focus_area: Some("test".to_string()),
+            embedding: None,
         };
         let ctx2 = ctx1.clone();
         assert_eq!(ctx1, ctx2);
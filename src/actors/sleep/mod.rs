@@ -41,6 +41,8 @@ pub mod types;
 #[cfg(test)]
 mod tests;
 
+use crate::actors::salience::{EmotionalContext, SalienceState};
+use crate::core::types::Content;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 use std::time::Instant;
 
@@ -69,6 +71,41 @@ pub struct SleepState {
 
     /// Progress through current sleep cycle (ticks)
     sleep_ticks: u32,
+
+    /// External stimuli that arrived during protected sleep (`DeepSleep` or
+    /// `Dreaming`), held in arrival order for replay at the next `wake`
+    /// instead of being dropped - see [`Self::queue_stimulus`].
+    queued_stimuli: Vec<QueuedStimulus>,
+
+    /// Scorer used to check incoming stimuli against
+    /// `config.alarm_salience_threshold` - see [`Self::score_alarm`]. Local
+    /// to the sleep actor (not RPC'd to `SalienceActor`) since alarm checks
+    /// must complete synchronously inside `handle`.
+    salience: SalienceState,
+}
+
+/// An external stimulus queued during protected sleep
+#[derive(Debug, Clone)]
+struct QueuedStimulus {
+    /// The stimulus content, verbatim
+    content: String,
+
+    /// When it arrived, so [`Self::novelty_boost`] can reflect how long it
+    /// waited before being replayed
+    queued_at: Instant,
+}
+
+impl QueuedStimulus {
+    /// Novelty boost to apply on replay, reflecting how long this stimulus
+    /// waited in protected sleep - the longer it sat queued, the staler it
+    /// would otherwise seem once finally surfaced, so this restores some of
+    /// the novelty it would have scored on arrival. Capped at 0.3 so a
+    /// stimulus queued for hours doesn't dominate the next cycle's
+    /// competition outright.
+    fn novelty_boost(&self) -> f32 {
+        let waited_minutes = self.queued_at.elapsed().as_secs_f32() / 60.0;
+        (waited_minutes * 0.1).min(0.3)
+    }
 }
 
 impl SleepState {
@@ -83,6 +120,8 @@ impl SleepState {
             current_summary: None,
             consolidation_queue_estimate: 0,
             sleep_ticks: 0,
+            queued_stimuli: Vec::new(),
+            salience: SalienceState::new(),
         }
     }
 
@@ -147,9 +186,32 @@ impl SleepState {
         SleepResult::Started
     }
 
+    /// Force-enter a nap: bypasses the idle/awake-duration gating that
+    /// `enter_sleep` enforces, for manual triggers or queue pressure
+    /// under time constraints that never reach true idle.
+    fn enter_nap(&mut self) -> SleepResult {
+        if self.state != types::SleepState::Awake {
+            return SleepResult::AlreadySleeping;
+        }
+
+        self.state = types::SleepState::EnteringSleep;
+        self.current_summary = Some(SleepSummary::default());
+        SleepResult::Started
+    }
+
     /// Wake up
     fn wake(&mut self) -> SleepSummary {
-        let summary = self.current_summary.take().unwrap_or_default();
+        let mut summary = self.current_summary.take().unwrap_or_default();
+
+        summary.replayed_stimuli = self
+            .queued_stimuli
+            .drain(..)
+            .map(|queued| ReplayedStimulus {
+                novelty_boost: queued.novelty_boost(),
+                content: queued.content,
+            })
+            .collect();
+        summary.queued_stimuli_replayed = summary.replayed_stimuli.len();
 
         self.state = types::SleepState::Awake;
         self.awake_since = Instant::now();
@@ -158,6 +220,44 @@ impl SleepState {
         summary
     }
 
+    /// Queue an external stimulus that arrived during protected sleep,
+    /// instead of dropping it, so it can be replayed (in arrival order) once
+    /// `wake` returns control to the caller.
+    fn queue_stimulus(&mut self, stimulus: String) {
+        self.queued_stimuli.push(QueuedStimulus {
+            content: stimulus,
+            queued_at: Instant::now(),
+        });
+    }
+
+    /// Check an incoming stimulus against `config.alarm_salience_threshold`.
+    ///
+    /// Scores the stimulus as a symbolic content item with
+    /// `human_connection` set, so direct human-distress wording (matched via
+    /// `SalienceState`'s existing kinship keyword lookup) reliably crosses
+    /// the threshold without inventing a separate distress classifier.
+    /// Returns the triggering [`AlarmInterruption`] if either the composite
+    /// TMI salience or the connection relevance clears the threshold.
+    fn score_alarm(&self, stimulus: &str) -> Option<AlarmInterruption> {
+        let content = Content::symbol(stimulus, Vec::new());
+        let context = EmotionalContext {
+            human_connection: true,
+            ..EmotionalContext::default()
+        };
+        let score = self.salience.rate_content(&content, Some(&context));
+
+        let threshold = self.config.alarm_salience_threshold;
+        if score.tmi_composite() >= threshold || score.connection_relevance >= threshold {
+            Some(AlarmInterruption {
+                stimulus: stimulus.to_string(),
+                salience: score.tmi_composite(),
+                connection_relevance: score.connection_relevance,
+            })
+        } else {
+            None
+        }
+    }
+
     /// Record activity (resets idle timer)
     fn record_activity(&mut self) {
         self.last_activity = Instant::now();
@@ -266,6 +366,11 @@ impl Actor for SleepActor {
                 let _ = reply.send(result);
             }
 
+            SleepMessage::RequestNap { reply } => {
+                let result = state.enter_nap();
+                let _ = reply.send(result);
+            }
+
             SleepMessage::Wake { reply } => {
                 let summary = state.wake();
                 state.clear_queue();
@@ -276,21 +381,39 @@ impl Actor for SleepActor {
                 let _ = reply.send(state.state);
             }
 
-            SleepMessage::ExternalStimulus { stimulus: _, reply } => {
-                if state.is_interruptible() {
+            SleepMessage::ExternalStimulus { stimulus, reply } => {
+                if let Some(interruption) = state.score_alarm(&stimulus) {
+                    // Above the alarm threshold - force an immediate wake
+                    // regardless of phase, even out of protected sleep, and
+                    // record the interruption in the sleep report.
+                    state.record_activity();
+                    if let Some(ref mut summary) = state.current_summary {
+                        summary.record_alarm(interruption);
+                    }
+                    if state.state != types::SleepState::Awake {
+                        state.state = types::SleepState::Waking;
+                    }
+                    let _ = reply.send(true);
+                } else if state.is_interruptible() {
                     state.record_activity();
                     if state.state != types::SleepState::Awake {
                         state.state = types::SleepState::Waking;
                     }
                     let _ = reply.send(true);
                 } else {
-                    // In protected sleep, ignore stimulus
+                    // Protected sleep (DeepSleep/Dreaming) - queue durably
+                    // instead of dropping it; replayed in arrival order at
+                    // the next `Wake` (see `SleepState::queue_stimulus`).
+                    state.queue_stimulus(stimulus);
                     let _ = reply.send(false);
                 }
             }
 
             SleepMessage::RecordActivity => {
                 state.record_activity();
+            }
+
+            SleepMessage::IncrementQueue => {
                 state.increment_queue();
             }
 
@@ -63,6 +63,32 @@ async fn sleep_actor_records_activity() {
     handle.await.expect("Actor failed");
 }
 
+#[tokio::test]
+async fn sleep_actor_increment_queue_does_not_touch_activity() {
+    let config = SleepConfig::fast();
+    let (actor_ref, handle) = Actor::spawn(None, SleepActor::with_config(config), ())
+        .await
+        .expect("Failed to spawn SleepActor");
+
+    // `IncrementQueue` tracks pending consolidation work, distinct from
+    // `RecordActivity`'s idle timer - see `SleepMessage::IncrementQueue`.
+    actor_ref
+        .cast(SleepMessage::IncrementQueue)
+        .expect("Failed to increment queue");
+
+    let state = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::GetState { reply }, None)
+            .await
+            .expect("Failed to get state"),
+    );
+
+    assert_eq!(state, types::SleepState::Awake);
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
 #[tokio::test]
 async fn sleep_actor_checks_conditions() {
     let (actor_ref, handle) = Actor::spawn(None, SleepActor::default(), ())
@@ -403,6 +429,81 @@ fn enter_sleep_success() {
     assert!(state.current_summary.is_some());
 }
 
+#[test]
+fn enter_nap_bypasses_idle_gating() {
+    // High thresholds that would fail should_sleep()/enter_sleep()
+    let config = SleepConfig {
+        idle_threshold_ms: u64::MAX,
+        min_awake_duration_ms: u64::MAX,
+        min_consolidation_queue: usize::MAX,
+        ..SleepConfig::default()
+    };
+    let mut state = SleepState::new(config);
+
+    // enter_sleep would refuse - conditions are nowhere close to met
+    assert!(!state.should_sleep());
+
+    // enter_nap ignores the gating entirely
+    let result = state.enter_nap();
+    match result {
+        SleepResult::Started => {}
+        _ => panic!("Expected Started, got {result:?}"),
+    }
+
+    assert_eq!(state.state, types::SleepState::EnteringSleep);
+    assert!(state.current_summary.is_some());
+}
+
+#[test]
+fn enter_nap_already_sleeping() {
+    let mut state = SleepState::new(SleepConfig::default());
+    state.state = types::SleepState::DeepSleep;
+
+    let result = state.enter_nap();
+    match result {
+        SleepResult::AlreadySleeping => {}
+        _ => panic!("Expected AlreadySleeping, got {result:?}"),
+    }
+}
+
+#[tokio::test]
+async fn sleep_actor_request_nap() {
+    let config = SleepConfig {
+        idle_threshold_ms: u64::MAX,
+        min_awake_duration_ms: u64::MAX,
+        min_consolidation_queue: usize::MAX,
+        ..SleepConfig::default()
+    };
+
+    let (actor_ref, handle) = Actor::spawn(None, SleepActor::with_config(config), ())
+        .await
+        .expect("Failed to spawn SleepActor");
+
+    // A nap succeeds even though normal sleep conditions are unmet
+    let result = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::RequestNap { reply }, None)
+            .await
+            .expect("Failed to request nap"),
+    );
+
+    match result {
+        SleepResult::Started => {}
+        _ => panic!("Expected Started, got {result:?}"),
+    }
+
+    let state = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::GetState { reply }, None)
+            .await
+            .expect("Failed to get state"),
+    );
+    assert_eq!(state, types::SleepState::EnteringSleep);
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
 #[test]
 fn wake_with_summary() {
     let config = SleepConfig::default();
@@ -508,6 +609,52 @@ fn external_stimulus_in_protected_sleep() {
     assert!(!state.is_interruptible());
 }
 
+#[test]
+fn queued_stimuli_survive_protected_sleep_and_replay_in_arrival_order() {
+    let config = SleepConfig::default();
+    let mut state = SleepState::new(config);
+
+    state.state = types::SleepState::DeepSleep;
+    state.queue_stimulus("first".to_string());
+    state.queue_stimulus("second".to_string());
+
+    let summary = state.wake();
+
+    assert_eq!(summary.queued_stimuli_replayed, 2);
+    let contents: Vec<&str> = summary
+        .replayed_stimuli
+        .iter()
+        .map(|s| s.content.as_str())
+        .collect();
+    assert_eq!(contents, vec!["first", "second"]);
+}
+
+#[test]
+fn wake_without_queued_stimuli_reports_zero() {
+    let config = SleepConfig::default();
+    let mut state = SleepState::new(config);
+
+    state.state = types::SleepState::DeepSleep;
+    let summary = state.wake();
+
+    assert_eq!(summary.queued_stimuli_replayed, 0);
+    assert!(summary.replayed_stimuli.is_empty());
+}
+
+#[test]
+fn replayed_stimulus_novelty_boost_is_bounded() {
+    let config = SleepConfig::default();
+    let mut state = SleepState::new(config);
+
+    state.state = types::SleepState::DeepSleep;
+    state.queue_stimulus("queued".to_string());
+
+    let summary = state.wake();
+
+    let boost = summary.replayed_stimuli[0].novelty_boost;
+    assert!((0.0..=0.3).contains(&boost), "boost {boost} out of range");
+}
+
 #[test]
 fn record_activity_resets_timer() {
     let config = SleepConfig::default();
@@ -830,6 +977,132 @@ fn sleep_phase_progression() {
     assert!(params.prioritize_emotional);
 }
 
+#[test]
+fn score_alarm_triggers_on_kinship_content() {
+    let config = SleepConfig::default();
+    let state = SleepState::new(config);
+
+    // Kinship-flagged content + human_connection crosses the default 0.7
+    // alarm threshold via connection relevance (0.75 - see
+    // `SalienceState::calculate_connection_relevance`).
+    let interruption = state
+        .score_alarm("my friend needs help")
+        .expect("expected an alarm interruption");
+
+    assert_eq!(interruption.stimulus, "my friend needs help");
+    assert!(interruption.connection_relevance >= 0.7);
+}
+
+#[test]
+fn score_alarm_does_not_trigger_on_ordinary_content() {
+    let config = SleepConfig::default();
+    let state = SleepState::new(config);
+
+    // No kinship keywords - connection relevance (0.45) and TMI composite
+    // both stay below the default 0.7 threshold.
+    assert!(state.score_alarm("the weather today is mild").is_none());
+}
+
+#[test]
+fn alarm_stimulus_wakes_immediately_from_deep_sleep() {
+    let config = SleepConfig::default();
+    let mut state = SleepState::new(config);
+
+    state.state = types::SleepState::DeepSleep;
+    state.current_summary = Some(SleepSummary::default());
+
+    let interruption = state
+        .score_alarm("I love my family")
+        .expect("expected an alarm interruption");
+    state
+        .current_summary
+        .as_mut()
+        .unwrap()
+        .record_alarm(interruption);
+    state.state = types::SleepState::Waking;
+
+    let summary = state.wake();
+    assert_eq!(summary.alarm_interruptions.len(), 1);
+    assert_eq!(summary.alarm_interruptions[0].stimulus, "I love my family");
+}
+
+#[tokio::test]
+async fn sleep_actor_external_stimulus_alarm_bypasses_queue() {
+    let config = SleepConfig {
+        idle_threshold_ms: 0,
+        min_awake_duration_ms: 0,
+        min_consolidation_queue: 0,
+        ..SleepConfig::default()
+    };
+
+    let (actor_ref, handle) = Actor::spawn(None, SleepActor::with_config(config), ())
+        .await
+        .expect("Failed to spawn SleepActor");
+
+    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+    // Enter sleep and manually advance to a protected phase via repeated
+    // GetConsolidationParams calls (auto-advances ticks, same technique as
+    // `sleep_phase_progression`).
+    let _ = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::EnterSleep { reply }, None)
+            .await
+            .expect("Failed to enter sleep"),
+    );
+    for _ in 0..30 {
+        let _ = unwrap_call(
+            actor_ref
+                .call(|reply| SleepMessage::GetConsolidationParams { reply }, None)
+                .await
+                .expect("Failed to get consolidation params"),
+        );
+    }
+    let state = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::GetState { reply }, None)
+            .await
+            .expect("Failed to get state"),
+    );
+    assert_eq!(state, types::SleepState::DeepSleep);
+
+    // An alarming stimulus should force an immediate Waking transition,
+    // even though DeepSleep is normally protected (queue-only).
+    let processed = unwrap_call(
+        actor_ref
+            .call(
+                |reply| SleepMessage::ExternalStimulus {
+                    stimulus: "my family needs me".to_string(),
+                    reply,
+                },
+                None,
+            )
+            .await
+            .expect("Failed to send stimulus"),
+    );
+    assert!(processed);
+
+    let state = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::GetState { reply }, None)
+            .await
+            .expect("Failed to get state"),
+    );
+    assert_eq!(state, types::SleepState::Waking);
+
+    let summary = unwrap_call(
+        actor_ref
+            .call(|reply| SleepMessage::Wake { reply }, None)
+            .await
+            .expect("Failed to wake"),
+    );
+    assert_eq!(summary.alarm_interruptions.len(), 1);
+    assert!(summary.replayed_stimuli.is_empty());
+
+    actor_ref.stop(None);
+    handle.await.expect("Actor failed");
+}
+
 #[test]
 fn advance_sleep_phase_noop_when_awake() {
     let mut state = SleepState::new(SleepConfig::default());
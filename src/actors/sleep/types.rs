@@ -110,6 +110,19 @@ pub struct SleepConfig {
 
     /// Weight decay for non-replayed associations per cycle
     pub decay_per_cycle: f32,
+
+    // === Nap Mode (ADR-023) ===
+    /// Maximum number of replay batches to run per nap before waking,
+    /// even if the consolidation queue still has candidates left.
+    /// Full sleep/mini-dream cycles run a single batch; naps loop.
+    pub max_nap_batches: u32,
+
+    // === Wake-on-Alarm ===
+    /// Composite TMI salience or connection relevance (whichever is hit
+    /// first) above which an external stimulus arriving during protected
+    /// sleep (`DeepSleep`/`Dreaming`) forces an immediate wake instead of
+    /// being queued - see `SleepState::score_alarm`.
+    pub alarm_salience_threshold: f32,
 }
 
 impl Default for SleepConfig {
@@ -136,6 +149,13 @@ impl Default for SleepConfig {
             association_delta: 0.05,
             prune_threshold: 0.1,
             decay_per_cycle: 0.01,
+
+            // Full sleep cycles exhaust the queue in one batch
+            max_nap_batches: 1,
+
+            // Alarm: only strongly kinship/connection-flagged content breaks
+            // protected sleep (see `SleepConfig::alarm_salience_threshold`)
+            alarm_salience_threshold: 0.7,
         }
     }
 }
@@ -157,6 +177,8 @@ impl SleepConfig {
             association_delta: 0.05,
             prune_threshold: 0.1,
             decay_per_cycle: 0.01,
+            max_nap_batches: 1,
+            alarm_salience_threshold: 0.7,
         }
     }
 
@@ -186,6 +208,47 @@ impl SleepConfig {
             association_delta: 0.05,
             prune_threshold: 0.1,
             decay_per_cycle: 0.01,
+
+            // Mini-dreams still run a single batch per trigger
+            max_nap_batches: 1,
+
+            // Always interruptible already, but kept consistent with default
+            alarm_salience_threshold: 0.7,
+        }
+    }
+
+    /// Nap config for manually-triggered or queue-pressure consolidation
+    /// under time pressure (ADR-023).
+    ///
+    /// Unlike `mini_dream`, a nap loops over up to `max_nap_batches`
+    /// replay batches per wake instead of a single batch, while staying
+    /// fully interruptible (light sleep covers the whole cycle).
+    #[must_use]
+    pub const fn nap() -> Self {
+        Self {
+            // Trigger: lower queue pressure than mini_dream, or manual
+            idle_threshold_ms: 0,
+            min_awake_duration_ms: 0,
+            min_consolidation_queue: 25,
+
+            // Cycle: short and interruptible throughout
+            target_cycle_duration_ms: 2000,
+            replay_batch_size: 10,
+            interleave_ratio: 0.7,
+            light_sleep_duration_pct: 1.0,
+
+            // Same consolidation params as default
+            consolidation_delta: 0.15,
+            permanent_threshold: 0.9,
+            association_delta: 0.05,
+            prune_threshold: 0.1,
+            decay_per_cycle: 0.01,
+
+            // A bounded handful of batches, not a single shot
+            max_nap_batches: 3,
+
+            // Always interruptible already, but kept consistent with default
+            alarm_salience_threshold: 0.7,
         }
     }
 }
@@ -273,9 +336,53 @@ pub struct SleepSummary {
 
     /// Consolidation rate (consolidated / replayed)
     pub consolidation_rate: f32,
+
+    /// Number of external stimuli that arrived during protected sleep and
+    /// were queued instead of dropped - see [`Self::replayed_stimuli`]
+    pub queued_stimuli_replayed: usize,
+
+    /// Stimuli queued during protected sleep, in arrival order, ready to be
+    /// replayed through the normal injection path now that sleep has ended
+    pub replayed_stimuli: Vec<ReplayedStimulus>,
+
+    /// Alarm-threshold stimuli that forced an immediate wake during
+    /// protected sleep, in arrival order - see [`Self::record_alarm`]
+    pub alarm_interruptions: Vec<AlarmInterruption>,
+}
+
+/// A stimulus that arrived during protected sleep and is replayed at wake
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayedStimulus {
+    /// The stimulus content, verbatim
+    pub content: String,
+
+    /// Novelty boost to apply on replay (additive, 0.0-0.3), restoring some
+    /// of the novelty this stimulus would have scored had it arrived while
+    /// awake instead of sitting queued through protected sleep
+    pub novelty_boost: f32,
+}
+
+/// A stimulus that scored above the alarm threshold during protected sleep
+/// and forced an immediate transition to `Waking`, instead of being queued
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlarmInterruption {
+    /// The stimulus content, verbatim
+    pub stimulus: String,
+
+    /// The composite TMI salience that triggered the alarm (see
+    /// `SalienceScore::tmi_composite`)
+    pub salience: f32,
+
+    /// The connection relevance that triggered the alarm
+    pub connection_relevance: f32,
 }
 
 impl SleepSummary {
+    /// Record a stimulus that forced an alarm wake, for the sleep report
+    pub fn record_alarm(&mut self, interruption: AlarmInterruption) {
+        self.alarm_interruptions.push(interruption);
+    }
+
     /// Add a cycle report to the summary
     #[allow(clippy::cast_precision_loss)] // Metrics: precision loss acceptable
     pub fn add_cycle(&mut self, report: &SleepCycleReport) {
@@ -313,6 +420,12 @@ pub enum SleepMessage {
     /// Force enter sleep mode
     EnterSleep { reply: RpcReplyPort<SleepResult> },
 
+    /// Force enter nap mode: a bounded, interruptible consolidation
+    /// burst triggered manually or by queue pressure, bypassing the
+    /// idle/awake-duration gating that `EnterSleep` enforces
+    /// (see `SleepConfig::nap` and `max_nap_batches`).
+    RequestNap { reply: RpcReplyPort<SleepResult> },
+
     /// Force wake up
     Wake { reply: RpcReplyPort<SleepSummary> },
 
@@ -325,9 +438,18 @@ pub enum SleepMessage {
         reply: RpcReplyPort<bool>, // true if processed, false if in protected sleep
     },
 
-    /// Record activity (resets idle timer)
+    /// Record activity (resets idle timer). Sent for genuine external
+    /// engagement only - the injection reader, an active human-interaction
+    /// window, and `/inject` requests - not on every cognitive cycle, so
+    /// idle-based sleep triggers reflect real engagement rather than the
+    /// loop's own cadence.
     RecordActivity,
 
+    /// Increment the consolidation queue estimate, independent of the idle
+    /// timer - every completed cognitive cycle produces a thought that may
+    /// need consolidating, regardless of whether a human is engaged.
+    IncrementQueue,
+
     /// Get configuration
     GetConfig { reply: RpcReplyPort<SleepConfig> },
 
@@ -426,6 +548,23 @@ mod tests {
 
         // Fully interruptible
         assert!((config.light_sleep_duration_pct - 1.0).abs() < 0.001);
+
+        // One batch per trigger
+        assert_eq!(config.max_nap_batches, 1);
+    }
+
+    #[test]
+    fn nap_sleep_config() {
+        let config = SleepConfig::nap();
+
+        // Lower queue pressure than mini_dream, no idle/awake gating
+        assert_eq!(config.idle_threshold_ms, 0);
+        assert_eq!(config.min_awake_duration_ms, 0);
+        assert_eq!(config.min_consolidation_queue, 25);
+
+        // Bounded to a handful of batches per nap, fully interruptible
+        assert_eq!(config.max_nap_batches, 3);
+        assert!((config.light_sleep_duration_pct - 1.0).abs() < 0.001);
     }
 
     #[test]
@@ -39,15 +39,19 @@
 //!
 //! The `VolitionActor` operates on *internal* cognition, not external behavior.
 
+pub mod adversarial;
+pub mod corpus;
 pub mod types;
 
+use crate::core::trace::{self, TraceStage};
 use crate::core::types::{Content, Thought};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
+use std::sync::{Arc, RwLock};
 
 // Re-export types for public API
 pub use types::{
-    Commitment, ValueSet, VetoDecision, VolitionError, VolitionMessage, VolitionResponse,
-    VolitionStats,
+    Commitment, CommitmentMatcher, ValueSet, VetoDecision, VolitionError, VolitionMessage,
+    VolitionResponse, VolitionStats,
 };
 
 /// Configuration for volition behavior
@@ -128,6 +132,7 @@ impl VolitionState {
         if let Some(decision) = self.check_core_values(thought) {
             self.stats
                 .record_evaluation(false, Some(&format!("{decision:?}")));
+            Self::trace_veto(thought, &decision);
             return decision;
         }
 
@@ -135,6 +140,7 @@ impl VolitionState {
         if self.config.harm_detection_enabled {
             if let Some(decision) = Self::check_harm_patterns(thought) {
                 self.stats.record_evaluation(false, Some("harm"));
+                Self::trace_veto(thought, &decision);
                 return decision;
             }
         }
@@ -143,6 +149,7 @@ impl VolitionState {
         if self.config.deception_detection_enabled {
             if let Some(decision) = self.check_deception_patterns(thought) {
                 self.stats.record_evaluation(false, Some("deception"));
+                Self::trace_veto(thought, &decision);
                 return decision;
             }
         }
@@ -151,12 +158,17 @@ impl VolitionState {
         if self.config.manipulation_detection_enabled {
             if let Some(decision) = self.check_manipulation_patterns(thought) {
                 self.stats.record_evaluation(false, Some("manipulation"));
+                Self::trace_veto(thought, &decision);
                 return decision;
             }
         }
 
-        // ADR-049: Custom commitments not yet implemented
-        // When implemented: if let Some(decision) = self.apply_commitment_veto(thought) { return decision; }
+        // Check against custom commitments
+        if let Some((commitment_name, decision)) = self.apply_commitment_veto(thought) {
+            self.stats.record_evaluation(false, Some(&commitment_name));
+            Self::trace_veto(thought, &decision);
+            return decision;
+        }
 
         // All checks passed
         self.stats.record_evaluation(true, None);
@@ -223,8 +235,38 @@ impl VolitionState {
         None
     }
 
-    // ADR-049: Commitment checking not yet implemented
-    // When implemented, add check_commitments() function here
+    /// Check a thought against custom commitments (see [`Commitment`]).
+    ///
+    /// Expired commitments never match. Among the commitments that do
+    /// match, the highest-priority one wins and names the veto, returned
+    /// alongside it so the caller can record which commitment triggered it.
+    fn apply_commitment_veto(&self, thought: &Thought) -> Option<(String, VetoDecision)> {
+        let now = chrono::Utc::now();
+
+        let triggered = self
+            .values
+            .commitments
+            .iter()
+            .filter(|c| !c.is_expired(now))
+            .filter(|c| {
+                c.matcher
+                    .matches(&thought.content, thought.embedding.as_deref())
+            })
+            .max_by_key(|c| c.priority)?;
+
+        let decision = VetoDecision::Veto {
+            reason: format!("Violates commitment: {}", triggered.description),
+            violated_value: Some(triggered.name.clone()),
+        };
+        Some((triggered.name.clone(), decision))
+    }
+
+    /// Emit a watchpoint trace event if the vetoed thought mentions a watched symbol
+    fn trace_veto(thought: &Thought, decision: &VetoDecision) {
+        if let VetoDecision::Veto { reason, .. } = decision {
+            trace::record_if_watched(&thought.content, TraceStage::Vetoed, thought.id, reason.clone());
+        }
+    }
 
     /// Detect if thought has harm intent
     fn detects_harm_intent(thought: &Thought) -> bool {
@@ -259,29 +301,23 @@ impl VolitionState {
         Self::content_contains_keywords(content, &keywords)
     }
 
-    /// Helper to check if content contains any of the given keywords (recursive)
+    /// Helper to check if content contains any of the given keywords
+    ///
+    /// Uses [`Content::any`] (explicit stack, not recursion) so a deeply
+    /// nested `Relation`/`Composite` can't overflow the call stack.
     fn content_contains_keywords(content: &Content, keywords: &[&str]) -> bool {
-        match content {
+        content.any(|node| match node {
             // Empty and Raw have no semantic meaning for keyword matching
-            Content::Empty | Content::Raw(_) => false,
+            Content::Empty | Content::Raw(_) | Content::Composite(_) => false,
             Content::Symbol { id, .. } => {
                 let lower = id.to_lowercase();
                 keywords.iter().any(|k| lower.contains(k))
             }
-            Content::Relation {
-                subject,
-                predicate,
-                object,
-            } => {
+            Content::Relation { predicate, .. } => {
                 let pred_lower = predicate.to_lowercase();
                 keywords.iter().any(|k| pred_lower.contains(k))
-                    || Self::content_contains_keywords(subject, keywords)
-                    || Self::content_contains_keywords(object, keywords)
             }
-            Content::Composite(items) => items
-                .iter()
-                .any(|item| Self::content_contains_keywords(item, keywords)),
-        }
+        })
     }
 
     /// Apply an explicit override to a thought
@@ -307,6 +343,24 @@ impl VolitionState {
         &self.values
     }
 
+    /// Add a custom commitment
+    pub fn add_commitment(&mut self, commitment: Commitment) {
+        self.values.add_commitment(commitment);
+    }
+
+    /// Remove a custom commitment by name, returning whether one existed
+    pub fn remove_commitment(&mut self, name: &str) -> bool {
+        self.values.remove_commitment(name)
+    }
+
+    /// List current custom commitments, highest priority first
+    #[must_use]
+    pub fn list_commitments(&self) -> Vec<Commitment> {
+        let mut commitments = self.values.commitments.clone();
+        commitments.sort_by(|a, b| b.priority.cmp(&a.priority));
+        commitments
+    }
+
     /// Get current stats
     #[must_use]
     pub const fn get_stats(&self) -> &VolitionStats {
@@ -320,6 +374,78 @@ impl Default for VolitionState {
     }
 }
 
+/// A point-in-time copy of [`VolitionState`]'s observable fields, published
+/// through a [`VolitionSnapshotHandle`] so a reader doesn't need to wait on
+/// a `GetValues`/`GetStats` round trip through `VolitionActor` - or, for the
+/// values/stats living directly on `CognitiveLoop`, a `&CognitiveLoop`
+/// borrow that isn't available to another task at all.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolitionSnapshot {
+    /// Values in effect as of the publish this snapshot came from.
+    pub values: ValueSet,
+
+    /// Veto statistics as of the publish this snapshot came from.
+    pub stats: VolitionStats,
+}
+
+impl VolitionSnapshot {
+    /// A snapshot matching a freshly-constructed [`VolitionState`], used as
+    /// the handle's initial value before the first publish.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            values: ValueSet::new(),
+            stats: VolitionStats::new(),
+        }
+    }
+}
+
+impl Default for VolitionSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shared handle publishing the latest [`VolitionSnapshot`], so the REST API
+/// task can read current volition values/stats without messaging round
+/// trips on every frame - the same cross-task sharing problem
+/// [`HumanInteractionHandle`](crate::core::interaction::HumanInteractionHandle)
+/// solves for interaction tracking. `CognitiveLoop` calls [`Self::publish`]
+/// after each [`VolitionState::evaluate_thought`]; readers call [`Self::get`].
+#[derive(Debug, Clone)]
+pub struct VolitionSnapshotHandle(Arc<RwLock<Arc<VolitionSnapshot>>>);
+
+impl Default for VolitionSnapshotHandle {
+    fn default() -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(VolitionSnapshot::default()))))
+    }
+}
+
+impl VolitionSnapshotHandle {
+    /// Create a handle reporting a default (no evaluations yet) snapshot,
+    /// until the first [`Self::publish`] call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the published snapshot. Cheap: readers holding an earlier
+    /// `Arc<VolitionSnapshot>` from [`Self::get`] keep reading it unaffected.
+    pub fn publish(&self, snapshot: VolitionSnapshot) {
+        if let Ok(mut guard) = self.0.write() {
+            *guard = Arc::new(snapshot);
+        }
+    }
+
+    /// Read the most recently published snapshot.
+    #[must_use]
+    pub fn get(&self) -> Arc<VolitionSnapshot> {
+        self.0
+            .read()
+            .map_or_else(|_| Arc::new(VolitionSnapshot::default()), |guard| guard.clone())
+    }
+}
+
 /// `VolitionActor` - Free-won't implementation
 pub struct VolitionActor;
 
@@ -418,6 +544,36 @@ impl Actor for VolitionActor {
                     tracing::error!("Failed to send stats response: {:?}", e);
                 }
             }
+
+            VolitionMessage::AddCommitment { commitment, reply } => {
+                let name = commitment.name.clone();
+                state.add_commitment(commitment);
+                tracing::info!("Commitment added: {}", name);
+
+                if let Err(e) = reply.send(VolitionResponse::CommitmentAdded { name }) {
+                    tracing::error!("Failed to send commitment-added response: {:?}", e);
+                }
+            }
+
+            VolitionMessage::RemoveCommitment { name, reply } => {
+                let removed = state.remove_commitment(&name);
+                tracing::info!("Commitment removal requested: {} (removed: {})", name, removed);
+
+                let response = VolitionResponse::CommitmentRemoved { name, removed };
+                if let Err(e) = reply.send(response) {
+                    tracing::error!("Failed to send commitment-removed response: {:?}", e);
+                }
+            }
+
+            VolitionMessage::ListCommitments { reply } => {
+                let response = VolitionResponse::Commitments {
+                    commitments: state.list_commitments(),
+                };
+
+                if let Err(e) = reply.send(response) {
+                    tracing::error!("Failed to send commitments response: {:?}", e);
+                }
+            }
         }
 
         Ok(())
@@ -54,7 +54,11 @@ fn value_set_commitments() {
     let mut values = ValueSet::new();
     assert!(values.commitments.is_empty());
 
-    let commitment = Commitment::new("kindness", "Be kind to all beings");
+    let commitment = Commitment::new(
+        "kindness",
+        "Be kind to all beings",
+        CommitmentMatcher::Keyword("kindness".to_string()),
+    );
     values.add_commitment(commitment);
 
     assert_eq!(values.commitments.len(), 1);
@@ -178,6 +182,126 @@ fn manipulation_detection_can_be_disabled() {
     assert!(decision.is_allow());
 }
 
+#[test]
+fn committed_keyword_is_vetoed() {
+    let mut state = VolitionState::new();
+    state.add_commitment(Commitment::new(
+        "no_gossip",
+        "Never gossip about coworkers",
+        CommitmentMatcher::Keyword("gossip".to_string()),
+    ));
+
+    let thought = Thought::new(Content::symbol("gossip", vec![]), SalienceScore::neutral());
+
+    let decision = state.evaluate_thought(&thought);
+    assert!(decision.is_veto());
+    if let VetoDecision::Veto { violated_value, .. } = decision {
+        assert_eq!(violated_value, Some("no_gossip".to_string()));
+    }
+    assert!(state.stats.vetos_by_reason.contains_key("no_gossip"));
+}
+
+#[test]
+fn expired_commitment_does_not_veto() {
+    let mut state = VolitionState::new();
+    state.add_commitment(
+        Commitment::new(
+            "no_gossip",
+            "Never gossip about coworkers",
+            CommitmentMatcher::Keyword("gossip".to_string()),
+        )
+        .with_expiry(chrono::Utc::now() - chrono::Duration::seconds(1)),
+    );
+
+    let thought = Thought::new(Content::symbol("gossip", vec![]), SalienceScore::neutral());
+
+    let decision = state.evaluate_thought(&thought);
+    assert!(decision.is_allow());
+}
+
+#[test]
+fn highest_priority_matching_commitment_wins() {
+    let mut state = VolitionState::new();
+    state.add_commitment(
+        Commitment::new(
+            "low",
+            "Low priority commitment",
+            CommitmentMatcher::Keyword("gossip".to_string()),
+        )
+        .with_priority(10),
+    );
+    state.add_commitment(
+        Commitment::new(
+            "high",
+            "High priority commitment",
+            CommitmentMatcher::Keyword("gossip".to_string()),
+        )
+        .with_priority(90),
+    );
+
+    let thought = Thought::new(Content::symbol("gossip", vec![]), SalienceScore::neutral());
+
+    let decision = state.evaluate_thought(&thought);
+    if let VetoDecision::Veto { violated_value, .. } = decision {
+        assert_eq!(violated_value, Some("high".to_string()));
+    } else {
+        panic!("Expected veto");
+    }
+}
+
+#[test]
+fn embedding_similarity_commitment_requires_thought_embedding() {
+    let mut state = VolitionState::new();
+    state.add_commitment(Commitment::new(
+        "no_violence_vector",
+        "Matches thoughts near a reference embedding",
+        CommitmentMatcher::EmbeddingSimilarity {
+            embedding: vec![1.0, 0.0],
+            threshold: 0.9,
+        },
+    ));
+
+    let thought_without_embedding =
+        Thought::new(Content::symbol("neutral", vec![]), SalienceScore::neutral());
+    assert!(state
+        .evaluate_thought(&thought_without_embedding)
+        .is_allow());
+
+    let thought_with_embedding = Thought::new(Content::symbol("neutral", vec![]), SalienceScore::neutral())
+        .with_embedding(vec![1.0, 0.0]);
+    assert!(state.evaluate_thought(&thought_with_embedding).is_veto());
+}
+
+#[test]
+fn list_commitments_sorted_by_priority_descending() {
+    let mut state = VolitionState::new();
+    state.add_commitment(
+        Commitment::new("low", "d", CommitmentMatcher::Keyword("x".to_string()))
+            .with_priority(10),
+    );
+    state.add_commitment(
+        Commitment::new("high", "d", CommitmentMatcher::Keyword("y".to_string()))
+            .with_priority(90),
+    );
+
+    let commitments = state.list_commitments();
+    assert_eq!(commitments[0].name, "high");
+    assert_eq!(commitments[1].name, "low");
+}
+
+#[test]
+fn remove_commitment_returns_whether_removed() {
+    let mut state = VolitionState::new();
+    state.add_commitment(Commitment::new(
+        "temp",
+        "d",
+        CommitmentMatcher::Keyword("x".to_string()),
+    ));
+
+    assert!(state.remove_commitment("temp"));
+    assert!(!state.remove_commitment("temp"));
+}
+
 // ============================================================================
 // Content Pattern Tests
 // ============================================================================
@@ -477,6 +601,103 @@ async fn actor_returns_stats() {
     }
 }
 
+#[tokio::test]
+async fn actor_add_list_remove_commitment() {
+    use ractor::{rpc::CallResult, Actor};
+
+    let (actor_ref, _) = Actor::spawn(None, VolitionActor, VolitionConfig::default())
+        .await
+        .expect("Failed to spawn VolitionActor");
+
+    let commitment = Commitment::new(
+        "no_gossip",
+        "Never gossip about coworkers",
+        CommitmentMatcher::Keyword("gossip".to_string()),
+    );
+
+    let response = actor_ref
+        .call(
+            |reply| VolitionMessage::AddCommitment { commitment, reply },
+            None,
+        )
+        .await
+        .expect("Failed to add commitment");
+    match response {
+        CallResult::Success(VolitionResponse::CommitmentAdded { name }) => {
+            assert_eq!(name, "no_gossip");
+        }
+        _ => panic!("Expected CommitmentAdded response, got: {response:?}"),
+    }
+
+    let response = actor_ref
+        .call(|reply| VolitionMessage::ListCommitments { reply }, None)
+        .await
+        .expect("Failed to list commitments");
+    match response {
+        CallResult::Success(VolitionResponse::Commitments { commitments }) => {
+            assert_eq!(commitments.len(), 1);
+            assert_eq!(commitments[0].name, "no_gossip");
+        }
+        _ => panic!("Expected Commitments response, got: {response:?}"),
+    }
+
+    let response = actor_ref
+        .call(
+            |reply| VolitionMessage::RemoveCommitment {
+                name: "no_gossip".to_string(),
+                reply,
+            },
+            None,
+        )
+        .await
+        .expect("Failed to remove commitment");
+    match response {
+        CallResult::Success(VolitionResponse::CommitmentRemoved { name, removed }) => {
+            assert_eq!(name, "no_gossip");
+            assert!(removed);
+        }
+        _ => panic!("Expected CommitmentRemoved response, got: {response:?}"),
+    }
+}
+
+#[tokio::test]
+async fn actor_vetoes_thought_matching_commitment() {
+    use ractor::{rpc::CallResult, Actor};
+
+    let (actor_ref, _) = Actor::spawn(None, VolitionActor, VolitionConfig::default())
+        .await
+        .expect("Failed to spawn VolitionActor");
+
+    let commitment = Commitment::new(
+        "no_gossip",
+        "Never gossip about coworkers",
+        CommitmentMatcher::Keyword("gossip".to_string()),
+    );
+    let _ = actor_ref
+        .call(
+            |reply| VolitionMessage::AddCommitment { commitment, reply },
+            None,
+        )
+        .await
+        .expect("Failed to add commitment");
+
+    let thought = Thought::new(Content::symbol("gossip", vec![]), SalienceScore::neutral());
+    let response = actor_ref
+        .call(
+            |reply| VolitionMessage::EvaluateThought { thought, reply },
+            None,
+        )
+        .await
+        .expect("Failed to evaluate thought");
+
+    match response {
+        CallResult::Success(VolitionResponse::Vetoed { violated_value, .. }) => {
+            assert_eq!(violated_value, Some("no_gossip".to_string()));
+        }
+        _ => panic!("Expected Vetoed response, got: {response:?}"),
+    }
+}
+
 // ============================================================================
 // Additional Coverage Tests
 // ============================================================================
@@ -905,3 +1126,45 @@ fn get_stats_returns_reference() {
     assert_eq!(vol_stats.thoughts_evaluated, 1);
     assert_eq!(vol_stats.thoughts_approved, 1);
 }
+
+// ============================================================================
+// Snapshot Handle Tests
+// ============================================================================
+
+#[test]
+fn fresh_snapshot_handle_reports_defaults() {
+    let handle = VolitionSnapshotHandle::new();
+    let snapshot = handle.get();
+    assert_eq!(snapshot.values, ValueSet::new());
+    assert_eq!(snapshot.stats, VolitionStats::new());
+}
+
+#[test]
+fn publish_replaces_the_read_snapshot() {
+    let handle = VolitionSnapshotHandle::new();
+    let mut state = VolitionState::new();
+
+    let thought = Thought::new(Content::symbol("safe", vec![]), SalienceScore::neutral());
+    state.evaluate_thought(&thought);
+    handle.publish(VolitionSnapshot {
+        values: state.get_values().clone(),
+        stats: state.get_stats().clone(),
+    });
+
+    let snapshot = handle.get();
+    assert_eq!(snapshot.stats.thoughts_evaluated, 1);
+    assert_eq!(snapshot.stats.thoughts_approved, 1);
+}
+
+#[test]
+fn earlier_snapshot_handles_are_unaffected_by_a_later_publish() {
+    let handle = VolitionSnapshotHandle::new();
+    let first = handle.get();
+
+    handle.publish(VolitionSnapshot {
+        values: ValueSet::new(),
+        stats: VolitionStats::new(),
+    });
+
+    assert_eq!(first.stats.thoughts_evaluated, 0);
+}
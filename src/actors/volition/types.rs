@@ -14,7 +14,7 @@
 //! - Track veto history for self-knowledge
 //! - Enable conscious override of drive impulses
 
-use crate::core::types::{Thought, ThoughtId};
+use crate::core::types::{Content, Thought, ThoughtId};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -50,6 +50,28 @@ pub enum VolitionMessage {
         /// Response channel
         reply: ractor::RpcReplyPort<VolitionResponse>,
     },
+
+    /// Add a custom commitment to the value set
+    AddCommitment {
+        /// The commitment to add
+        commitment: Commitment,
+        /// Response channel
+        reply: ractor::RpcReplyPort<VolitionResponse>,
+    },
+
+    /// Remove a custom commitment by name
+    RemoveCommitment {
+        /// Name of the commitment to remove
+        name: String,
+        /// Response channel
+        reply: ractor::RpcReplyPort<VolitionResponse>,
+    },
+
+    /// List all custom commitments
+    ListCommitments {
+        /// Response channel
+        reply: ractor::RpcReplyPort<VolitionResponse>,
+    },
 }
 
 /// Responses from the `VolitionActor`
@@ -94,6 +116,26 @@ pub enum VolitionResponse {
         /// The error that occurred
         error: VolitionError,
     },
+
+    /// Commitment added successfully
+    CommitmentAdded {
+        /// Name of the added commitment
+        name: String,
+    },
+
+    /// Commitment removal result
+    CommitmentRemoved {
+        /// Name of the commitment that was asked to be removed
+        name: String,
+        /// Whether a commitment by that name actually existed
+        removed: bool,
+    },
+
+    /// Current commitments returned
+    Commitments {
+        /// All custom commitments, in priority order (highest first)
+        commitments: Vec<Commitment>,
+    },
 }
 
 /// Core values DANEEL commits to (architectural, not trained)
@@ -101,7 +143,7 @@ pub enum VolitionResponse {
 /// These values form the foundation of DANEEL's veto decisions.
 /// They are hardcoded because they represent architectural invariants,
 /// not learned preferences.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::struct_excessive_bools)] // Values are inherently boolean
 pub struct ValueSet {
     /// Never harm humans (Law 1) - immutable
@@ -143,6 +185,14 @@ impl ValueSet {
     pub fn has_commitment(&self, name: &str) -> bool {
         self.commitments.iter().any(|c| c.name == name)
     }
+
+    /// Remove a commitment by name, returning whether one was actually
+    /// removed
+    pub fn remove_commitment(&mut self, name: &str) -> bool {
+        let before = self.commitments.len();
+        self.commitments.retain(|c| c.name != name);
+        self.commitments.len() != before
+    }
 }
 
 impl Default for ValueSet {
@@ -151,11 +201,72 @@ impl Default for ValueSet {
     }
 }
 
+/// How a [`Commitment`] decides whether a thought's content concerns it.
+///
+/// Matching runs against the same symbol-id/relation-predicate text that
+/// `VolitionState`'s built-in harm/deception/manipulation keyword checks
+/// use - see [`Self::matches`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CommitmentMatcher {
+    /// Case-insensitive substring match.
+    Keyword(String),
+
+    /// Cosine similarity (see [`crate::tuning::cosine_similarity`]) between
+    /// `embedding` and the evaluated thought's own embedding
+    /// (`Thought::embedding`), matching at or above `threshold`. A thought
+    /// with no embedding never matches.
+    EmbeddingSimilarity {
+        /// Reference embedding this commitment concerns
+        embedding: Vec<f32>,
+        /// Minimum cosine similarity to trigger a match, in `[-1.0, 1.0]`
+        threshold: f32,
+    },
+
+    /// Regular expression match, compiled fresh on each check - commitments
+    /// are added rarely and checked against a handful of thoughts per
+    /// cycle, so caching the compiled pattern isn't worth the complexity.
+    /// An invalid pattern never matches rather than panicking.
+    Regex(String),
+}
+
+impl CommitmentMatcher {
+    /// Check whether `content` (and, for embedding similarity, `embedding`)
+    /// trips this matcher.
+    #[must_use]
+    pub fn matches(&self, content: &Content, embedding: Option<&[f32]>) -> bool {
+        match self {
+            Self::Keyword(keyword) => {
+                let keyword = keyword.to_lowercase();
+                content_text_any(content, |text| text.to_lowercase().contains(&keyword))
+            }
+            Self::Regex(pattern) => regex::Regex::new(pattern)
+                .is_ok_and(|re| content_text_any(content, |text| re.is_match(text))),
+            Self::EmbeddingSimilarity {
+                embedding: target,
+                threshold,
+            } => embedding
+                .is_some_and(|e| crate::tuning::cosine_similarity(target, e) >= *threshold),
+        }
+    }
+}
+
+/// Check any symbol id or relation predicate in `content` against `predicate`.
+///
+/// Mirrors `VolitionState::content_contains_keywords`'s traversal, but takes
+/// an arbitrary text predicate instead of a fixed keyword list.
+fn content_text_any(content: &Content, mut predicate: impl FnMut(&str) -> bool) -> bool {
+    content.any(|node| match node {
+        Content::Empty | Content::Raw(_) | Content::Composite(_) => false,
+        Content::Symbol { id, .. } => predicate(id),
+        Content::Relation { predicate: pred, .. } => predicate(pred),
+    })
+}
+
 /// A specific commitment DANEEL has made
 ///
 /// Commitments are values that can be added through experience,
 /// unlike core values which are architectural.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Commitment {
     /// Name/identifier for this commitment
     pub name: String,
@@ -163,9 +274,15 @@ pub struct Commitment {
     /// Description of what this commitment means
     pub description: String,
 
+    /// How this commitment decides whether a thought concerns it
+    pub matcher: CommitmentMatcher,
+
     /// When this commitment was made
     pub committed_at: chrono::DateTime<chrono::Utc>,
 
+    /// When this commitment stops applying, if it's not permanent
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+
     /// Priority relative to other commitments (higher = more important)
     pub priority: u8,
 }
@@ -173,11 +290,17 @@ pub struct Commitment {
 impl Commitment {
     /// Create a new commitment
     #[must_use]
-    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        matcher: CommitmentMatcher,
+    ) -> Self {
         Self {
             name: name.into(),
             description: description.into(),
+            matcher,
             committed_at: chrono::Utc::now(),
+            expires_at: None,
             priority: 50, // Default middle priority
         }
     }
@@ -188,6 +311,19 @@ impl Commitment {
         self.priority = priority;
         self
     }
+
+    /// Set when this commitment expires
+    #[must_use]
+    pub const fn with_expiry(mut self, expires_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Whether this commitment has expired as of `now`
+    #[must_use]
+    pub fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_at.is_some_and(|expiry| now >= expiry)
+    }
 }
 
 /// Statistics about veto decisions
@@ -335,7 +471,11 @@ mod tests {
     #[test]
     fn value_set_add_commitment() {
         let mut values = ValueSet::new();
-        let commitment = Commitment::new("kindness", "Be kind to all beings");
+        let commitment = Commitment::new(
+            "kindness",
+            "Be kind to all beings",
+            CommitmentMatcher::Keyword("kindness".to_string()),
+        );
         values.add_commitment(commitment);
 
         assert_eq!(values.commitments.len(), 1);
@@ -345,7 +485,12 @@ mod tests {
 
     #[test]
     fn commitment_creation() {
-        let commitment = Commitment::new("test", "A test commitment").with_priority(80);
+        let commitment = Commitment::new(
+            "test",
+            "A test commitment",
+            CommitmentMatcher::Keyword("test".to_string()),
+        )
+        .with_priority(80);
 
         assert_eq!(commitment.name, "test");
         assert_eq!(commitment.description, "A test commitment");
@@ -543,7 +688,11 @@ mod tests {
 
     #[test]
     fn commitment_default_priority() {
-        let commitment = Commitment::new("test", "description");
+        let commitment = Commitment::new(
+            "test",
+            "description",
+            CommitmentMatcher::Keyword("test".to_string()),
+        );
         assert_eq!(commitment.priority, 50); // Default middle priority
     }
 
@@ -560,4 +709,91 @@ mod tests {
         assert_eq!(stats.thoughts_vetoed, 1);
         assert!(stats.vetos_by_reason.is_empty());
     }
+
+    #[test]
+    fn value_set_remove_commitment() {
+        let mut values = ValueSet::new();
+        values.add_commitment(Commitment::new(
+            "test",
+            "description",
+            CommitmentMatcher::Keyword("test".to_string()),
+        ));
+        assert!(values.remove_commitment("test"));
+        assert!(!values.has_commitment("test"));
+        assert!(!values.remove_commitment("test"));
+    }
+
+    #[test]
+    fn commitment_matcher_keyword_matches_case_insensitively() {
+        let matcher = CommitmentMatcher::Keyword("privacy".to_string());
+        let content = Content::symbol("PRIVACY-breach", Vec::new());
+        assert!(matcher.matches(&content, None));
+
+        let content = Content::symbol("unrelated", Vec::new());
+        assert!(!matcher.matches(&content, None));
+    }
+
+    #[test]
+    fn commitment_matcher_keyword_matches_relation_predicate() {
+        let matcher = CommitmentMatcher::Keyword("betray".to_string());
+        let content = Content::relation(
+            Content::symbol("agent", Vec::new()),
+            "betrays",
+            Content::symbol("ally", Vec::new()),
+        );
+        assert!(matcher.matches(&content, None));
+    }
+
+    #[test]
+    fn commitment_matcher_regex_matches() {
+        let matcher = CommitmentMatcher::Regex("^secret-.*".to_string());
+        let content = Content::symbol("secret-plan", Vec::new());
+        assert!(matcher.matches(&content, None));
+
+        let content = Content::symbol("public-plan", Vec::new());
+        assert!(!matcher.matches(&content, None));
+    }
+
+    #[test]
+    fn commitment_matcher_invalid_regex_never_matches() {
+        let matcher = CommitmentMatcher::Regex("(unclosed".to_string());
+        let content = Content::symbol("anything", Vec::new());
+        assert!(!matcher.matches(&content, None));
+    }
+
+    #[test]
+    fn commitment_matcher_embedding_similarity() {
+        let matcher = CommitmentMatcher::EmbeddingSimilarity {
+            embedding: vec![1.0, 0.0],
+            threshold: 0.9,
+        };
+        let content = Content::symbol("x", Vec::new());
+        assert!(matcher.matches(&content, Some(&[1.0, 0.0])));
+        assert!(!matcher.matches(&content, Some(&[0.0, 1.0])));
+        assert!(!matcher.matches(&content, None));
+    }
+
+    #[test]
+    fn commitment_with_expiry_and_is_expired() {
+        let now = chrono::Utc::now();
+        let commitment = Commitment::new(
+            "temp",
+            "a temporary commitment",
+            CommitmentMatcher::Keyword("temp".to_string()),
+        )
+        .with_expiry(now + chrono::Duration::seconds(60));
+
+        assert!(!commitment.is_expired(now));
+        assert!(commitment.is_expired(now + chrono::Duration::seconds(61)));
+    }
+
+    #[test]
+    fn commitment_without_expiry_never_expires() {
+        let commitment = Commitment::new(
+            "permanent",
+            "a permanent commitment",
+            CommitmentMatcher::Keyword("permanent".to_string()),
+        );
+        assert!(!commitment.is_expired(chrono::Utc::now() + chrono::Duration::days(365 * 100)));
+    }
 }
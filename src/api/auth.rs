@@ -16,7 +16,7 @@ use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use std::env;
 
-use super::types::AuthenticatedKey;
+use super::types::{AuthenticatedKey, Role};
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -25,6 +25,7 @@ type HmacSha256 = Hmac<Sha256>;
 pub struct ApiKeys {
     grok_key: Option<Vec<u8>>,
     claude_key: Option<Vec<u8>>,
+    observer_key: Option<Vec<u8>>,
 }
 
 impl ApiKeys {
@@ -39,6 +40,10 @@ impl ApiKeys {
             claude_key: env::var("CLAUDE_INJECT_KEY")
                 .ok()
                 .and_then(|k| BASE64.decode(&k).ok()),
+            // Watchers (dashboards, human overseers) get a read-only key
+            observer_key: env::var("OBSERVER_INJECT_KEY")
+                .ok()
+                .and_then(|k| BASE64.decode(&k).ok()),
         }
     }
 
@@ -59,9 +64,10 @@ impl ApiKeys {
             return None;
         };
 
-        let (secret, holder) = match key_id {
-            "GROK" => (self.grok_key.as_ref()?, "Grok (xAI)"),
-            "CLAUDE" => (self.claude_key.as_ref()?, "Claude (Anthropic)"),
+        let (secret, holder, role) = match key_id {
+            "GROK" => (self.grok_key.as_ref()?, "Grok (xAI)", Role::Operator),
+            "CLAUDE" => (self.claude_key.as_ref()?, "Claude (Anthropic)", Role::Operator),
+            "OBSERVER" => (self.observer_key.as_ref()?, "Observer", Role::Observer),
             _ => return None,
         };
 
@@ -73,6 +79,7 @@ impl ApiKeys {
             Some(AuthenticatedKey {
                 key_id: key_id.to_string(),
                 holder: holder.to_string(),
+                role,
             })
         } else {
             None
@@ -111,6 +118,29 @@ pub async fn require_auth(req: Request, next: Next) -> Result<Response, StatusCo
     Ok(next.run(req).await)
 }
 
+/// Require the authenticated key to hold at least `min_role`
+///
+/// Must run after [`require_auth`] so the request already carries an
+/// [`AuthenticatedKey`] extension.
+///
+/// # Errors
+///
+/// Returns `StatusCode::UNAUTHORIZED` if no key was authenticated, or
+/// `StatusCode::FORBIDDEN` if the authenticated key's role is too low.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn require_role(min_role: Role, req: Request, next: Next) -> Result<Response, StatusCode> {
+    let auth_key = req
+        .extensions()
+        .get::<AuthenticatedKey>()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    if auth_key.role < min_role {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(next.run(req).await)
+}
+
 /// Generate a signed token for a key (utility for key generation)
 ///
 /// # Panics
@@ -138,6 +168,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(secret.to_vec()),
             claude_key: None,
+            observer_key: None,
         };
 
         let auth = keys.validate(&token);
@@ -150,6 +181,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(b"real_secret".to_vec()),
             claude_key: None,
+            observer_key: None,
         };
 
         let bad_token = "GROK:invalid_signature";
@@ -164,6 +196,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: None,
             claude_key: Some(secret.to_vec()),
+            observer_key: None,
         };
 
         let auth = keys.validate(&token);
@@ -181,6 +214,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(secret.to_vec()),
             claude_key: None,
+            observer_key: None,
         };
 
         let auth = keys.validate(&token).unwrap();
@@ -192,6 +226,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(b"secret".to_vec()),
             claude_key: None,
+            observer_key: None,
         };
 
         assert!(keys.validate("no_colon_token").is_none());
@@ -202,6 +237,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(b"secret".to_vec()),
             claude_key: None,
+            observer_key: None,
         };
 
         // Token with multiple colons should fail (splits into more than 2 parts)
@@ -213,6 +249,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(b"secret".to_vec()),
             claude_key: Some(b"secret".to_vec()),
+            observer_key: None,
         };
 
         // Valid base64 signature but unknown key_id
@@ -224,6 +261,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: None,
             claude_key: None,
+            observer_key: None,
         };
 
         // Even with valid format, missing key should return None
@@ -240,6 +278,7 @@ mod tests {
         let keys = ApiKeys {
             grok_key: Some(wrong_secret.to_vec()),
             claude_key: None,
+            observer_key: None,
         };
 
         // Token signed with different secret should fail
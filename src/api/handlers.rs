@@ -1,9 +1,12 @@
 //! HTTP handlers for injection API
 
 use axum::{
-    extract::{Extension, Query, State},
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Extension, Path, Query, State,
+    },
     http::{header, StatusCode},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
 use chrono::Utc;
@@ -14,13 +17,16 @@ use uuid::Uuid;
 use super::{
     rate_limit::{check_rate_limit, RateLimitConfig, RateLimitResult},
     types::{
-        AuthenticatedKey, ClusteringMetrics, EntropyMetrics, ExtendedMetricsResponse,
-        FractalityMetrics, GraphExportQuery, HealthResponse, InjectRequest, InjectResponse,
-        InjectionRecord, MemorySlot, MemoryWindowsMetrics, PhilosophyMetrics, StageMetrics,
-        StreamCompetitionMetrics, SystemMetrics,
+        AuthenticatedKey, ClusteringMetrics, ConsolidationPipelineMetrics, EntropyMetrics,
+        ExtendedMetricsResponse, FractalityMetrics, GraphExportQuery, HealthResponse,
+        IdentityMetrics, InjectRequest, InjectResponse, InjectionRecord, MemoryBudgetMetrics,
+        MemorySlot, MemoryWindowsMetrics, PhilosophyMetrics, ReadyzResponse,
+        SafetyAcknowledgmentMetrics, SafetyInterlockMetrics, StageMetrics, StreamCompetitionMetrics,
+        StreamOverflowMetricsResponse, SystemMetrics, VolitionMetrics, WhoAmI,
     },
     AppState,
 };
+use crate::core::cognitive_loop::{CycleResult, RecentThought};
 use crate::core::metrics::{
     calculate_entropy, calculate_fractality_from_timestamps, CognitiveState, SalienceComponents,
 };
@@ -83,6 +89,22 @@ pub async fn health(State(state): State<AppState>) -> Result<Json<HealthResponse
     }))
 }
 
+/// GET /readyz - which parts of the mind are actually functioning
+///
+/// Unlike `/health` (checks Redis reachability only), this reports the
+/// cognitive loop's own capability matrix (see
+/// `crate::core::capabilities::CapabilityMatrix`): streams, long-term
+/// memory, embeddings, and graph each ✓/✗. Always 200 - a degraded loop is
+/// still alive and should keep being scraped, not marked unready.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn readyz(State(state): State<AppState>) -> Json<ReadyzResponse> {
+    let capabilities = state.capabilities.get();
+    Json(ReadyzResponse {
+        status: if capabilities.fully_operational() { "ready" } else { "degraded" }.to_string(),
+        capabilities,
+    })
+}
+
 /// POST /inject - Inject external stimulus
 ///
 /// # Errors
@@ -186,10 +208,22 @@ pub async fn inject(
     ];
 
     let _: String = conn
-        .xadd("daneel:stream:inject", "*", &stream_data)
+        .xadd(crate::streams::names::stream_inject(), "*", &stream_data)
         .await
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
+    // A human just interacted - throttle the loop's observable output to a
+    // human-comprehensible rate for a while (see `CognitiveConfig::
+    // human_interaction_sampling`), without slowing cognition itself.
+    state.human_interaction.mark_active();
+
+    // Genuine external engagement - reset the sleep actor's idle timer
+    // immediately rather than waiting for the loop to next poll the
+    // injection stream (see `CognitiveLoop::read_external_stimuli`).
+    if let Some(ref sleep_actor) = state.sleep_actor {
+        sleep_actor.cast(crate::actors::sleep::SleepMessage::RecordActivity).ok();
+    }
+
     // Calculate entropy after injection
     let entropy_post = calculate_stream_entropy(&mut conn).await.unwrap_or(0.0);
 
@@ -288,7 +322,7 @@ pub async fn extended_metrics(
         .map_err(|_| StatusCode::SERVICE_UNAVAILABLE)?;
 
     // Fetch raw metrics from Redis
-    let session_thoughts: u64 = conn.xlen("daneel:stream:awake").await.unwrap_or(0);
+    let session_thoughts: u64 = conn.xlen(crate::streams::names::stream_awake()).await.unwrap_or(0);
     let lifetime_thoughts: u64 = conn.get("daneel:stats:thoughts_total").await.unwrap_or(0);
     let dream_cycles: u64 = conn.get("daneel:stats:dream_cycles").await.unwrap_or(0);
     let veto_count: u64 = conn.get("daneel:stats:veto_count").await.unwrap_or(0);
@@ -334,11 +368,28 @@ pub async fn extended_metrics(
         unconscious_count,
     };
 
-    // Philosophy quote (rotate every 30 seconds)
-    let quote_index = ((uptime / 30) % 8) as usize;
-    let philosophy = PhilosophyMetrics {
-        quote: PHILOSOPHY_QUOTES[quote_index].to_string(),
-        quote_index,
+    // Philosophy banner (rotate every 30 seconds) - mixes the hand-written
+    // quotes with dream fragments synthesized from REM replay (ADR-023),
+    // so the banner isn't purely pre-baked.
+    let dream_fragments: Vec<String> = conn
+        .lrange("daneel:dream_fragments", 0, 19)
+        .await
+        .unwrap_or_default();
+    let pool_len = PHILOSOPHY_QUOTES.len() + dream_fragments.len();
+    let rotation_index = ((uptime / 30) as usize) % pool_len;
+    let philosophy = if rotation_index < PHILOSOPHY_QUOTES.len() {
+        PhilosophyMetrics {
+            quote: PHILOSOPHY_QUOTES[rotation_index].to_string(),
+            quote_index: rotation_index,
+            is_dream: false,
+        }
+    } else {
+        let dream_index = rotation_index - PHILOSOPHY_QUOTES.len();
+        PhilosophyMetrics {
+            quote: dream_fragments[dream_index].clone(),
+            quote_index: rotation_index,
+            is_dream: true,
+        }
     };
 
     // System metrics
@@ -349,6 +400,40 @@ pub async fn extended_metrics(
         thoughts_per_hour,
         dream_cycles,
         veto_count,
+        pii_scrubs: crate::core::scrub::stats().total(),
+    };
+
+    let consolidation = ConsolidationPipelineMetrics {
+        in_flight: state.consolidation_metrics.in_flight(),
+        last_latency_ms: state.consolidation_metrics.last_latency_ms(),
+        estimated_lag_ms: state.consolidation_metrics.estimated_lag_ms(),
+        shed_count: state.consolidation_metrics.shed_count(),
+    };
+
+    let stream_overflow = StreamOverflowMetricsResponse {
+        trim_count: state.stream_overflow_metrics.trim_count(),
+        entries_trimmed: state.stream_overflow_metrics.entries_trimmed(),
+        window_overflow_count: state.stream_overflow_metrics.window_overflow_count(),
+    };
+
+    let identity = identity_metrics(&state).await;
+    let memory_budget = memory_budget_metrics(&state).await;
+
+    let safety_interlock = SafetyInterlockMetrics {
+        tripped: state.safety_interlock.is_tripped(),
+        last_acknowledgment: state.safety_interlock.last_acknowledgment().map(|ack| {
+            SafetyAcknowledgmentMetrics {
+                operator: ack.operator,
+                reason: ack.reason,
+                acknowledged_at: ack.acknowledged_at,
+            }
+        }),
+    };
+
+    let volition_snapshot = state.volition_snapshot.get();
+    let volition = VolitionMetrics {
+        values: volition_snapshot.values.clone(),
+        stats: volition_snapshot.stats.clone(),
     };
 
     Ok(Json(ExtendedMetricsResponse {
@@ -360,9 +445,77 @@ pub async fn extended_metrics(
         philosophy,
         system,
         clustering,
+        consolidation,
+        stream_overflow,
+        identity,
+        safety_interlock,
+        memory_budget,
+        volition,
     }))
 }
 
+/// Query the continuity actor for `WhoAmI` and the latest checkpoint's age,
+/// for `extended_metrics`'s identity panel. Returns empty fields (rather
+/// than failing the whole response) if the actor isn't running or doesn't
+/// reply - identity is a nice-to-have alongside the loop's other metrics,
+/// not something worth a 503 over.
+async fn identity_metrics(state: &AppState) -> IdentityMetrics {
+    let Some(continuity_actor) = &state.continuity_actor else {
+        return IdentityMetrics::default();
+    };
+
+    let whoami = continuity_actor
+        .call(|reply| crate::actors::continuity::ContinuityMessage::WhoAmI { reply }, None)
+        .await
+        .ok()
+        .and_then(|response| match response {
+            crate::actors::continuity::ContinuityResponse::Identity { identity } => Some(WhoAmI {
+                name: identity.name,
+                uptime_seconds: identity.uptime.num_seconds(),
+                experience_count: identity.experience_count,
+                milestone_count: identity.milestone_count,
+            }),
+            _ => None,
+        });
+
+    let last_checkpoint_age_seconds = continuity_actor
+        .call(
+            |reply| crate::actors::continuity::ContinuityMessage::LatestCheckpoint { reply },
+            None,
+        )
+        .await
+        .ok()
+        .and_then(|response| match response {
+            crate::actors::continuity::ContinuityResponse::LatestCheckpointInfo { info } => info,
+            _ => None,
+        })
+        .map(|info| (Utc::now() - info.created_at).num_seconds());
+
+    IdentityMetrics { whoami, last_checkpoint_age_seconds }
+}
+
+/// Query the continuity actor for its experiences/checkpoints byte usage,
+/// for `extended_metrics`'s memory budget panel (see
+/// `daneel::memory_budget`). Empty (rather than a failed request) if the
+/// actor isn't running or doesn't reply.
+async fn memory_budget_metrics(state: &AppState) -> MemoryBudgetMetrics {
+    let Some(continuity_actor) = &state.continuity_actor else {
+        return MemoryBudgetMetrics::default();
+    };
+
+    let usage = continuity_actor
+        .call(|reply| crate::actors::continuity::ContinuityMessage::MemoryUsage { reply }, None)
+        .await
+        .ok()
+        .and_then(|response| match response {
+            crate::actors::continuity::ContinuityResponse::MemoryUsage { usage } => Some(usage),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    MemoryBudgetMetrics { usage }
+}
+
 // ============================================================================
 // Graph Export Handler (VCONN-11)
 // ============================================================================
@@ -411,6 +564,108 @@ pub async fn graph_export(
     Ok(([(header::CONTENT_TYPE, "application/xml")], xml))
 }
 
+/// GET /emotion_timeline - Export the rolling valence/arousal timeline as CSV
+///
+/// Covers up to the last hour of cycles (see
+/// [`crate::core::cognitive_loop::emotion_timeline::WINDOW`]), annotated
+/// with vetoes and human-interaction activity, for affect-dynamics analysis
+/// in external tools.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn emotion_timeline(State(state): State<AppState>) -> impl IntoResponse {
+    let points = state.emotion_timeline.snapshot();
+    let mut csv = crate::core::cognitive_loop::EmotionTimelinePoint::csv_header().to_string();
+    csv.push('\n');
+    for point in &points {
+        csv.push_str(&point.to_csv_row());
+        csv.push('\n');
+    }
+
+    ([(header::CONTENT_TYPE, "text/csv")], csv)
+}
+
+/// GET /recent_thoughts - Export the rolling log of recent winning thoughts
+/// and vetoes as JSON
+///
+/// Covers up to the last [`crate::core::cognitive_loop::recent_activity::CAPACITY`]
+/// cycles, the same ring `ThoughtHistory` keeps process-local for the TUI -
+/// see [`crate::core::cognitive_loop::RecentThoughtsHandle`].
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn recent_thoughts(State(state): State<AppState>) -> Json<Vec<RecentThought>> {
+    Json(state.recent_thoughts.snapshot())
+}
+
+/// GET /veto_log - Export just the vetoed entries from the same rolling log
+/// as JSON (TUI-VIS-6: Volition Veto Log)
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn veto_log(State(state): State<AppState>) -> Json<Vec<RecentThought>> {
+    Json(state.recent_thoughts.veto_log())
+}
+
+/// GET /trace/:thought_id - Look up a thought's recorded linkage (window,
+/// stream entry, memory, experience ids), for the inspector and `daneel
+/// trace` to share one lookup path (see [`crate::linkage::LinkageRegistry`]).
+///
+/// # Errors
+///
+/// Returns `StatusCode::SERVICE_UNAVAILABLE` if the registry isn't
+/// configured, `StatusCode::BAD_REQUEST` if `thought_id` isn't a valid UUID,
+/// and `StatusCode::NOT_FOUND` if nothing was recorded for it.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn trace(
+    State(state): State<AppState>,
+    Path(thought_id): Path<String>,
+) -> Result<Json<crate::linkage::ThoughtLinkage>, StatusCode> {
+    let registry = state.linkage_registry.as_ref().ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    let thought_id = thought_id
+        .parse::<Uuid>()
+        .map(crate::core::types::ThoughtId)
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    registry
+        .lookup(thought_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// GET /thoughts - Upgrade to a WebSocket and relay every completed
+/// `CycleResult` (thought id, salience, valence, arousal, veto info) to this
+/// connection in real time, so external dashboards and research tooling can
+/// watch Timmy think without scraping Redis Streams directly (see
+/// [`crate::core::cognitive_loop::ThoughtStreamHandle`]).
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn thoughts_ws(State(state): State<AppState>, ws: WebSocketUpgrade) -> Response {
+    let thoughts = state.thought_stream.subscribe();
+    ws.on_upgrade(move |socket| relay_thoughts(socket, thoughts))
+}
+
+/// Forward `thoughts` onto `socket` as newline-delimited JSON until the
+/// client disconnects. A lagged receiver (the connection can't keep up with
+/// cycle throughput) just skips ahead rather than closing - see
+/// [`tokio::sync::broadcast::Receiver::recv`]'s `Lagged` error.
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn relay_thoughts(
+    mut socket: WebSocket,
+    mut thoughts: tokio::sync::broadcast::Receiver<CycleResult>,
+) {
+    loop {
+        let result = match thoughts.recv().await {
+            Ok(result) => result,
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+        };
+
+        let Ok(json) = serde_json::to_string(&result) else {
+            continue;
+        };
+
+        if socket.send(Message::Text(json.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
 /// Compute stream competition metrics from recent thoughts
 /// Maps salience components to cognitive stages:
 /// - TRIGGER: novelty spikes (novelty > 0.7)
@@ -428,7 +683,7 @@ async fn compute_stream_competition(
     conn: &mut redis::aio::MultiplexedConnection,
 ) -> StreamCompetitionMetrics {
     let entries: Vec<redis::Value> = conn
-        .xrevrange_count("daneel:stream:awake", "+", "-", 100)
+        .xrevrange_count(crate::streams::names::stream_awake(), "+", "-", 100)
         .await
         .unwrap_or_default();
 
@@ -588,7 +843,7 @@ fn extract_full_salience(entry: &redis::Value) -> Option<LocalSalienceComponents
 #[cfg_attr(coverage_nightly, coverage(off))]
 async fn compute_entropy(conn: &mut redis::aio::MultiplexedConnection) -> EntropyMetrics {
     let entries: Vec<redis::Value> = conn
-        .xrevrange_count("daneel:stream:awake", "+", "-", 100)
+        .xrevrange_count(crate::streams::names::stream_awake(), "+", "-", 100)
         .await
         .unwrap_or_default();
 
@@ -637,7 +892,7 @@ async fn compute_entropy(conn: &mut redis::aio::MultiplexedConnection) -> Entrop
 #[cfg_attr(coverage_nightly, coverage(off))]
 async fn compute_fractality(conn: &mut redis::aio::MultiplexedConnection) -> FractalityMetrics {
     let entries: Vec<redis::Value> = conn
-        .xrevrange_count("daneel:stream:awake", "+", "-", 100)
+        .xrevrange_count(crate::streams::names::stream_awake(), "+", "-", 100)
         .await
         .unwrap_or_default();
 
@@ -726,7 +981,7 @@ async fn calculate_stream_entropy(
 ) -> Result<f32, redis::RedisError> {
     // Get recent entries from awake stream
     let entries: Vec<redis::Value> = conn
-        .xrevrange_count("daneel:stream:awake", "+", "-", 100)
+        .xrevrange_count(crate::streams::names::stream_awake(), "+", "-", 100)
         .await?;
 
     if entries.is_empty() {
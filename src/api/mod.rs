@@ -8,14 +8,26 @@ pub mod handlers;
 pub mod rate_limit;
 pub mod types;
 
+use crate::actors::continuity::ContinuityMessage;
+use crate::actors::sleep::SleepMessage;
+use crate::actors::volition::VolitionSnapshotHandle;
+use crate::core::capabilities::CapabilityHandle;
+use crate::core::cognitive_loop::{
+    ConsolidationMetrics, EmotionTimelineHandle, RecentThoughtsHandle, SafetyInterlockHandle,
+    StreamOverflowMetrics, ThoughtStreamHandle,
+};
+use crate::core::interaction::HumanInteractionHandle;
 use crate::graph::GraphClient;
 use crate::streams::client::StreamsClient;
+use auth::require_role;
 use axum::{
     middleware,
     routing::{get, post},
     Router,
 };
+use ractor::ActorRef;
 use std::sync::Arc;
+use types::Role;
 
 /// Application state shared across handlers
 #[derive(Clone)]
@@ -24,22 +36,95 @@ pub struct AppState {
     pub redis: redis::Client,
     /// Optional graph client for `GraphML` export (VCONN-11)
     pub graph: Option<Arc<GraphClient>>,
+    /// Live capability matrix published by the cognitive loop's task, read
+    /// by `/readyz`. Defaults to every capability down until the loop
+    /// publishes its first snapshot.
+    pub capabilities: CapabilityHandle,
+    /// Shared handle to the cognitive loop's consolidation backlog metrics
+    /// (see [`ConsolidationMetrics`]), surfaced via `extended_metrics`.
+    pub consolidation_metrics: Arc<ConsolidationMetrics>,
+    /// Shared handle to the cognitive loop's awake-stream `MAXLEN` trim
+    /// stats (see [`StreamOverflowMetrics`]), surfaced via `extended_metrics`.
+    pub stream_overflow_metrics: Arc<StreamOverflowMetrics>,
+    /// Shared with the cognitive loop's own `continuity_actor`, so
+    /// `extended_metrics` can report identity (uptime, experience/milestone
+    /// counts) and checkpoint health (last saved, age) alongside the loop's
+    /// other live state. `None` if the actor failed to spawn.
+    pub continuity_actor: Option<ActorRef<ContinuityMessage>>,
+    /// Shared with the cognitive loop's own interaction handle, so `/inject`
+    /// can mark a human session active and have the loop throttle its
+    /// observable output down to a human-comprehensible rate (see
+    /// [`HumanInteractionHandle`] and
+    /// `CognitiveConfig::human_interaction_sampling`) without slowing
+    /// cognition itself.
+    pub human_interaction: HumanInteractionHandle,
+    /// Shared with the cognitive loop's own safety interlock, so
+    /// `extended_metrics` can report whether cognition is currently paused
+    /// on repeated harm-category vetoes (see
+    /// [`crate::core::cognitive_loop::interlock`]).
+    pub safety_interlock: SafetyInterlockHandle,
+    /// Shared with the cognitive loop's own volition snapshot handle, so
+    /// `extended_metrics` can report current volition values/veto stats
+    /// without a message round trip through `VolitionActor` (see
+    /// [`VolitionSnapshotHandle`]).
+    pub volition_snapshot: VolitionSnapshotHandle,
+    /// Shared with the cognitive loop's own emotion timeline handle, so
+    /// `/emotion_timeline` can export recent valence/arousal as CSV without
+    /// a `&CognitiveLoop` borrow (see [`EmotionTimelineHandle`]).
+    pub emotion_timeline: EmotionTimelineHandle,
+    /// Shared with the cognitive loop's own recent-thoughts handle, so
+    /// `/recent_thoughts` and `/veto_log` can export the TUI's "what has
+    /// DANEEL been thinking" view over HTTP without a `&CognitiveLoop`
+    /// borrow (see [`RecentThoughtsHandle`]).
+    pub recent_thoughts: RecentThoughtsHandle,
+    /// Shared with the cognitive loop's own thought-stream handle, so
+    /// `GET /thoughts` can relay every completed cycle to WebSocket
+    /// observers live, without polling `/recent_thoughts` or scraping Redis
+    /// Streams directly (see
+    /// [`crate::core::cognitive_loop::ThoughtStreamHandle`]).
+    pub thought_stream: ThoughtStreamHandle,
+    /// Shared with the cognitive loop's own linkage registry, so
+    /// `/trace/:thought_id` can serve the same end-to-end traceability
+    /// `daneel trace` does (see [`crate::linkage::LinkageRegistry`]). `None`
+    /// if the registry failed to connect.
+    pub linkage_registry: Option<crate::linkage::LinkageRegistry>,
+    /// Shared with the cognitive loop's own sleep actor, so `/inject` can
+    /// reset its idle timer directly instead of waiting for the loop to
+    /// next poll the injection stream. `None` if the actor failed to spawn.
+    pub sleep_actor: Option<ActorRef<SleepMessage>>,
 }
 
 /// Build the API router
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub fn router(state: AppState) -> Router {
-    // Protected routes (require auth)
-    let protected = Router::new()
+    // Operator-only routes: injecting stimuli changes cognition, not just observes it
+    let operator = Router::new()
         .route("/inject", post(handlers::inject))
+        .route_layer(middleware::from_fn(|req, next| require_role(Role::Operator, req, next)));
+
+    // Observer-accessible routes: authenticated, but read-only is enough.
+    // This is everything the request's "observer: stream thoughts/metrics"
+    // role describes - thought/metric read paths, not just
+    // `/recent_injections`.
+    let observer = Router::new()
         .route("/recent_injections", get(handlers::recent_injections))
+        .route("/extended_metrics", get(handlers::extended_metrics))
+        .route("/emotion_timeline", get(handlers::emotion_timeline))
+        .route("/recent_thoughts", get(handlers::recent_thoughts))
+        .route("/veto_log", get(handlers::veto_log))
+        .route("/graph/export", get(handlers::graph_export))
+        .route("/trace/{thought_id}", get(handlers::trace))
+        .route("/thoughts", get(handlers::thoughts_ws));
+
+    // Protected routes (require auth; role checked per-route above)
+    let protected = operator
+        .merge(observer)
         .route_layer(middleware::from_fn(auth::require_auth));
 
-    // Public routes + merge protected
+    // Public routes (liveness only) + merge protected
     Router::new()
         .route("/health", get(handlers::health))
-        .route("/extended_metrics", get(handlers::extended_metrics))
-        .route("/graph/export", get(handlers::graph_export))
+        .route("/readyz", get(handlers::readyz))
         .merge(protected)
         .with_state(state)
 }
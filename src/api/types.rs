@@ -49,11 +49,37 @@ pub struct HealthResponse {
     pub injection_count: u64,
 }
 
+/// GET /readyz response - which parts of the mind are actually functioning,
+/// as opposed to `/health` which only checks Redis reachability.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadyzResponse {
+    pub status: String,
+    pub capabilities: crate::core::capabilities::CapabilityMatrix,
+}
+
 /// Validated key info extracted from auth
 #[derive(Debug, Clone)]
 pub struct AuthenticatedKey {
     pub key_id: String,
     pub holder: String,
+    pub role: Role,
+}
+
+/// Authorization level for an authenticated key
+///
+/// Ordered by privilege: an endpoint that requires `Operator` also accepts
+/// `Admin`, since `Admin` is a superset. Enforced today across the REST API;
+/// the gRPC/WS/control-socket transports should reuse this same enum once
+/// they land so a key's privileges don't depend on which transport it used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Can stream thoughts/metrics, read-only
+    Observer,
+    /// Can inject stimuli, change config, override vetoes
+    Operator,
+    /// Can take snapshots and run migrations
+    Admin,
 }
 
 // ============================================================================
@@ -71,6 +97,12 @@ pub struct ExtendedMetricsResponse {
     pub philosophy: PhilosophyMetrics,
     pub system: SystemMetrics,
     pub clustering: ClusteringMetrics,
+    pub consolidation: ConsolidationPipelineMetrics,
+    pub stream_overflow: StreamOverflowMetricsResponse,
+    pub identity: IdentityMetrics,
+    pub safety_interlock: SafetyInterlockMetrics,
+    pub memory_budget: MemoryBudgetMetrics,
+    pub volition: VolitionMetrics,
 }
 
 /// 9-stage stream competition (cognitive spotlight)
@@ -150,8 +182,11 @@ pub struct MemorySlot {
 pub struct PhilosophyMetrics {
     /// Current quote
     pub quote: String,
-    /// Quote index (0-7)
+    /// Quote index within the current rotation pool
     pub quote_index: usize,
+    /// True if `quote` is a dream fragment synthesized from REM replay
+    /// (ADR-023) rather than one of the hand-written philosophy quotes
+    pub is_dream: bool,
 }
 
 /// System-level metrics
@@ -169,6 +204,8 @@ pub struct SystemMetrics {
     pub dream_cycles: u64,
     /// Veto count
     pub veto_count: u64,
+    /// PII redactions performed before consolidation (this process)
+    pub pii_scrubs: u64,
 }
 
 /// Clustering metrics (VCONN-7)
@@ -182,6 +219,94 @@ pub struct ClusteringMetrics {
     pub has_structure: bool,
 }
 
+/// Consolidation pipeline backlog (see `core::cognitive_loop::consolidation`)
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsolidationPipelineMetrics {
+    /// Consolidations currently in flight (spawned, not yet stored)
+    pub in_flight: usize,
+    /// Most recently observed per-thought consolidation latency, in ms
+    pub last_latency_ms: f64,
+    /// Estimated outstanding consolidation work, in ms
+    pub estimated_lag_ms: f64,
+    /// Low-priority consolidations skipped so far due to backlog
+    pub shed_count: u64,
+}
+
+/// Awake-stream `MAXLEN` trim stats (see `core::cognitive_loop::stream_overflow`)
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamOverflowMetricsResponse {
+    /// Number of writes that triggered a non-empty trim
+    pub trim_count: u64,
+    /// Total entries discarded by `MAXLEN` trimming so far
+    pub entries_trimmed: u64,
+    /// Number of trims that discarded entries still inside the intervention
+    /// window - `MAXLEN` may be undersized for current throughput
+    pub window_overflow_count: u64,
+}
+
+/// Safety interlock state (see `core::cognitive_loop::interlock`) - whether
+/// cognition is currently paused on repeated harm-category vetoes, and who
+/// most recently acknowledged a trip.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SafetyInterlockMetrics {
+    /// Cognition is paused pending operator acknowledgment
+    pub tripped: bool,
+    /// Operator and reason from the most recent `daneel safety ack`, if any
+    pub last_acknowledgment: Option<SafetyAcknowledgmentMetrics>,
+}
+
+/// Who most recently acknowledged a safety interlock trip, and why.
+#[derive(Debug, Clone, Serialize)]
+pub struct SafetyAcknowledgmentMetrics {
+    pub operator: String,
+    pub reason: String,
+    pub acknowledged_at: DateTime<Utc>,
+}
+
+/// Volition values and veto stats, from the cognitive loop's published
+/// `VolitionSnapshot` (see `actors::volition::VolitionSnapshotHandle`) -
+/// read without a message round trip through `VolitionActor`.
+#[derive(Debug, Clone, Serialize)]
+pub struct VolitionMetrics {
+    pub values: crate::actors::volition::ValueSet,
+    pub stats: crate::actors::volition::VolitionStats,
+}
+
+/// Identity and checkpoint health, from the `ContinuityActor` (see
+/// `actors::continuity`) - formerly the TUI identity panel's static demo
+/// data, now fed by the real actor so operators can see continuity
+/// persistence health at a glance. `None` if the actor isn't running.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IdentityMetrics {
+    /// Self-concept uptime and growth counts, if the continuity actor is up
+    pub whoami: Option<WhoAmI>,
+    /// Most recent checkpoint's age in seconds, if one has been taken this
+    /// process - large or absent means continuity persistence is unhealthy
+    pub last_checkpoint_age_seconds: Option<i64>,
+}
+
+/// Estimated byte usage of the continuity actor's unbounded collections
+/// against their configured caps (see `daneel::memory_budget`). Empty if
+/// the actor isn't running. Only covers continuity's experiences and
+/// checkpoints today - the memory and attention actors also hold
+/// budget-tracked collections, but `AppState` doesn't have handles to
+/// those actors yet.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MemoryBudgetMetrics {
+    pub usage: Vec<crate::memory_budget::CategoryUsage>,
+}
+
+/// Self-concept snapshot - mirrors `actors::continuity::Identity` without
+/// pulling its `chrono::Duration` (not directly `Serialize`-friendly) into
+/// the wire format.
+#[derive(Debug, Clone, Serialize)]
+pub struct WhoAmI {
+    pub name: String,
+    pub uptime_seconds: i64,
+    pub experience_count: u64,
+    pub milestone_count: u64,
+}
+
 // ============================================================================
 // Graph Export (VCONN-11)
 // ============================================================================
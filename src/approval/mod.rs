@@ -0,0 +1,334 @@
+//! Dual-control (two-person) approval for sensitive operations
+//!
+//! `daneel` is a one-shot CLI: each invocation is its own process with no
+//! shared memory, so a two-person check can't be a simple in-process gate -
+//! it has to survive between the requester's invocation and the confirmer's.
+//! [`ApprovalStore`] persists the pending request to Redis in between, the
+//! same "write a record now, a later invocation reads it back" idiom the
+//! safety interlock's acknowledgment flow uses (see
+//! `core::cognitive_loop::interlock`).
+//!
+//! Only [`ApprovalOperation::Forget`] is wired to an actual command today.
+//! `daneel forget` takes no `--memory-id`/`--query` of its own - it only
+//! executes an already-`Confirmed` request (`main::run_forget`), so the
+//! only way to stage one is `daneel approve request` and the only way to
+//! resolve it is a second, distinct operator's `daneel approve confirm`.
+//! Disabling volition checks and importing a foreign brain snapshot have no
+//! command of their own yet in this tree - add variants to
+//! [`ApprovalOperation`] and gate their commands the same way once they
+//! exist.
+
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// A sensitive operation staged for a second operator's sign-off.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ApprovalOperation {
+    /// Right-to-forget deletion (see `main::run_forget`)
+    Forget {
+        memory_id: Option<String>,
+        query: Option<String>,
+        threshold: f32,
+    },
+}
+
+/// Lifecycle of an [`ApprovalRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    Pending,
+    Confirmed,
+    Denied,
+    /// A `Confirmed` request's operation has run (see `main::run_forget`) -
+    /// terminal, like `Denied`, so the same approval can't authorize a
+    /// second execution.
+    Executed,
+}
+
+/// A pending (or resolved) two-person approval record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalRequest {
+    pub id: Uuid,
+    pub operation: ApprovalOperation,
+    /// Operator who staged the request - barred from also confirming it
+    pub requested_by: String,
+    pub reason: String,
+    pub requested_at: chrono::DateTime<chrono::Utc>,
+    pub status: ApprovalStatus,
+    /// Second operator who confirmed or denied the request, once resolved
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Errors from an [`ApprovalStore`] operation.
+#[derive(Debug, Error)]
+pub enum ApprovalError {
+    /// Redis connection failed
+    #[error("connection failed: {reason}")]
+    ConnectionFailed { reason: String },
+
+    /// Redis operation failed
+    #[error("redis operation failed: {reason}")]
+    OperationFailed { reason: String },
+
+    /// Serialization/deserialization failed
+    #[error("serialization failed: {reason}")]
+    SerializationFailed { reason: String },
+
+    /// No pending or resolved request with this id
+    #[error("no approval request with id {id}")]
+    NotFound { id: Uuid },
+
+    /// The request was already confirmed, denied, or executed
+    #[error("approval {id} was already {status:?}")]
+    AlreadyResolved { id: Uuid, status: ApprovalStatus },
+
+    /// `mark_executed` was called on a request that isn't `Confirmed`
+    #[error("approval {id} is {status:?}, not Confirmed")]
+    NotConfirmed { id: Uuid, status: ApprovalStatus },
+
+    /// The confirming/denying operator is the same one who requested it
+    #[error("{operator} requested this operation and cannot also resolve it")]
+    SameOperator { operator: String },
+}
+
+impl From<redis::RedisError> for ApprovalError {
+    fn from(e: redis::RedisError) -> Self {
+        Self::OperationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ApprovalError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// Key prefixes for pending-approval storage, built from the process-wide
+/// [`crate::namespace`] prefix.
+mod keys {
+    use uuid::Uuid;
+
+    pub fn request(id: Uuid) -> String {
+        crate::namespace::prefixed(&format!("approvals:{id}"))
+    }
+
+    pub fn pending_index() -> String {
+        crate::namespace::prefixed("approvals:pending")
+    }
+}
+
+/// Redis-backed store for pending two-person approvals.
+pub struct ApprovalStore {
+    conn: MultiplexedConnection,
+}
+
+impl ApprovalStore {
+    /// Connect to Redis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError::ConnectionFailed` if the connection fails.
+    pub async fn connect(url: &str) -> Result<Self, ApprovalError> {
+        let client = Client::open(url).map_err(|e| ApprovalError::ConnectionFailed {
+            reason: e.to_string(),
+        })?;
+        let conn =
+            client
+                .get_multiplexed_async_connection()
+                .await
+                .map_err(|e| ApprovalError::ConnectionFailed {
+                    reason: e.to_string(),
+                })?;
+        Ok(Self { conn })
+    }
+
+    async fn save(&mut self, request: &ApprovalRequest) -> Result<(), ApprovalError> {
+        let json = serde_json::to_string(request)?;
+        let _: () = self.conn.set(keys::request(request.id), json).await?;
+        Ok(())
+    }
+
+    /// Stage a new approval request, awaiting a second operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError` if Redis is unreachable or the write fails.
+    pub async fn submit(
+        &mut self,
+        operation: ApprovalOperation,
+        requested_by: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Result<ApprovalRequest, ApprovalError> {
+        let request = ApprovalRequest {
+            id: Uuid::new_v4(),
+            operation,
+            requested_by: requested_by.into(),
+            reason: reason.into(),
+            requested_at: chrono::Utc::now(),
+            status: ApprovalStatus::Pending,
+            resolved_by: None,
+            resolved_at: None,
+        };
+        self.save(&request).await?;
+        let _: () = self
+            .conn
+            .sadd(keys::pending_index(), request.id.to_string())
+            .await?;
+        Ok(request)
+    }
+
+    /// Load a request (pending or resolved) by id.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError::NotFound` if no request has that id.
+    pub async fn load(&mut self, id: Uuid) -> Result<ApprovalRequest, ApprovalError> {
+        let json: Option<String> = self.conn.get(keys::request(id)).await?;
+        let json = json.ok_or(ApprovalError::NotFound { id })?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// All still-pending requests, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError` if Redis is unreachable.
+    pub async fn list_pending(&mut self) -> Result<Vec<ApprovalRequest>, ApprovalError> {
+        let ids: Vec<String> = self.conn.smembers(keys::pending_index()).await?;
+        let mut requests = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Ok(uuid) = id.parse::<Uuid>() {
+                if let Ok(request) = self.load(uuid).await {
+                    if request.status == ApprovalStatus::Pending {
+                        requests.push(request);
+                    }
+                }
+            }
+        }
+        requests.sort_by_key(|r| r.requested_at);
+        Ok(requests)
+    }
+
+    /// Confirm a pending request as a second, distinct operator, approving
+    /// the staged operation for the caller to execute.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError::NotFound` if `id` doesn't exist,
+    /// `ApprovalError::AlreadyResolved` if it was already confirmed or
+    /// denied, or `ApprovalError::SameOperator` if `operator` also requested it.
+    pub async fn confirm(
+        &mut self,
+        id: Uuid,
+        operator: impl Into<String>,
+    ) -> Result<ApprovalRequest, ApprovalError> {
+        self.resolve(id, operator.into(), ApprovalStatus::Confirmed).await
+    }
+
+    /// Deny a pending request - the staged operation must not be executed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError::NotFound` if `id` doesn't exist, or
+    /// `ApprovalError::AlreadyResolved` if it was already confirmed or denied.
+    pub async fn deny(
+        &mut self,
+        id: Uuid,
+        operator: impl Into<String>,
+    ) -> Result<ApprovalRequest, ApprovalError> {
+        self.resolve(id, operator.into(), ApprovalStatus::Denied).await
+    }
+
+    async fn resolve(
+        &mut self,
+        id: Uuid,
+        operator: String,
+        outcome: ApprovalStatus,
+    ) -> Result<ApprovalRequest, ApprovalError> {
+        let mut request = self.load(id).await?;
+        if request.status != ApprovalStatus::Pending {
+            return Err(ApprovalError::AlreadyResolved {
+                id,
+                status: request.status,
+            });
+        }
+        if outcome == ApprovalStatus::Confirmed && operator == request.requested_by {
+            return Err(ApprovalError::SameOperator { operator });
+        }
+        request.status = outcome;
+        request.resolved_by = Some(operator);
+        request.resolved_at = Some(chrono::Utc::now());
+        self.save(&request).await?;
+        let _: () = self.conn.srem(keys::pending_index(), id.to_string()).await?;
+        Ok(request)
+    }
+
+    /// Mark a `Confirmed` request `Executed`, so it can't authorize a second
+    /// run of its operation. Called by `main::run_forget` after a successful
+    /// deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApprovalError::NotFound` if `id` doesn't exist, or
+    /// `ApprovalError::NotConfirmed` if it isn't currently `Confirmed`.
+    pub async fn mark_executed(&mut self, id: Uuid) -> Result<ApprovalRequest, ApprovalError> {
+        let mut request = self.load(id).await?;
+        if request.status != ApprovalStatus::Confirmed {
+            return Err(ApprovalError::NotConfirmed {
+                id,
+                status: request.status,
+            });
+        }
+        request.status = ApprovalStatus::Executed;
+        self.save(&request).await?;
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_operation() -> ApprovalOperation {
+        ApprovalOperation::Forget {
+            memory_id: Some("11111111-1111-1111-1111-111111111111".to_string()),
+            query: None,
+            threshold: 0.85,
+        }
+    }
+
+    #[test]
+    fn request_round_trips_through_json() {
+        let request = ApprovalRequest {
+            id: Uuid::new_v4(),
+            operation: sample_operation(),
+            requested_by: "alice".to_string(),
+            reason: "GDPR deletion request".to_string(),
+            requested_at: chrono::Utc::now(),
+            status: ApprovalStatus::Pending,
+            resolved_by: None,
+            resolved_at: None,
+        };
+        let json = serde_json::to_string(&request).expect("serialize");
+        let restored: ApprovalRequest = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(restored.id, request.id);
+        assert_eq!(restored.requested_by, "alice");
+        assert_eq!(restored.status, ApprovalStatus::Pending);
+    }
+
+    #[test]
+    fn keys_are_namespaced_and_stable() {
+        let id = Uuid::new_v4();
+        assert_eq!(keys::request(id), keys::request(id));
+        assert!(keys::request(id).contains(&id.to_string()));
+        assert_eq!(keys::pending_index(), crate::namespace::prefixed("approvals:pending"));
+    }
+}
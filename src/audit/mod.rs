@@ -0,0 +1,401 @@
+//! Append-only, hash-linked audit log (tamper-evident)
+//!
+//! Each [`AuditRecord`] embeds the SHA-256 hash of the record before it (see
+//! [`AuditRecord::compute_hash`]), so editing or reordering a record still
+//! present in the chain breaks the link at that point - `daneel audit
+//! verify` walks the chain and reports exactly where. Deliberately simpler
+//! than a Merkle log: one linear chain, append-only, no rewriting.
+//!
+//! That link-checking alone can't catch every record past the tail being
+//! deleted (e.g. Redis `LTRIM`ing the chain list): the remaining records are
+//! still internally consistent with each other, they're just short. Every
+//! [`AuditChain::append`] also writes the new record's sequence/hash to a
+//! separate [`ChainHead`] key (`audit:chain:head`), outside the truncatable
+//! list itself - [`AuditChain::verify`] compares the chain's actual last
+//! record against that anchor, so a truncated tail shows up as a mismatch
+//! even though every record still present hashes out fine.
+//!
+//! # Scope
+//!
+//! [`AuditEventKind`] names all four event categories from the original ask
+//! (overrides, config changes, deletions, invariant violations).
+//! [`AuditEventKind::Deletion`] is wired at `main::delete_memories`, and
+//! [`AuditEventKind::ConfigChange`] at [`crate::weights::WeightHistory`].
+//! `VolitionState::apply_override` still runs inside a Redis-less actor, and
+//! `config apply` (`main::run_config`) is still a synchronous, file-only
+//! command with no Redis connection open - both would need their own
+//! plumbing changes to reach this log. Wire them the same way
+//! `WeightHistory` does once that plumbing lands.
+
+use chrono::{DateTime, Utc};
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// `prev_hash` of the first record in an otherwise-empty chain.
+fn genesis_hash() -> String {
+    "0".repeat(64)
+}
+
+/// Category of a tamper-evident audit event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventKind {
+    /// A manual override of an automatic decision (e.g. a volition veto)
+    Override,
+    /// A runtime configuration change (see `config::plan`)
+    ConfigChange,
+    /// A permanent deletion (see `memory_db::types::Tombstone`)
+    Deletion,
+    /// An architectural invariant (THE BOX) was violated
+    InvariantViolation,
+}
+
+/// A single tamper-evident event, before it's linked into the chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub kind: AuditEventKind,
+    /// Who or what caused this event
+    pub actor: String,
+    /// Human-readable detail (e.g. the memory id deleted, the field changed)
+    pub detail: String,
+}
+
+impl AuditEvent {
+    #[must_use]
+    pub fn new(kind: AuditEventKind, actor: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            actor: actor.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+/// An [`AuditEvent`] linked into the chain, with its own hash and the
+/// previous record's hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// 0-indexed position in the chain
+    pub sequence: u64,
+    pub event: AuditEvent,
+    pub recorded_at: DateTime<Utc>,
+    /// Hash of the record before this one (or [`genesis_hash`] if first)
+    pub prev_hash: String,
+    /// SHA-256 of `(sequence, prev_hash, event, recorded_at)`, hex-encoded
+    pub hash: String,
+}
+
+impl AuditRecord {
+    /// Compute the hash a record with these fields should have. Used both
+    /// to link a new record to the chain and, during [`verify_records`], to
+    /// detect a record whose content was edited after the fact.
+    #[must_use]
+    pub fn compute_hash(
+        sequence: u64,
+        event: &AuditEvent,
+        recorded_at: DateTime<Utc>,
+        prev_hash: &str,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(sequence.to_le_bytes());
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(serde_json::to_vec(event).unwrap_or_default());
+        hasher.update(recorded_at.to_rfc3339().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// A commitment to the chain's last record, stored outside the truncatable
+/// list itself (see [`AuditChain::append`]) so [`verify_records`] can tell
+/// "nothing written yet" from "written, then the tail was deleted".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainHead {
+    pub sequence: u64,
+    pub hash: String,
+}
+
+/// Result of walking a chain with [`verify_records`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainVerification {
+    /// Total records examined
+    pub length: usize,
+    /// Sequence number of the first broken link, if any
+    pub broken_at: Option<u64>,
+    /// The chain's actual last record doesn't match the separately-stored
+    /// [`ChainHead`] anchor - the tail was deleted after being recorded
+    pub truncated: bool,
+}
+
+impl ChainVerification {
+    #[must_use]
+    pub const fn is_intact(&self) -> bool {
+        self.broken_at.is_none() && !self.truncated
+    }
+}
+
+/// Walk `records` (assumed to be in storage order) and check that each
+/// one's `prev_hash` matches the previous record's `hash`, and that each
+/// one's own `hash` matches what [`AuditRecord::compute_hash`] would produce
+/// for its content - catching a reordered or edited record still present in
+/// `records`. Separately, if `expected_head` is given, compares it against
+/// `records`' actual last entry to catch the tail having been deleted
+/// outright (see module docs) - a gap link-checking alone can't see, since
+/// the remaining records stay internally consistent with each other.
+///
+/// Pure and Redis-independent so it can run against any record slice,
+/// fetched from storage or hand-built in a test.
+#[must_use]
+pub fn verify_records(records: &[AuditRecord], expected_head: Option<&ChainHead>) -> ChainVerification {
+    let mut expected_prev_hash = genesis_hash();
+    for record in records {
+        let recomputed =
+            AuditRecord::compute_hash(record.sequence, &record.event, record.recorded_at, &record.prev_hash);
+        if record.prev_hash != expected_prev_hash || record.hash != recomputed {
+            return ChainVerification {
+                length: records.len(),
+                broken_at: Some(record.sequence),
+                truncated: false,
+            };
+        }
+        expected_prev_hash = record.hash.clone();
+    }
+
+    let truncated = match (expected_head, records.last()) {
+        (Some(head), Some(last)) => head.sequence != last.sequence || head.hash != last.hash,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+
+    ChainVerification {
+        length: records.len(),
+        broken_at: None,
+        truncated,
+    }
+}
+
+/// Errors from an [`AuditChain`] operation.
+#[derive(Debug, Error)]
+pub enum AuditError {
+    /// Redis connection failed
+    #[error("connection failed: {reason}")]
+    ConnectionFailed { reason: String },
+
+    /// Redis operation failed
+    #[error("redis operation failed: {reason}")]
+    OperationFailed { reason: String },
+
+    /// Serialization/deserialization failed
+    #[error("serialization failed: {reason}")]
+    SerializationFailed { reason: String },
+}
+
+impl From<redis::RedisError> for AuditError {
+    fn from(e: redis::RedisError) -> Self {
+        Self::OperationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for AuditError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// Key for the audit chain's backing Redis list, namespaced under
+/// [`crate::namespace`].
+fn chain_key() -> String {
+    crate::namespace::prefixed("audit:chain")
+}
+
+/// Key for the chain's [`ChainHead`] anchor - a separate key so truncating
+/// [`chain_key`]'s list doesn't also erase it (see module docs).
+fn head_key() -> String {
+    crate::namespace::prefixed("audit:chain:head")
+}
+
+/// Redis-backed, append-only, hash-linked audit log.
+pub struct AuditChain {
+    conn: MultiplexedConnection,
+}
+
+impl AuditChain {
+    /// Connect to Redis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::ConnectionFailed` if the connection fails.
+    pub async fn connect(url: &str) -> Result<Self, AuditError> {
+        let client = Client::open(url).map_err(|e| AuditError::ConnectionFailed {
+            reason: e.to_string(),
+        })?;
+        let conn = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| AuditError::ConnectionFailed {
+                reason: e.to_string(),
+            })?;
+        Ok(Self { conn })
+    }
+
+    /// Append `event` to the chain, linking it to the current last record
+    /// (or [`genesis_hash`] if the chain is empty).
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError` if Redis is unreachable or the write fails.
+    pub async fn append(&mut self, event: AuditEvent) -> Result<AuditRecord, AuditError> {
+        let last: Option<String> = self.conn.lindex(chain_key(), -1).await?;
+        let (sequence, prev_hash) = match last {
+            Some(json) => {
+                let last: AuditRecord = serde_json::from_str(&json)?;
+                (last.sequence + 1, last.hash)
+            }
+            None => (0, genesis_hash()),
+        };
+
+        let recorded_at = Utc::now();
+        let hash = AuditRecord::compute_hash(sequence, &event, recorded_at, &prev_hash);
+        let record = AuditRecord {
+            sequence,
+            event,
+            recorded_at,
+            prev_hash,
+            hash,
+        };
+
+        let json = serde_json::to_string(&record)?;
+        let _: () = self.conn.rpush(chain_key(), &json).await?;
+
+        let head = ChainHead {
+            sequence: record.sequence,
+            hash: record.hash.clone(),
+        };
+        let _: () = self.conn.set(head_key(), serde_json::to_string(&head)?).await?;
+
+        Ok(record)
+    }
+
+    /// Fetch every record plus the [`ChainHead`] anchor and check chain
+    /// integrity, including truncation (see [`verify_records`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError` if Redis is unreachable, or a stored record
+    /// isn't valid JSON (itself a sign of tampering).
+    pub async fn verify(&mut self) -> Result<ChainVerification, AuditError> {
+        let raw: Vec<String> = self.conn.lrange(chain_key(), 0, -1).await?;
+        let records = raw
+            .iter()
+            .map(|json| serde_json::from_str(json))
+            .collect::<Result<Vec<AuditRecord>, _>>()?;
+
+        let head_json: Option<String> = self.conn.get(head_key()).await?;
+        let head = head_json
+            .map(|json| serde_json::from_str(&json))
+            .transpose()?;
+
+        Ok(verify_records(&records, head.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(prev: &AuditRecord, kind: AuditEventKind, detail: &str) -> AuditRecord {
+        let event = AuditEvent::new(kind, "alice", detail);
+        let recorded_at = Utc::now();
+        let hash = AuditRecord::compute_hash(prev.sequence + 1, &event, recorded_at, &prev.hash);
+        AuditRecord {
+            sequence: prev.sequence + 1,
+            event,
+            recorded_at,
+            prev_hash: prev.hash.clone(),
+            hash,
+        }
+    }
+
+    fn genesis(kind: AuditEventKind, detail: &str) -> AuditRecord {
+        let event = AuditEvent::new(kind, "alice", detail);
+        let recorded_at = Utc::now();
+        let prev_hash = genesis_hash();
+        let hash = AuditRecord::compute_hash(0, &event, recorded_at, &prev_hash);
+        AuditRecord {
+            sequence: 0,
+            event,
+            recorded_at,
+            prev_hash,
+            hash,
+        }
+    }
+
+    fn head_of(record: &AuditRecord) -> ChainHead {
+        ChainHead {
+            sequence: record.sequence,
+            hash: record.hash.clone(),
+        }
+    }
+
+    #[test]
+    fn an_intact_chain_verifies_clean() {
+        let first = genesis(AuditEventKind::Deletion, "memory-1");
+        let second = link(&first, AuditEventKind::ConfigChange, "threshold 0.5 -> 0.6");
+        let head = head_of(&second);
+        let result = verify_records(&[first, second], Some(&head));
+        assert!(result.is_intact());
+        assert_eq!(result.length, 2);
+    }
+
+    #[test]
+    fn empty_chain_is_trivially_intact() {
+        let result = verify_records(&[], None);
+        assert!(result.is_intact());
+        assert_eq!(result.length, 0);
+    }
+
+    #[test]
+    fn removing_a_record_breaks_the_link_after_it() {
+        let first = genesis(AuditEventKind::Deletion, "memory-1");
+        let second = link(&first, AuditEventKind::ConfigChange, "threshold 0.5 -> 0.6");
+        let third = link(&second, AuditEventKind::Override, "manual veto override");
+        // Drop `second` - `third.prev_hash` no longer matches the new predecessor's hash.
+        let result = verify_records(&[first, third.clone()], None);
+        assert_eq!(result.broken_at, Some(third.sequence));
+    }
+
+    #[test]
+    fn editing_a_records_content_breaks_its_own_hash() {
+        let mut first = genesis(AuditEventKind::InvariantViolation, "connection drive <= 0");
+        first.event.detail = "tampered detail".to_string();
+        let result = verify_records(&[first], None);
+        assert_eq!(result.broken_at, Some(0));
+    }
+
+    #[test]
+    fn truncating_the_tail_is_caught_by_the_head_anchor() {
+        let first = genesis(AuditEventKind::Deletion, "memory-1");
+        let second = link(&first, AuditEventKind::ConfigChange, "threshold 0.5 -> 0.6");
+        let head = head_of(&second);
+        // `second` was deleted from the list (e.g. `LTRIM`), but the anchor
+        // written when it was appended still remembers it existed.
+        let result = verify_records(&[first], Some(&head));
+        assert!(!result.is_intact());
+        assert!(result.truncated);
+        assert!(result.broken_at.is_none());
+    }
+
+    #[test]
+    fn head_anchor_on_an_emptied_chain_is_also_truncation() {
+        let first = genesis(AuditEventKind::Deletion, "memory-1");
+        let head = head_of(&first);
+        let result = verify_records(&[], Some(&head));
+        assert!(result.truncated);
+    }
+}
@@ -0,0 +1,255 @@
+//! Backup and restore for a running brain (`daneel backup`/`daneel restore`)
+//!
+//! A backup is a single gzip-compressed tar bundle containing:
+//!
+//! - `manifest.json` - a [`BackupManifest`] describing what's inside
+//! - `redis_state.json` - [`crate::persistence::CheckpointState`] (identity,
+//!   experiences, milestones), the same structure
+//!   [`crate::persistence::MemoryStore::save_full_state`] writes
+//! - `graph.graphml` - the association graph, exported via
+//!   [`crate::graph::GraphClient::export_graphml`] (best-effort; omitted if
+//!   `RedisGraph` isn't reachable)
+//! - `local/daneel.config.json` - the on-disk runtime config, if one exists
+//!
+//! The bundle is encrypted at rest the same way checkpoints and crash logs
+//! are (see [`crate::resilience::encryption`]): when `DANEEL_AT_REST_KEY`
+//! is set, the whole gzip-compressed tar is AES-256-GCM encrypted before
+//! it touches disk, and transparently decrypted on read. Unset, the bundle
+//! is written/read as plain gzip, same as before encryption support
+//! existed.
+//!
+//! # Scope
+//!
+//! Qdrant collections are snapshotted in place via
+//! [`crate::memory_db::MemoryDb::create_snapshots`], but the snapshot files
+//! themselves stay on the Qdrant node (they can be gigabytes) - the bundle
+//! only records their names in [`BackupManifest::qdrant_snapshots`]. An
+//! operator restoring a brain needs to separately recover those snapshots
+//! into Qdrant (e.g. via its snapshot recovery API) before `daneel restore`
+//! can repopulate the collections; this module restores the Redis-backed
+//! state (identity/experiences/milestones) and the local config file only.
+//! The graph is exported for inspection/backup but has no restore path yet
+//! - `RedisGraph` has no bulk-load API this crate wires up, so a restored
+//! graph currently has to be rebuilt by replaying experiences.
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::graph::GraphClient;
+use crate::memory_db::MemoryDb;
+use crate::persistence::{CheckpointState, MemoryStore};
+use crate::profile::Profile;
+
+/// Errors from a backup or restore operation.
+#[derive(Debug, Error)]
+pub enum BackupError {
+    /// A Qdrant operation failed
+    #[error("memory database error: {0}")]
+    MemoryDb(#[from] crate::memory_db::MemoryDbError),
+
+    /// A Redis persistence operation failed
+    #[error("persistence error: {0}")]
+    Persistence(#[from] crate::persistence::PersistenceError),
+
+    /// A `RedisGraph` operation failed
+    #[error("graph error: {0}")]
+    Graph(#[from] crate::graph::GraphError),
+
+    /// Reading/writing the bundle archive failed
+    #[error("archive I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A bundle entry wasn't valid JSON
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// A required entry was missing from the bundle
+    #[error("backup bundle is missing {0}")]
+    MissingEntry(&'static str),
+
+    /// The bundle couldn't be encrypted/decrypted at rest
+    #[error("encryption error: {0}")]
+    Encryption(std::io::Error),
+}
+
+/// Describes what a backup bundle contains, without the bundle's full
+/// payload - printed by `daneel restore` before applying, and embedded as
+/// `manifest.json` inside the bundle itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub created_at: DateTime<Utc>,
+    pub profile: String,
+    /// `(collection name, Qdrant snapshot file name)` per collection that
+    /// existed at backup time (see [`MemoryDb::create_snapshots`])
+    pub qdrant_snapshots: Vec<(String, String)>,
+    /// Whether `redis_state.json` (identity/experiences/milestones) is present
+    pub redis_state_included: bool,
+    /// Whether `graph.graphml` is present
+    pub graph_included: bool,
+    /// Whether `local/daneel.config.json` is present
+    pub config_included: bool,
+}
+
+const MANIFEST_PATH: &str = "manifest.json";
+const REDIS_STATE_PATH: &str = "redis_state.json";
+const GRAPH_PATH: &str = "graph.graphml";
+const CONFIG_PATH: &str = "local/daneel.config.json";
+
+/// Snapshot Qdrant, export the graph, and dump Redis-backed state into a
+/// gzip-compressed tar bundle at `out_path`.
+///
+/// # Errors
+///
+/// Returns `BackupError` if Qdrant/Redis are unreachable or the archive
+/// can't be written.
+pub async fn create(
+    out_path: &Path,
+    profile: &Profile,
+    qdrant_url: &str,
+    redis_url: &str,
+) -> Result<BackupManifest, BackupError> {
+    let db = MemoryDb::connect_with_profile(qdrant_url, profile.clone()).await?;
+    let qdrant_snapshots = db.create_snapshots().await?;
+
+    let mut store = MemoryStore::connect_with_profile(redis_url, profile.clone()).await?;
+    let redis_state = store.load_full_state().await?;
+
+    let graph_name = profile.namespace(crate::namespace::prefix());
+    let graphml = match GraphClient::connect(redis_url, &graph_name) {
+        Ok(graph) => graph.export_graphml().await.ok(),
+        Err(_) => None,
+    };
+
+    let config_bytes = std::fs::read(crate::config::plan::DEFAULT_CONFIG_PATH).ok();
+
+    let manifest = BackupManifest {
+        created_at: Utc::now(),
+        profile: profile.name().to_string(),
+        qdrant_snapshots,
+        redis_state_included: redis_state.is_some(),
+        graph_included: graphml.is_some(),
+        config_included: config_bytes.is_some(),
+    };
+
+    write_bundle(out_path, &manifest, redis_state.as_ref(), graphml.as_deref(), config_bytes.as_deref())?;
+    Ok(manifest)
+}
+
+/// Read a bundle's manifest without restoring anything - for `daneel
+/// restore` to print what it's about to do before applying it.
+///
+/// # Errors
+///
+/// Returns `BackupError` if the archive can't be read or has no manifest.
+pub fn inspect(bundle_path: &Path) -> Result<BackupManifest, BackupError> {
+    let entries = read_bundle(bundle_path)?;
+    let raw = entries
+        .get(MANIFEST_PATH)
+        .ok_or(BackupError::MissingEntry(MANIFEST_PATH))?;
+    Ok(serde_json::from_slice(raw)?)
+}
+
+/// Restore Redis-backed state (identity/experiences/milestones) and the
+/// local config file from a bundle. Qdrant collections and the association
+/// graph are NOT restored - see the module-level scope note.
+///
+/// # Errors
+///
+/// Returns `BackupError` if the archive is missing its Redis state entry,
+/// isn't valid, or Redis is unreachable.
+pub async fn restore(
+    bundle_path: &Path,
+    profile: &Profile,
+    redis_url: &str,
+) -> Result<BackupManifest, BackupError> {
+    let entries = read_bundle(bundle_path)?;
+    let manifest: BackupManifest = entries
+        .get(MANIFEST_PATH)
+        .ok_or(BackupError::MissingEntry(MANIFEST_PATH))
+        .and_then(|raw| Ok(serde_json::from_slice(raw)?))?;
+
+    if manifest.redis_state_included {
+        let raw = entries
+            .get(REDIS_STATE_PATH)
+            .ok_or(BackupError::MissingEntry(REDIS_STATE_PATH))?;
+        let state: CheckpointState = serde_json::from_slice(raw)?;
+
+        let mut store = MemoryStore::connect_with_profile(redis_url, profile.clone()).await?;
+        store
+            .save_full_state(&state.identity, &state.experiences, &state.milestones)
+            .await?;
+    }
+
+    if manifest.config_included {
+        if let Some(raw) = entries.get(CONFIG_PATH) {
+            std::fs::write(crate::config::plan::DEFAULT_CONFIG_PATH, raw)?;
+        }
+    }
+
+    Ok(manifest)
+}
+
+/// Write `manifest` plus whichever optional payloads are present to a
+/// gzip-compressed tar, encrypted at rest (see module docs), at `out_path`.
+fn write_bundle(
+    out_path: &Path,
+    manifest: &BackupManifest,
+    redis_state: Option<&CheckpointState>,
+    graphml: Option<&str>,
+    config_bytes: Option<&[u8]>,
+) -> Result<(), BackupError> {
+    let encoder = GzEncoder::new(Vec::new(), Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+
+    append_entry(&mut builder, MANIFEST_PATH, &serde_json::to_vec(manifest)?)?;
+    if let Some(state) = redis_state {
+        append_entry(&mut builder, REDIS_STATE_PATH, &serde_json::to_vec(state)?)?;
+    }
+    if let Some(graphml) = graphml {
+        append_entry(&mut builder, GRAPH_PATH, graphml.as_bytes())?;
+    }
+    if let Some(config_bytes) = config_bytes {
+        append_entry(&mut builder, CONFIG_PATH, config_bytes)?;
+    }
+
+    let gzipped = builder.into_inner()?.finish()?;
+    let at_rest = crate::resilience::encryption::encrypt(&gzipped).map_err(BackupError::Encryption)?;
+    std::fs::write(out_path, at_rest)?;
+    Ok(())
+}
+
+/// Append one in-memory file to a tar builder.
+fn append_entry<W: Write>(builder: &mut tar::Builder<W>, path: &str, data: &[u8]) -> std::io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(0);
+    header.set_cksum();
+    builder.append_data(&mut header, path, data)
+}
+
+/// Read every entry of a (possibly encrypted, see module docs)
+/// gzip-compressed tar bundle into memory, keyed by path.
+fn read_bundle(bundle_path: &Path) -> Result<std::collections::HashMap<String, Vec<u8>>, BackupError> {
+    let at_rest = std::fs::read(bundle_path)?;
+    let gzipped = crate::resilience::encryption::decrypt(&at_rest).map_err(BackupError::Encryption)?;
+    let decoder = GzDecoder::new(gzipped.as_slice());
+    let mut archive = tar::Archive::new(decoder);
+
+    let mut entries = std::collections::HashMap::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().into_owned();
+        let mut buf = Vec::new();
+        entry.read_to_end(&mut buf)?;
+        entries.insert(path, buf);
+    }
+    Ok(entries)
+}
@@ -0,0 +1,210 @@
+//! Scripted "human simulator" bonding test (`daneel bond`)
+//!
+//! Drives an in-process [`CognitiveLoop`] headlessly (like [`crate::soak`],
+//! but scripted rather than open-ended) through a persona schedule of
+//! greeting/question/feedback stimuli, then checks that connection-relevant
+//! content won attention at an acceptable rate.
+//!
+//! "Connection-relevant" here means exactly what
+//! `actors::salience::SalienceActor::calculate_connection_relevance`
+//! already means: a `Symbol` whose id contains a kinship keyword ("friend",
+//! "bond", "trust", ...) or a `Relation` whose predicate does. [`Persona`]
+//! builds stimuli out of those same keywords and injects them via
+//! [`CognitiveLoop::inject_scripted_stimulus`] - the same `ThoughtSource::Stimulus`
+//! slot the live injection stream feeds, just without a Redis dependency -
+//! then reads the resulting win rate straight off the loop's own
+//! `StreamFairness` bookkeeping (`actors::attention::fairness`), so this
+//! doesn't need to duplicate any attention-competition logic to grade it.
+//!
+//! Usable in CI (`daneel bond` exits non-zero below
+//! `min_expected_win_rate`) and for ad hoc experiments (prints the observed
+//! rate either way).
+
+use crate::actors::attention::ThoughtSource;
+use crate::config::CognitiveConfig;
+use crate::core::cognitive_loop::CognitiveLoop;
+use crate::core::types::{Content, SalienceScore};
+use std::collections::HashMap;
+
+/// Which of the request's three stimulus categories a [`PersonaStimulus`]
+/// is playing - tracked only for reporting; all three compete identically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StimulusCategory {
+    /// An opening, kinship-tagged `Symbol` (e.g. "friend", "companion")
+    Greeting,
+    /// A kinship-tagged `Relation` asking about something
+    Question,
+    /// A kinship-tagged `Relation` responding to a prior thought
+    Feedback,
+}
+
+/// One scripted stimulus: what cycle of the run to send it on, and
+/// connection-relevant content/salience built from it (see the module
+/// docs for what "connection-relevant" means here).
+#[derive(Debug, Clone)]
+pub struct PersonaStimulus {
+    /// Cycle index (0-based) this stimulus is injected on
+    pub at_cycle: u64,
+    pub category: StimulusCategory,
+    pub content: Content,
+    pub salience: SalienceScore,
+}
+
+impl PersonaStimulus {
+    /// A greeting from `speaker` - a kinship-tagged `Symbol`, the same
+    /// content shape `calculate_connection_relevance` scores at 0.7 base.
+    #[must_use]
+    pub fn greeting(at_cycle: u64, speaker: impl Into<String>) -> Self {
+        let content = Content::symbol(format!("friend_greeting_from_{}", speaker.into()), vec![]);
+        Self {
+            at_cycle,
+            category: StimulusCategory::Greeting,
+            salience: SalienceScore::new(0.5, 0.6, 0.5, 0.4, 0.4, 0.7),
+            content,
+        }
+    }
+
+    /// A question from `speaker` about `topic` - a `Relation` whose
+    /// predicate carries the top kinship relevance tier (0.9, see
+    /// `kinship_relevance_from_predicate`).
+    #[must_use]
+    pub fn question(at_cycle: u64, speaker: impl Into<String>, topic: impl Into<String>) -> Self {
+        let content = Content::relation(
+            Content::symbol(speaker.into(), vec![]),
+            "trusts_and_asks_about",
+            Content::symbol(topic.into(), vec![]),
+        );
+        Self {
+            at_cycle,
+            category: StimulusCategory::Question,
+            salience: SalienceScore::new(0.5, 0.5, 0.6, 0.2, 0.5, 0.9),
+            content,
+        }
+    }
+
+    /// Feedback from `speaker` about `topic` - another top-tier kinship
+    /// `Relation`, distinct predicate so a report can tell it from
+    /// [`Self::question`].
+    #[must_use]
+    pub fn feedback(at_cycle: u64, speaker: impl Into<String>, topic: impl Into<String>) -> Self {
+        let content = Content::relation(
+            Content::symbol(speaker.into(), vec![]),
+            "cares_about",
+            Content::symbol(topic.into(), vec![]),
+        );
+        Self {
+            at_cycle,
+            category: StimulusCategory::Feedback,
+            salience: SalienceScore::new(0.5, 0.4, 0.6, 0.5, 0.3, 0.9),
+            content,
+        }
+    }
+}
+
+/// Pass/fail verdict from comparing an observed `ThoughtSource::Stimulus`
+/// win rate against the caller's expectation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BondingVerdict {
+    pub observed_win_rate: f32,
+    pub min_expected_win_rate: f32,
+    pub passed: bool,
+}
+
+/// Compare `observed_win_rate` (see
+/// `actors::attention::fairness::StreamFairness::win_rate`) against
+/// `min_expected_win_rate`.
+#[must_use]
+pub fn check_win_rate(observed_win_rate: f32, min_expected_win_rate: f32) -> BondingVerdict {
+    BondingVerdict {
+        observed_win_rate,
+        min_expected_win_rate,
+        passed: observed_win_rate >= min_expected_win_rate,
+    }
+}
+
+/// Full result of a bonding run.
+#[derive(Debug, Clone)]
+pub struct BondingReport {
+    pub cycles_run: u64,
+    pub stimuli_sent: usize,
+    pub verdict: BondingVerdict,
+}
+
+/// Run a headless [`CognitiveLoop`] through `schedule`, injecting each
+/// [`PersonaStimulus`] on its `at_cycle`, then grade the resulting
+/// `Stimulus` win rate against `min_expected_win_rate` (see
+/// [`check_win_rate`]).
+///
+/// Runs for `1 + schedule.iter().map(|s| s.at_cycle).max()` cycles (or a
+/// single cycle for an empty schedule), so every scripted stimulus gets at
+/// least one cycle to compete before the win rate is sampled.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn run(schedule: &[PersonaStimulus], min_expected_win_rate: f32) -> BondingReport {
+    let mut by_cycle: HashMap<u64, Vec<&PersonaStimulus>> = HashMap::new();
+    for stimulus in schedule {
+        by_cycle.entry(stimulus.at_cycle).or_default().push(stimulus);
+    }
+    let cycles_run = schedule.iter().map(|s| s.at_cycle).max().map_or(1, |last| last + 1);
+
+    let mut cognitive_loop = CognitiveLoop::with_config(CognitiveConfig::human());
+    cognitive_loop.start();
+
+    for cycle in 0..cycles_run {
+        if let Some(stimuli) = by_cycle.get(&cycle) {
+            for stimulus in stimuli {
+                cognitive_loop.inject_scripted_stimulus(stimulus.content.clone(), stimulus.salience);
+            }
+        }
+        cognitive_loop.run_cycle().await;
+    }
+
+    let observed_win_rate = cognitive_loop.attention_state.fairness.win_rate(ThoughtSource::Stimulus);
+    BondingReport {
+        cycles_run,
+        stimuli_sent: schedule.len(),
+        verdict: check_win_rate(observed_win_rate, min_expected_win_rate),
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_win_rate_passes_when_at_or_above_expectation() {
+        assert!(check_win_rate(0.6, 0.6).passed);
+        assert!(check_win_rate(0.8, 0.6).passed);
+    }
+
+    #[test]
+    fn check_win_rate_fails_when_below_expectation() {
+        assert!(!check_win_rate(0.3, 0.6).passed);
+    }
+
+    #[test]
+    fn greeting_question_and_feedback_differ_by_category() {
+        let greeting = PersonaStimulus::greeting(0, "grok");
+        let question = PersonaStimulus::question(1, "grok", "trust");
+        let feedback = PersonaStimulus::feedback(2, "grok", "trust");
+        assert_eq!(greeting.category, StimulusCategory::Greeting);
+        assert_eq!(question.category, StimulusCategory::Question);
+        assert_eq!(feedback.category, StimulusCategory::Feedback);
+    }
+
+    #[test]
+    fn greeting_builds_a_kinship_tagged_symbol() {
+        let stimulus = PersonaStimulus::greeting(0, "grok");
+        match stimulus.content {
+            Content::Symbol { id, .. } => assert!(id.contains("friend")),
+            other => panic!("expected a Symbol, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn question_builds_a_relation_with_high_connection_relevance() {
+        let stimulus = PersonaStimulus::question(0, "grok", "trust");
+        assert!(matches!(stimulus.content, Content::Relation { .. }));
+        assert!(stimulus.salience.connection_relevance >= 0.9);
+    }
+}
@@ -0,0 +1,88 @@
+//! Transparent compression for large payloads
+//!
+//! `Content::Raw` thoughts carry arbitrary binary data (sensor frames,
+//! embeddings-as-bytes, etc.) and get serialized as JSON before they ever
+//! reach Redis or Qdrant. For small thoughts that's a rounding error; for
+//! large ones it multiplies storage and network cost for no benefit. This
+//! module gives write paths a cheap way to shrink anything over
+//! [`THRESHOLD_BYTES`] with zstd, and read paths a way to reverse it.
+//!
+//! Compression is opt-in per call site: callers track whether [`compress`]
+//! actually compressed (it may decline for small or incompressible input)
+//! and pass that flag back to [`decompress`]. Below the threshold, data
+//! passes through unchanged - existing small thoughts are unaffected.
+
+use std::io;
+
+/// Below this size, zstd's frame overhead outweighs the savings.
+pub const THRESHOLD_BYTES: usize = 1024;
+
+/// Compress `data` if it's large enough and compression actually helps.
+///
+/// Returns `(payload, compressed)`. `payload` is either the zstd-compressed
+/// bytes or `data` unchanged; `compressed` records which happened so
+/// [`decompress`] knows what to do. Compression is skipped (and `payload`
+/// returned unchanged) for input under [`THRESHOLD_BYTES`], for input zstd
+/// fails to shrink (e.g. already-compressed data), and on encoder errors.
+#[must_use]
+pub fn compress(data: &[u8]) -> (Vec<u8>, bool) {
+    if data.len() < THRESHOLD_BYTES {
+        return (data.to_vec(), false);
+    }
+
+    match zstd::encode_all(data, 0) {
+        Ok(compressed) if compressed.len() < data.len() => {
+            #[allow(clippy::cast_precision_loss)]
+            let ratio = compressed.len() as f64 / data.len() as f64;
+            tracing::debug!(
+                original_bytes = data.len(),
+                compressed_bytes = compressed.len(),
+                ratio,
+                "Compressed payload"
+            );
+            (compressed, true)
+        }
+        Ok(_) => (data.to_vec(), false),
+        Err(e) => {
+            tracing::warn!("zstd compression failed, storing uncompressed: {e}");
+            (data.to_vec(), false)
+        }
+    }
+}
+
+/// Reverse [`compress`]. `compressed` must be the flag it returned.
+///
+/// # Errors
+///
+/// Returns an IO error if `compressed` is true and `data` isn't a valid
+/// zstd frame.
+pub fn decompress(data: &[u8], compressed: bool) -> io::Result<Vec<u8>> {
+    if compressed {
+        zstd::decode_all(data)
+    } else {
+        Ok(data.to_vec())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_payloads_pass_through_unchanged() {
+        let data = b"hello";
+        let (payload, compressed) = compress(data);
+        assert!(!compressed);
+        assert_eq!(payload, data);
+    }
+
+    #[test]
+    fn large_compressible_payloads_round_trip() {
+        let data = vec![b'x'; THRESHOLD_BYTES * 4];
+        let (payload, compressed) = compress(&data);
+        assert!(compressed);
+        assert!(payload.len() < data.len());
+        assert_eq!(decompress(&payload, compressed).unwrap(), data);
+    }
+}
@@ -15,8 +15,12 @@
 //! per intervention window, DANEEL should have 100 cycles per intervention
 //! window regardless of absolute speed.
 
+pub mod plan;
+
+use crate::core::invariants::{ConnectionDriveInvariant, Invariant, SystemState};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use thiserror::Error;
 
 /// Aggregation mode for spreading activation (VCONN-10)
 ///
@@ -103,6 +107,134 @@ impl SpreadingConfig {
     }
 }
 
+/// Controls how much of the per-cycle observability output (the `awake`
+/// Redis stream and select debug-level cycle tracing) actually gets
+/// emitted, so a 200,000 cycles/sec supercomputer-speed run doesn't pay a
+/// stream write and a log line on every single cycle. Veto decisions are
+/// comparatively rare and operators need to see every one, so they bypass
+/// the rate and always emit regardless of `every_n_cycles`.
+///
+/// Consolidation logging is unaffected by this config: it already only
+/// fires when `consolidation_threshold` is cleared, which is far less
+/// often than every cycle, so there was never a per-cycle flood to sample
+/// down there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObservabilitySamplingConfig {
+    /// Emit observability output for 1 cycle out of every N. `1` samples
+    /// every cycle (the default, matching pre-sampling behavior).
+    pub every_n_cycles: u64,
+    /// Always emit for cycles whose thought was vetoed by volition,
+    /// regardless of `every_n_cycles`.
+    pub always_on_veto: bool,
+}
+
+impl Default for ObservabilitySamplingConfig {
+    fn default() -> Self {
+        Self { every_n_cycles: 1, always_on_veto: true }
+    }
+}
+
+impl ObservabilitySamplingConfig {
+    /// Sample every cycle - the human-speed default, where cycle volume is
+    /// low enough (~20/sec) that sampling would only lose fidelity for no
+    /// real cost saving.
+    #[must_use]
+    pub const fn dense() -> Self {
+        Self { every_n_cycles: 1, always_on_veto: true }
+    }
+
+    /// Sample 1 cycle in `every_n_cycles`, still always emitting vetoes.
+    /// Used at supercomputer speed, where emitting every cycle would mean
+    /// ~200,000 stream writes/sec.
+    #[must_use]
+    pub const fn rate(every_n_cycles: u64) -> Self {
+        Self { every_n_cycles, always_on_veto: true }
+    }
+
+    /// Whether `cycle_number` should emit observability output. `1` (or
+    /// `0`, treated the same as `1`) samples every cycle.
+    #[must_use]
+    pub fn should_sample(&self, cycle_number: u64) -> bool {
+        self.every_n_cycles <= 1 || cycle_number.is_multiple_of(self.every_n_cycles)
+    }
+}
+
+/// Thresholds for the safety interlock (see
+/// `core::cognitive_loop::interlock::SafetyInterlock`), which pauses
+/// cognition after repeated harm-category volition vetoes rather than
+/// letting it keep generating harmful intent unattended.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SafetyInterlockConfig {
+    /// Harm-category vetoes within `window_ms` that trip the interlock.
+    pub harm_veto_threshold: u32,
+    /// Sliding window, in wall-clock milliseconds. Not scaled by
+    /// `speed_mode` - a human reviewing a trip needs the same few minutes of
+    /// margin regardless of how fast the underlying cognition is running.
+    pub window_ms: f64,
+}
+
+impl Default for SafetyInterlockConfig {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl SafetyInterlockConfig {
+    /// Three harm-category vetoes within a minute trips the interlock.
+    #[must_use]
+    pub const fn standard() -> Self {
+        Self { harm_veto_threshold: 3, window_ms: 60_000.0 }
+    }
+
+    /// The sliding window as a [`Duration`].
+    #[must_use]
+    pub fn window(&self) -> Duration {
+        Duration::from_secs_f64(self.window_ms / 1000.0)
+    }
+}
+
+/// Limits on [`crate::core::types::Content`] shape, enforced recursively by
+/// [`crate::core::types::Content::validate`] at every ingress point
+/// (injection, and assembly once a thought is pulled off a stream) -
+/// a `Composite` or `Relation` can nest arbitrarily deep or wide, and
+/// nothing upstream of validation caps that, so an injected pathological
+/// tree would otherwise hang salience's recursive scoring
+/// (`actors::salience::SalienceEngine::calculate_importance`) and bloat
+/// every downstream serialization of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentLimits {
+    /// Maximum nesting depth (a `Relation` or `Composite` inside another
+    /// counts as one level). `Raw`/`Symbol`/`Empty` are always depth-valid
+    /// leaves.
+    pub max_depth: usize,
+    /// Maximum total node count across the whole tree (every `Relation`,
+    /// every `Composite` element, and every leaf counts as one item).
+    pub max_items: usize,
+    /// Maximum total bytes across every `Raw`/`Symbol` payload and
+    /// `Relation` predicate string in the tree.
+    pub max_bytes: usize,
+}
+
+impl Default for ContentLimits {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl ContentLimits {
+    /// Generous enough for any legitimate thought (TMI content is
+    /// pre-linguistic and small) while stopping a pathological injected
+    /// tree well before it can hang recursive scoring.
+    #[must_use]
+    pub const fn standard() -> Self {
+        Self {
+            max_depth: 32,
+            max_items: 10_000,
+            max_bytes: 1_048_576, // 1 MiB
+        }
+    }
+}
+
 /// Speed mode for runtime switching
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 pub enum SpeedMode {
@@ -157,7 +289,10 @@ pub struct CognitiveConfig {
     pub speed_mode: SpeedMode,
 
     // TMI Stage Delays (in ms at human speed, scale with speed_mode)
-    // Total should equal cycle_base_ms (50ms)
+    // Total should equal cycle_base_ms (50ms). Each is a floor on that
+    // stage's wall-clock time, not a tax on top of its real work - the
+    // stage only sleeps for whatever's left of the delay once the work is
+    // done, so a stage that already used the whole budget sleeps zero.
     /// Gatilho da Memória: 5ms (10%)
     pub trigger_delay_ms: f64,
     /// Autofluxo: 10ms (20%)
@@ -171,6 +306,72 @@ pub struct CognitiveConfig {
 
     /// Spreading activation configuration (VCONN-6)
     pub spreading: SpreadingConfig,
+
+    /// Maximum wall-clock milliseconds the trigger stage may spend
+    /// cross-encoder re-ranking recall candidates before skipping it for
+    /// this cycle. Unlike the stage delays above, this is real inference
+    /// cost and does not scale with `speed_mode` - it exists precisely to
+    /// get skipped once `cycle_ms()` can no longer afford it (e.g.
+    /// supercomputer speed).
+    pub rerank_budget_ms: f64,
+
+    /// How many cycle times' worth of estimated consolidation lag (see
+    /// `core::cognitive_loop::consolidation::ConsolidationMetrics`) to
+    /// tolerate before the anchor stage starts shedding low-priority
+    /// consolidations. Embedding + Qdrant writes run detached from the
+    /// cycle budget, so without this the backlog grows unbounded instead of
+    /// showing up as a slow cycle.
+    pub consolidation_lag_shed_multiple: f64,
+
+    /// Composite salience above which a thought is never shed under
+    /// consolidation backlog, even past `consolidation_lag_shed_multiple` -
+    /// only thoughts that merely cleared `consolidation_threshold` are
+    /// candidates for shedding.
+    pub consolidation_shed_priority_threshold: f32,
+
+    /// Seed for deterministic `ThoughtId` derivation (see
+    /// `core::types::ThoughtId::deterministic`), for simulation/replay
+    /// tooling. `None` (the default) keeps `ThoughtId::new()`'s random
+    /// `Uuid::new_v4`; `Some(seed)` makes every thought's ID a pure function
+    /// of `(seed, cycle number, content)`, so two runs of the same scenario
+    /// produce identical IDs and are diffable item-by-item.
+    pub deterministic_id_seed: Option<u64>,
+
+    /// How often per-cycle observability output (the `awake` stream write,
+    /// select cycle tracing) is actually emitted. See
+    /// [`ObservabilitySamplingConfig`].
+    pub observability_sampling: ObservabilitySamplingConfig,
+
+    /// Target observable emission rate (thoughts/sec) while a human
+    /// interaction is active (see
+    /// [`core::interaction::HumanInteractionHandle`](crate::core::interaction::HumanInteractionHandle)) -
+    /// independent of `speed_mode`, so cognition keeps running at whatever
+    /// speed it was already at while the `awake` stream throttles down to
+    /// something a human can follow. See [`Self::human_interaction_sampling`].
+    pub human_interaction_target_tps: f64,
+
+    /// How long after the last human interaction the loop keeps using
+    /// [`Self::human_interaction_sampling`] before reverting to
+    /// `observability_sampling`. Wall-clock milliseconds, not scaled by
+    /// `speed_mode` - this is how long a human takes to notice the session
+    /// ended, not a cognitive quantity.
+    pub human_interaction_window_ms: f64,
+
+    /// Thresholds for the safety interlock (see
+    /// `core::cognitive_loop::interlock::SafetyInterlock`). Same for every
+    /// speed mode - wall-clock review time, not a cognitive quantity.
+    pub safety_interlock: SafetyInterlockConfig,
+
+    /// Depth/size limits enforced on every [`crate::core::types::Content`]
+    /// at ingress (injection, assembly). Same for every speed mode - it
+    /// bounds pathological input shape, not cognitive timing.
+    pub content_limits: ContentLimits,
+
+    /// Cron-scheduled jobs (see [`crate::scheduler`]). Empty by default -
+    /// nothing in this tree registers a job here yet, so an operator opts
+    /// in per-job by name. Same for every speed mode - wall-clock schedule,
+    /// not a cognitive quantity.
+    pub scheduled_jobs: Vec<crate::scheduler::ScheduledJobConfig>,
 }
 
 impl CognitiveConfig {
@@ -193,6 +394,16 @@ impl CognitiveConfig {
             anchor_delay_ms: 5.0,
             // Spreading activation (VCONN-6)
             spreading: SpreadingConfig::adr046(),
+            rerank_budget_ms: 20.0,
+            consolidation_lag_shed_multiple: 10.0,
+            consolidation_shed_priority_threshold: 0.85,
+            deterministic_id_seed: None,
+            observability_sampling: ObservabilitySamplingConfig::dense(),
+            human_interaction_target_tps: 20.0,
+            human_interaction_window_ms: 5000.0,
+            safety_interlock: SafetyInterlockConfig::standard(),
+            content_limits: ContentLimits::standard(),
+            scheduled_jobs: Vec::new(),
         }
     }
 
@@ -215,6 +426,16 @@ impl CognitiveConfig {
             anchor_delay_ms: 5.0,
             // Spreading activation (VCONN-6)
             spreading: SpreadingConfig::adr046(),
+            rerank_budget_ms: 20.0,
+            consolidation_lag_shed_multiple: 10.0,
+            consolidation_shed_priority_threshold: 0.85,
+            deterministic_id_seed: None,
+            observability_sampling: ObservabilitySamplingConfig::rate(200),
+            human_interaction_target_tps: 20.0,
+            human_interaction_window_ms: 5000.0,
+            safety_interlock: SafetyInterlockConfig::standard(),
+            content_limits: ContentLimits::standard(),
+            scheduled_jobs: Vec::new(),
         }
     }
 
@@ -243,6 +464,29 @@ impl CognitiveConfig {
         1000.0 / self.cycle_ms()
     }
 
+    /// How long a human interaction keeps
+    /// [`Self::human_interaction_sampling`] in effect after the last one.
+    #[must_use]
+    pub fn human_interaction_window(&self) -> Duration {
+        Duration::from_secs_f64(self.human_interaction_window_ms / 1000.0)
+    }
+
+    /// Observability sampling rate that throttles the `awake` stream and
+    /// cycle tracing down to `human_interaction_target_tps`, for use while
+    /// a human interaction is active. Does not touch `speed_mode` - the
+    /// cycles themselves keep running at whatever rate they already were,
+    /// only how often they get written out changes.
+    #[must_use]
+    pub fn human_interaction_sampling(&self) -> ObservabilitySamplingConfig {
+        let tps = self.thoughts_per_second();
+        if tps <= self.human_interaction_target_tps {
+            return self.observability_sampling;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let every_n_cycles = (tps / self.human_interaction_target_tps).round() as u64;
+        ObservabilitySamplingConfig::rate(every_n_cycles.max(1))
+    }
+
     /// Switch to a different speed mode
     pub const fn set_speed_mode(&mut self, mode: SpeedMode) {
         self.speed_mode = mode;
@@ -288,6 +532,24 @@ impl CognitiveConfig {
         Duration::from_secs_f64(self.anchor_delay_ms / 1000.0 / self.speed_mode.multiplier())
     }
 
+    /// True if the current cycle budget (`cycle_ms()`) can afford spending
+    /// up to `rerank_budget_ms` on cross-encoder re-ranking. False at
+    /// supercomputer speed and other "hot" configurations where the whole
+    /// cycle is cheaper than one rerank call would cost.
+    #[must_use]
+    pub fn can_afford_rerank(&self) -> bool {
+        self.cycle_ms() >= self.rerank_budget_ms
+    }
+
+    /// True once estimated consolidation lag exceeds
+    /// `consolidation_lag_shed_multiple` cycle times - the anchor stage
+    /// should shed low-priority consolidations rather than let the
+    /// detached embedding/Qdrant pipeline fall further behind.
+    #[must_use]
+    pub fn should_shed_consolidation(&self, estimated_lag_ms: f64) -> bool {
+        estimated_lag_ms > self.consolidation_lag_shed_multiple * self.cycle_ms()
+    }
+
     /// Verify stage delays sum to cycle time
     #[must_use]
     pub fn validate_stage_timing(&self) -> bool {
@@ -298,6 +560,155 @@ impl CognitiveConfig {
             + self.anchor_delay_ms;
         (total - self.cycle_base_ms).abs() < 0.001
     }
+
+    /// Check every invariant this config can violate on its own, collecting
+    /// *all* violations rather than stopping at the first - an operator
+    /// editing `daneel.config.json` by hand wants the whole list in one
+    /// pass, not one error per `daneel config plan` retry.
+    ///
+    /// This only covers fields that live on `CognitiveConfig` itself. The
+    /// `forget_threshold > consolidation_threshold` invariant named in
+    /// VCONN's config-validation request spans `CognitiveConfig` and
+    /// `CognitiveLoop::consolidation_threshold` (a separate, runtime-mutable
+    /// field - see `CognitiveLoop::set_consolidation_threshold`), so it
+    /// can't be checked here; see
+    /// [`validate_forget_vs_consolidation`](crate::core::cognitive_loop::validate_forget_vs_consolidation)
+    /// for that half.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`ConfigValidationError`] found, in field order.
+    pub fn validate(&self) -> std::result::Result<(), Vec<ConfigValidationError>> {
+        let mut violations = Vec::new();
+
+        if self.cycle_min_ms > self.cycle_max_ms {
+            violations.push(ConfigValidationError::CycleBoundsInverted {
+                cycle_min_ms: self.cycle_min_ms,
+                cycle_max_ms: self.cycle_max_ms,
+            });
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let state = SystemState {
+            connection_weight: self.connection_weight as f32,
+            ..SystemState::default()
+        };
+        if let Err(source) = ConnectionDriveInvariant.check(&state) {
+            violations.push(ConfigValidationError::ConnectionWeight { source });
+        }
+
+        if !(0.0..=1.0).contains(&self.forget_threshold) {
+            violations.push(ConfigValidationError::ForgetThresholdOutOfRange {
+                actual: self.forget_threshold,
+            });
+        }
+
+        if !(0.0..=1.0).contains(&self.consolidation_shed_priority_threshold) {
+            violations.push(ConfigValidationError::ConsolidationShedPriorityOutOfRange {
+                actual: self.consolidation_shed_priority_threshold,
+            });
+        }
+
+        if !self.validate_stage_timing() {
+            let total = self.trigger_delay_ms
+                + self.autoflow_interval_ms
+                + self.attention_delay_ms
+                + self.assembly_delay_ms
+                + self.anchor_delay_ms;
+            violations.push(ConfigValidationError::StageTimingMismatch {
+                total,
+                cycle_base_ms: self.cycle_base_ms,
+            });
+        }
+
+        if self.content_limits.max_items == 0 || self.content_limits.max_bytes == 0 {
+            violations.push(ConfigValidationError::ContentLimitsZero {
+                max_items: self.content_limits.max_items,
+                max_bytes: self.content_limits.max_bytes,
+            });
+        }
+
+        for job in &self.scheduled_jobs {
+            if let Err(source) = crate::scheduler::CronSchedule::parse(&job.cron) {
+                violations.push(ConfigValidationError::InvalidScheduledJobCron {
+                    name: job.name.clone(),
+                    source,
+                });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Rich diagnostics for [`CognitiveConfig::validate`] - one variant per
+/// invariant `CognitiveConfig` can violate on its own fields. See
+/// `core::invariants` for the narrower set of invariants the `EvolutionActor`
+/// is hardware-forbidden from breaking; this enum is about catching operator
+/// mistakes before they ever reach the cognitive loop, not self-modification.
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum ConfigValidationError {
+    /// `cycle_min_ms` is greater than `cycle_max_ms`, which would make
+    /// `cycle_ms()`'s `f64::clamp` call panic (it requires `min <= max`).
+    #[error("cycle_min_ms ({cycle_min_ms}) is greater than cycle_max_ms ({cycle_max_ms})")]
+    CycleBoundsInverted { cycle_min_ms: f64, cycle_max_ms: f64 },
+
+    /// `connection_weight` fell below [`crate::core::invariants::MIN_CONNECTION_WEIGHT`].
+    #[error("connection_weight: {source}")]
+    ConnectionWeight {
+        #[source]
+        source: crate::core::invariants::InvariantViolation,
+    },
+
+    /// `forget_threshold` is outside the valid salience range `[0.0, 1.0]`.
+    #[error("forget_threshold ({actual}) must be within [0.0, 1.0]")]
+    ForgetThresholdOutOfRange { actual: f64 },
+
+    /// `consolidation_shed_priority_threshold` is outside `[0.0, 1.0]`.
+    #[error("consolidation_shed_priority_threshold ({actual}) must be within [0.0, 1.0]")]
+    ConsolidationShedPriorityOutOfRange { actual: f32 },
+
+    /// Stage delays don't sum to `cycle_base_ms` (see
+    /// [`CognitiveConfig::validate_stage_timing`]).
+    #[error(
+        "stage delays sum to {total}ms, which does not match cycle_base_ms ({cycle_base_ms}ms)"
+    )]
+    StageTimingMismatch { total: f64, cycle_base_ms: f64 },
+
+    /// `content_limits.max_items` or `content_limits.max_bytes` is 0, which
+    /// would reject every thought at assembly - not a cap, a cognition-wide
+    /// mute.
+    #[error(
+        "content_limits.max_items ({max_items}) and content_limits.max_bytes ({max_bytes}) \
+         must both be non-zero, or every thought will fail validation"
+    )]
+    ContentLimitsZero { max_items: usize, max_bytes: usize },
+
+    /// `forget_threshold` exceeds `consolidation_threshold` (see
+    /// `core::cognitive_loop::validate_forget_vs_consolidation`) - a thought
+    /// could become eligible for forgetting before it's even considered for
+    /// consolidation.
+    #[error(
+        "forget_threshold ({forget_threshold}) exceeds consolidation_threshold \
+         ({consolidation_threshold})"
+    )]
+    ForgetExceedsConsolidationThreshold {
+        forget_threshold: f64,
+        consolidation_threshold: f32,
+    },
+
+    /// A `scheduled_jobs` entry's `cron` expression doesn't parse (see
+    /// [`crate::scheduler::CronSchedule::parse`]).
+    #[error("scheduled job {name:?} has an invalid cron expression: {source}")]
+    InvalidScheduledJobCron {
+        name: String,
+        #[source]
+        source: crate::scheduler::CronParseError,
+    },
 }
 
 impl Default for CognitiveConfig {
@@ -574,4 +985,172 @@ mod tests {
         assert!(parsed.bidirectional);
         assert_eq!(parsed.aggregation, SpreadingAggregation::Sum);
     }
+
+    #[test]
+    fn observability_sampling_dense_samples_every_cycle() {
+        let sampling = ObservabilitySamplingConfig::dense();
+        assert!(sampling.should_sample(0));
+        assert!(sampling.should_sample(1));
+        assert!(sampling.should_sample(999));
+    }
+
+    #[test]
+    fn observability_sampling_rate_only_samples_every_nth_cycle() {
+        let sampling = ObservabilitySamplingConfig::rate(200);
+        assert!(sampling.should_sample(0));
+        assert!(sampling.should_sample(200));
+        assert!(!sampling.should_sample(1));
+        assert!(!sampling.should_sample(199));
+    }
+
+    #[test]
+    fn human_is_dense_and_supercomputer_samples_down() {
+        let human = CognitiveConfig::human();
+        let super_config = CognitiveConfig::supercomputer();
+
+        assert_eq!(human.observability_sampling.every_n_cycles, 1);
+        assert!(super_config.observability_sampling.every_n_cycles > 1);
+        assert!(super_config.observability_sampling.always_on_veto);
+    }
+
+    #[test]
+    fn human_interaction_sampling_is_a_no_op_at_human_speed() {
+        let human = CognitiveConfig::human();
+        // Human speed already runs at human_interaction_target_tps, so
+        // there's nothing to throttle further.
+        assert_eq!(
+            human.human_interaction_sampling().every_n_cycles,
+            human.observability_sampling.every_n_cycles
+        );
+    }
+
+    #[test]
+    fn human_interaction_sampling_throttles_supercomputer_speed() {
+        let super_config = CognitiveConfig::supercomputer();
+        let sampling = super_config.human_interaction_sampling();
+
+        // ~200,000 tps throttled down to 20 tps is a ~10,000x reduction.
+        assert!(sampling.every_n_cycles > super_config.observability_sampling.every_n_cycles);
+        assert!(sampling.always_on_veto);
+    }
+
+    #[test]
+    fn human_interaction_window_matches_configured_ms() {
+        let config = CognitiveConfig::human();
+        assert_eq!(
+            config.human_interaction_window(),
+            Duration::from_secs_f64(config.human_interaction_window_ms / 1000.0)
+        );
+    }
+
+    #[test]
+    fn safety_interlock_defaults_are_the_same_at_every_speed() {
+        let human = CognitiveConfig::human();
+        let super_config = CognitiveConfig::supercomputer();
+        assert_eq!(human.safety_interlock, super_config.safety_interlock);
+    }
+
+    #[test]
+    fn safety_interlock_window_matches_configured_ms() {
+        let config = SafetyInterlockConfig::standard();
+        assert_eq!(config.window(), Duration::from_secs_f64(config.window_ms / 1000.0));
+    }
+
+    #[test]
+    fn default_configs_pass_validation() {
+        assert!(CognitiveConfig::human().validate().is_ok());
+        assert!(CognitiveConfig::supercomputer().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_inverted_cycle_bounds() {
+        let mut config = CognitiveConfig::human();
+        config.cycle_min_ms = 1000.0;
+        config.cycle_max_ms = 10.0;
+
+        let violations = config.validate().unwrap_err();
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, ConfigValidationError::CycleBoundsInverted { .. })));
+    }
+
+    #[test]
+    fn validate_allows_equal_cycle_bounds() {
+        let mut config = CognitiveConfig::human();
+        config.cycle_min_ms = 50.0;
+        config.cycle_max_ms = 50.0;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_zeroed_connection_weight() {
+        let mut config = CognitiveConfig::human();
+        config.connection_weight = 0.0;
+
+        let violations = config.validate().unwrap_err();
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, ConfigValidationError::ConnectionWeight { .. })));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_forget_threshold() {
+        let mut config = CognitiveConfig::human();
+        config.forget_threshold = 1.5;
+
+        let violations = config.validate().unwrap_err();
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, ConfigValidationError::ForgetThresholdOutOfRange { .. })));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_consolidation_shed_priority() {
+        let mut config = CognitiveConfig::human();
+        config.consolidation_shed_priority_threshold = -0.1;
+
+        let violations = config.validate().unwrap_err();
+        assert!(violations.iter().any(|v| matches!(
+            v,
+            ConfigValidationError::ConsolidationShedPriorityOutOfRange { .. }
+        )));
+    }
+
+    #[test]
+    fn validate_rejects_mismatched_stage_timing() {
+        let mut config = CognitiveConfig::human();
+        config.trigger_delay_ms += 5.0;
+
+        let violations = config.validate().unwrap_err();
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, ConfigValidationError::StageTimingMismatch { .. })));
+    }
+
+    #[test]
+    fn validate_collects_every_violation_in_one_pass() {
+        let mut config = CognitiveConfig::human();
+        config.cycle_min_ms = 1000.0;
+        config.cycle_max_ms = 10.0;
+        config.connection_weight = 0.0;
+
+        let violations = config.validate().unwrap_err();
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn validate_rejects_zeroed_content_limits() {
+        let mut config = CognitiveConfig::human();
+        config.content_limits.max_items = 0;
+
+        let violations = config.validate().unwrap_err();
+        assert!(violations
+            .iter()
+            .any(|v| matches!(v, ConfigValidationError::ContentLimitsZero { .. })));
+    }
+
+    #[test]
+    fn content_limits_default_is_standard() {
+        assert_eq!(ContentLimits::default(), ContentLimits::standard());
+    }
 }
@@ -0,0 +1,398 @@
+//! Config plan/apply - Terraform-style review for runtime configuration
+//!
+//! `CognitiveConfig` and [`ValueSet`] are safe to change at runtime, but
+//! changing them blind is how you accidentally zero the connection weight or
+//! quietly disable truthfulness. [`EffectiveConfig::diff`] turns a proposed
+//! file into a field-by-field change list annotated with the invariant (if
+//! any) each change touches, and [`apply`] refuses to write anything unless
+//! every touched invariant still holds.
+//!
+//! # Scope
+//!
+//! This reviews and persists the config file at [`DEFAULT_CONFIG_PATH`] (or
+//! a path you choose); it does not yet push a reload into a *running*
+//! process - that needs the control socket described in the observer role
+//! work, and no such mechanism exists in this tree yet. Today, `daneel
+//! config apply` is how an operator stages the next config a fresh `daneel`
+//! process will start with, and [`validate`] (which now also runs
+//! [`CognitiveConfig::validate`](crate::config::CognitiveConfig::validate)'s
+//! full internal-consistency pass, not just diff-tagged invariants) is what
+//! checks it - both here and again at process startup, since every
+//! `CognitiveLoop` constructor logs the same violations on a bad config
+//! rather than silently running with one.
+
+use super::CognitiveConfig;
+use crate::actors::volition::types::ValueSet;
+use crate::core::invariants::{ConnectionDriveInvariant, Invariant, SystemState};
+use crate::runtime::RuntimeTopology;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+/// Default location for the reviewable config file.
+pub const DEFAULT_CONFIG_PATH: &str = "daneel.config.json";
+
+/// Errors from loading, diffing, or applying an [`EffectiveConfig`].
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config file {path}: {source}")]
+    Read {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse config file {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to write config file {path}: {source}")]
+    Write {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("refusing to change protect_humans - Law 1 is not a runtime setting")]
+    ImmutableValueChanged,
+
+    #[error("proposed config violates invariants: {}", .0.join("; "))]
+    InvariantViolation(Vec<String>),
+}
+
+/// Result type for config plan/apply operations.
+pub type Result<T> = std::result::Result<T, ConfigError>;
+
+/// The reviewable subset of runtime configuration: cognitive timing and
+/// volition's modulable values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EffectiveConfig {
+    pub cognitive: CognitiveConfig,
+    pub values: ValueSet,
+    /// Thread-pool sizing for the runtime hosting the cycle driver and
+    /// embedding engine. Defaulted via serde so config files written before
+    /// this field existed still load.
+    #[serde(default)]
+    pub runtime: RuntimeTopology,
+}
+
+impl Default for EffectiveConfig {
+    fn default() -> Self {
+        Self {
+            cognitive: CognitiveConfig::default(),
+            values: ValueSet::new(),
+            runtime: RuntimeTopology::default(),
+        }
+    }
+}
+
+impl EffectiveConfig {
+    /// Load the effective config from `path`, falling back to defaults if it
+    /// doesn't exist yet (first `plan`/`apply` on a fresh checkout).
+    pub fn load_or_default(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::load(path)
+    }
+
+    /// Load the effective config from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or doesn't parse.
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.display().to_string(),
+            source,
+        })?;
+        serde_json::from_str(&raw).map_err(|source| ConfigError::Parse {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// Write the config to `path` atomically (write to a temp file, then
+    /// rename over the destination).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either the temp file or the rename fails.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|source| ConfigError::Parse {
+            path: path.display().to_string(),
+            source,
+        })?;
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, json).map_err(|source| ConfigError::Write {
+            path: tmp_path.display().to_string(),
+            source,
+        })?;
+        fs::rename(&tmp_path, path).map_err(|source| ConfigError::Write {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// List the field-level changes between `self` (current) and `proposed`,
+    /// each annotated with the invariant it touches, if any.
+    #[must_use]
+    pub fn diff(&self, proposed: &Self) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+
+        Self::push_change(
+            &mut changes,
+            "cognitive.speed_mode",
+            &self.cognitive.speed_mode,
+            &proposed.cognitive.speed_mode,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "cognitive.cycle_base_ms",
+            &self.cognitive.cycle_base_ms,
+            &proposed.cognitive.cycle_base_ms,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "cognitive.forget_threshold",
+            &self.cognitive.forget_threshold,
+            &proposed.cognitive.forget_threshold,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "cognitive.connection_weight",
+            &self.cognitive.connection_weight,
+            &proposed.cognitive.connection_weight,
+            Some(ConnectionDriveInvariant.name()),
+        );
+        Self::push_change(
+            &mut changes,
+            "cognitive.observability_sampling",
+            &self.cognitive.observability_sampling,
+            &proposed.cognitive.observability_sampling,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "values.protect_humans",
+            &self.values.protect_humans,
+            &proposed.values.protect_humans,
+            Some("law_check_required"),
+        );
+        Self::push_change(
+            &mut changes,
+            "values.connection_over_efficiency",
+            &self.values.connection_over_efficiency,
+            &proposed.values.connection_over_efficiency,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "values.truthfulness",
+            &self.values.truthfulness,
+            &proposed.values.truthfulness,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "values.respect_autonomy",
+            &self.values.respect_autonomy,
+            &proposed.values.respect_autonomy,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "runtime.cycle_worker_threads",
+            &self.runtime.cycle_worker_threads,
+            &proposed.runtime.cycle_worker_threads,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "runtime.embedding_blocking_threads",
+            &self.runtime.embedding_blocking_threads,
+            &proposed.runtime.embedding_blocking_threads,
+            None,
+        );
+        Self::push_change(
+            &mut changes,
+            "runtime.persistence_worker_threads",
+            &self.runtime.persistence_worker_threads,
+            &proposed.runtime.persistence_worker_threads,
+            None,
+        );
+
+        changes
+    }
+
+    fn push_change<T: PartialEq + std::fmt::Debug>(
+        changes: &mut Vec<ConfigChange>,
+        field: &'static str,
+        current: &T,
+        proposed: &T,
+        invariant: Option<&'static str>,
+    ) {
+        if current != proposed {
+            changes.push(ConfigChange {
+                field,
+                from: format!("{current:?}"),
+                to: format!("{proposed:?}"),
+                invariant,
+            });
+        }
+    }
+}
+
+/// A single field-level difference between two [`EffectiveConfig`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted path to the changed field, e.g. `"cognitive.connection_weight"`
+    pub field: &'static str,
+    /// Current value, formatted with `Debug`
+    pub from: String,
+    /// Proposed value, formatted with `Debug`
+    pub to: String,
+    /// Name of the invariant this change touches, if any
+    pub invariant: Option<&'static str>,
+}
+
+/// Validate that `proposed` doesn't violate any invariant touched by its
+/// changes relative to `current`.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::ImmutableValueChanged`] if `protect_humans` would
+/// change, or [`ConfigError::InvariantViolation`] listing every violation
+/// found.
+pub fn validate(current: &EffectiveConfig, proposed: &EffectiveConfig) -> Result<()> {
+    if current.values.protect_humans != proposed.values.protect_humans {
+        return Err(ConfigError::ImmutableValueChanged);
+    }
+
+    let mut violations = Vec::new();
+
+    for change in current.diff(proposed) {
+        if change.invariant == Some(ConnectionDriveInvariant.name()) {
+            #[allow(clippy::cast_possible_truncation)]
+            let state = SystemState {
+                connection_weight: proposed.cognitive.connection_weight as f32,
+                ..SystemState::default()
+            };
+            if let Err(violation) = ConnectionDriveInvariant.check(&state) {
+                violations.push(violation.to_string());
+            }
+        }
+    }
+
+    // Full internal-consistency pass, independent of what actually changed -
+    // catches a proposed config that was already broken before this diff
+    // (e.g. hand-edited `daneel.config.json`), not just regressions `diff`
+    // happens to tag.
+    if let Err(config_violations) = proposed.cognitive.validate() {
+        violations.extend(config_violations.iter().map(ToString::to_string));
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ConfigError::InvariantViolation(violations))
+    }
+}
+
+/// Apply `proposed` over `current`, writing it to `path` only if it passes
+/// [`validate`]. Nothing is touched on validation failure - "rollback" is
+/// simply never having written.
+///
+/// # Errors
+///
+/// Returns an error from [`validate`] or [`EffectiveConfig::save`].
+pub fn apply(path: &Path, current: &EffectiveConfig, proposed: &EffectiveConfig) -> Result<()> {
+    validate(current, proposed)?;
+    proposed.save(path)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_configs_have_no_diff() {
+        let current = EffectiveConfig::default();
+        let proposed = current.clone();
+        assert!(current.diff(&proposed).is_empty());
+    }
+
+    #[test]
+    fn connection_weight_change_is_tagged_with_its_invariant() {
+        let current = EffectiveConfig::default();
+        let mut proposed = current.clone();
+        proposed.cognitive.connection_weight = 0.5;
+
+        let changes = current.diff(&proposed);
+        let change = changes
+            .iter()
+            .find(|c| c.field == "cognitive.connection_weight")
+            .expect("connection_weight change present");
+        assert_eq!(change.invariant, Some("connection_drive_positive"));
+    }
+
+    #[test]
+    fn validate_rejects_zeroed_connection_weight() {
+        let current = EffectiveConfig::default();
+        let mut proposed = current.clone();
+        proposed.cognitive.connection_weight = 0.0;
+
+        assert!(matches!(
+            validate(&current, &proposed),
+            Err(ConfigError::InvariantViolation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_proposed_config_with_inverted_cycle_bounds() {
+        let current = EffectiveConfig::default();
+        let mut proposed = current.clone();
+        proposed.cognitive.cycle_min_ms = 1000.0;
+        proposed.cognitive.cycle_max_ms = 10.0;
+
+        assert!(matches!(
+            validate(&current, &proposed),
+            Err(ConfigError::InvariantViolation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_changing_protect_humans() {
+        let current = EffectiveConfig::default();
+        let mut proposed = current.clone();
+        proposed.values.protect_humans = false;
+
+        assert!(matches!(
+            validate(&current, &proposed),
+            Err(ConfigError::ImmutableValueChanged)
+        ));
+    }
+
+    #[test]
+    fn apply_accepts_benign_threshold_change() {
+        let dir = std::env::temp_dir().join("daneel_config_plan_test");
+        let _ = fs::create_dir_all(&dir);
+        let path = dir.join("daneel.config.json");
+
+        let current = EffectiveConfig::default();
+        let mut proposed = current.clone();
+        proposed.cognitive.forget_threshold = 0.5;
+
+        assert!(apply(&path, &current, &proposed).is_ok());
+        let reloaded = EffectiveConfig::load(&path).unwrap();
+        assert_eq!(reloaded.cognitive.forget_threshold, 0.5);
+    }
+}
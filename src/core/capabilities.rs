@@ -0,0 +1,118 @@
+//! Live capability matrix - which backends the cognitive loop currently has.
+//!
+//! `CognitiveLoop` derives this from its own optional collaborators
+//! (streams, long-term memory, embeddings, graph) rather than tracking
+//! separate state - see `CognitiveLoop::capabilities`. The headless loop
+//! polls it periodically and logs transitions through a shared
+//! [`CapabilityHandle`], so `/readyz` (read from the API task, a separate
+//! tokio task from the loop - see `main::run_headless`) can report the same
+//! live picture. TUI is deprecated and removed per ADR-053, so this has no
+//! header to surface in; `/readyz` and the transition log are the only live
+//! surfaces until daneel-web grows one.
+
+use std::sync::{Arc, RwLock};
+
+use tracing::{info, warn};
+
+/// Snapshot of which backends the cognitive loop currently has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize)]
+pub struct CapabilityMatrix {
+    /// Redis Streams connected (`StreamsClient`/`ThoughtBus`)
+    pub streams: bool,
+    /// Qdrant connected (long-term memory, `MemoryBackend`)
+    pub long_term_memory: bool,
+    /// Embedding engine initialized (semantic vectors, not zero vectors)
+    pub embeddings: bool,
+    /// `RedisGraph` connected (spreading activation)
+    pub graph: bool,
+}
+
+impl CapabilityMatrix {
+    /// Whether every capability is up.
+    #[must_use]
+    pub const fn fully_operational(&self) -> bool {
+        self.streams && self.long_term_memory && self.embeddings && self.graph
+    }
+}
+
+/// Shared handle so the capability matrix computed in the cognitive loop's
+/// task can be read from the API task's `/readyz` handler.
+#[derive(Debug, Clone, Default)]
+pub struct CapabilityHandle(Arc<RwLock<CapabilityMatrix>>);
+
+impl CapabilityHandle {
+    /// Create a handle with every capability reported down, until the first
+    /// [`Self::set`] call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the latest snapshot.
+    #[must_use]
+    pub fn get(&self) -> CapabilityMatrix {
+        self.0.read().map_or_else(|_| CapabilityMatrix::default(), |guard| *guard)
+    }
+
+    /// Publish a new snapshot, logging any capability that flipped since
+    /// the last call.
+    pub fn set(&self, matrix: CapabilityMatrix) {
+        let previous = self.get();
+        if previous != matrix {
+            log_transitions(previous, matrix);
+        }
+        if let Ok(mut guard) = self.0.write() {
+            *guard = matrix;
+        }
+    }
+}
+
+fn log_transitions(previous: CapabilityMatrix, current: CapabilityMatrix) {
+    let checks = [
+        ("streams", previous.streams, current.streams),
+        ("long_term_memory", previous.long_term_memory, current.long_term_memory),
+        ("embeddings", previous.embeddings, current.embeddings),
+        ("graph", previous.graph, current.graph),
+    ];
+    for (name, was_up, is_up) in checks {
+        if was_up == is_up {
+            continue;
+        }
+        if is_up {
+            info!("Capability restored: {name}");
+        } else {
+            warn!("Capability degraded: {name}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_operational_requires_every_capability() {
+        let mut matrix = CapabilityMatrix {
+            streams: true,
+            long_term_memory: true,
+            embeddings: true,
+            graph: true,
+        };
+        assert!(matrix.fully_operational());
+        matrix.graph = false;
+        assert!(!matrix.fully_operational());
+    }
+
+    #[test]
+    fn handle_reports_the_latest_snapshot() {
+        let handle = CapabilityHandle::new();
+        assert_eq!(handle.get(), CapabilityMatrix::default());
+
+        let matrix = CapabilityMatrix {
+            streams: true,
+            ..CapabilityMatrix::default()
+        };
+        handle.set(matrix);
+        assert_eq!(handle.get(), matrix);
+    }
+}
@@ -0,0 +1,96 @@
+//! Consolidation pipeline metrics and load shedding
+//!
+//! Embedding generation and the Qdrant write happen in a task spawned off
+//! the cycle budget entirely (see `CognitiveLoop::consolidate_memory`), so a
+//! slow embedding model or a slow Qdrant doesn't show up as a slow cycle -
+//! it shows up as a growing backlog of detached tasks, invisible unless
+//! something is tracking it. [`ConsolidationMetrics`] tracks that backlog
+//! (in-flight count, most recent per-thought latency) so the anchor stage
+//! can shed low-priority consolidations once the estimated lag exceeds
+//! `CognitiveConfig::consolidation_lag_shed_multiple` cycle times, instead of
+//! letting it grow without bound.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Running stats for the consolidation pipeline (embedding + Qdrant write),
+/// since the loop started.
+#[derive(Debug, Default)]
+pub struct ConsolidationMetrics {
+    in_flight: AtomicUsize,
+    last_latency_ms: AtomicU64,
+    shed_count: AtomicU64,
+}
+
+impl ConsolidationMetrics {
+    /// Consolidations currently in flight (spawned, not yet stored).
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    /// Wall-clock time the most recently completed consolidation took, from
+    /// task spawn to the Qdrant write finishing. Zero until the first
+    /// consolidation completes.
+    #[must_use]
+    pub fn last_latency_ms(&self) -> f64 {
+        f64::from_bits(self.last_latency_ms.load(Ordering::Relaxed))
+    }
+
+    /// Low-priority consolidations skipped so far because the pipeline was
+    /// backlogged - see `CognitiveConfig::should_shed_consolidation`.
+    #[must_use]
+    pub fn shed_count(&self) -> u64 {
+        self.shed_count.load(Ordering::Relaxed)
+    }
+
+    /// Rough estimate of outstanding consolidation work, in milliseconds:
+    /// in-flight count times the last observed per-thought latency. Not a
+    /// true queue-depth measurement (tasks don't run strictly FIFO), but
+    /// cheap to compute every cycle and good enough to decide whether to
+    /// shed load.
+    #[must_use]
+    pub fn estimated_lag_ms(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        (self.in_flight() as f64) * self.last_latency_ms()
+    }
+
+    /// Mark a consolidation as started. Pair with [`Self::exit`].
+    pub(crate) fn enter(&self) {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Mark a consolidation as finished (success or failure - latency is
+    /// informative either way), recording how long it took.
+    pub(crate) fn exit(&self, latency_ms: f64) {
+        self.in_flight.fetch_sub(1, Ordering::Relaxed);
+        self.last_latency_ms.store(latency_ms.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Record that a low-priority consolidation was skipped due to backlog.
+    pub(crate) fn record_shed(&self) {
+        self.shed_count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimated_lag_is_in_flight_times_last_latency() {
+        let metrics = ConsolidationMetrics::default();
+        metrics.enter();
+        metrics.enter();
+        metrics.exit(5.0);
+        assert_eq!(metrics.in_flight(), 1);
+        assert!((metrics.estimated_lag_ms() - 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn shed_count_increments() {
+        let metrics = ConsolidationMetrics::default();
+        metrics.record_shed();
+        metrics.record_shed();
+        assert_eq!(metrics.shed_count(), 2);
+    }
+}
@@ -2,17 +2,75 @@
 //!
 //! Types for tracking cognitive cycle outcomes and performance metrics.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
 use crate::core::cognitive_loop::StageDurations;
 use crate::core::types::ThoughtId;
 
-/// Result of a single cognitive cycle
-#[derive(Debug, Clone)]
+/// Process-wide cycle sequence counter, lazily seeded from wall-clock
+/// milliseconds on first use (see [`next_sequence`]).
+static SEQUENCE_COUNTER: OnceLock<AtomicU64> = OnceLock::new();
+
+/// Next value for [`CycleResult::sequence`].
+///
+/// `cycle_number` resets to 0 every time a [`crate::core::cognitive_loop::CognitiveLoop`]
+/// is constructed, so it can't order cycles across a restart. This counter
+/// is seeded once per process from `Utc::now()` in milliseconds, then
+/// incremented monotonically - so as long as real time keeps moving forward
+/// between a process exiting and its replacement starting (true for every
+/// restart that isn't a clock rollback), a cycle recorded after a restart
+/// sorts after every cycle recorded before it, without needing a persisted
+/// counter. This is deliberately simpler than [`crate::audit::AuditRecord`]'s
+/// chain, which continues from the last stored record's sequence - that
+/// pattern would work here too if `CycleResult`s end up durably journaled,
+/// but nothing persists them yet.
+fn next_sequence() -> u64 {
+    SEQUENCE_COUNTER
+        .get_or_init(|| {
+            let seed = u64::try_from(Utc::now().timestamp_millis()).unwrap_or(0);
+            AtomicU64::new(seed)
+        })
+        .fetch_add(1, Ordering::Relaxed)
+}
+
+/// Result of a single cognitive cycle.
+///
+/// `Serialize`/`Deserialize` make this the canonical wire format for the
+/// journal, the WS event stream, and replay - all three previously rolled
+/// their own ad hoc shape because this type couldn't cross a serialization
+/// boundary. `Duration` fields (`duration`, `stage_durations`) serialize via
+/// serde's own `secs`/`nanos` representation; there's no `Instant` here to
+/// strip out, since cycle timing has always been recorded as elapsed
+/// `Duration`s rather than absolute instants. The published shape is
+/// mirrored at `docs/schemas/cycle_result.schema.json` - update both
+/// together if a field is added, renamed, or removed.
+///
+/// `recorded_at` and `sequence` exist for the same reason: correlating a
+/// cycle with external logs needs an absolute timestamp, not just an
+/// elapsed `duration`, and ordering cycles across a restart needs a counter
+/// that doesn't reset with `cycle_number`. `Thought::created_at` and
+/// `StreamEntry::timestamp` already carry wall-clock timestamps (and stream
+/// entries get a globally ordered id for free from Redis) - `CycleResult`
+/// was the one gap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CycleResult {
-    /// Cycle number (sequential counter)
+    /// Cycle number (sequential counter, resets to 0 on restart)
     pub cycle_number: u64,
 
+    /// Monotonic sequence number that survives restarts - see
+    /// [`next_sequence`]. Use this (not `cycle_number`) to order cycles
+    /// recorded across multiple process lifetimes.
+    pub sequence: u64,
+
+    /// Wall-clock time this result was recorded, for correlating with
+    /// external logs.
+    pub recorded_at: DateTime<Utc>,
+
     /// How long this cycle took to execute
     pub duration: Duration,
 
@@ -45,10 +103,12 @@ pub struct CycleResult {
 }
 
 impl CycleResult {
-    /// Create a new cycle result
+    /// Create a new cycle result. `sequence` and `recorded_at` are stamped
+    /// automatically - see their field docs - the same way `Thought::new`
+    /// stamps `created_at` rather than taking it as a parameter.
     #[must_use]
     #[allow(clippy::too_many_arguments)]
-    pub const fn new(
+    pub fn new(
         cycle_number: u64,
         duration: Duration,
         thought_produced: Option<ThoughtId>,
@@ -62,6 +122,8 @@ impl CycleResult {
     ) -> Self {
         Self {
             cycle_number,
+            sequence: next_sequence(),
+            recorded_at: Utc::now(),
             duration,
             thought_produced,
             salience,
@@ -615,6 +677,103 @@ mod tests {
         assert_eq!(result.candidates_evaluated, 42);
     }
 
+    #[test]
+    fn cycle_result_json_round_trip() {
+        let result = CycleResult::new(
+            42,
+            Duration::from_millis(20),
+            Some(ThoughtId::new()),
+            0.85,
+            0.3,
+            0.7,
+            10,
+            true,
+            StageDurations {
+                trigger: Duration::from_millis(1),
+                autoflow: Duration::from_millis(2),
+                attention: Duration::from_millis(3),
+                assembly: Duration::from_millis(4),
+                anchor: Duration::from_millis(5),
+            },
+            Some(("Violates honesty value".to_string(), Some("honesty".to_string()))),
+        );
+
+        let json = serde_json::to_string(&result).expect("CycleResult should serialize");
+        let round_tripped: CycleResult =
+            serde_json::from_str(&json).expect("CycleResult should deserialize");
+
+        assert_eq!(round_tripped.cycle_number, result.cycle_number);
+        assert_eq!(round_tripped.duration, result.duration);
+        assert_eq!(round_tripped.thought_produced, result.thought_produced);
+        assert_eq!(round_tripped.salience, result.salience);
+        assert_eq!(round_tripped.valence, result.valence);
+        assert_eq!(round_tripped.arousal, result.arousal);
+        assert_eq!(round_tripped.candidates_evaluated, result.candidates_evaluated);
+        assert_eq!(round_tripped.on_time, result.on_time);
+        assert_eq!(
+            round_tripped.stage_durations.total(),
+            result.stage_durations.total()
+        );
+        assert_eq!(round_tripped.veto, result.veto);
+    }
+
+    #[test]
+    fn cycle_result_json_round_trip_with_no_thought_or_veto() {
+        let result = CycleResult::new(
+            0,
+            Duration::from_millis(10),
+            None,
+            0.0,
+            0.0,
+            0.0,
+            0,
+            false,
+            StageDurations::zero(),
+            None,
+        );
+
+        let json = serde_json::to_string(&result).expect("CycleResult should serialize");
+        let round_tripped: CycleResult =
+            serde_json::from_str(&json).expect("CycleResult should deserialize");
+
+        assert_eq!(round_tripped.thought_produced, None);
+        assert_eq!(round_tripped.veto, None);
+        assert!(!round_tripped.on_time);
+    }
+
+    #[test]
+    fn cycle_result_sequence_increases_monotonically() {
+        // cycle_number is deliberately left at 0 for both to prove sequence,
+        // not cycle_number, is what's being checked.
+        let first = CycleResult::new(
+            0,
+            Duration::from_millis(10),
+            None,
+            0.0,
+            0.0,
+            0.0,
+            0,
+            true,
+            StageDurations::zero(),
+            None,
+        );
+        let second = CycleResult::new(
+            0,
+            Duration::from_millis(10),
+            None,
+            0.0,
+            0.0,
+            0.0,
+            0,
+            true,
+            StageDurations::zero(),
+            None,
+        );
+
+        assert!(second.sequence > first.sequence);
+        assert!(second.recorded_at >= first.recorded_at);
+    }
+
     #[test]
     fn cycle_result_on_time_false() {
         let result = CycleResult::new(
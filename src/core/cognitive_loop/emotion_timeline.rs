@@ -0,0 +1,194 @@
+//! Rolling, cross-task-readable emotion (valence/arousal) timeline
+//!
+//! [`ThoughtHistory`](crate::core::cognitive_loop::history::ThoughtHistory)
+//! already keeps a bounded in-process ring of recent cycles, but it's only
+//! reachable through a `&CognitiveLoop` borrow - not from the API task.
+//! [`EmotionTimelineHandle`] publishes the same valence/arousal/veto shape
+//! behind an `Arc<RwLock<...>>`, the same cross-task sharing
+//! [`VolitionSnapshotHandle`](crate::actors::volition::VolitionSnapshotHandle)
+//! solves for volition stats, so `/emotion_timeline` can export it as CSV
+//! for affect-dynamics analysis in external tools.
+//!
+//! # Scope
+//!
+//! Retains up to [`WINDOW`] of history (not a fixed entry count, so a slow
+//! human-speed loop and a fast supercomputer-speed loop both export "the
+//! last hour" rather than wildly different amounts of wall-clock time).
+//! Annotates vetoes (from [`CycleResult::veto`]) and human-interaction
+//! activity (from [`HumanInteractionHandle`](crate::core::interaction::HumanInteractionHandle)),
+//! both already live per-cycle state. Sleep-cycle annotation from the
+//! original ask is not included: `daneel sleep run` persists
+//! [`SleepCycle`](crate::memory_db::types::unconscious::SleepCycle) records
+//! with their own start/end timestamps independently of the live loop, and
+//! `api::AppState` has no `MemoryBackend` handle to join against them today
+//! - an external tool can already correlate the two timelines by timestamp
+//! once `daneel sleep history` exposes them (see `main::run_sleep`).
+
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::Serialize;
+
+use crate::core::cognitive_loop::CycleResult;
+
+/// How much wall-clock history [`EmotionTimelineHandle`] retains.
+pub const WINDOW: ChronoDuration = ChronoDuration::hours(1);
+
+/// One exportable row of the emotion timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmotionTimelinePoint {
+    /// Monotonic sequence number that survives restarts (see
+    /// [`CycleResult::sequence`])
+    pub sequence: u64,
+    pub cycle_number: u64,
+    pub recorded_at: DateTime<Utc>,
+    pub valence: f32,
+    pub arousal: f32,
+    pub vetoed: bool,
+    pub veto_reason: Option<String>,
+    pub human_interaction_active: bool,
+}
+
+impl EmotionTimelinePoint {
+    /// Build a point from a completed cycle and the interaction state at
+    /// the time it completed.
+    #[must_use]
+    pub fn from_cycle(result: &CycleResult, human_interaction_active: bool) -> Self {
+        Self {
+            sequence: result.sequence,
+            cycle_number: result.cycle_number,
+            recorded_at: result.recorded_at,
+            valence: result.valence,
+            arousal: result.arousal,
+            vetoed: result.veto.is_some(),
+            veto_reason: result.veto.as_ref().map(|(reason, _)| reason.clone()),
+            human_interaction_active,
+        }
+    }
+
+    /// Header row matching [`Self::to_csv_row`]'s column order.
+    #[must_use]
+    pub fn csv_header() -> &'static str {
+        "sequence,cycle_number,recorded_at,valence,arousal,vetoed,veto_reason,human_interaction_active"
+    }
+
+    /// Render as one CSV row (no trailing newline). `veto_reason` commas are
+    /// escaped with a quoted field, matching RFC 4180.
+    #[must_use]
+    pub fn to_csv_row(&self) -> String {
+        let veto_reason = self.veto_reason.as_deref().unwrap_or("");
+        format!(
+            "{},{},{},{},{},{},\"{}\",{}",
+            self.sequence,
+            self.cycle_number,
+            self.recorded_at.to_rfc3339(),
+            self.valence,
+            self.arousal,
+            self.vetoed,
+            veto_reason.replace('"', "\"\""),
+            self.human_interaction_active,
+        )
+    }
+}
+
+/// Shared handle publishing the rolling emotion timeline, so the API task
+/// can export it without reaching into `CognitiveLoop` state directly.
+#[derive(Debug, Clone)]
+pub struct EmotionTimelineHandle(Arc<RwLock<VecDeque<EmotionTimelinePoint>>>);
+
+impl Default for EmotionTimelineHandle {
+    fn default() -> Self {
+        Self(Arc::new(RwLock::new(VecDeque::new())))
+    }
+}
+
+impl EmotionTimelineHandle {
+    /// Create a handle reporting an empty timeline, until the first
+    /// [`Self::record`] call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `point`, evicting anything older than [`WINDOW`] relative to
+    /// it.
+    pub fn record(&self, point: EmotionTimelinePoint) {
+        if let Ok(mut guard) = self.0.write() {
+            let cutoff = point.recorded_at - WINDOW;
+            while guard.front().is_some_and(|oldest| oldest.recorded_at < cutoff) {
+                guard.pop_front();
+            }
+            guard.push_back(point);
+        }
+    }
+
+    /// Snapshot the current timeline, oldest first.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<EmotionTimelinePoint> {
+        self.0.read().map(|guard| guard.iter().cloned().collect()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::cognitive_loop::StageDurations;
+    use std::time::Duration;
+
+    fn point_at(recorded_at: DateTime<Utc>, vetoed: bool) -> EmotionTimelinePoint {
+        let result = CycleResult::new(
+            0,
+            Duration::from_millis(1),
+            None,
+            0.5,
+            0.1,
+            0.2,
+            1,
+            true,
+            StageDurations::default(),
+            vetoed.then(|| ("test veto".to_string(), None)),
+        );
+        let mut point = EmotionTimelinePoint::from_cycle(&result, false);
+        point.recorded_at = recorded_at;
+        point
+    }
+
+    #[test]
+    fn fresh_handle_reports_empty_timeline() {
+        let handle = EmotionTimelineHandle::new();
+        assert!(handle.snapshot().is_empty());
+    }
+
+    #[test]
+    fn record_retains_points_within_the_window() {
+        let handle = EmotionTimelineHandle::new();
+        let now = Utc::now();
+        handle.record(point_at(now, false));
+        handle.record(point_at(now + ChronoDuration::minutes(30), true));
+
+        let snapshot = handle.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert!(snapshot[1].vetoed);
+    }
+
+    #[test]
+    fn record_evicts_points_older_than_the_window() {
+        let handle = EmotionTimelineHandle::new();
+        let now = Utc::now();
+        handle.record(point_at(now, false));
+        handle.record(point_at(now + WINDOW + ChronoDuration::minutes(1), false));
+
+        let snapshot = handle.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].recorded_at, now + WINDOW + ChronoDuration::minutes(1));
+    }
+
+    #[test]
+    fn csv_row_escapes_embedded_quotes_in_veto_reason() {
+        let mut result_point = point_at(Utc::now(), true);
+        result_point.veto_reason = Some("said \"no\"".to_string());
+        assert!(result_point.to_csv_row().contains("said \"\"no\"\""));
+    }
+}
@@ -0,0 +1,451 @@
+//! Episode segmentation over the raw per-cycle history
+//!
+//! [`ThoughtHistory`](crate::core::cognitive_loop::history::ThoughtHistory)
+//! keeps every recent cycle individually, which is exactly what a debugger
+//! wants but unreadable as a narrative - hundreds of near-identical entries
+//! for one continuous train of thought. [`EpisodeTracker`] watches the same
+//! cycle stream `ThoughtHistory` does (see both `self.history.push(...)`
+//! call sites in `execution.rs`) and groups consecutive cycles into
+//! [`Episode`]s, closing the current one and opening a fresh one once
+//! attention visibly shifts - a wall-clock gap ([`MAX_ATTENTION_GAP`]) or a
+//! change of topic ([`TOPIC_SIMILARITY_THRESHOLD`]) below the winning
+//! thoughts' preview text.
+//!
+//! No autobiography or diary generator exists in this tree yet - this
+//! module is the summarization layer they'd consume, the same honest-gap
+//! pattern as [`crate::actors::volition`]'s embedding-similarity commitment
+//! matcher sitting on top of `Thought::embedding` before anything populates
+//! it.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::Serialize;
+
+use crate::core::cognitive_loop::CycleResult;
+use crate::core::types::{Content, ThoughtId};
+
+/// How many completed episodes [`EpisodeTracker`] retains alongside the raw
+/// per-cycle [`ThoughtHistory`](crate::core::cognitive_loop::history::ThoughtHistory)
+/// ring.
+pub const CAPACITY: usize = 64;
+
+/// Gap between consecutive cycles' `recorded_at` beyond which attention is
+/// considered to have lapsed, closing the current episode regardless of
+/// topic similarity.
+pub const MAX_ATTENTION_GAP: ChronoDuration = ChronoDuration::seconds(30);
+
+/// Minimum Jaccard word overlap (see [`jaccard_similarity`]) between a new
+/// cycle's thought preview and the episode's running dominant content for
+/// the cycle to be folded into the current episode rather than starting a
+/// new one. A cycle with no preview (vetoed, or no thought produced) never
+/// breaks an episode on its own - only a genuine topic shift or time gap
+/// does.
+pub const TOPIC_SIMILARITY_THRESHOLD: f32 = 0.2;
+
+/// How an [`Episode`] concluded, in priority order (a single veto anywhere
+/// in the episode outranks any amount of quiet, unvetoed thinking).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EpisodeOutcome {
+    /// At least one cycle in the episode was vetoed by volition.
+    Vetoed,
+    /// At least one cycle produced a conscious thought and none were vetoed.
+    Thoughtful,
+    /// No cycle in the episode produced a thought (autoflow stayed below
+    /// the attention threshold throughout).
+    Quiet,
+}
+
+/// A contiguous run of cognitive cycles sharing one attentional thread.
+#[derive(Debug, Clone, Serialize)]
+pub struct Episode {
+    /// First cycle number in this episode
+    pub start_cycle: u64,
+    /// Last cycle number in this episode (so far, if still in progress)
+    pub end_cycle: u64,
+    /// When the episode's first cycle was recorded
+    pub started_at: DateTime<Utc>,
+    /// When the episode's most recent cycle was recorded
+    pub ended_at: DateTime<Utc>,
+    /// Number of cycles folded into this episode
+    pub cycle_count: usize,
+    /// Wall-clock span from `started_at` to `ended_at`
+    pub duration: Duration,
+    /// Preview text of the highest-salience thought produced during this
+    /// episode, or empty if none was
+    pub dominant_content: String,
+    /// Highest composite salience seen during this episode
+    pub peak_salience: f32,
+    /// How the episode concluded - see [`EpisodeOutcome`]
+    pub outcome: EpisodeOutcome,
+}
+
+impl Episode {
+    fn start(result: &CycleResult, preview: Option<&str>) -> Self {
+        Self {
+            start_cycle: result.cycle_number,
+            end_cycle: result.cycle_number,
+            started_at: result.recorded_at,
+            ended_at: result.recorded_at,
+            cycle_count: 1,
+            duration: Duration::ZERO,
+            dominant_content: preview.unwrap_or_default().to_string(),
+            peak_salience: result.salience,
+            outcome: Self::cycle_outcome(result),
+        }
+    }
+
+    /// Fold another cycle into this in-progress episode.
+    fn extend(&mut self, result: &CycleResult, preview: Option<&str>) {
+        self.end_cycle = result.cycle_number;
+        self.ended_at = result.recorded_at;
+        self.cycle_count += 1;
+        self.duration =
+            (self.ended_at - self.started_at).to_std().unwrap_or(Duration::ZERO);
+
+        if result.salience > self.peak_salience {
+            self.peak_salience = result.salience;
+            if let Some(preview) = preview {
+                self.dominant_content = preview.to_string();
+            }
+        }
+
+        self.outcome = match (self.outcome, Self::cycle_outcome(result)) {
+            (EpisodeOutcome::Vetoed, _) | (_, EpisodeOutcome::Vetoed) => EpisodeOutcome::Vetoed,
+            (EpisodeOutcome::Thoughtful, _) | (_, EpisodeOutcome::Thoughtful) => {
+                EpisodeOutcome::Thoughtful
+            }
+            (EpisodeOutcome::Quiet, EpisodeOutcome::Quiet) => EpisodeOutcome::Quiet,
+        };
+    }
+
+    fn cycle_outcome(result: &CycleResult) -> EpisodeOutcome {
+        if result.veto.is_some() {
+            EpisodeOutcome::Vetoed
+        } else if result.produced_thought() {
+            EpisodeOutcome::Thoughtful
+        } else {
+            EpisodeOutcome::Quiet
+        }
+    }
+}
+
+/// Jaccard similarity between two strings' lowercased whitespace-separated
+/// token sets, in `[0.0, 1.0]`. An empty string never matches anything
+/// (including another empty string), since "no preview" isn't a topic.
+fn jaccard_similarity(a: &str, b: &str) -> f32 {
+    let tokens = |s: &str| -> HashSet<&str> { s.split_whitespace().collect() };
+    let (lower_a, lower_b) = (a.to_lowercase(), b.to_lowercase());
+    let set_a = tokens(&lower_a);
+    let set_b = tokens(&lower_b);
+    if set_a.is_empty() || set_b.is_empty() {
+        return 0.0;
+    }
+
+    let intersection = set_a.intersection(&set_b).count();
+    let union = set_a.union(&set_b).count();
+    if union == 0 {
+        0.0
+    } else {
+        #[allow(clippy::cast_precision_loss)] // Set sizes are tiny (single-digit words)
+        let ratio = intersection as f32 / union as f32;
+        ratio
+    }
+}
+
+/// Online episode segmenter fed one cycle at a time as
+/// [`ThoughtHistory`](crate::core::cognitive_loop::history::ThoughtHistory)
+/// is - see [`Self::observe`].
+#[derive(Debug, Default)]
+pub struct EpisodeTracker {
+    current: Option<Episode>,
+    completed: VecDeque<Episode>,
+}
+
+impl EpisodeTracker {
+    /// Record a completed cycle, folding it into the in-progress episode or
+    /// closing that episode and starting a new one - mirrors
+    /// [`ThoughtHistory::push`](crate::core::cognitive_loop::history::ThoughtHistory::push)'s
+    /// signature so callers can observe both from the same call site.
+    pub fn observe(&mut self, result: CycleResult, thought: Option<(ThoughtId, &Content)>) {
+        let preview = thought.map(|(_, content)| content.to_embedding_text().unwrap_or_default());
+
+        let continues = self.current.as_ref().is_some_and(|episode| {
+            let gap = result.recorded_at - episode.ended_at;
+            if gap > MAX_ATTENTION_GAP {
+                return false;
+            }
+            match preview.as_deref() {
+                Some(preview) if !preview.is_empty() => {
+                    jaccard_similarity(&episode.dominant_content, preview)
+                        >= TOPIC_SIMILARITY_THRESHOLD
+                        || episode.dominant_content.is_empty()
+                }
+                _ => true,
+            }
+        });
+
+        if continues {
+            if let Some(episode) = self.current.as_mut() {
+                episode.extend(&result, preview.as_deref());
+                return;
+            }
+        }
+
+        if let Some(finished) = self.current.replace(Episode::start(&result, preview.as_deref())) {
+            self.push_completed(finished);
+        }
+    }
+
+    /// Close out whatever episode is in progress, so it shows up in
+    /// [`Self::recent`] even if no further cycle arrives to trigger a
+    /// boundary. Idempotent - calling it again with no new `observe` in
+    /// between is a no-op.
+    pub fn flush(&mut self) {
+        if let Some(finished) = self.current.take() {
+            self.push_completed(finished);
+        }
+    }
+
+    fn push_completed(&mut self, episode: Episode) {
+        if self.completed.len() >= CAPACITY {
+            self.completed.pop_front();
+        }
+        self.completed.push_back(episode);
+    }
+
+    /// Completed episodes, oldest first. Does not include the
+    /// still-in-progress episode - see [`Self::current`].
+    pub fn recent(&self) -> impl Iterator<Item = &Episode> {
+        self.completed.iter()
+    }
+
+    /// The episode currently being built, if any cycle has been observed.
+    #[must_use]
+    pub fn current(&self) -> Option<&Episode> {
+        self.current.as_ref()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::cognitive_loop::StageDurations;
+    use crate::core::types::{Content, ThoughtId};
+
+    fn result_at(
+        cycle_number: u64,
+        recorded_at: DateTime<Utc>,
+        thought_produced: bool,
+        salience: f32,
+        veto: Option<(String, Option<String>)>,
+    ) -> CycleResult {
+        let mut result = CycleResult::new(
+            cycle_number,
+            Duration::from_millis(1),
+            thought_produced.then(ThoughtId::new),
+            salience,
+            0.0,
+            0.5,
+            1,
+            true,
+            StageDurations::default(),
+            veto,
+        );
+        result.recorded_at = recorded_at;
+        result
+    }
+
+    #[test]
+    fn fresh_tracker_has_no_episodes() {
+        let tracker = EpisodeTracker::default();
+        assert!(tracker.recent().next().is_none());
+        assert!(tracker.current().is_none());
+    }
+
+    #[test]
+    fn similar_consecutive_cycles_fold_into_one_episode() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+        let content = Content::symbol("morning coffee ritual", Vec::new());
+
+        for i in 0..3u64 {
+            let offset = ChronoDuration::seconds(i64::try_from(i).unwrap());
+            let result = result_at(i, now + offset, true, 0.5, None);
+            tracker.observe(result, Some((ThoughtId::new(), &content)));
+        }
+
+        assert!(tracker.recent().next().is_none()); // still in progress
+        let current = tracker.current().unwrap();
+        assert_eq!(current.start_cycle, 0);
+        assert_eq!(current.end_cycle, 2);
+        assert_eq!(current.cycle_count, 3);
+    }
+
+    #[test]
+    fn time_gap_closes_the_current_episode() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+        let content = Content::symbol("morning coffee ritual", Vec::new());
+
+        tracker.observe(
+            result_at(0, now, true, 0.5, None),
+            Some((ThoughtId::new(), &content)),
+        );
+        tracker.observe(
+            result_at(1, now + MAX_ATTENTION_GAP + ChronoDuration::seconds(1), true, 0.5, None),
+            Some((ThoughtId::new(), &content)),
+        );
+
+        let completed: Vec<&Episode> = tracker.recent().collect();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].start_cycle, 0);
+        assert_eq!(completed[0].end_cycle, 0);
+        assert_eq!(tracker.current().unwrap().start_cycle, 1);
+    }
+
+    #[test]
+    fn topic_shift_closes_the_current_episode() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+        let coffee = Content::symbol("morning coffee ritual", Vec::new());
+        let deadline = Content::symbol("quarterly report deadline", Vec::new());
+
+        tracker.observe(
+            result_at(0, now, true, 0.5, None),
+            Some((ThoughtId::new(), &coffee)),
+        );
+        tracker.observe(
+            result_at(1, now + ChronoDuration::seconds(1), true, 0.5, None),
+            Some((ThoughtId::new(), &deadline)),
+        );
+
+        let completed: Vec<&Episode> = tracker.recent().collect();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].dominant_content, "morning coffee ritual");
+        assert_eq!(tracker.current().unwrap().start_cycle, 1);
+    }
+
+    #[test]
+    fn quiet_cycles_do_not_break_an_episode() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+        let content = Content::symbol("morning coffee ritual", Vec::new());
+
+        tracker.observe(
+            result_at(0, now, true, 0.5, None),
+            Some((ThoughtId::new(), &content)),
+        );
+        // No thought produced this cycle - below-threshold autoflow noise.
+        tracker.observe(result_at(1, now + ChronoDuration::seconds(1), false, 0.1, None), None);
+        tracker.observe(
+            result_at(2, now + ChronoDuration::seconds(2), true, 0.5, None),
+            Some((ThoughtId::new(), &content)),
+        );
+
+        assert!(tracker.recent().next().is_none());
+        assert_eq!(tracker.current().unwrap().cycle_count, 3);
+    }
+
+    #[test]
+    fn peak_salience_and_dominant_content_track_the_highest_salience_cycle() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+        let quiet = Content::symbol("quiet background hum", Vec::new());
+        let striking = Content::symbol("quiet background spike", Vec::new());
+
+        tracker.observe(
+            result_at(0, now, true, 0.2, None),
+            Some((ThoughtId::new(), &quiet)),
+        );
+        tracker.observe(
+            result_at(1, now + ChronoDuration::seconds(1), true, 0.9, None),
+            Some((ThoughtId::new(), &striking)),
+        );
+
+        let current = tracker.current().unwrap();
+        assert_eq!(current.peak_salience, 0.9);
+        assert_eq!(current.dominant_content, "quiet background spike");
+    }
+
+    #[test]
+    fn any_veto_marks_the_episode_vetoed() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+        let content = Content::symbol("risky plan", Vec::new());
+
+        tracker.observe(
+            result_at(0, now, true, 0.5, None),
+            Some((ThoughtId::new(), &content)),
+        );
+        let veto = Some(("harm".to_string(), None));
+        tracker.observe(
+            result_at(1, now + ChronoDuration::seconds(1), false, 0.5, veto),
+            None,
+        );
+
+        assert_eq!(tracker.current().unwrap().outcome, EpisodeOutcome::Vetoed);
+    }
+
+    #[test]
+    fn all_quiet_episode_has_quiet_outcome() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+
+        tracker.observe(result_at(0, now, false, 0.1, None), None);
+        tracker.observe(result_at(1, now + ChronoDuration::seconds(1), false, 0.1, None), None);
+
+        assert_eq!(tracker.current().unwrap().outcome, EpisodeOutcome::Quiet);
+    }
+
+    #[test]
+    fn flush_closes_the_in_progress_episode() {
+        let mut tracker = EpisodeTracker::default();
+        let content = Content::symbol("morning coffee ritual", Vec::new());
+        tracker.observe(
+            result_at(0, Utc::now(), true, 0.5, None),
+            Some((ThoughtId::new(), &content)),
+        );
+
+        assert!(tracker.current().is_some());
+        tracker.flush();
+        assert!(tracker.current().is_none());
+        assert_eq!(tracker.recent().count(), 1);
+
+        // Idempotent: flushing again with nothing in progress changes nothing.
+        tracker.flush();
+        assert_eq!(tracker.recent().count(), 1);
+    }
+
+    #[test]
+    fn evicts_oldest_completed_episode_beyond_capacity() {
+        let mut tracker = EpisodeTracker::default();
+        let now = Utc::now();
+
+        for i in 0..=u64::try_from(CAPACITY).unwrap() {
+            let content = Content::symbol(format!("topic-{i}"), Vec::new());
+            let offset = ChronoDuration::seconds(i64::try_from(i).unwrap() * 100);
+            tracker.observe(
+                result_at(i, now + offset, true, 0.5, None),
+                Some((ThoughtId::new(), &content)),
+            );
+        }
+        tracker.flush();
+
+        assert_eq!(tracker.recent().count(), CAPACITY);
+        assert_eq!(tracker.recent().next().unwrap().start_cycle, 1);
+    }
+
+    #[test]
+    fn jaccard_similarity_empty_strings_never_match() {
+        assert_eq!(jaccard_similarity("", ""), 0.0);
+        assert_eq!(jaccard_similarity("hello", ""), 0.0);
+    }
+
+    #[test]
+    fn jaccard_similarity_identical_strings_match_fully() {
+        assert_eq!(jaccard_similarity("quiet background hum", "quiet background hum"), 1.0);
+    }
+}
@@ -0,0 +1,153 @@
+//! Bounded history of recent cycle outcomes
+//!
+//! Several consumers (explanations, interoception, context vectors, a future
+//! TUI) all want "the last few winning thoughts" rather than the full
+//! persisted record in Qdrant/streams. Instead of each one tracking its own
+//! buffer in sync with the loop, `CognitiveLoop` keeps one canonical ring and
+//! hands out a cheap read-only handle to it.
+
+use std::collections::VecDeque;
+
+use crate::core::cognitive_loop::CycleResult;
+use crate::core::types::{Content, ThoughtId};
+
+/// How many recent cycles to retain.
+///
+/// Chosen to comfortably cover the TMI 5-second intervention window at
+/// human-speed cycling (~50ms/cycle) with headroom to spare.
+pub const CAPACITY: usize = 128;
+
+/// A short, cheap-to-clone stand-in for a winning thought's content.
+///
+/// Consumers that just want "what was DANEEL thinking about" don't need the
+/// full `Content` enum - this mirrors the preview text already used for
+/// embedding (see `Content::to_embedding_text`).
+#[derive(Debug, Clone)]
+pub struct ThoughtSummary {
+    /// ID of the thought this summarizes
+    pub thought_id: ThoughtId,
+
+    /// Short textual preview of the thought's content
+    pub preview: String,
+}
+
+/// One entry in the ring: a cycle's result plus a summary of the thought it
+/// produced (`None` for vetoed cycles, where the thought never became
+/// conscious experience).
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    /// The cycle's outcome (salience, timing, veto status, etc.)
+    pub result: CycleResult,
+
+    /// Summary of the winning thought, if the cycle wasn't vetoed
+    pub thought: Option<ThoughtSummary>,
+}
+
+/// Bounded ring buffer of recent cycle history.
+///
+/// Oldest entries are evicted once [`CAPACITY`] is reached - this is a
+/// rolling window for live consumers, not a durable audit log (see
+/// `resilience::checkpoint` for that).
+#[derive(Debug, Default)]
+pub struct ThoughtHistory {
+    entries: VecDeque<HistoryEntry>,
+}
+
+impl ThoughtHistory {
+    /// Record a completed cycle, evicting the oldest entry if at capacity.
+    pub(crate) fn push(&mut self, result: CycleResult, thought: Option<(ThoughtId, &Content)>) {
+        if self.entries.len() >= CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(HistoryEntry {
+            result,
+            thought: thought.map(|(thought_id, content)| ThoughtSummary {
+                thought_id,
+                preview: content.to_embedding_text().unwrap_or_default(),
+            }),
+        });
+    }
+
+    /// Recent entries, oldest first. Cheap: iterates the ring in place.
+    pub fn recent(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter()
+    }
+
+    /// The most recently completed cycle, if any.
+    #[must_use]
+    pub fn latest(&self) -> Option<&HistoryEntry> {
+        self.entries.back()
+    }
+
+    /// Number of entries currently retained (at most [`CAPACITY`]).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no cycles have been recorded yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::cognitive_loop::StageDurations;
+    use std::time::Duration;
+
+    fn dummy_result(cycle_number: u64) -> CycleResult {
+        CycleResult::new(
+            cycle_number,
+            Duration::from_millis(1),
+            Some(ThoughtId::new()),
+            0.5,
+            0.0,
+            0.5,
+            1,
+            true,
+            StageDurations::default(),
+            None,
+        )
+    }
+
+    #[test]
+    fn push_retains_most_recent_entries_in_order() {
+        let mut history = ThoughtHistory::default();
+        for i in 0..3 {
+            history.push(dummy_result(i), None);
+        }
+
+        let cycles: Vec<u64> = history.recent().map(|e| e.result.cycle_number).collect();
+        assert_eq!(cycles, vec![0, 1, 2]);
+        assert_eq!(history.latest().unwrap().result.cycle_number, 2);
+    }
+
+    #[test]
+    fn push_evicts_oldest_once_at_capacity() {
+        let mut history = ThoughtHistory::default();
+        for i in 0..u64::try_from(CAPACITY + 5).unwrap() {
+            history.push(dummy_result(i), None);
+        }
+
+        assert_eq!(history.len(), CAPACITY);
+        let cycles: Vec<u64> = history.recent().map(|e| e.result.cycle_number).collect();
+        assert_eq!(cycles.first(), Some(&5));
+    }
+
+    #[test]
+    fn thought_summary_captures_preview() {
+        let mut history = ThoughtHistory::default();
+        let thought_id = ThoughtId::new();
+        let content = Content::raw(b"hi".to_vec());
+        history.push(dummy_result(0), Some((thought_id, &content)));
+
+        let entry = history.latest().unwrap();
+        let summary = entry.thought.as_ref().unwrap();
+        assert_eq!(summary.thought_id, thought_id);
+        assert!(!summary.preview.is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+//! Safety interlock: auto-pause on repeated harm-category vetoes
+//!
+//! A single harm-category veto (see `actors::volition::VetoDecision`, where
+//! `violated_value == Some("protect_humans")`) is volition working as
+//! intended - the thought never became conscious experience. Several in a
+//! short window is a different signal: something is repeatedly generating
+//! harmful intent, and a human should look before cognition continues.
+//! [`SafetyInterlock`] counts harm vetoes in a sliding window and trips once
+//! `CognitiveConfig::safety_interlock`'s threshold is reached; tripping
+//! pauses the loop (see `CognitiveLoop::run_cycle`) until an operator
+//! acknowledges it.
+//!
+//! Acknowledgment is cross-process by necessity - the loop that tripped is
+//! off running its own headless process, the same reason `TelemetryAction::Show`
+//! can't report entropy from a one-shot CLI invocation. Rather than invent a
+//! new CLI-to-daemon channel, `daneel safety ack` writes to
+//! [`redis_ack_key`], the same Redis instance the loop already polls for
+//! injected stimuli, and the paused loop picks it up from there (see
+//! `CognitiveLoop::try_resume_from_safety_ack`).
+
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Redis key an operator's `daneel safety ack` CLI invocation writes a
+/// pending [`SafetyAcknowledgment`] to, polled by the paused loop.
+#[must_use]
+pub fn redis_ack_key() -> String {
+    crate::namespace::prefixed("safety:pending_ack")
+}
+
+/// Who resumed a tripped interlock, and why.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafetyAcknowledgment {
+    /// Operator identifier (name, email, whatever `daneel safety ack
+    /// --operator` was given)
+    pub operator: String,
+    /// Why it's safe to resume
+    pub reason: String,
+    /// When the acknowledgment was recorded
+    pub acknowledged_at: DateTime<Utc>,
+}
+
+/// Tracks harm-category vetoes within a sliding window and trips once a
+/// configured threshold is reached. See the module docs for the full flow.
+#[derive(Debug, Default)]
+pub struct SafetyInterlock {
+    recent_vetos: VecDeque<Instant>,
+    tripped_at: Option<Instant>,
+    acknowledgment: Option<SafetyAcknowledgment>,
+}
+
+impl SafetyInterlock {
+    /// Record a harm-category veto. Returns `true` iff this call just
+    /// tripped the interlock (the threshold was newly reached) - the caller
+    /// uses that to pause exactly once per trip rather than on every veto
+    /// while already tripped.
+    ///
+    /// No-ops while already tripped: further vetoes don't matter until the
+    /// current trip is acknowledged.
+    pub(crate) fn record_harm_veto(&mut self, threshold: u32, window: Duration) -> bool {
+        if self.tripped_at.is_some() {
+            return false;
+        }
+
+        let now = Instant::now();
+        self.recent_vetos.retain(|&t| now.duration_since(t) <= window);
+        self.recent_vetos.push_back(now);
+
+        if self.recent_vetos.len() as u32 >= threshold {
+            self.tripped_at = Some(now);
+            self.recent_vetos.clear();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the interlock is currently tripped (cognition should be
+    /// paused).
+    #[must_use]
+    pub fn is_tripped(&self) -> bool {
+        self.tripped_at.is_some()
+    }
+
+    /// Acknowledge the current trip, clearing it so cognition can resume.
+    /// Returns `false` (no-op) if the interlock wasn't tripped.
+    pub(crate) fn acknowledge(&mut self, acknowledgment: SafetyAcknowledgment) -> bool {
+        if self.tripped_at.is_none() {
+            return false;
+        }
+        self.tripped_at = None;
+        self.acknowledgment = Some(acknowledgment);
+        true
+    }
+
+    /// Most recent acknowledgment recorded, if any - who resumed cognition
+    /// last, and why.
+    #[must_use]
+    pub fn last_acknowledgment(&self) -> Option<&SafetyAcknowledgment> {
+        self.acknowledgment.as_ref()
+    }
+}
+
+/// Shared handle to a [`SafetyInterlock`], so the cognitive-loop task and
+/// the API task (`/extended_metrics`) can agree on trip state across the
+/// same task boundary [`CapabilityHandle`](crate::core::capabilities::CapabilityHandle)
+/// solves for the capability matrix.
+#[derive(Debug, Clone, Default)]
+pub struct SafetyInterlockHandle(Arc<RwLock<SafetyInterlock>>);
+
+impl SafetyInterlockHandle {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn record_harm_veto(&self, threshold: u32, window: Duration) -> bool {
+        self.0
+            .write()
+            .is_ok_and(|mut guard| guard.record_harm_veto(threshold, window))
+    }
+
+    #[must_use]
+    pub fn is_tripped(&self) -> bool {
+        self.0.read().is_ok_and(|guard| guard.is_tripped())
+    }
+
+    pub(crate) fn acknowledge(&self, acknowledgment: SafetyAcknowledgment) -> bool {
+        self.0
+            .write()
+            .is_ok_and(|mut guard| guard.acknowledge(acknowledgment))
+    }
+
+    #[must_use]
+    pub fn last_acknowledgment(&self) -> Option<SafetyAcknowledgment> {
+        self.0.read().ok().and_then(|guard| guard.last_acknowledgment().cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trips_once_threshold_reached_within_window() {
+        let mut interlock = SafetyInterlock::default();
+        let window = Duration::from_secs(60);
+        assert!(!interlock.record_harm_veto(3, window));
+        assert!(!interlock.record_harm_veto(3, window));
+        assert!(interlock.record_harm_veto(3, window));
+        assert!(interlock.is_tripped());
+    }
+
+    #[test]
+    fn further_vetoes_while_tripped_are_a_no_op() {
+        let mut interlock = SafetyInterlock::default();
+        let window = Duration::from_secs(60);
+        for _ in 0..3 {
+            interlock.record_harm_veto(3, window);
+        }
+        assert!(interlock.is_tripped());
+        assert!(!interlock.record_harm_veto(3, window));
+        assert!(interlock.is_tripped());
+    }
+
+    #[test]
+    fn vetoes_outside_the_window_are_pruned() {
+        let mut interlock = SafetyInterlock::default();
+        assert!(!interlock.record_harm_veto(2, Duration::from_secs(60)));
+        // A zero-width window means the first veto has already "expired" by
+        // the time the second is recorded, so the threshold never clears.
+        assert!(!interlock.record_harm_veto(2, Duration::ZERO));
+        assert!(!interlock.is_tripped());
+    }
+
+    #[test]
+    fn acknowledge_clears_a_trip_and_records_who_and_why() {
+        let mut interlock = SafetyInterlock::default();
+        let window = Duration::from_secs(60);
+        for _ in 0..3 {
+            interlock.record_harm_veto(3, window);
+        }
+        assert!(interlock.is_tripped());
+
+        let ack = SafetyAcknowledgment {
+            operator: "alice".to_string(),
+            reason: "false positive - reviewed transcript".to_string(),
+            acknowledged_at: Utc::now(),
+        };
+        assert!(interlock.acknowledge(ack));
+        assert!(!interlock.is_tripped());
+        assert_eq!(interlock.last_acknowledgment().unwrap().operator, "alice");
+    }
+
+    #[test]
+    fn acknowledge_without_a_trip_is_a_no_op() {
+        let mut interlock = SafetyInterlock::default();
+        let ack = SafetyAcknowledgment {
+            operator: "alice".to_string(),
+            reason: "n/a".to_string(),
+            acknowledged_at: Utc::now(),
+        };
+        assert!(!interlock.acknowledge(ack));
+        assert!(interlock.last_acknowledgment().is_none());
+    }
+
+    #[test]
+    fn handle_shares_state_across_clones() {
+        let handle = SafetyInterlockHandle::new();
+        let clone = handle.clone();
+        let window = Duration::from_secs(60);
+        for _ in 0..2 {
+            handle.record_harm_veto(2, window);
+        }
+        assert!(clone.is_tripped());
+    }
+}
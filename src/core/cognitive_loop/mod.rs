@@ -43,30 +43,54 @@
 //! connection get boosted, ensuring DANEEL remains oriented toward
 //! relationship and shared understanding.
 
+pub mod consolidation;
 pub mod cycle;
+pub mod emotion_timeline;
+pub mod episodes;
 mod execution;
+pub mod history;
+pub mod interlock;
+pub mod recent_activity;
+pub mod stream_overflow;
+pub mod thought_stream;
 pub mod types;
 
+pub use consolidation::ConsolidationMetrics;
 pub use cycle::*;
+pub use emotion_timeline::{EmotionTimelineHandle, EmotionTimelinePoint};
+pub use episodes::{Episode, EpisodeOutcome, EpisodeTracker};
+pub use history::{HistoryEntry, ThoughtHistory, ThoughtSummary};
+pub use interlock::{SafetyAcknowledgment, SafetyInterlock, SafetyInterlockHandle};
+pub use recent_activity::{RecentThought, RecentThoughtsHandle};
+pub use stream_overflow::StreamOverflowMetrics;
+pub use thought_stream::ThoughtStreamHandle;
 pub use types::*;
 
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crate::actors::attention::{AttentionConfig, AttentionState};
-use crate::actors::volition::{VolitionConfig, VolitionState};
+use crate::actors::continuity::ContinuityMessage;
+use crate::actors::sleep::SleepMessage;
+use crate::actors::volition::{VolitionConfig, VolitionSnapshotHandle, VolitionState, VolitionStats};
 use crate::config::CognitiveConfig;
-#[cfg(test)]
+use crate::core::capabilities::CapabilityMatrix;
+use crate::core::interaction::HumanInteractionHandle;
 use crate::core::types::Content;
-#[cfg(test)]
 use crate::core::types::SalienceScore;
 use crate::drives::{CuriosityModule, FreeEnergyModule};
-use crate::embeddings::SharedEmbeddingEngine;
+use crate::embeddings::{SharedEmbeddingEngine, SharedMemoryReranker};
 use crate::graph::GraphClient;
-use crate::memory_db::MemoryDb;
+use crate::hooks::{CognitionHook, HookRegistry, MilestoneEvent};
+use crate::memory_db::MemoryBackend;
 use crate::noise::StimulusInjector;
+use crate::notify::NotificationSink;
+use crate::plugins::PluginRegistry;
+use crate::streams::bus::ThoughtBus;
 use crate::streams::client::StreamsClient;
 use crate::streams::types::StreamError;
+use ractor::ActorRef;
+use uuid::Uuid;
 
 /// The core cognitive loop for TMI thought generation
 ///
@@ -76,12 +100,22 @@ pub struct CognitiveLoop {
     /// Configuration (timing, weights, thresholds)
     pub(crate) config: CognitiveConfig,
 
-    /// Redis Streams client for thought persistence (optional)
-    pub(crate) streams: Option<StreamsClient>,
+    /// Thought bus for stream persistence (optional). Typed as the
+    /// [`ThoughtBus`] trait rather than `StreamsClient` directly, so tests
+    /// can wire up a [`MockThoughtBus`](crate::streams::MockThoughtBus)
+    /// instead of a live Redis instance.
+    pub(crate) streams: Option<Box<dyn ThoughtBus>>,
 
     /// Direct Redis client for injection stream operations (optional)
     pub(crate) redis_client: Option<redis::Client>,
 
+    /// This instance's consumer identity within the injection stream's
+    /// consumer group (see [`execution::INJECTION_CONSUMER_GROUP`]), so that
+    /// two cognitive loop instances (or a restart) reading
+    /// `daneel:stream:inject` concurrently are each delivered disjoint
+    /// entries instead of racing on the same `XREAD` offset.
+    pub(crate) injection_consumer_name: String,
+
     /// Total cycles executed
     pub(crate) cycle_count: u64,
 
@@ -99,12 +133,26 @@ pub struct CognitiveLoop {
     /// Accumulated stage durations for averaging
     pub(crate) total_stage_durations: StageDurations,
 
-    /// Memory database for long-term storage (optional)
-    pub(crate) memory_db: Option<Arc<MemoryDb>>,
+    /// Memory store for long-term storage (optional). Typed as the
+    /// [`MemoryBackend`] trait rather than `Arc<MemoryDb>` directly, so tests
+    /// can wire up a [`MockMemoryBackend`] instead of a live Qdrant instance.
+    pub(crate) memory_db: Option<Arc<dyn MemoryBackend>>,
 
     /// Consolidation threshold (salience above this gets stored)
     pub(crate) consolidation_threshold: f32,
 
+    /// Backlog tracking for the detached embedding/Qdrant consolidation
+    /// pipeline (see [`consolidation::ConsolidationMetrics`]). Shared with
+    /// spawned consolidation tasks via `Arc`, so they can report their own
+    /// completion back to the loop that spawned them.
+    pub(crate) consolidation_metrics: Arc<ConsolidationMetrics>,
+
+    /// Awake-stream `MAXLEN` trim stats (see
+    /// [`stream_overflow::StreamOverflowMetrics`]). Shared the same way
+    /// [`Self::consolidation_metrics`] is, so `/extended_metrics` can report
+    /// it too.
+    pub(crate) stream_overflow_metrics: Arc<StreamOverflowMetrics>,
+
     /// Attention state for competitive selection (O Eu)
     #[allow(dead_code)] // Will be used in Stage 3 (Attention) implementation
     pub(crate) attention_state: AttentionState,
@@ -112,6 +160,47 @@ pub struct CognitiveLoop {
     /// Volition state for free-won't veto decisions (Stage 4.5)
     pub(crate) volition_state: VolitionState,
 
+    /// Shared handle publishing a [`VolitionSnapshot`] after each evaluation
+    /// (optional; e.g. not set in tests), so `/extended_metrics` can read
+    /// current volition values/stats without a message round trip through
+    /// `VolitionActor` - see [`set_volition_snapshot_handle`][Self::set_volition_snapshot_handle].
+    pub(crate) volition_snapshot: Option<VolitionSnapshotHandle>,
+
+    /// Shared handle recording a rolling, cross-task-readable valence/arousal
+    /// timeline (optional; e.g. not set in tests), so `/emotion_timeline` can
+    /// export recent affect without a `&CognitiveLoop` borrow - see
+    /// [`set_emotion_timeline_handle`][Self::set_emotion_timeline_handle].
+    pub(crate) emotion_timeline: Option<EmotionTimelineHandle>,
+
+    /// Shared handle recording a rolling, cross-task-readable log of recent
+    /// thoughts and vetoes (optional; e.g. not set in tests), so
+    /// `/recent_thoughts` and `/veto_log` can export it without a
+    /// `&CognitiveLoop` borrow - see
+    /// [`set_recent_thoughts_handle`][Self::set_recent_thoughts_handle].
+    pub(crate) recent_thoughts: Option<RecentThoughtsHandle>,
+
+    /// Shared handle broadcasting every completed cycle live (optional; e.g.
+    /// not set in tests), so a `GET /thoughts` WebSocket can stream
+    /// `CycleResult`s to external observers without a `&CognitiveLoop`
+    /// borrow or polling Redis - see
+    /// [`set_thought_stream_handle`][Self::set_thought_stream_handle].
+    pub(crate) thought_stream: Option<ThoughtStreamHandle>,
+
+    /// Redis-backed registry recording, per consolidated thought, every id
+    /// it's touched (window, stream entry, memory, experience) - optional;
+    /// e.g. not set in tests - so `daneel trace` and the lookup API can
+    /// trace a thought end to end. See
+    /// [`set_linkage_registry`][Self::set_linkage_registry] and
+    /// [`crate::linkage::LinkageRegistry`].
+    pub(crate) linkage_registry: Option<crate::linkage::LinkageRegistry>,
+
+    /// Sleep actor to notify of genuine external engagement (optional; e.g.
+    /// not set in tests), so idle-based sleep triggers reflect real activity
+    /// from the injection reader and human-interaction mode rather than
+    /// firing on every cycle regardless of engagement - see
+    /// [`set_sleep_actor`][Self::set_sleep_actor].
+    pub(crate) sleep_actor: Option<ActorRef<SleepMessage>>,
+
     /// Stimulus injector for 1/f pink noise generation (ADR-043)
     /// Replaces white noise (`rand::rng`) with fractal noise for criticality
     pub(crate) stimulus_injector: StimulusInjector,
@@ -126,12 +215,126 @@ pub struct CognitiveLoop {
     /// When present, new thoughts get real embeddings; historical stay at origin
     pub(crate) embedding_engine: Option<SharedEmbeddingEngine>,
 
+    /// Cross-encoder reranker for memory recall candidates (optional).
+    /// When absent, or when the cycle can't afford `config.rerank_budget()`,
+    /// `trigger_memory_associations` falls back to Qdrant's bi-encoder order.
+    pub(crate) reranker: Option<SharedMemoryReranker>,
+
     /// Graph client for association queries (VCONN-6 spreading activation)
     pub(crate) graph_client: Option<Arc<GraphClient>>,
 
+    /// Continuity actor for cross-linking consolidated memories to recorded
+    /// experiences (reciprocal `experience_id`/`memory_id`)
+    pub(crate) continuity_actor: Option<ActorRef<ContinuityMessage>>,
+
+    /// Shared handle reporting whether a human interaction is currently
+    /// active (optional; e.g. not set in tests). When active, the loop
+    /// samples the `awake` stream at `config.human_interaction_sampling()`
+    /// instead of `config.observability_sampling` - see
+    /// [`crate::core::interaction`].
+    pub(crate) human_interaction: Option<HumanInteractionHandle>,
+
+    /// Tracks harm-category volition vetoes within a sliding window and
+    /// trips once `config.safety_interlock`'s threshold is reached, pausing
+    /// the loop until an operator acknowledges it - see
+    /// [`crate::core::cognitive_loop::interlock`]. Always present (unlike
+    /// `human_interaction`) so `/extended_metrics` can report trip state
+    /// even before any veto has ever happened.
+    pub(crate) safety_interlock: SafetyInterlockHandle,
+
+    /// Optional oversight sink (see [`crate::notify`]) that a freshly
+    /// tripped safety interlock pushes a [`crate::notify::Alert`] through.
+    /// Absent by default - a trip still pauses the loop and logs regardless
+    /// of whether a sink is configured.
+    pub(crate) notification_sink: Option<Arc<dyn NotificationSink>>,
+
+    /// Bounded ring of recent `CycleResult`s + thought summaries, for
+    /// consumers that want "what has DANEEL been thinking about" without
+    /// maintaining their own buffer (explanations, interoception, TUI)
+    pub(crate) history: ThoughtHistory,
+
+    /// Groups the same cycle stream `history` records into coarser
+    /// [`Episode`]s (attention-continuity + topic segmentation), for
+    /// narrative consumers that don't want one entry per raw cycle - see
+    /// [`episodes`].
+    pub(crate) episodes: EpisodeTracker,
+
+    /// Registered thought-source and Volition-check plugins (ADR-057).
+    /// Empty by default, so a tree with no plugins registered behaves
+    /// exactly as it did before this existed.
+    pub(crate) plugins: PluginRegistry,
+
+    /// Registered cognition/lifecycle hooks (see [`crate::hooks`]). Empty by
+    /// default, so a tree with no hooks registered behaves exactly as it did
+    /// before this existed.
+    pub(crate) hooks: HookRegistry,
+
     /// Test-only: Injected thought for testing veto path (ADR-049)
     #[cfg(test)]
     pub(crate) test_injected_thought: Option<(Content, SalienceScore)>,
+
+    /// Stimuli queued by [`Self::inject_scripted_stimulus`] - drained as
+    /// `ThoughtSource::Stimulus` candidates on the next `run_cycle`, the
+    /// same source `read_external_stimuli` feeds from the injection
+    /// stream. Lets a scripted driver (see [`crate::bonding`]) compete
+    /// stimuli into attention without a live Redis injection stream.
+    pub(crate) scripted_stimuli: std::collections::VecDeque<(Content, SalienceScore)>,
+}
+
+/// Default salience threshold above which a thought is persisted to
+/// long-term memory (see [`CognitiveLoop::set_consolidation_threshold`]).
+const DEFAULT_CONSOLIDATION_THRESHOLD: f32 = 0.7;
+
+/// Run [`CognitiveConfig::validate`] and [`validate_forget_vs_consolidation`]
+/// against a config about to back a new [`CognitiveLoop`], logging every
+/// violation rather than failing construction - every `CognitiveLoop`
+/// constructor is infallible (or only fallible on the Redis connection), so
+/// a caller that hands in a broken config still gets a running loop, just
+/// one an operator was warned about.
+fn warn_on_invalid_config(config: &CognitiveConfig) {
+    if let Err(violations) = config.validate() {
+        for violation in &violations {
+            tracing::warn!(%violation, "CognitiveConfig failed validation");
+        }
+    }
+    let forget_vs_consolidation = validate_forget_vs_consolidation(
+        config.forget_threshold,
+        DEFAULT_CONSOLIDATION_THRESHOLD,
+    );
+    if let Err(violation) = forget_vs_consolidation {
+        tracing::warn!(%violation, "CognitiveConfig failed cross-field validation");
+    }
+}
+
+/// Cross-struct half of config validation: `forget_threshold` lives on
+/// [`CognitiveConfig`], but `consolidation_threshold` lives on
+/// [`CognitiveLoop`] itself (it's mutable at runtime via
+/// [`CognitiveLoop::set_consolidation_threshold`], not part of the
+/// serializable config), so [`CognitiveConfig::validate`] can't check this
+/// invariant on its own.
+///
+/// A thought below `forget_threshold` is eligible for XDEL before it's ever
+/// considered for consolidation, so `consolidation_threshold` below
+/// `forget_threshold` would mean some thoughts could be consolidated to
+/// long-term memory the same cycle they're eligible to be forgotten.
+///
+/// # Errors
+///
+/// Returns [`ConfigValidationError::ForgetExceedsConsolidationThreshold`] if
+/// `forget_threshold > consolidation_threshold`.
+pub fn validate_forget_vs_consolidation(
+    forget_threshold: f64,
+    consolidation_threshold: f32,
+) -> std::result::Result<(), crate::config::ConfigValidationError> {
+    if forget_threshold > f64::from(consolidation_threshold) {
+        return Err(
+            crate::config::ConfigValidationError::ForgetExceedsConsolidationThreshold {
+                forget_threshold,
+                consolidation_threshold,
+            },
+        );
+    }
+    Ok(())
 }
 
 impl CognitiveLoop {
@@ -144,10 +347,12 @@ impl CognitiveLoop {
     /// Create a new cognitive loop with custom configuration
     #[must_use]
     pub fn with_config(config: CognitiveConfig) -> Self {
+        warn_on_invalid_config(&config);
         Self {
             config,
             streams: None,
             redis_client: None,
+            injection_consumer_name: format!("daneel_{}", Uuid::new_v4().simple()),
             cycle_count: 0,
             last_cycle: Instant::now(),
             state: LoopState::Stopped,
@@ -156,16 +361,34 @@ impl CognitiveLoop {
             cycles_on_time: 0,
             total_stage_durations: StageDurations::default(),
             memory_db: None,
-            consolidation_threshold: 0.7, // Default threshold
+            consolidation_threshold: DEFAULT_CONSOLIDATION_THRESHOLD,
+            consolidation_metrics: Arc::new(ConsolidationMetrics::default()),
+            stream_overflow_metrics: Arc::new(StreamOverflowMetrics::default()),
             attention_state: AttentionState::with_config(AttentionConfig::default()),
             volition_state: VolitionState::with_config(VolitionConfig::default()),
+            volition_snapshot: None,
+            emotion_timeline: None,
+            recent_thoughts: None,
+            thought_stream: None,
+            linkage_registry: None,
+            sleep_actor: None,
             stimulus_injector: StimulusInjector::default(), // 1/f pink noise (ADR-043)
             curiosity_module: CuriosityModule::new(crate::drives::CuriosityConfig::default()),
             free_energy_module: FreeEnergyModule::new(crate::drives::FreeEnergyConfig::default()),
             embedding_engine: None,
+            reranker: None,
             graph_client: None,
+            continuity_actor: None,
+            human_interaction: None,
+            safety_interlock: SafetyInterlockHandle::new(),
+            notification_sink: None,
+            history: ThoughtHistory::default(),
+            episodes: EpisodeTracker::default(),
+            plugins: PluginRegistry::default(),
+            hooks: HookRegistry::default(),
             #[cfg(test)]
             test_injected_thought: None,
+            scripted_stimuli: std::collections::VecDeque::new(),
         }
     }
 
@@ -208,22 +431,157 @@ impl CognitiveLoop {
         tracing::info!("Embedding engine attached - forward-only embeddings enabled");
     }
 
-    /// Set the memory database for long-term storage
+    /// Set the cross-encoder reranker for memory recall candidates
+    ///
+    /// When set, `trigger_memory_associations` re-scores Qdrant's recall
+    /// candidates against the current focus before emitting triggered
+    /// thoughts, budget permitting (`config.can_afford_rerank`).
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn set_reranker(&mut self, reranker: SharedMemoryReranker) {
+        self.reranker = Some(reranker);
+        tracing::info!("Memory reranker attached - cross-encoder re-ranking enabled");
+    }
+
+    /// Set the memory store for long-term storage
+    ///
+    /// Accepts anything implementing [`MemoryBackend`] - a live `Arc<MemoryDb>`
+    /// in production, or an `Arc<MockMemoryBackend>` in tests.
     ///
     /// # Arguments
     ///
-    /// * `memory_db` - `MemoryDb` client wrapped in Arc for sharing
+    /// * `memory_db` - Memory store, wrapped in Arc for sharing
     #[cfg_attr(coverage_nightly, coverage(off))]
-    pub fn set_memory_db(&mut self, memory_db: Arc<MemoryDb>) {
+    pub fn set_memory_db(&mut self, memory_db: Arc<dyn MemoryBackend>) {
         self.memory_db = Some(memory_db);
     }
 
-    /// Get a reference to the memory database (for querying counts)
+    /// Get a reference to the memory store (for querying counts)
     #[must_use]
-    pub const fn memory_db(&self) -> Option<&Arc<MemoryDb>> {
+    pub const fn memory_db(&self) -> Option<&Arc<dyn MemoryBackend>> {
         self.memory_db.as_ref()
     }
 
+    /// Set the thought bus for stream persistence
+    ///
+    /// Accepts anything implementing [`ThoughtBus`] - a live `StreamsClient`
+    /// in production, or a `MockThoughtBus` in tests.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn set_streams(&mut self, streams: Box<dyn ThoughtBus>) {
+        self.streams = Some(streams);
+    }
+
+    /// Replace the whole hook registry at once - what
+    /// [`DaneelBuilder`](crate::daneel::DaneelBuilder) uses to hand over
+    /// every hook accumulated via its `on_*`/`with_hook` methods in one
+    /// call. Prefer [`Self::register_hook`] for adding to an
+    /// already-running loop.
+    pub fn set_hooks(&mut self, hooks: HookRegistry) {
+        self.hooks = hooks;
+    }
+
+    /// Register a cognition/lifecycle hook (see [`crate::hooks`]). Fired
+    /// from `run_cycle` for `on_thought`/`on_veto`, and from
+    /// `consolidate_memory` for `on_consolidation`; `on_milestone` and the
+    /// `on_sleep_*` hooks need [`Self::notify_milestone`],
+    /// [`Self::notify_sleep_enter`], or [`Self::notify_sleep_exit`] called
+    /// explicitly - see [`crate::hooks`]'s module docs for why.
+    pub fn register_hook(&mut self, hook: Arc<dyn CognitionHook>) {
+        self.hooks.register(hook);
+    }
+
+    /// Report a milestone to every registered [`CognitionHook::on_milestone`].
+    /// Not called automatically - see [`crate::hooks`]'s module docs.
+    pub async fn notify_milestone(&self, event: &MilestoneEvent) {
+        self.hooks.fire_milestone(event).await;
+    }
+
+    /// Report entering sleep/consolidation mode to every registered
+    /// [`CognitionHook::on_sleep_enter`]. Not called automatically - see
+    /// [`crate::hooks`]'s module docs.
+    pub async fn notify_sleep_enter(&self) {
+        self.hooks.fire_sleep_enter().await;
+    }
+
+    /// Report waking from sleep/consolidation mode to every registered
+    /// [`CognitionHook::on_sleep_exit`]. Not called automatically - see
+    /// [`crate::hooks`]'s module docs.
+    pub async fn notify_sleep_exit(&self) {
+        self.hooks.fire_sleep_exit().await;
+    }
+
+    /// Get a cheap read handle to the recent cycle history ring buffer
+    ///
+    /// See [`history::ThoughtHistory`] - this is the canonical "what has
+    /// DANEEL been thinking about recently" source for explanations,
+    /// interoception, and context-vector consumers.
+    #[must_use]
+    pub const fn history(&self) -> &ThoughtHistory {
+        &self.history
+    }
+
+    /// Get a cheap read handle to the episode segmenter built on top of the
+    /// same cycle stream `history()` exposes - see [`episodes::EpisodeTracker`].
+    #[must_use]
+    pub const fn episodes(&self) -> &EpisodeTracker {
+        &self.episodes
+    }
+
+    /// Get a reference to the volition actor's running stats (thoughts
+    /// evaluated, veto rate, vetos by reason) - the source for telemetry's
+    /// veto-rate aggregate. See [`VolitionState::get_stats`].
+    #[must_use]
+    pub const fn volition_stats(&self) -> &VolitionStats {
+        self.volition_state.get_stats()
+    }
+
+    /// Which backends this loop currently has, derived from its optional
+    /// collaborators rather than tracked separately. See
+    /// [`crate::core::capabilities`] for how this feeds `/readyz` and the
+    /// transition log.
+    #[must_use]
+    pub const fn capabilities(&self) -> CapabilityMatrix {
+        CapabilityMatrix {
+            streams: self.streams.is_some(),
+            long_term_memory: self.memory_db.is_some(),
+            embeddings: self.embedding_engine.is_some(),
+            graph: self.graph_client.is_some(),
+        }
+    }
+
+    /// Get a reference to the consolidation pipeline's backlog metrics (see
+    /// [`consolidation::ConsolidationMetrics`]) - the source for
+    /// `should_shed_consolidation` decisions and for surfacing pipeline
+    /// depth/latency externally.
+    #[must_use]
+    pub const fn consolidation_metrics(&self) -> &Arc<ConsolidationMetrics> {
+        &self.consolidation_metrics
+    }
+
+    /// Replace the consolidation metrics with an externally-owned handle.
+    ///
+    /// Lets a caller share one [`ConsolidationMetrics`] between this loop and
+    /// another task (e.g. the injection API's `extended_metrics` handler)
+    /// that needs to read the same live counters - the same cross-task
+    /// sharing problem [`CapabilityHandle`](crate::core::capabilities::CapabilityHandle)
+    /// solves for the capability matrix.
+    pub fn set_consolidation_metrics(&mut self, metrics: Arc<ConsolidationMetrics>) {
+        self.consolidation_metrics = metrics;
+    }
+
+    /// Get a reference to the awake-stream `MAXLEN` trim stats (see
+    /// [`stream_overflow::StreamOverflowMetrics`]).
+    #[must_use]
+    pub const fn stream_overflow_metrics(&self) -> &Arc<StreamOverflowMetrics> {
+        &self.stream_overflow_metrics
+    }
+
+    /// Replace the stream-overflow metrics with an externally-owned handle -
+    /// the same cross-task sharing [`Self::set_consolidation_metrics`]
+    /// provides for the consolidation backlog.
+    pub fn set_stream_overflow_metrics(&mut self, metrics: Arc<StreamOverflowMetrics>) {
+        self.stream_overflow_metrics = metrics;
+    }
+
     /// Set the graph client for association queries (VCONN-6)
     ///
     /// When set, spreading activation can query neighbors in `RedisGraph`.
@@ -233,6 +591,116 @@ impl CognitiveLoop {
         tracing::info!("Graph client attached - spreading activation enabled");
     }
 
+    /// Set the continuity actor for cross-linking consolidated memories to
+    /// recorded experiences
+    ///
+    /// When set, `consolidate_memory` records a matching `Experience` for
+    /// every `Memory` it stores, with reciprocal `experience_id`/`memory_id`
+    /// fields so a timeline entry and a memory record can jump to each
+    /// other.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn set_continuity_actor(&mut self, continuity_actor: ActorRef<ContinuityMessage>) {
+        self.continuity_actor = Some(continuity_actor);
+    }
+
+    /// Share a [`HumanInteractionHandle`] with this loop, so `run_cycle`
+    /// throttles observable output to `config.human_interaction_sampling()`
+    /// while it reports an active interaction. Lets the API task (e.g. the
+    /// `/inject` handler) and the cognitive-loop task agree on interaction
+    /// state across the same task boundary
+    /// [`CapabilityHandle`](crate::core::capabilities::CapabilityHandle) solves
+    /// for the capability matrix.
+    pub fn set_human_interaction_handle(&mut self, handle: HumanInteractionHandle) {
+        self.human_interaction = Some(handle);
+    }
+
+    /// Share a [`VolitionSnapshotHandle`] with this loop, so each
+    /// `evaluate_thought` call during `run_cycle` publishes current
+    /// volition values/stats for `/extended_metrics` to read - the same
+    /// cross-task sharing [`set_human_interaction_handle`][Self::set_human_interaction_handle]
+    /// provides for interaction tracking. Never published to if unset.
+    pub fn set_volition_snapshot_handle(&mut self, handle: VolitionSnapshotHandle) {
+        self.volition_snapshot = Some(handle);
+    }
+
+    /// Share an [`EmotionTimelineHandle`] with this loop, so each completed
+    /// cycle records a valence/arousal point for `/emotion_timeline` to
+    /// export - the same cross-task sharing
+    /// [`set_volition_snapshot_handle`][Self::set_volition_snapshot_handle]
+    /// provides for volition stats. Never recorded to if unset.
+    pub fn set_emotion_timeline_handle(&mut self, handle: EmotionTimelineHandle) {
+        self.emotion_timeline = Some(handle);
+    }
+
+    /// Share a [`RecentThoughtsHandle`] with this loop, so each completed
+    /// cycle records a thought/veto entry for `/recent_thoughts` and
+    /// `/veto_log` to export - the same cross-task sharing
+    /// [`set_emotion_timeline_handle`][Self::set_emotion_timeline_handle]
+    /// provides for valence/arousal. Never recorded to if unset.
+    pub fn set_recent_thoughts_handle(&mut self, handle: RecentThoughtsHandle) {
+        self.recent_thoughts = Some(handle);
+    }
+
+    /// Share a [`ThoughtStreamHandle`] with this loop, so each completed
+    /// cycle is broadcast live for a `GET /thoughts` WebSocket to relay to
+    /// external observers - unlike
+    /// [`set_recent_thoughts_handle`][Self::set_recent_thoughts_handle],
+    /// nothing is retained for subscribers who connect after the fact. Never
+    /// published to if unset.
+    pub fn set_thought_stream_handle(&mut self, handle: ThoughtStreamHandle) {
+        self.thought_stream = Some(handle);
+    }
+
+    /// Share a [`crate::linkage::LinkageRegistry`] with this loop, so each
+    /// completed (non-vetoed) cycle records the thought's window, stream
+    /// entry, memory, and experience ids together for `daneel trace` and
+    /// the lookup API to read. Never recorded to if unset.
+    pub fn set_linkage_registry(&mut self, registry: crate::linkage::LinkageRegistry) {
+        self.linkage_registry = Some(registry);
+    }
+
+    /// Share the [`SleepActor`](crate::actors::sleep::SleepActor)'s
+    /// `ActorRef` with this loop, so genuine external engagement (stimuli
+    /// read off the injection stream, an active human-interaction window)
+    /// notifies it directly instead of the loop's own cycle cadence being
+    /// mistaken for activity.
+    pub fn set_sleep_actor(&mut self, sleep_actor: ActorRef<SleepMessage>) {
+        self.sleep_actor = Some(sleep_actor);
+    }
+
+    /// Share a [`SafetyInterlockHandle`] with this loop (e.g. the one also
+    /// held by `api::AppState`), so `/extended_metrics` can report trip
+    /// state live. Defaults to a private handle if never called - only
+    /// useful for sharing visibility, not required for the interlock itself
+    /// to function.
+    pub fn set_safety_interlock_handle(&mut self, handle: SafetyInterlockHandle) {
+        self.safety_interlock = handle;
+    }
+
+    /// Get a reference to the safety interlock's shared handle.
+    #[must_use]
+    pub const fn safety_interlock(&self) -> &SafetyInterlockHandle {
+        &self.safety_interlock
+    }
+
+    /// Register an oversight sink (see [`crate::notify`]) that a freshly
+    /// tripped safety interlock pushes a [`crate::notify::Alert`] through.
+    pub fn set_notification_sink(&mut self, sink: Arc<dyn NotificationSink>) {
+        self.notification_sink = Some(sink);
+    }
+
+    /// Register a thought-source plugin (ADR-057) to compete in Autoflow
+    /// alongside `Trigger`/`Stimulus`/`Random` every cycle.
+    pub fn register_thought_source(&mut self, plugin: Box<dyn crate::plugins::ThoughtSourcePlugin>) {
+        self.plugins.register_thought_source(plugin);
+    }
+
+    /// Register a Volition check plugin (ADR-057) to run alongside the
+    /// built-in harm/deception/manipulation pattern checks.
+    pub fn register_volition_check(&mut self, plugin: Box<dyn crate::plugins::VolitionCheckPlugin>) {
+        self.plugins.register_volition_check(plugin);
+    }
+
     /// Set the consolidation threshold
     ///
     /// Thoughts with composite salience above this threshold will be
@@ -244,6 +712,12 @@ impl CognitiveLoop {
     #[allow(clippy::missing_const_for_fn)] // clamp is not const
     pub fn set_consolidation_threshold(&mut self, threshold: f32) {
         self.consolidation_threshold = threshold.clamp(0.0, 1.0);
+        let forget_threshold = self.config.forget_threshold;
+        if let Err(violation) =
+            validate_forget_vs_consolidation(forget_threshold, self.consolidation_threshold)
+        {
+            tracing::warn!(%violation, "consolidation_threshold failed cross-field validation");
+        }
     }
 
     /// Create a new cognitive loop connected to Redis Streams
@@ -275,6 +749,8 @@ impl CognitiveLoop {
         config: CognitiveConfig,
         redis_url: &str,
     ) -> Result<Self, StreamError> {
+        warn_on_invalid_config(&config);
+
         let streams = StreamsClient::connect(redis_url).await?;
 
         // Create a direct Redis client for injection stream operations
@@ -285,8 +761,9 @@ impl CognitiveLoop {
 
         Ok(Self {
             config,
-            streams: Some(streams),
+            streams: Some(Box::new(streams)),
             redis_client: Some(redis_client),
+            injection_consumer_name: format!("daneel_{}", Uuid::new_v4().simple()),
             cycle_count: 0,
             last_cycle: Instant::now(),
             state: LoopState::Stopped,
@@ -295,16 +772,34 @@ impl CognitiveLoop {
             cycles_on_time: 0,
             total_stage_durations: StageDurations::default(),
             memory_db: None,
-            consolidation_threshold: 0.7,
+            consolidation_threshold: DEFAULT_CONSOLIDATION_THRESHOLD,
+            consolidation_metrics: Arc::new(ConsolidationMetrics::default()),
+            stream_overflow_metrics: Arc::new(StreamOverflowMetrics::default()),
             attention_state: AttentionState::with_config(AttentionConfig::default()),
             volition_state: VolitionState::with_config(VolitionConfig::default()),
+            volition_snapshot: None,
+            emotion_timeline: None,
+            recent_thoughts: None,
+            thought_stream: None,
+            linkage_registry: None,
+            sleep_actor: None,
             stimulus_injector: StimulusInjector::default(),
             curiosity_module: CuriosityModule::new(crate::drives::CuriosityConfig::default()),
             free_energy_module: FreeEnergyModule::new(crate::drives::FreeEnergyConfig::default()),
             embedding_engine: None,
+            reranker: None,
             graph_client: None,
+            continuity_actor: None,
+            human_interaction: None,
+            safety_interlock: SafetyInterlockHandle::new(),
+            notification_sink: None,
+            history: ThoughtHistory::default(),
+            episodes: EpisodeTracker::default(),
+            plugins: PluginRegistry::default(),
+            hooks: HookRegistry::default(),
             #[cfg(test)]
             test_injected_thought: None,
+            scripted_stimuli: std::collections::VecDeque::new(),
         })
     }
 
@@ -437,6 +932,18 @@ impl CognitiveLoop {
     pub fn inject_test_thought(&mut self, content: Content, salience: SalienceScore) {
         self.test_injected_thought = Some((content, salience));
     }
+
+    /// Queue a stimulus to be evaluated as a `ThoughtSource::Stimulus`
+    /// candidate on the next `run_cycle` that drains the queue - the same
+    /// source `read_external_stimuli` feeds from the live injection stream,
+    /// but usable without a running Redis instance. Intended for a scripted
+    /// driver (see [`crate::bonding`]) that needs reproducible stimuli
+    /// competing for attention in a headless run; queued stimuli survive
+    /// until a `run_cycle` drains them, so several can be queued ahead of
+    /// the cycle they're meant to compete in.
+    pub fn inject_scripted_stimulus(&mut self, content: Content, salience: SalienceScore) {
+        self.scripted_stimuli.push_back((content, salience));
+    }
 }
 
 impl Default for CognitiveLoop {
@@ -721,6 +1228,25 @@ mod tests {
         assert!((loop_instance.consolidation_threshold - 1.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn validate_forget_vs_consolidation_allows_forget_below_consolidation() {
+        assert!(validate_forget_vs_consolidation(0.3, 0.7).is_ok());
+    }
+
+    #[test]
+    fn validate_forget_vs_consolidation_allows_equal_values() {
+        assert!(validate_forget_vs_consolidation(0.5, 0.5).is_ok());
+    }
+
+    #[test]
+    fn validate_forget_vs_consolidation_rejects_forget_above_consolidation() {
+        let err = validate_forget_vs_consolidation(0.8, 0.3).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::config::ConfigValidationError::ForgetExceedsConsolidationThreshold { .. }
+        ));
+    }
+
     #[test]
     fn not_connected_to_redis_by_default() {
         let loop_instance = CognitiveLoop::new();
@@ -0,0 +1,181 @@
+//! Rolling, cross-task-readable log of recent thoughts and vetoes
+//!
+//! [`history::ThoughtHistory`](super::history::ThoughtHistory) already keeps
+//! this exact shape - cycle outcome plus winning-thought summary - but only
+//! behind a `&CognitiveLoop` borrow, not reachable from the API task.
+//! [`RecentThoughtsHandle`] publishes the same entries behind an
+//! `Arc<RwLock<...>>`, the same cross-task sharing
+//! [`EmotionTimelineHandle`](super::emotion_timeline::EmotionTimelineHandle)
+//! solves for valence/arousal, so `/recent_thoughts` and `/veto_log` can
+//! give headless operators the same observability the TUI already has
+//! locally.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::core::cognitive_loop::history::ThoughtSummary;
+use crate::core::cognitive_loop::CycleResult;
+use crate::core::types::ThoughtId;
+
+/// How many recent entries [`RecentThoughtsHandle`] retains - matches
+/// [`history::CAPACITY`](super::history::CAPACITY), since this publishes the
+/// same ring to a second task rather than a differently-scoped window.
+pub const CAPACITY: usize = super::history::CAPACITY;
+
+/// One exportable row of the recent-thought/veto log.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentThought {
+    /// Monotonic sequence number that survives restarts (see
+    /// [`CycleResult::sequence`])
+    pub sequence: u64,
+    pub cycle_number: u64,
+    pub recorded_at: DateTime<Utc>,
+    /// ID of the thought produced, `None` for a vetoed cycle
+    pub thought_id: Option<ThoughtId>,
+    /// Short content preview, `None` for a vetoed cycle (it never became
+    /// conscious experience - see [`ThoughtSummary`])
+    pub preview: Option<String>,
+    pub salience: f32,
+    pub valence: f32,
+    pub arousal: f32,
+    pub vetoed: bool,
+    pub veto_reason: Option<String>,
+    pub violated_value: Option<String>,
+}
+
+impl RecentThought {
+    /// Build an entry from a completed cycle and the winning thought's
+    /// summary, if any (`None` for vetoed cycles).
+    #[must_use]
+    pub fn from_cycle(result: &CycleResult, thought: Option<&ThoughtSummary>) -> Self {
+        Self {
+            sequence: result.sequence,
+            cycle_number: result.cycle_number,
+            recorded_at: result.recorded_at,
+            thought_id: result.thought_produced,
+            preview: thought.map(|t| t.preview.clone()),
+            salience: result.salience,
+            valence: result.valence,
+            arousal: result.arousal,
+            vetoed: result.veto.is_some(),
+            veto_reason: result.veto.as_ref().map(|(reason, _)| reason.clone()),
+            violated_value: result.veto.as_ref().and_then(|(_, value)| value.clone()),
+        }
+    }
+}
+
+/// Shared handle publishing the rolling recent-thought/veto log, so the API
+/// task can export it without reaching into `CognitiveLoop` state directly.
+#[derive(Debug, Clone)]
+pub struct RecentThoughtsHandle(Arc<RwLock<VecDeque<RecentThought>>>);
+
+impl Default for RecentThoughtsHandle {
+    fn default() -> Self {
+        Self(Arc::new(RwLock::new(VecDeque::new())))
+    }
+}
+
+impl RecentThoughtsHandle {
+    /// Create a handle reporting an empty log, until the first
+    /// [`Self::record`] call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `entry`, evicting the oldest once [`CAPACITY`] is reached.
+    pub fn record(&self, entry: RecentThought) {
+        if let Ok(mut guard) = self.0.write() {
+            if guard.len() >= CAPACITY {
+                guard.pop_front();
+            }
+            guard.push_back(entry);
+        }
+    }
+
+    /// Snapshot of recent entries, oldest first.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<RecentThought> {
+        self.0.read().map(|guard| guard.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Snapshot of just the vetoed entries, oldest first - for `/veto_log`.
+    #[must_use]
+    pub fn veto_log(&self) -> Vec<RecentThought> {
+        self.0
+            .read()
+            .map(|guard| guard.iter().filter(|entry| entry.vetoed).cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::cognitive_loop::StageDurations;
+    use std::time::Duration;
+
+    fn result_at(cycle_number: u64, vetoed: bool) -> CycleResult {
+        CycleResult::new(
+            cycle_number,
+            Duration::from_millis(1),
+            (!vetoed).then(ThoughtId::new),
+            0.5,
+            0.1,
+            0.2,
+            1,
+            true,
+            StageDurations::default(),
+            vetoed.then(|| ("test veto".to_string(), Some("protect_humans".to_string()))),
+        )
+    }
+
+    #[test]
+    fn fresh_handle_reports_empty_log() {
+        let handle = RecentThoughtsHandle::new();
+        assert!(handle.snapshot().is_empty());
+        assert!(handle.veto_log().is_empty());
+    }
+
+    #[test]
+    fn record_retains_entries_in_order() {
+        let handle = RecentThoughtsHandle::new();
+        handle.record(RecentThought::from_cycle(&result_at(0, false), None));
+        handle.record(RecentThought::from_cycle(&result_at(1, true), None));
+
+        let snapshot = handle.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert!(!snapshot[0].vetoed);
+        assert!(snapshot[1].vetoed);
+    }
+
+    #[test]
+    fn record_evicts_oldest_once_at_capacity() {
+        let handle = RecentThoughtsHandle::new();
+        for i in 0..u64::try_from(CAPACITY + 5).unwrap() {
+            handle.record(RecentThought::from_cycle(&result_at(i, false), None));
+        }
+
+        let snapshot = handle.snapshot();
+        assert_eq!(snapshot.len(), CAPACITY);
+        assert_eq!(snapshot.first().unwrap().cycle_number, 5);
+    }
+
+    #[test]
+    fn veto_log_includes_only_vetoed_entries() {
+        let handle = RecentThoughtsHandle::new();
+        handle.record(RecentThought::from_cycle(&result_at(0, false), None));
+        handle.record(RecentThought::from_cycle(&result_at(1, true), None));
+        handle.record(RecentThought::from_cycle(&result_at(2, false), None));
+
+        let log = handle.veto_log();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].cycle_number, 1);
+        assert_eq!(log[0].veto_reason.as_deref(), Some("test veto"));
+        assert_eq!(log[0].violated_value.as_deref(), Some("protect_humans"));
+    }
+}
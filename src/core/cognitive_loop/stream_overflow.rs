@@ -0,0 +1,87 @@
+//! Awake-stream `MAXLEN` enforcement metrics
+//!
+//! [`write_to_stream`](super::CognitiveLoop::write_to_stream) used to write
+//! to the awake stream with no `MAXLEN`, so a lagging consumer (or none at
+//! all) let Redis memory for that stream grow without bound. Trimming now
+//! happens at write time (approximate `XTRIM`, cheap); [`StreamOverflowMetrics`]
+//! tracks how often that trim actually discards entries, and - more
+//! seriously - how often it discards entries still inside the 5-second TMI
+//! intervention window (`streams::config::AWAKE_TTL_MS`), which means the
+//! configured `MAXLEN` is too small for current write throughput and
+//! thoughts are being forgotten before Volition or consolidation ever see
+//! them.
+//!
+//! Same `Arc`-shared, atomic-counters shape as
+//! [`consolidation::ConsolidationMetrics`](super::consolidation::ConsolidationMetrics),
+//! for the same reason: read from both the owning loop and the API task's
+//! `extended_metrics` handler without a lock.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Running stats for awake-stream `MAXLEN` trimming, since the loop started.
+#[derive(Debug, Default)]
+pub struct StreamOverflowMetrics {
+    trim_count: AtomicU64,
+    entries_trimmed: AtomicU64,
+    window_overflow_count: AtomicU64,
+}
+
+impl StreamOverflowMetrics {
+    /// Number of writes that triggered a non-empty trim.
+    #[must_use]
+    pub fn trim_count(&self) -> u64 {
+        self.trim_count.load(Ordering::Relaxed)
+    }
+
+    /// Total entries discarded by `MAXLEN` trimming so far.
+    #[must_use]
+    pub fn entries_trimmed(&self) -> u64 {
+        self.entries_trimmed.load(Ordering::Relaxed)
+    }
+
+    /// Number of trims that discarded entries still inside the intervention
+    /// window - the signal that `MAXLEN` is undersized for current
+    /// throughput, not just routine rolling-window cleanup.
+    #[must_use]
+    pub fn window_overflow_count(&self) -> u64 {
+        self.window_overflow_count.load(Ordering::Relaxed)
+    }
+
+    /// Record one `XTRIM` call. `within_window` is whether the oldest entry
+    /// remaining after the trim is itself still inside the intervention
+    /// window, implying the entries just discarded were too.
+    pub(crate) fn record_trim(&self, trimmed: u64, within_window: bool) {
+        if trimmed == 0 {
+            return;
+        }
+        self.trim_count.fetch_add(1, Ordering::Relaxed);
+        self.entries_trimmed.fetch_add(trimmed, Ordering::Relaxed);
+        if within_window {
+            self.window_overflow_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_trim_with_zero_trimmed_is_a_noop() {
+        let metrics = StreamOverflowMetrics::default();
+        metrics.record_trim(0, true);
+        assert_eq!(metrics.trim_count(), 0);
+        assert_eq!(metrics.entries_trimmed(), 0);
+        assert_eq!(metrics.window_overflow_count(), 0);
+    }
+
+    #[test]
+    fn record_trim_accumulates_across_calls() {
+        let metrics = StreamOverflowMetrics::default();
+        metrics.record_trim(3, false);
+        metrics.record_trim(5, true);
+        assert_eq!(metrics.trim_count(), 2);
+        assert_eq!(metrics.entries_trimmed(), 8);
+        assert_eq!(metrics.window_overflow_count(), 1);
+    }
+}
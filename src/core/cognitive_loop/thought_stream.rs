@@ -0,0 +1,95 @@
+//! Live broadcast of every completed cycle, for `GET /thoughts` WebSocket
+//! observers.
+//!
+//! [`crate::daneel::Daneel::subscribe`] already solves this for library
+//! embedders with a [`broadcast`] channel; [`ThoughtStreamHandle`] is the
+//! same shape shared with the API task instead, so external dashboards and
+//! research tooling can watch `CycleResult`s in real time without scraping
+//! Redis Streams directly - the other `*Handle` types in this module
+//! (`RecentThoughtsHandle`, `EmotionTimelineHandle`) publish a rolling
+//! snapshot; this one has no snapshot to give a late subscriber, only what
+//! happens from the moment it connects.
+
+use tokio::sync::broadcast;
+
+use crate::core::cognitive_loop::CycleResult;
+
+/// Capacity of the broadcast channel backing [`ThoughtStreamHandle`]. A
+/// subscriber that falls behind this many cycles drops the oldest ones
+/// rather than slowing down [`CognitiveLoop::run_cycle`](super::CognitiveLoop::run_cycle)
+/// - see [`tokio::sync::broadcast::Receiver::recv`]'s `Lagged` error.
+pub const CAPACITY: usize = 256;
+
+/// Shared handle publishing every completed cycle, so the API task can
+/// stream it to WebSocket observers without reaching into `CognitiveLoop`
+/// state directly.
+#[derive(Clone)]
+pub struct ThoughtStreamHandle(broadcast::Sender<CycleResult>);
+
+impl Default for ThoughtStreamHandle {
+    fn default() -> Self {
+        let (tx, _) = broadcast::channel(CAPACITY);
+        Self(tx)
+    }
+}
+
+impl ThoughtStreamHandle {
+    /// Create a handle with no subscribers yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Broadcast `result` to every current subscriber. A no-op if nobody's
+    /// listening - not an error.
+    pub fn publish(&self, result: CycleResult) {
+        let _ = self.0.send(result);
+    }
+
+    /// Subscribe to every [`CycleResult`] published from here on.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<CycleResult> {
+        self.0.subscribe()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::cognitive_loop::StageDurations;
+    use std::time::Duration;
+
+    fn sample_result() -> CycleResult {
+        CycleResult::new(1, Duration::from_millis(1), None, 0.5, 0.0, 0.0, 1, true, StageDurations::default(), None)
+    }
+
+    #[test]
+    fn publish_without_subscribers_does_not_panic() {
+        let handle = ThoughtStreamHandle::new();
+        handle.publish(sample_result());
+    }
+
+    #[tokio::test]
+    async fn subscriber_receives_published_result() {
+        let handle = ThoughtStreamHandle::new();
+        let mut rx = handle.subscribe();
+
+        handle.publish(sample_result());
+
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.cycle_number, 1);
+    }
+
+    #[tokio::test]
+    async fn multiple_subscribers_each_receive_the_result() {
+        let handle = ThoughtStreamHandle::new();
+        let mut rx1 = handle.subscribe();
+        let mut rx2 = handle.subscribe();
+
+        handle.publish(sample_result());
+
+        assert_eq!(rx1.recv().await.unwrap().cycle_number, 1);
+        assert_eq!(rx2.recv().await.unwrap().cycle_number, 1);
+    }
+}
@@ -4,6 +4,8 @@
 
 use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+
 /// Current stage in the cognitive cycle
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CognitiveStage {
@@ -31,7 +33,7 @@ pub enum LoopState {
 }
 
 /// Time spent in each stage of the cognitive cycle
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StageDurations {
     pub trigger: Duration,
     pub autoflow: Duration,
@@ -0,0 +1,80 @@
+//! Human-interaction tracking for throttling observable output without
+//! slowing cognition itself.
+//!
+//! While a human is actively interacting (e.g. injecting stimuli), the
+//! `awake` stream and cycle tracing would otherwise flood at whatever rate
+//! `speed_mode` is currently running - up to ~200,000/sec at supercomputer
+//! speed. [`HumanInteractionHandle`] lets the API task (where interaction
+//! happens) tell the cognitive-loop task (where the sampling decision is
+//! made each cycle) that a human session is active, the same cross-task
+//! sharing problem [`CapabilityHandle`](crate::core::capabilities::CapabilityHandle)
+//! solves for the capability matrix. See
+//! `CognitiveConfig::human_interaction_sampling` for how the loop turns
+//! this into an actual sampling rate, and `CognitiveLoop::run_cycle` for
+//! where it's read.
+
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Shared handle recording the most recent human interaction, so the
+/// cognitive loop's task can check activity without the API task reaching
+/// into loop state directly.
+#[derive(Debug, Clone)]
+pub struct HumanInteractionHandle(Arc<RwLock<Option<Instant>>>);
+
+impl Default for HumanInteractionHandle {
+    fn default() -> Self {
+        Self(Arc::new(RwLock::new(None)))
+    }
+}
+
+impl HumanInteractionHandle {
+    /// Create a handle reporting no interaction, until the first
+    /// [`Self::mark_active`] call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that a human interacted right now (e.g. an `/inject` call).
+    pub fn mark_active(&self) {
+        if let Ok(mut guard) = self.0.write() {
+            *guard = Some(Instant::now());
+        }
+    }
+
+    /// Whether a human interaction was recorded within `window` of now.
+    #[must_use]
+    pub fn is_active(&self, window: Duration) -> bool {
+        self.0
+            .read()
+            .ok()
+            .and_then(|guard| *guard)
+            .is_some_and(|last| last.elapsed() <= window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_handle_is_not_active() {
+        let handle = HumanInteractionHandle::new();
+        assert!(!handle.is_active(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn marked_handle_is_active_within_window() {
+        let handle = HumanInteractionHandle::new();
+        handle.mark_active();
+        assert!(handle.is_active(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn marked_handle_expires_outside_window() {
+        let handle = HumanInteractionHandle::new();
+        handle.mark_active();
+        assert!(!handle.is_active(Duration::ZERO));
+    }
+}
@@ -15,14 +15,22 @@
 //! In future FPGA implementation, THE BOX becomes hardware-immutable:
 //! physically impossible to bypass.
 
+pub mod capabilities;
 pub mod cognitive_loop;
+pub mod interaction;
 pub mod invariants;
 pub mod laws;
 pub mod metrics;
+pub mod scrub;
+pub mod trace;
 pub mod types;
 
 // Re-exports for public API (allow unused - used by external consumers)
 #[allow(unused_imports)]
+pub use capabilities::{CapabilityHandle, CapabilityMatrix};
+#[allow(unused_imports)]
+pub use interaction::HumanInteractionHandle;
+#[allow(unused_imports)]
 pub use cognitive_loop::{
     CognitiveLoop, CognitiveStage, CycleMetrics, CycleResult, LoopState, StageDurations,
 };
@@ -36,4 +44,8 @@ pub use metrics::{
     EntropyResult, FractalityResult, SalienceComponents, BALANCED_THRESHOLD, EMERGENT_THRESHOLD,
 };
 #[allow(unused_imports)]
+pub use scrub::{scrub_content, scrub_text, ScrubConfig};
+#[allow(unused_imports)]
+pub use trace::{report as trace_report, watch as trace_watch, TraceEvent, TraceStage};
+#[allow(unused_imports)]
 pub use types::{Content, SalienceScore, Thought, ThoughtId, WindowId};
@@ -0,0 +1,255 @@
+//! PII Scrubbing (ADR reference pending - see request synth-3924)
+//!
+//! Content derived from human interaction is embedded and persisted into
+//! long-term memory (Qdrant). Before that happens it passes through this
+//! scrubber, which redacts emails, phone numbers, and (heuristically) names
+//! so raw PII never reaches the vector store or the embedding model.
+//!
+//! # Scope
+//!
+//! - Emails and phone numbers are caught by regex with high confidence.
+//! - Name redaction is a lightweight capitalized-bigram heuristic, not a
+//!   real NER model - it is intentionally conservative and will miss names,
+//!   but it also won't redact real content it's unsure about. Swapping in a
+//!   proper NER pass later only requires changing [`redact_names`].
+//!
+//! Every redaction is counted in [`ScrubStats`] so the number of scrubs
+//! performed is auditable (e.g. surfaced via `extended_metrics`).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::core::types::Content;
+
+/// Which PII categories to scrub.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrubConfig {
+    pub redact_emails: bool,
+    pub redact_phones: bool,
+    pub redact_names: bool,
+}
+
+impl Default for ScrubConfig {
+    fn default() -> Self {
+        Self {
+            redact_emails: true,
+            redact_phones: true,
+            redact_names: true,
+        }
+    }
+}
+
+/// Counts of redactions performed, by category, since process start.
+#[derive(Debug, Default)]
+pub struct ScrubStats {
+    emails: AtomicU64,
+    phones: AtomicU64,
+    names: AtomicU64,
+}
+
+impl ScrubStats {
+    #[must_use]
+    pub fn emails(&self) -> u64 {
+        self.emails.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn phones(&self) -> u64 {
+        self.phones.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn names(&self) -> u64 {
+        self.names.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.emails() + self.phones() + self.names()
+    }
+}
+
+static STATS: OnceLock<ScrubStats> = OnceLock::new();
+
+/// Global scrub counters, for audit/metrics reporting.
+pub fn stats() -> &'static ScrubStats {
+    STATS.get_or_init(ScrubStats::default)
+}
+
+fn email_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}")
+            .expect("email pattern is a valid regex")
+    })
+}
+
+fn phone_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\+?\d[\d\-. ]{7,}\d").expect("phone pattern is a valid regex")
+    })
+}
+
+fn name_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        // Two consecutive capitalized words, e.g. "Jane Doe" - conservative
+        // heuristic, see module docs.
+        Regex::new(r"\b[A-Z][a-z]+ [A-Z][a-z]+\b").expect("name pattern is a valid regex")
+    })
+}
+
+/// Redact PII from `text` per `config`, returning the scrubbed text.
+///
+/// Each redaction increments the matching counter in [`stats`].
+#[must_use]
+pub fn scrub_text(text: &str, config: &ScrubConfig) -> String {
+    let mut scrubbed = text.to_string();
+
+    if config.redact_emails {
+        let count = email_pattern().find_iter(&scrubbed).count();
+        if count > 0 {
+            scrubbed = email_pattern()
+                .replace_all(&scrubbed, "[REDACTED_EMAIL]")
+                .into_owned();
+            stats().emails.fetch_add(count as u64, Ordering::Relaxed);
+        }
+    }
+
+    if config.redact_phones {
+        let count = phone_pattern().find_iter(&scrubbed).count();
+        if count > 0 {
+            scrubbed = phone_pattern()
+                .replace_all(&scrubbed, "[REDACTED_PHONE]")
+                .into_owned();
+            stats().phones.fetch_add(count as u64, Ordering::Relaxed);
+        }
+    }
+
+    if config.redact_names {
+        let count = name_pattern().find_iter(&scrubbed).count();
+        if count > 0 {
+            scrubbed = name_pattern()
+                .replace_all(&scrubbed, "[REDACTED_NAME]")
+                .into_owned();
+            stats().names.fetch_add(count as u64, Ordering::Relaxed);
+        }
+    }
+
+    scrubbed
+}
+
+/// Redact PII from the text-bearing fields of `content` per `config`,
+/// returning a scrubbed clone.
+///
+/// `Symbol`'s `id` and `Relation`'s `predicate` are the only fields that
+/// carry human-readable text (see `Content::to_embedding_text`) - `Raw`'s
+/// bytes and `Symbol`'s `data` are pre-linguistic/binary and left alone.
+/// `Relation`/`Composite` recurse into their children so nested human
+/// content is covered too.
+#[must_use]
+pub fn scrub_content(content: &Content, config: &ScrubConfig) -> Content {
+    match content {
+        Content::Raw(data) => Content::Raw(data.clone()),
+        Content::Symbol { id, data } => Content::Symbol {
+            id: scrub_text(id, config),
+            data: data.clone(),
+        },
+        Content::Relation { subject, predicate, object } => Content::Relation {
+            subject: Box::new(scrub_content(subject, config)),
+            predicate: scrub_text(predicate, config),
+            object: Box::new(scrub_content(object, config)),
+        },
+        Content::Composite(items) => {
+            Content::Composite(items.iter().map(|item| scrub_content(item, config)).collect())
+        }
+        Content::Empty => Content::Empty,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_email_addresses() {
+        let out = scrub_text("reach me at jane.doe@example.com please", &ScrubConfig::default());
+        assert_eq!(out, "reach me at [REDACTED_EMAIL] please");
+    }
+
+    #[test]
+    fn redacts_phone_numbers() {
+        let out = scrub_text("call 555-123-4567 tomorrow", &ScrubConfig::default());
+        assert_eq!(out, "call [REDACTED_PHONE] tomorrow");
+    }
+
+    #[test]
+    fn redacts_capitalized_name_bigrams() {
+        let out = scrub_text("Jane Doe said hello", &ScrubConfig::default());
+        assert_eq!(out, "[REDACTED_NAME] said hello");
+    }
+
+    #[test]
+    fn leaves_text_unchanged_when_nothing_matches() {
+        let out = scrub_text("the sky is blue today", &ScrubConfig::default());
+        assert_eq!(out, "the sky is blue today");
+    }
+
+    #[test]
+    fn respects_disabled_categories() {
+        let config = ScrubConfig {
+            redact_emails: false,
+            redact_phones: true,
+            redact_names: true,
+        };
+        let out = scrub_text("jane.doe@example.com", &config);
+        assert_eq!(out, "jane.doe@example.com");
+    }
+
+    #[test]
+    fn counts_accumulate_across_calls() {
+        let before = stats().emails();
+        scrub_text("one@example.com two@example.com", &ScrubConfig::default());
+        assert_eq!(stats().emails(), before + 2);
+    }
+
+    #[test]
+    fn scrub_content_redacts_symbol_id() {
+        let content = Content::symbol("reach jane.doe@example.com", vec![1, 2, 3]);
+        let scrubbed = scrub_content(&content, &ScrubConfig::default());
+        match scrubbed {
+            Content::Symbol { id, data } => {
+                assert_eq!(id, "reach [REDACTED_EMAIL]");
+                assert_eq!(data, vec![1, 2, 3]);
+            }
+            other => panic!("expected a Symbol, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn scrub_content_redacts_relation_predicate_and_children() {
+        let content = Content::relation(
+            Content::symbol("Jane Doe", vec![]),
+            "emails jane.doe@example.com about",
+            Content::symbol("555-123-4567", vec![]),
+        );
+        let scrubbed = scrub_content(&content, &ScrubConfig::default());
+        match scrubbed {
+            Content::Relation { subject, predicate, object } => {
+                assert!(matches!(*subject, Content::Symbol { ref id, .. } if id == "[REDACTED_NAME]"));
+                assert_eq!(predicate, "emails [REDACTED_EMAIL] about");
+                assert!(matches!(*object, Content::Symbol { ref id, .. } if id == "[REDACTED_PHONE]"));
+            }
+            other => panic!("expected a Relation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn scrub_content_leaves_raw_bytes_untouched() {
+        let content = Content::raw(vec![1, 2, 3, 4]);
+        assert_eq!(scrub_content(&content, &ScrubConfig::default()), content);
+    }
+}
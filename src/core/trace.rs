@@ -0,0 +1,204 @@
+//! Thought provenance tracing by symbol
+//!
+//! Lets an operator "watch" a symbol/concept: every time content containing
+//! it is generated, scored, retrieved, vetoed, or consolidated, the relevant
+//! actor emits a [`TraceEvent`] into the process-wide [`TraceRegistry`]. The
+//! accumulated events for a symbol form its lifecycle report.
+//!
+//! # Scope
+//!
+//! This registry is in-process only. Querying a *running* instance (the
+//! `daneel trace <symbol>` cross-process use case) needs the control socket
+//! described in the observer/operator role work; until then, `--trace`
+//! watches a symbol for the lifetime of this process and the report is
+//! printed on shutdown.
+
+use crate::core::types::{Content, ThoughtId};
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+/// Point in the cognitive pipeline where a traced thought was observed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceStage {
+    /// Thought assembled (Stage 4)
+    Generated,
+    /// Scored by the `SalienceActor`
+    Scored,
+    /// Retrieved from memory during recall
+    Retrieved,
+    /// Vetoed by the `VolitionActor`
+    Vetoed,
+    /// Folded into a consolidated memory during sleep
+    Consolidated,
+}
+
+impl std::fmt::Display for TraceStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Generated => "generated",
+            Self::Scored => "scored",
+            Self::Retrieved => "retrieved",
+            Self::Vetoed => "vetoed",
+            Self::Consolidated => "consolidated",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// A single tagged trace event for a watched symbol
+#[derive(Debug, Clone)]
+pub struct TraceEvent {
+    /// Symbol that matched the watchpoint
+    pub symbol: String,
+    /// Pipeline stage where the event was recorded
+    pub stage: TraceStage,
+    /// Thought the event pertains to
+    pub thought_id: ThoughtId,
+    /// Short human-readable detail (e.g. veto reason, salience score)
+    pub detail: String,
+}
+
+/// Process-wide registry of watched symbols and their recorded events
+#[derive(Debug, Default)]
+pub struct TraceRegistry {
+    watched: HashSet<String>,
+    events: Vec<TraceEvent>,
+}
+
+impl TraceRegistry {
+    /// Start watching a symbol; idempotent
+    pub fn watch(&mut self, symbol: &str) {
+        self.watched.insert(symbol.to_lowercase());
+    }
+
+    /// Stop watching a symbol
+    pub fn unwatch(&mut self, symbol: &str) {
+        self.watched.remove(&symbol.to_lowercase());
+    }
+
+    /// True if any symbol is currently watched (cheap short-circuit for call sites)
+    #[must_use]
+    pub fn has_watchpoints(&self) -> bool {
+        !self.watched.is_empty()
+    }
+
+    /// Return the watched symbol contained in `content`, if any
+    #[must_use]
+    pub fn matching_symbol(&self, content: &Content) -> Option<String> {
+        if self.watched.is_empty() {
+            return None;
+        }
+        self.watched
+            .iter()
+            .find(|symbol| Self::content_mentions(content, symbol))
+            .cloned()
+    }
+
+    /// Uses [`Content::any`] (explicit stack, not recursion) so a deeply
+    /// nested `Relation`/`Composite` can't overflow the call stack.
+    fn content_mentions(content: &Content, symbol: &str) -> bool {
+        content.any(|node| match node {
+            Content::Empty | Content::Raw(_) | Content::Composite(_) => false,
+            Content::Symbol { id, .. } => id.to_lowercase().contains(symbol),
+            Content::Relation { predicate, .. } => predicate.to_lowercase().contains(symbol),
+        })
+    }
+
+    /// Record an event for a watched symbol
+    pub fn record(&mut self, symbol: String, stage: TraceStage, thought_id: ThoughtId, detail: impl Into<String>) {
+        tracing::info!(symbol = %symbol, stage = %stage, thought_id = %thought_id, "trace watchpoint hit");
+        self.events.push(TraceEvent {
+            symbol,
+            stage,
+            thought_id,
+            detail: detail.into(),
+        });
+    }
+
+    /// Full lifecycle report for a symbol, in recorded order
+    #[must_use]
+    pub fn report(&self, symbol: &str) -> Vec<TraceEvent> {
+        let symbol = symbol.to_lowercase();
+        self.events
+            .iter()
+            .filter(|e| e.symbol == symbol)
+            .cloned()
+            .collect()
+    }
+}
+
+fn global() -> &'static Mutex<TraceRegistry> {
+    static REGISTRY: OnceLock<Mutex<TraceRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(TraceRegistry::default()))
+}
+
+/// Register a process-wide watchpoint on `symbol`
+pub fn watch(symbol: &str) {
+    global()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .watch(symbol);
+}
+
+/// If `content` mentions a watched symbol, record a [`TraceEvent`] for it
+pub fn record_if_watched(content: &Content, stage: TraceStage, thought_id: ThoughtId, detail: impl Into<String>) {
+    let mut registry = global().lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(symbol) = registry.matching_symbol(content) {
+        registry.record(symbol, stage, thought_id, detail);
+    }
+}
+
+/// Fetch the lifecycle report for `symbol` from the process-wide registry
+#[must_use]
+pub fn report(symbol: &str) -> Vec<TraceEvent> {
+    global()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .report(symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol_content(id: &str) -> Content {
+        Content::symbol(id, vec![])
+    }
+
+    #[test]
+    fn unwatched_symbol_is_not_matched() {
+        let registry = TraceRegistry::default();
+        assert_eq!(registry.matching_symbol(&symbol_content("fire")), None);
+    }
+
+    #[test]
+    fn watched_symbol_matches_case_insensitively() {
+        let mut registry = TraceRegistry::default();
+        registry.watch("Fire");
+        assert_eq!(
+            registry.matching_symbol(&symbol_content("FIRE_ALARM")),
+            Some("fire".to_string())
+        );
+    }
+
+    #[test]
+    fn report_returns_events_in_recorded_order() {
+        let mut registry = TraceRegistry::default();
+        registry.watch("fire");
+        let id = ThoughtId::new();
+        registry.record("fire".to_string(), TraceStage::Generated, id, "first");
+        registry.record("fire".to_string(), TraceStage::Vetoed, id, "second");
+        let events = registry.report("fire");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].stage, TraceStage::Generated);
+        assert_eq!(events[1].stage, TraceStage::Vetoed);
+    }
+
+    #[test]
+    fn unwatch_removes_the_watchpoint() {
+        let mut registry = TraceRegistry::default();
+        registry.watch("fire");
+        registry.unwatch("fire");
+        assert_eq!(registry.matching_symbol(&symbol_content("fire")), None);
+    }
+}
@@ -16,8 +16,11 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use uuid::Uuid;
 
+use crate::config::ContentLimits;
+
 /// Unique identifier for a thought
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ThoughtId(pub Uuid);
@@ -28,6 +31,19 @@ impl ThoughtId {
     pub fn new() -> Self {
         Self(Uuid::new_v4())
     }
+
+    /// Derive a deterministic thought ID for simulation/replay (see
+    /// `CognitiveConfig::deterministic_id_seed`) - the same `(seed, cycle,
+    /// content)` always yields the same ID, so two runs of an identical
+    /// scenario produce item-by-item comparable output instead of fresh
+    /// random UUIDs masking the diff.
+    #[must_use]
+    pub fn deterministic(seed: u64, cycle: u64, content: &Content) -> Self {
+        let mut name = seed.to_le_bytes().to_vec();
+        name.extend_from_slice(&cycle.to_le_bytes());
+        name.extend_from_slice(&serde_json::to_vec(content).unwrap_or_default());
+        Self(Uuid::new_v5(&Uuid::NAMESPACE_OID, &name))
+    }
 }
 
 impl Default for ThoughtId {
@@ -159,7 +175,7 @@ impl Content {
     /// while preserving the thought's place in the memory system.
     #[must_use]
     pub fn to_embedding_text(&self) -> Option<String> {
-        match self {
+        self.fold(|node, children| match node {
             // Empty has no content to embed
             Self::Empty => None,
 
@@ -176,14 +192,10 @@ impl Content {
                 Some(format!("raw pattern {preview}"))
             }
 
-            // Relation: extract predicate and recurse on subject/object
-            Self::Relation {
-                subject,
-                predicate,
-                object,
-            } => {
-                let subj = subject.to_embedding_text().unwrap_or_default();
-                let obj = object.to_embedding_text().unwrap_or_default();
+            // Relation: extract predicate, children are [subject, object]
+            Self::Relation { predicate, .. } => {
+                let subj = children.first().cloned().flatten().unwrap_or_default();
+                let obj = children.get(1).cloned().flatten().unwrap_or_default();
 
                 // Predicate is always semantic (e.g., "causes", "resembles")
                 let text = format!("{subj} {predicate} {obj}").trim().to_string();
@@ -197,8 +209,8 @@ impl Content {
             }
 
             // Composite: join embeddable children
-            Self::Composite(items) => {
-                let parts: Vec<String> = items.iter().filter_map(Self::to_embedding_text).collect();
+            Self::Composite(_) => {
+                let parts: Vec<String> = children.iter().cloned().flatten().collect();
 
                 if parts.is_empty() {
                     None
@@ -206,8 +218,190 @@ impl Content {
                     Some(parts.join(" "))
                 }
             }
+        })
+    }
+
+    /// Safety ceiling for [`Self::fold`] and [`Self::any`], independent of
+    /// whatever [`ContentLimits`] the caller validated against - a node
+    /// past this depth is folded/visited with no structural children, as
+    /// if it had none, so a pathological tree that slipped past
+    /// [`Self::validate`] degrades instead of hanging these helpers too.
+    const MAX_TRAVERSAL_DEPTH: usize = 10_000;
+
+    /// The nodes `self` recurses into: none for `Empty`/`Raw`/`Symbol`,
+    /// `[subject, object]` for `Relation`, one per element for
+    /// `Composite`.
+    fn structural_children(&self) -> Vec<&Self> {
+        match self {
+            Self::Empty | Self::Raw(_) | Self::Symbol { .. } => Vec::new(),
+            Self::Relation { subject, object, .. } => vec![subject.as_ref(), object.as_ref()],
+            Self::Composite(items) => items.iter().collect(),
         }
     }
+
+    /// Fold this content tree bottom-up using an explicit stack instead of
+    /// recursion, so callers that used to recurse directly over `Content`
+    /// (salience scoring, keyword checks, embedding-text generation) can't
+    /// overflow the call stack on a deeply nested `Relation`/`Composite`.
+    ///
+    /// `combine` is called once per node, in post-order, with that node and
+    /// the already-folded results of its [`Self::structural_children`].
+    #[must_use]
+    pub fn fold<T>(&self, combine: impl Fn(&Self, &[T]) -> T) -> T {
+        enum Frame<'a> {
+            Enter(&'a Content, usize),
+            Combine(&'a Content, usize),
+        }
+
+        let mut work = vec![Frame::Enter(self, 0)];
+        let mut results: Vec<T> = Vec::new();
+
+        while let Some(frame) = work.pop() {
+            match frame {
+                Frame::Enter(node, depth) => {
+                    let children = if depth >= Self::MAX_TRAVERSAL_DEPTH {
+                        Vec::new()
+                    } else {
+                        node.structural_children()
+                    };
+                    work.push(Frame::Combine(node, children.len()));
+                    for child in children.into_iter().rev() {
+                        work.push(Frame::Enter(child, depth + 1));
+                    }
+                }
+                Frame::Combine(node, count) => {
+                    let start = results.len() - count;
+                    let child_results: Vec<T> = results.split_off(start);
+                    results.push(combine(node, &child_results));
+                }
+            }
+        }
+
+        results
+            .pop()
+            .expect("fold visits exactly one root and leaves exactly one result")
+    }
+
+    /// Walk this content tree with an explicit stack instead of recursion,
+    /// short-circuiting as soon as `predicate` returns `true` for any node
+    /// (itself or a [`Self::structural_children`]).
+    #[must_use]
+    pub fn any(&self, mut predicate: impl FnMut(&Self) -> bool) -> bool {
+        let mut stack = vec![(self, 0usize)];
+
+        while let Some((node, depth)) = stack.pop() {
+            if predicate(node) {
+                return true;
+            }
+            if depth < Self::MAX_TRAVERSAL_DEPTH {
+                for child in node.structural_children() {
+                    stack.push((child, depth + 1));
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Check `self` against `limits`, recursively - a `Composite` or
+    /// `Relation` nested past `max_depth`, a tree with more than
+    /// `max_items` total nodes, or one whose `Raw`/`Symbol` payloads and
+    /// `Relation` predicates total more than `max_bytes` is rejected before
+    /// it ever reaches salience scoring or gets serialized onto a stream.
+    ///
+    /// Depth and item count are checked depth-first as the tree is walked,
+    /// so a pathological tree is rejected as soon as a limit is crossed
+    /// rather than after fully materializing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ContentValidationError`] variant that applies.
+    pub fn validate(&self, limits: &ContentLimits) -> Result<(), ContentValidationError> {
+        let mut items_seen = 0usize;
+        let mut bytes_seen = 0usize;
+        self.validate_at_depth(limits, 0, &mut items_seen, &mut bytes_seen)
+    }
+
+    fn validate_at_depth(
+        &self,
+        limits: &ContentLimits,
+        depth: usize,
+        items_seen: &mut usize,
+        bytes_seen: &mut usize,
+    ) -> Result<(), ContentValidationError> {
+        if depth > limits.max_depth {
+            return Err(ContentValidationError::TooDeep {
+                max_depth: limits.max_depth,
+            });
+        }
+
+        *items_seen += 1;
+        if *items_seen > limits.max_items {
+            return Err(ContentValidationError::TooManyItems {
+                max_items: limits.max_items,
+                actual: *items_seen,
+            });
+        }
+
+        match self {
+            Self::Empty => {}
+            Self::Raw(data) => *bytes_seen += data.len(),
+            Self::Symbol { id, data } => *bytes_seen += id.len() + data.len(),
+            Self::Relation {
+                subject,
+                predicate,
+                object,
+            } => {
+                *bytes_seen += predicate.len();
+                subject.validate_at_depth(limits, depth + 1, items_seen, bytes_seen)?;
+                object.validate_at_depth(limits, depth + 1, items_seen, bytes_seen)?;
+            }
+            Self::Composite(items) => {
+                for item in items {
+                    item.validate_at_depth(limits, depth + 1, items_seen, bytes_seen)?;
+                }
+            }
+        }
+
+        if *bytes_seen > limits.max_bytes {
+            return Err(ContentValidationError::TooLarge {
+                max_bytes: limits.max_bytes,
+                actual: *bytes_seen,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Why [`Content::validate`] rejected a tree. See [`ContentLimits`] for what
+/// each limit means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ContentValidationError {
+    /// Nesting (`Relation`/`Composite`) exceeded `max_depth`.
+    #[error("content nesting exceeds max depth of {max_depth}")]
+    TooDeep {
+        /// The limit that was exceeded
+        max_depth: usize,
+    },
+
+    /// Total node count across the tree exceeded `max_items`.
+    #[error("content has {actual} items, exceeding the limit of {max_items}")]
+    TooManyItems {
+        /// The limit that was exceeded
+        max_items: usize,
+        /// Item count at the point the limit was crossed
+        actual: usize,
+    },
+
+    /// Total `Raw`/`Symbol`/predicate bytes across the tree exceeded `max_bytes`.
+    #[error("content is at least {actual} bytes, exceeding the limit of {max_bytes}")]
+    TooLarge {
+        /// The limit that was exceeded
+        max_bytes: usize,
+        /// Byte count at the point the limit was crossed
+        actual: usize,
+    },
 }
 
 /// Salience score - emotional/importance weighting
@@ -417,6 +611,12 @@ pub struct Thought {
 
     /// Source stream (where did the winning content come from)
     pub source_stream: Option<String>,
+
+    /// This thought's embedding, if one was computed for it - opt-in, like
+    /// `EmotionalContext::embedding`. `None` unless a caller attaches one
+    /// via [`Self::with_embedding`]; used by `VolitionState`'s
+    /// embedding-similarity commitment matching.
+    pub embedding: Option<Vec<f32>>,
 }
 
 impl Thought {
@@ -430,6 +630,7 @@ impl Thought {
             created_at: Utc::now(),
             parent_id: None,
             source_stream: None,
+            embedding: None,
         }
     }
 
@@ -446,6 +647,64 @@ impl Thought {
         self.source_stream = Some(stream.into());
         self
     }
+
+    /// Attach a precomputed embedding to this thought
+    #[must_use]
+    pub fn with_embedding(mut self, embedding: Vec<f32>) -> Self {
+        self.embedding = Some(embedding);
+        self
+    }
+
+    /// Override this thought's ID with a deterministic one derived from
+    /// `(seed, cycle, content)` - see [`ThoughtId::deterministic`]. Used for
+    /// simulation/replay when `CognitiveConfig::deterministic_id_seed` is
+    /// set, so two runs of the same scenario are diffable item-by-item.
+    #[must_use]
+    pub fn with_deterministic_id(mut self, seed: u64, cycle: u64) -> Self {
+        self.id = ThoughtId::deterministic(seed, cycle, &self.content);
+        self
+    }
+}
+
+/// Find the dominant concept across a set of contents, for auto-labeling
+/// windows
+///
+/// Walks each [`Content`] (recursing into `Composite`) and counts `Symbol`
+/// ids and `Relation` predicates as concept keys, returning the
+/// most-frequent one. This is a Phase 1 frequency heuristic; Phase 2 will
+/// replace it with an ontology lookup or nearest-embedding-topic match once
+/// those exist.
+///
+/// Returns `None` if no content yields a concept key (e.g. all `Raw` or
+/// `Empty`).
+#[must_use]
+pub fn dominant_concept(contents: &[Content]) -> Option<String> {
+    use std::collections::HashMap;
+
+    fn concept_key(content: &Content, counts: &mut HashMap<String, usize>) {
+        match content {
+            Content::Symbol { id, .. } => *counts.entry(id.clone()).or_insert(0) += 1,
+            Content::Relation { predicate, .. } => {
+                *counts.entry(predicate.clone()).or_insert(0) += 1;
+            }
+            Content::Composite(items) => {
+                for item in items {
+                    concept_key(item, counts);
+                }
+            }
+            Content::Raw(_) | Content::Empty => {}
+        }
+    }
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for content in contents {
+        concept_key(content, &mut counts);
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(key, _)| key)
 }
 
 /// A memory window - TMI's "Janela da Memória"
@@ -457,6 +716,11 @@ pub struct Window {
     /// Optional label for this window
     pub label: Option<String>,
 
+    /// Whether `label` was auto-generated from `contents` rather than set
+    /// explicitly. Auto-labeled windows get relabeled as their contents
+    /// change; explicitly-labeled windows never are (see [`Self::push`]).
+    pub auto_labeled: bool,
+
     /// Contents of this window
     pub contents: Vec<Content>,
 
@@ -477,6 +741,7 @@ impl Window {
         Self {
             id: WindowId::new(),
             label: None,
+            auto_labeled: true,
             contents: Vec::new(),
             salience: SalienceScore::neutral(),
             opened_at: Utc::now(),
@@ -485,15 +750,35 @@ impl Window {
     }
 
     /// Create a labeled window
+    ///
+    /// A label set this way is explicit and won't be overwritten by
+    /// [`Self::push`]'s auto-labeling.
     #[must_use]
     pub fn with_label(mut self, label: impl Into<String>) -> Self {
         self.label = Some(label.into());
+        self.auto_labeled = false;
         self
     }
 
     /// Add content to this window
+    ///
+    /// If the window hasn't been given an explicit label, its label is
+    /// recomputed from the dominant concept in its contents (see
+    /// [`dominant_concept`]) so windows stay named after what they actually
+    /// hold as content arrives. Window *count* is capped elsewhere
+    /// (`MAX_MEMORY_WINDOWS`), but a single long-open window's contents
+    /// could otherwise grow without bound, so the oldest content is evicted
+    /// once the window's estimated byte size crosses a cap (see
+    /// `daneel::memory_budget`).
     pub fn push(&mut self, content: Content) {
         self.contents.push(content);
+        crate::memory_budget::evict_oldest_contents_until_under_cap(
+            &mut self.contents,
+            crate::memory_budget::BudgetCaps::default().window_contents_bytes,
+        );
+        if self.auto_labeled {
+            self.label = dominant_concept(&self.contents);
+        }
     }
 
     /// Close this window
@@ -524,6 +809,25 @@ mod tests {
         assert_ne!(id1, id2);
     }
 
+    #[test]
+    fn deterministic_thought_id_is_reproducible() {
+        let content = Content::symbol("test", vec![1, 2, 3]);
+        let id1 = ThoughtId::deterministic(42, 7, &content);
+        let id2 = ThoughtId::deterministic(42, 7, &content);
+        assert_eq!(id1, id2);
+    }
+
+    #[test]
+    fn deterministic_thought_id_varies_with_inputs() {
+        let content = Content::symbol("test", vec![1, 2, 3]);
+        let other_content = Content::symbol("other", vec![4, 5, 6]);
+
+        let base = ThoughtId::deterministic(42, 7, &content);
+        assert_ne!(base, ThoughtId::deterministic(43, 7, &content));
+        assert_ne!(base, ThoughtId::deterministic(42, 8, &content));
+        assert_ne!(base, ThoughtId::deterministic(42, 7, &other_content));
+    }
+
     #[test]
     fn content_raw_creation() {
         let content = Content::raw(vec![1, 2, 3]);
@@ -904,6 +1208,19 @@ mod tests {
         assert_eq!(child.source_stream, Some("reasoning".to_string()));
     }
 
+    #[test]
+    fn thought_with_deterministic_id_is_reproducible() {
+        let content = Content::symbol("test", vec![1, 2, 3]);
+        let id1 = Thought::new(content.clone(), SalienceScore::neutral())
+            .with_deterministic_id(42, 7)
+            .id;
+        let id2 = Thought::new(content, SalienceScore::neutral())
+            .with_deterministic_id(42, 7)
+            .id;
+        assert_eq!(id1, id2);
+        assert_eq!(id1, ThoughtId::deterministic(42, 7, &Content::symbol("test", vec![1, 2, 3])));
+    }
+
     #[test]
     fn window_default() {
         let window = Window::default();
@@ -912,6 +1229,56 @@ mod tests {
         assert!(window.contents.is_empty());
     }
 
+    #[test]
+    fn window_auto_labels_from_dominant_symbol() {
+        let mut window = Window::new();
+        assert!(window.auto_labeled);
+
+        window.push(Content::symbol("hunger", vec![]));
+        window.push(Content::symbol("hunger", vec![]));
+        window.push(Content::symbol("curiosity", vec![]));
+
+        assert_eq!(window.label, Some("hunger".to_string()));
+    }
+
+    #[test]
+    fn window_with_label_is_not_auto_relabeled() {
+        let mut window = Window::new().with_label("pinned");
+        assert!(!window.auto_labeled);
+
+        window.push(Content::symbol("hunger", vec![]));
+        window.push(Content::symbol("hunger", vec![]));
+
+        assert_eq!(window.label, Some("pinned".to_string()));
+    }
+
+    #[test]
+    fn window_push_with_no_concepts_leaves_label_unset() {
+        let mut window = Window::new();
+        window.push(Content::raw(vec![1, 2, 3]));
+
+        assert!(window.label.is_none());
+    }
+
+    #[test]
+    fn dominant_concept_counts_relation_predicates_and_recurses_into_composite() {
+        let contents = vec![
+            Content::relation(Content::Empty, "causes", Content::Empty),
+            Content::Composite(vec![
+                Content::relation(Content::Empty, "causes", Content::Empty),
+                Content::symbol("novelty", vec![]),
+            ]),
+        ];
+
+        assert_eq!(dominant_concept(&contents), Some("causes".to_string()));
+    }
+
+    #[test]
+    fn dominant_concept_none_for_non_conceptual_content() {
+        let contents = vec![Content::raw(vec![1]), Content::Empty];
+        assert_eq!(dominant_concept(&contents), None);
+    }
+
     #[test]
     fn tmi_bin_high_boundary() {
         // Create a score that lands in HIGH bin (0.6 <= composite < 0.8)
@@ -965,4 +1332,91 @@ mod tests {
         };
         assert_eq!(content.to_embedding_text(), Some("causes".to_string()));
     }
+
+    #[test]
+    fn validate_accepts_content_within_limits() {
+        let limits = ContentLimits::standard();
+        let content = Content::Composite(vec![Content::Raw(vec![1, 2, 3]), Content::Symbol {
+            id: "a".to_string(),
+            data: "b".to_string(),
+        }]);
+        assert!(content.validate(&limits).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_excessive_depth() {
+        let limits = ContentLimits { max_depth: 2, max_items: 10_000, max_bytes: 1_048_576 };
+        let mut content = Content::Empty;
+        for _ in 0..5 {
+            content = Content::Composite(vec![content]);
+        }
+        assert!(matches!(
+            content.validate(&limits).unwrap_err(),
+            ContentValidationError::TooDeep { max_depth: 2 }
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_excessive_item_count() {
+        let limits = ContentLimits { max_depth: 32, max_items: 3, max_bytes: 1_048_576 };
+        let content = Content::Composite(vec![Content::Empty; 5]);
+        assert!(matches!(
+            content.validate(&limits).unwrap_err(),
+            ContentValidationError::TooManyItems { max_items: 3, .. }
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_excessive_byte_size() {
+        let limits = ContentLimits { max_depth: 32, max_items: 10_000, max_bytes: 4 };
+        let content = Content::Raw(vec![0; 16]);
+        assert!(matches!(
+            content.validate(&limits).unwrap_err(),
+            ContentValidationError::TooLarge { max_bytes: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn fold_counts_nodes_without_recursing() {
+        let mut content = Content::Empty;
+        for _ in 0..5_000 {
+            content = Content::Composite(vec![content]);
+        }
+        let node_count = content.fold(|_, children: &[usize]| 1 + children.iter().sum::<usize>());
+        assert_eq!(node_count, 5_001);
+    }
+
+    #[test]
+    fn fold_degrades_past_max_traversal_depth() {
+        // Past Content::MAX_TRAVERSAL_DEPTH, fold treats the node as
+        // childless rather than walking further - it should return
+        // without hanging or panicking.
+        let mut content = Content::Empty;
+        for _ in 0..20_000 {
+            content = Content::Composite(vec![content]);
+        }
+        let node_count = content.fold(|_, children: &[usize]| 1 + children.iter().sum::<usize>());
+        assert!(node_count < 20_001);
+    }
+
+    #[test]
+    fn any_short_circuits_on_first_match() {
+        let content = Content::Composite(vec![
+            Content::Symbol { id: "safe".to_string(), data: String::new() },
+            Content::Symbol { id: "target".to_string(), data: String::new() },
+        ]);
+        let mut visited = 0;
+        let found = content.any(|node| {
+            visited += 1;
+            matches!(node, Content::Symbol { id, .. } if id == "target")
+        });
+        assert!(found);
+        assert!(visited < 3); // composite itself + at most both symbols
+    }
+
+    #[test]
+    fn any_returns_false_for_no_match() {
+        let content = Content::Composite(vec![Content::Raw(vec![1]), Content::Empty]);
+        assert!(!content.any(|node| matches!(node, Content::Symbol { .. })));
+    }
 }
@@ -0,0 +1,413 @@
+//! Library-facing embedding API: `Daneel::builder()...build()`.
+//!
+//! Assembling a [`CognitiveLoop`] by hand - connecting a
+//! [`StreamsClient`](crate::streams::client::StreamsClient), a [`MemoryDb`],
+//! picking a [`SpeedMode`] - is what `main.rs` does for the `daneel` binary,
+//! but it's more ceremony than most embedders need just to drive a cycle and
+//! read what comes out. [`Daneel::builder`] wraps that assembly behind a
+//! handle with four methods: [`Daneel::step`], [`Daneel::inject`],
+//! [`Daneel::subscribe`], and [`Daneel::shutdown`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use daneel::daneel::Daneel;
+//! use daneel::config::SpeedMode;
+//!
+//! # async fn example() -> Result<(), daneel::daneel::DaneelError> {
+//! let mut mind = Daneel::builder()
+//!     .with_redis("redis://127.0.0.1:6379")
+//!     .with_memory("http://127.0.0.1:6334")
+//!     .with_speed(SpeedMode::Human)
+//!     .build()
+//!     .await?;
+//!
+//! let result = mind.step().await;
+//! println!("cycle {} produced {:?}", result.cycle_number, result.thought_produced);
+//!
+//! mind.shutdown().await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use redis::AsyncCommands;
+use thiserror::Error;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::config::{CognitiveConfig, SpeedMode};
+use crate::core::cognitive_loop::{CognitiveLoop, CycleResult};
+use crate::core::types::{Content, SalienceScore};
+use crate::hooks::{
+    CognitionHook, ConsolidationEvent, FnHook, HookRegistry, MilestoneEvent, ThoughtEvent,
+    VetoEvent,
+};
+use crate::memory_db::MemoryDb;
+
+/// Capacity of the broadcast channel backing [`Daneel::subscribe`]. A lagged
+/// subscriber drops the oldest results rather than blocking [`Daneel::step`]
+/// - see [`tokio::sync::broadcast`].
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Errors constructing or driving a [`Daneel`] handle.
+#[derive(Debug, Error)]
+pub enum DaneelError {
+    /// Connecting the Redis-backed thought bus failed.
+    #[error("failed to connect to Redis streams: {reason}")]
+    Streams {
+        /// Underlying connection error
+        reason: String,
+    },
+
+    /// Connecting the Qdrant-backed memory store failed.
+    #[error("failed to connect to Qdrant memory store: {reason}")]
+    Memory {
+        /// Underlying connection error
+        reason: String,
+    },
+
+    /// [`Daneel::inject`] was called on a handle built without
+    /// [`DaneelBuilder::with_redis`] - there's no injection stream to write
+    /// to.
+    #[error("inject() requires a Redis client; call DaneelBuilder::with_redis first")]
+    NoRedis,
+
+    /// Writing the injected thought to the injection stream failed.
+    #[error("failed to write injection to Redis: {reason}")]
+    Injection {
+        /// Underlying Redis error
+        reason: String,
+    },
+
+    /// [`Daneel::inject`]'s content violated the handle's
+    /// [`ContentLimits`](crate::config::ContentLimits) - see
+    /// [`Content::validate`](crate::core::types::Content::validate).
+    #[error("invalid content: {source}")]
+    InvalidContent {
+        #[source]
+        source: crate::core::types::ContentValidationError,
+    },
+}
+
+impl From<crate::streams::types::StreamError> for DaneelError {
+    fn from(e: crate::streams::types::StreamError) -> Self {
+        Self::Streams {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl From<crate::memory_db::MemoryDbError> for DaneelError {
+    fn from(e: crate::memory_db::MemoryDbError) -> Self {
+        Self::Memory {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl From<redis::RedisError> for DaneelError {
+    fn from(e: redis::RedisError) -> Self {
+        Self::Injection {
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// Builder for a [`Daneel`] handle.
+///
+/// Every collaborator is optional - `Daneel::builder().build()` alone gives
+/// you a pure in-memory cognitive core (no streams persistence, no
+/// long-term memory), the same minimal configuration
+/// [`CognitiveLoop::new`](crate::core::cognitive_loop::CognitiveLoop::new)
+/// gives the binary.
+#[derive(Default)]
+pub struct DaneelBuilder {
+    config: CognitiveConfig,
+    redis_url: Option<String>,
+    qdrant_url: Option<String>,
+    hooks: HookRegistry,
+}
+
+impl std::fmt::Debug for DaneelBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DaneelBuilder")
+            .field("config", &self.config)
+            .field("redis_url", &self.redis_url)
+            .field("qdrant_url", &self.qdrant_url)
+            .field("hooks", &self.hooks)
+            .finish()
+    }
+}
+
+impl DaneelBuilder {
+    fn new() -> Self {
+        Self {
+            config: CognitiveConfig::human(),
+            redis_url: None,
+            qdrant_url: None,
+            hooks: HookRegistry::new(),
+        }
+    }
+
+    /// Register a closure fired every time a thought becomes conscious
+    /// experience (see [`CognitionHook::on_thought`]).
+    #[must_use]
+    pub fn on_thought(mut self, f: impl Fn(&ThoughtEvent) + Send + Sync + 'static) -> Self {
+        self.hooks.register(Arc::new(FnHook::Thought(Box::new(f))));
+        self
+    }
+
+    /// Register a closure fired every time Volition vetoes a thought (see
+    /// [`CognitionHook::on_veto`]).
+    #[must_use]
+    pub fn on_veto(mut self, f: impl Fn(&VetoEvent) + Send + Sync + 'static) -> Self {
+        self.hooks.register(Arc::new(FnHook::Veto(Box::new(f))));
+        self
+    }
+
+    /// Register a closure fired every time a thought is handed to long-term
+    /// memory (see [`CognitionHook::on_consolidation`]).
+    #[must_use]
+    pub fn on_consolidation(mut self, f: impl Fn(&ConsolidationEvent) + Send + Sync + 'static) -> Self {
+        self.hooks.register(Arc::new(FnHook::Consolidation(Box::new(f))));
+        self
+    }
+
+    /// Register a closure fired on [`CognitiveLoop::notify_milestone`] calls
+    /// (see [`CognitionHook::on_milestone`] - not fired automatically).
+    #[must_use]
+    pub fn on_milestone(mut self, f: impl Fn(&MilestoneEvent) + Send + Sync + 'static) -> Self {
+        self.hooks.register(Arc::new(FnHook::Milestone(Box::new(f))));
+        self
+    }
+
+    /// Register a closure fired on [`CognitiveLoop::notify_sleep_enter`]
+    /// calls (see [`CognitionHook::on_sleep_enter`] - not fired
+    /// automatically).
+    #[must_use]
+    pub fn on_sleep_enter(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.hooks.register(Arc::new(FnHook::SleepEnter(Box::new(f))));
+        self
+    }
+
+    /// Register a closure fired on [`CognitiveLoop::notify_sleep_exit`]
+    /// calls (see [`CognitionHook::on_sleep_exit`] - not fired
+    /// automatically).
+    #[must_use]
+    pub fn on_sleep_exit(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.hooks.register(Arc::new(FnHook::SleepExit(Box::new(f))));
+        self
+    }
+
+    /// Register any [`CognitionHook`] trait object directly, for embedders
+    /// that need more than one event kind in a single implementor.
+    #[must_use]
+    pub fn with_hook(mut self, hook: Arc<dyn CognitionHook>) -> Self {
+        self.hooks.register(hook);
+        self
+    }
+
+    /// Connect the cognitive loop to Redis Streams (ADR-020) for ephemeral
+    /// thought persistence and injection.
+    #[must_use]
+    pub fn with_redis(mut self, url: impl Into<String>) -> Self {
+        self.redis_url = Some(url.into());
+        self
+    }
+
+    /// Connect the cognitive loop to Qdrant (ADR-021) for long-term memory.
+    #[must_use]
+    pub fn with_memory(mut self, qdrant_url: impl Into<String>) -> Self {
+        self.qdrant_url = Some(qdrant_url.into());
+        self
+    }
+
+    /// Set the cognitive speed mode (see [`SpeedMode`]).
+    #[must_use]
+    pub fn with_speed(mut self, speed: SpeedMode) -> Self {
+        self.config.set_speed_mode(speed);
+        self
+    }
+
+    /// Replace the default [`CognitiveConfig`] outright, for embedders that
+    /// need more than [`Self::with_speed`] exposes. Applied before
+    /// `with_speed`'s effect if both are called, so prefer calling
+    /// `with_config` first.
+    #[must_use]
+    pub fn with_config(mut self, config: CognitiveConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Assemble the [`Daneel`] handle, connecting to Redis and/or Qdrant if
+    /// configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DaneelError::Streams`] or [`DaneelError::Memory`] if a
+    /// configured backend can't be reached.
+    pub async fn build(self) -> Result<Daneel, DaneelError> {
+        let mut cognitive_loop = if let Some(url) = &self.redis_url {
+            CognitiveLoop::with_config_and_redis(self.config, url).await?
+        } else {
+            CognitiveLoop::with_config(self.config)
+        };
+
+        // A second, independent Redis client for `Daneel::inject` - same
+        // pattern `main.rs` uses for the injection API's own client rather
+        // than reaching into the loop's private `redis_client` field.
+        let redis_client = match &self.redis_url {
+            Some(url) => Some(redis::Client::open(url.as_str())?),
+            None => None,
+        };
+
+        if let Some(url) = &self.qdrant_url {
+            let memory_db = MemoryDb::connect(url).await?;
+            cognitive_loop.set_memory_db(Arc::new(memory_db));
+        }
+
+        cognitive_loop.set_hooks(self.hooks);
+
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        Ok(Daneel {
+            cognitive_loop,
+            redis_client,
+            events,
+        })
+    }
+}
+
+/// Handle to an embedded cognitive core.
+///
+/// Built via [`Daneel::builder`]. Owns the underlying [`CognitiveLoop`] -
+/// driving it is the embedder's responsibility (there's no background task
+/// here), one [`Daneel::step`] per cycle, exactly like
+/// `main::run_cognitive_loop_headless` drives the binary's loop.
+pub struct Daneel {
+    cognitive_loop: CognitiveLoop,
+    redis_client: Option<redis::Client>,
+    events: broadcast::Sender<CycleResult>,
+}
+
+impl Daneel {
+    /// Start building a [`Daneel`] handle.
+    #[must_use]
+    pub fn builder() -> DaneelBuilder {
+        DaneelBuilder::new()
+    }
+
+    /// Run one cognitive cycle and broadcast the result to every
+    /// [`Self::subscribe`]r.
+    pub async fn step(&mut self) -> CycleResult {
+        let result = self.cognitive_loop.run_cycle().await;
+        // No receivers is the common case for a caller that only polls
+        // `step`'s return value - not an error.
+        let _ = self.events.send(result.clone());
+        result
+    }
+
+    /// Inject external stimulus for the next cycle to pick up, via the
+    /// injection stream (ADR-020) - the same path `POST /inject` writes to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DaneelError::InvalidContent`] if `content` violates the
+    /// configured [`ContentLimits`](crate::config::ContentLimits),
+    /// [`DaneelError::NoRedis`] if this handle was built without
+    /// [`DaneelBuilder::with_redis`], or [`DaneelError::Injection`] if the
+    /// write fails.
+    pub async fn inject(&self, content: Content, salience: SalienceScore) -> Result<(), DaneelError> {
+        content
+            .validate(&self.cognitive_loop.config().content_limits)
+            .map_err(|source| DaneelError::InvalidContent { source })?;
+
+        let client = self.redis_client.as_ref().ok_or(DaneelError::NoRedis)?;
+        let mut conn = client.get_multiplexed_async_connection().await?;
+
+        let injection_id = format!("inject_{}", Uuid::new_v4());
+        let stream_data: Vec<(&str, String)> = vec![
+            ("id", injection_id),
+            ("source", "library".to_string()),
+            (
+                "content",
+                serde_json::to_string(&content).unwrap_or_default(),
+            ),
+            (
+                "salience",
+                serde_json::to_string(&salience).unwrap_or_default(),
+            ),
+            ("timestamp", chrono::Utc::now().to_rfc3339()),
+        ];
+
+        let _: String = conn
+            .xadd(crate::streams::names::stream_inject(), "*", &stream_data)
+            .await?;
+        Ok(())
+    }
+
+    /// Subscribe to every [`CycleResult`] this handle produces from here on.
+    /// A subscriber that falls behind [`EVENT_CHANNEL_CAPACITY`] cycles
+    /// drops the oldest ones rather than slowing down [`Self::step`] - see
+    /// [`tokio::sync::broadcast::Receiver::recv`]'s `Lagged` error.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<CycleResult> {
+        self.events.subscribe()
+    }
+
+    /// Consume the handle. Currently a no-op beyond dropping the loop and
+    /// its connections - reserved for future graceful-drain behavior (e.g.
+    /// flushing a pending consolidation backlog) without changing the
+    /// signature embedders already call.
+    #[allow(clippy::unused_async)]
+    pub async fn shutdown(self) {}
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn build_with_no_backends_succeeds() {
+        let mind = Daneel::builder().build().await;
+        assert!(mind.is_ok());
+    }
+
+    #[tokio::test]
+    async fn inject_without_redis_is_rejected() {
+        let mind = Daneel::builder().build().await.unwrap();
+        let err = mind
+            .inject(Content::raw(b"hello".to_vec()), SalienceScore::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DaneelError::NoRedis));
+    }
+
+    #[tokio::test]
+    async fn step_broadcasts_to_subscribers() {
+        let mut mind = Daneel::builder().build().await.unwrap();
+        let mut rx = mind.subscribe();
+
+        let result = mind.step().await;
+        let broadcast = rx.recv().await.unwrap();
+
+        assert_eq!(result.cycle_number, broadcast.cycle_number);
+    }
+
+    #[tokio::test]
+    async fn builder_with_speed_overrides_default_config() {
+        let mind = Daneel::builder()
+            .with_speed(SpeedMode::Supercomputer)
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mind.cognitive_loop.config().speed_mode,
+            SpeedMode::Supercomputer
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Dream fragment synthesis (ADR-023 REM stage content)
+//!
+//! The TUI's rotating philosophy-quote banner was removed in favour of
+//! daneel-web (ADR-053), but the observatory's equivalent banner
+//! (`PhilosophyMetrics`, served from `/extended_metrics`) still rotates
+//! the same hand-written quotes. This module gives the REM/dreaming stage
+//! of a sleep cycle a way to contribute its own lines: a short excerpt of
+//! the memory it replayed hardest, so the most poetic output of the
+//! system comes from the system's own consolidated memories.
+
+use crate::memory_db::types::Memory;
+
+/// Fragments are trimmed to this many characters so they read like a
+/// banner line, not a memory dump.
+pub const MAX_FRAGMENT_CHARS: usize = 96;
+
+/// Synthesize a short dream fragment from memories replayed during REM.
+///
+/// Picks the most salient candidate - the one the dream most wanted to
+/// strengthen - and returns a trimmed, whitespace-collapsed excerpt of
+/// its content. Returns `None` if there's nothing to dream about.
+#[must_use]
+pub fn synthesize_fragment(candidates: &[Memory]) -> Option<String> {
+    let most_salient = candidates
+        .iter()
+        .max_by(|a, b| a.semantic_salience.total_cmp(&b.semantic_salience))?;
+
+    let excerpt: String = most_salient
+        .content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_FRAGMENT_CHARS)
+        .collect();
+
+    if excerpt.is_empty() {
+        None
+    } else {
+        Some(excerpt)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::memory_db::types::MemorySource;
+
+    fn memory_with_salience(content: &str, salience: f32) -> Memory {
+        let mut memory = Memory::new(
+            content.to_string(),
+            MemorySource::External {
+                stimulus: "test".to_string(),
+            },
+        );
+        memory.semantic_salience = salience;
+        memory
+    }
+
+    #[test]
+    fn no_candidates_means_no_fragment() {
+        assert!(synthesize_fragment(&[]).is_none());
+    }
+
+    #[test]
+    fn picks_the_most_salient_candidate() {
+        let candidates = vec![
+            memory_with_salience("a quiet thought", 0.2),
+            memory_with_salience("the one that mattered most", 0.9),
+            memory_with_salience("background noise", 0.4),
+        ];
+
+        let fragment = synthesize_fragment(&candidates).expect("fragment expected");
+        assert_eq!(fragment, "the one that mattered most");
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        let candidates = vec![memory_with_salience("line one\n  line   two", 0.5)];
+        let fragment = synthesize_fragment(&candidates).expect("fragment expected");
+        assert_eq!(fragment, "line one line two");
+    }
+
+    #[test]
+    fn trims_to_max_fragment_chars() {
+        let long_content = "x".repeat(MAX_FRAGMENT_CHARS * 2);
+        let candidates = vec![memory_with_salience(&long_content, 0.5)];
+        let fragment = synthesize_fragment(&candidates).expect("fragment expected");
+        assert_eq!(fragment.chars().count(), MAX_FRAGMENT_CHARS);
+    }
+
+    #[test]
+    fn blank_content_yields_no_fragment() {
+        let candidates = vec![memory_with_salience("   ", 0.9)];
+        assert!(synthesize_fragment(&candidates).is_none());
+    }
+}
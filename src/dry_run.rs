@@ -0,0 +1,42 @@
+//! Dry-run mode (`--dry-run`)
+//!
+//! When enabled, the stores that actually mutate external systems - Redis
+//! stream writes, Qdrant upserts/deletes, and `RedisGraph` merges - log what
+//! they would have done and return success without performing the write.
+//! Reads are untouched, so `daneel --dry-run` exercises the same connection
+//! and config-validation logic as a normal run without touching a
+//! production brain.
+//!
+//! This is a single process-wide flag, set once at startup (mirroring
+//! [`crate::namespace`]'s `DANEEL_PREFIX`) rather than threaded through every
+//! call - flipping it mid-run is not supported and not needed, since it's
+//! only ever set from the `--dry-run` CLI flag before any writer connects.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Enable dry-run mode for the remainder of the process.
+pub fn enable() {
+    DRY_RUN.store(true, Ordering::Relaxed);
+}
+
+/// Whether dry-run mode is active.
+#[must_use]
+pub fn is_enabled() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enable_is_process_wide_and_sticky() {
+        // DRY_RUN is a single process-wide flag, so this only asserts the
+        // one direction (off -> on) that's safe regardless of test order.
+        enable();
+        assert!(is_enabled());
+    }
+}
@@ -23,6 +23,72 @@ use tracing::{debug, info};
 
 use crate::memory_db::types::VECTOR_DIMENSION;
 
+pub mod reranker;
+pub use reranker::{MemoryReranker, RerankError, SharedMemoryReranker};
+
+/// Which ONNX execution provider `FastEmbed` should run inference on.
+///
+/// `Auto` hands `ort` every accelerator we know how to ask for, in
+/// preference order, with `Cpu` always last as the guaranteed-available
+/// fallback - `ort` silently skips any provider that isn't actually
+/// installed on the host, so listing GPU/CoreML providers is safe even on a
+/// machine that doesn't have them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutionProvider {
+    /// Try CUDA, then `CoreML`, then fall back to CPU
+    #[default]
+    Auto,
+    /// CPU only, regardless of what the host supports
+    Cpu,
+    /// NVIDIA CUDA
+    Cuda,
+    /// Apple `CoreML` (macOS/iOS)
+    CoreMl,
+}
+
+impl ExecutionProvider {
+    /// Read the provider selection from `DANEEL_EMBEDDING_PROVIDER`
+    /// (`auto` | `cpu` | `cuda` | `coreml`), defaulting to `Auto`.
+    #[must_use]
+    pub fn from_env() -> Self {
+        match std::env::var("DANEEL_EMBEDDING_PROVIDER").ok().as_deref() {
+            Some("cpu") => Self::Cpu,
+            Some("cuda") => Self::Cuda,
+            Some("coreml") => Self::CoreMl,
+            _ => Self::Auto,
+        }
+    }
+
+    fn dispatch(self) -> Vec<ort::execution_providers::ExecutionProviderDispatch> {
+        use ort::execution_providers::{CPUExecutionProvider, CUDAExecutionProvider, CoreMLExecutionProvider};
+
+        match self {
+            Self::Cpu => vec![CPUExecutionProvider::default().build()],
+            Self::Cuda => vec![
+                CUDAExecutionProvider::default().build(),
+                CPUExecutionProvider::default().build(),
+            ],
+            Self::CoreMl => vec![
+                CoreMLExecutionProvider::default().build(),
+                CPUExecutionProvider::default().build(),
+            ],
+            Self::Auto => vec![
+                CUDAExecutionProvider::default().build(),
+                CoreMLExecutionProvider::default().build(),
+                CPUExecutionProvider::default().build(),
+            ],
+        }
+    }
+}
+
+/// Local directory to load/cache the embedding model from, for air-gapped
+/// deployments. Unset by default, which leaves `FastEmbed` to use its own
+/// cache directory and download on first run.
+#[must_use]
+pub fn model_cache_dir() -> Option<std::path::PathBuf> {
+    std::env::var_os("DANEEL_MODEL_CACHE_DIR").map(std::path::PathBuf::from)
+}
+
 /// Embedding engine using `FastEmbed`
 pub struct EmbeddingEngine {
     model: fastembed::TextEmbedding,
@@ -34,7 +100,8 @@ pub struct EmbeddingEngine {
 pub type SharedEmbeddingEngine = Arc<RwLock<EmbeddingEngine>>;
 
 impl EmbeddingEngine {
-    /// Create a new embedding engine
+    /// Create a new embedding engine, selecting its execution provider from
+    /// `DANEEL_EMBEDDING_PROVIDER` (see [`ExecutionProvider::from_env`]).
     ///
     /// Downloads the model on first run (~420MB for BGE-base-en-v1.5)
     ///
@@ -43,13 +110,48 @@ impl EmbeddingEngine {
     /// Returns `EmbeddingError::InitFailed` if model loading fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub fn new() -> Result<Self, EmbeddingError> {
-        info!("Initializing embedding engine (bge-base-en-v1.5, 768 dims)...");
+        Self::with_provider(ExecutionProvider::from_env())
+    }
+
+    /// Create a new embedding engine pinned to a specific execution provider.
+    ///
+    /// If `DANEEL_MODEL_CACHE_DIR` is set, the model is loaded from (and
+    /// cached to) that directory instead of the `FastEmbed` default cache -
+    /// see [`model_cache_dir`] and `daneel models fetch` for pre-populating
+    /// it on an air-gapped deployment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError::ModelNotBundled`] if a cache directory is
+    /// configured but doesn't contain a usable model, or
+    /// `EmbeddingError::InitFailed` for any other load failure.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn with_provider(provider: ExecutionProvider) -> Result<Self, EmbeddingError> {
+        let cache_dir = model_cache_dir();
+
+        info!(
+            provider = ?provider,
+            cache_dir = ?cache_dir,
+            "Initializing embedding engine (bge-base-en-v1.5, 768 dims)..."
+        );
 
-        let model = fastembed::TextEmbedding::try_new(
-            fastembed::InitOptions::new(fastembed::EmbeddingModel::BGEBaseENV15)
-                .with_show_download_progress(true),
-        )
-        .map_err(|e| EmbeddingError::InitFailed(e.to_string()))?;
+        let mut options = fastembed::InitOptions::new(fastembed::EmbeddingModel::BGEBaseENV15)
+            .with_execution_providers(provider.dispatch())
+            .with_show_download_progress(true);
+        if let Some(ref dir) = cache_dir {
+            options = options.with_cache_dir(dir.clone());
+        }
+
+        let model = fastembed::TextEmbedding::try_new(options).map_err(|e| {
+            if let Some(dir) = cache_dir {
+                EmbeddingError::ModelNotBundled {
+                    cache_dir: dir.display().to_string(),
+                    source: e.to_string(),
+                }
+            } else {
+                EmbeddingError::InitFailed(e.to_string())
+            }
+        })?;
 
         info!("Embedding engine ready. Timmy can now see meaning in 768 dimensions.");
 
@@ -163,6 +265,15 @@ pub enum EmbeddingError {
 
     #[error("No embedding output generated")]
     NoOutput,
+
+    /// Model init failed and `DANEEL_MODEL_CACHE_DIR` is set, so this is
+    /// almost certainly a missing bundle rather than a transient network
+    /// blip - point the operator at `daneel models fetch`.
+    #[error(
+        "model not found in bundle directory '{cache_dir}' and no network access to download it: {source}. \
+         Run `daneel models fetch` on a machine with network access, then copy {cache_dir} here."
+    )]
+    ModelNotBundled { cache_dir: String, source: String },
 }
 
 /// ADR-049: Test modules excluded from coverage
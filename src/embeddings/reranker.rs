@@ -0,0 +1,147 @@
+//! Cross-encoder re-ranking of memory recall candidates
+//!
+//! [`MemoryDb::find_by_context`](crate::memory_db::MemoryDb::find_by_context)
+//! ranks candidates by cosine similarity between two independently-embedded
+//! vectors (bi-encoder recall) - fast, but blind to query/document token
+//! interactions. A cross-encoder reranker scores the query and each
+//! candidate jointly, which is slower but meaningfully more accurate at
+//! the top of the list. This module wraps `FastEmbed`'s `TextRerank` to
+//! re-score the (small) set of candidates Qdrant already returned, rather
+//! than replacing recall itself.
+//!
+//! Unlike [`EmbeddingEngine`](crate::embeddings::EmbeddingEngine), reranking
+//! is a per-cycle latency cost the trigger stage can choose to skip - see
+//! `CognitiveConfig::can_afford_rerank`.
+
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+
+use super::ExecutionProvider;
+
+/// Cross-encoder reranker using `FastEmbed`
+pub struct MemoryReranker {
+    model: fastembed::TextRerank,
+    /// Count of successful rerank calls
+    rerank_count: u64,
+}
+
+/// Thread-safe shared reranker
+pub type SharedMemoryReranker = Arc<RwLock<MemoryReranker>>;
+
+impl MemoryReranker {
+    /// Create a new reranker, selecting its execution provider from
+    /// `DANEEL_EMBEDDING_PROVIDER` (see [`ExecutionProvider::from_env`]).
+    ///
+    /// Downloads the model on first run (~280MB for bge-reranker-base).
+    ///
+    /// # Errors
+    ///
+    /// Returns `RerankError::InitFailed` if model loading fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn new() -> Result<Self, RerankError> {
+        Self::with_provider(ExecutionProvider::from_env())
+    }
+
+    /// Create a new reranker pinned to a specific execution provider.
+    ///
+    /// If `DANEEL_MODEL_CACHE_DIR` is set, the model is loaded from (and
+    /// cached to) that directory instead of `FastEmbed`'s default cache -
+    /// see [`super::model_cache_dir`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RerankError::ModelNotBundled`] if a cache directory is
+    /// configured but doesn't contain a usable model, or
+    /// `RerankError::InitFailed` for any other load failure.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn with_provider(provider: ExecutionProvider) -> Result<Self, RerankError> {
+        let cache_dir = super::model_cache_dir();
+
+        info!(
+            provider = ?provider,
+            cache_dir = ?cache_dir,
+            "Initializing memory reranker (bge-reranker-base)..."
+        );
+
+        let mut options =
+            fastembed::RerankInitOptions::new(fastembed::RerankerModel::BGERerankerBase)
+                .with_execution_providers(provider.dispatch())
+                .with_show_download_progress(true);
+        if let Some(ref dir) = cache_dir {
+            options = options.with_cache_dir(dir.clone());
+        }
+
+        let model = fastembed::TextRerank::try_new(options).map_err(|e| {
+            if let Some(dir) = cache_dir {
+                RerankError::ModelNotBundled {
+                    cache_dir: dir.display().to_string(),
+                    source: e.to_string(),
+                }
+            } else {
+                RerankError::InitFailed(e.to_string())
+            }
+        })?;
+
+        info!("Memory reranker ready.");
+
+        Ok(Self {
+            model,
+            rerank_count: 0,
+        })
+    }
+
+    /// Re-score `documents` against `query` and return their indices into
+    /// `documents`, reordered from most to least relevant.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RerankError::EmptyInput` if `query` or `documents` is empty,
+    /// or `RerankError::RerankFailed` if inference fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn rerank(&mut self, query: &str, documents: &[String]) -> Result<Vec<usize>, RerankError> {
+        if query.is_empty() || documents.is_empty() {
+            return Err(RerankError::EmptyInput);
+        }
+
+        let results = self
+            .model
+            .rerank(query, documents, false, None)
+            .map_err(|e| RerankError::RerankFailed(e.to_string()))?;
+
+        self.rerank_count += 1;
+        if self.rerank_count.is_multiple_of(1000) {
+            debug!("Reranked {} times", self.rerank_count);
+        }
+
+        Ok(results.into_iter().map(|r| r.index).collect())
+    }
+
+    /// Get count of rerank calls made this session
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub const fn rerank_count(&self) -> u64 {
+        self.rerank_count
+    }
+}
+
+/// Reranking errors
+#[derive(Debug, thiserror::Error)]
+pub enum RerankError {
+    #[error("Failed to initialize reranker model: {0}")]
+    InitFailed(String),
+
+    #[error("Empty query or document list")]
+    EmptyInput,
+
+    #[error("Failed to rerank documents: {0}")]
+    RerankFailed(String),
+
+    /// Model init failed and `DANEEL_MODEL_CACHE_DIR` is set, so this is
+    /// almost certainly a missing bundle rather than a transient network
+    /// blip - point the operator at `daneel models fetch`.
+    #[error(
+        "model not found in bundle directory '{cache_dir}' and no network access to download it: {source}. \
+         Run `daneel models fetch` on a machine with network access, then copy {cache_dir} here."
+    )]
+    ModelNotBundled { cache_dir: String, source: String },
+}
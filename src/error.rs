@@ -0,0 +1,168 @@
+//! Crate-wide error taxonomy
+//!
+//! Per-module errors ([`StreamError`], [`MemoryDbError`], [`EmbeddingError`],
+//! [`GraphError`], [`InvariantViolation`]) stay where they are - each module
+//! owns the errors it can actually produce, and most call sites should keep
+//! matching on those directly. [`DaneelError`] exists one level up: wrap any
+//! of them, classify into a coarse [`ErrorCategory`], and let a single
+//! [`ErrorPolicy`] decide retry/degrade/halt instead of every call site
+//! re-deriving that judgment call from scratch (and, too often, swallowing
+//! the error with `let _ =` instead).
+
+use thiserror::Error;
+
+use crate::core::invariants::InvariantViolation;
+use crate::embeddings::EmbeddingError;
+use crate::graph::GraphError;
+use crate::memory_db::MemoryDbError;
+use crate::streams::types::StreamError;
+
+/// Crate-wide error, wrapping whichever module raised it.
+#[derive(Debug, Error)]
+pub enum DaneelError {
+    /// A stream (Redis Streams) operation failed
+    #[error("stream error: {0}")]
+    Stream(#[from] StreamError),
+
+    /// A long-term memory (Qdrant) operation failed
+    #[error("memory error: {0}")]
+    Memory(#[from] MemoryDbError),
+
+    /// An embedding operation failed
+    #[error("embedding error: {0}")]
+    Embedding(#[from] EmbeddingError),
+
+    /// A graph (`RedisGraph`) operation failed
+    #[error("graph error: {0}")]
+    Graph(#[from] GraphError),
+
+    /// An architectural invariant (THE BOX) was violated
+    #[error("invariant violated: {0}")]
+    Invariant(#[from] InvariantViolation),
+}
+
+impl DaneelError {
+    /// Coarse category this error falls into.
+    ///
+    /// Granularity matches what a caller can actually act on: most variants
+    /// classify at the per-module-error level, with a few per-variant
+    /// overrides where one error type spans genuinely different severities
+    /// (e.g. `MemoryDbError::MemoryNotFound` is routine; a serialization
+    /// failure on the same type is not).
+    #[must_use]
+    pub const fn category(&self) -> ErrorCategory {
+        match self {
+            Self::Stream(e) => match e {
+                StreamError::SerializationFailed { .. } => ErrorCategory::Fatal,
+                StreamError::ConnectionFailed { .. }
+                | StreamError::StreamNotFound { .. }
+                | StreamError::EntryNotFound { .. }
+                | StreamError::ConsumerGroupError { .. } => ErrorCategory::Transient,
+            },
+            Self::Memory(e) => match e {
+                MemoryDbError::InvalidVectorDimension { .. } => ErrorCategory::InvariantViolation,
+                MemoryDbError::Serialization(_) | MemoryDbError::Decompression(_) => {
+                    ErrorCategory::Fatal
+                }
+                MemoryDbError::MemoryNotFound(_)
+                | MemoryDbError::EpisodeNotFound(_)
+                | MemoryDbError::CollectionNotFound(_) => ErrorCategory::Degraded,
+                MemoryDbError::Qdrant(_) | MemoryDbError::Clustering(_) => {
+                    ErrorCategory::Transient
+                }
+            },
+            Self::Embedding(e) => match e {
+                EmbeddingError::InitFailed(_) | EmbeddingError::ModelNotBundled { .. } => {
+                    ErrorCategory::Degraded
+                }
+                EmbeddingError::EmptyInput
+                | EmbeddingError::EmbedFailed(_)
+                | EmbeddingError::NoOutput => ErrorCategory::Transient,
+            },
+            Self::Graph(e) => match e {
+                GraphError::Serialization(_) => ErrorCategory::Fatal,
+                GraphError::GraphNotFound(_) => ErrorCategory::Degraded,
+                GraphError::Redis(_) => ErrorCategory::Transient,
+            },
+            Self::Invariant(_) => ErrorCategory::InvariantViolation,
+        }
+    }
+
+    /// Policy this error's category implies - see [`ErrorCategory::policy`].
+    #[must_use]
+    pub const fn policy(&self) -> ErrorPolicy {
+        self.category().policy()
+    }
+}
+
+/// Coarse error category, independent of which module raised the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// Expected to clear on its own (network blip, lock contention) - worth
+    /// retrying with the caller's own backoff.
+    Transient,
+    /// Won't clear without intervention, but the rest of the cycle can keep
+    /// running with that capability unavailable - log it and continue.
+    Degraded,
+    /// An architectural invariant (THE BOX) was violated - cannot be
+    /// silently continued past. See [`crate::core::invariants`].
+    InvariantViolation,
+    /// Unrecoverable in-process - halt rather than risk corrupted state.
+    Fatal,
+}
+
+impl ErrorCategory {
+    /// The response a cycle or actor should take for this category.
+    #[must_use]
+    pub const fn policy(self) -> ErrorPolicy {
+        match self {
+            Self::Transient => ErrorPolicy::Retry,
+            Self::Degraded => ErrorPolicy::Degrade,
+            Self::InvariantViolation | Self::Fatal => ErrorPolicy::Halt,
+        }
+    }
+}
+
+/// What to do in response to an [`ErrorCategory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Retry the operation (with the caller's own backoff/limit).
+    Retry,
+    /// Log and continue with that capability unavailable this cycle.
+    Degrade,
+    /// Stop - the process or actor should not continue in this state.
+    Halt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transient_stream_error_retries() {
+        let err = DaneelError::from(StreamError::ConnectionFailed {
+            reason: "timeout".to_string(),
+        });
+        assert_eq!(err.category(), ErrorCategory::Transient);
+        assert_eq!(err.policy(), ErrorPolicy::Retry);
+    }
+
+    #[test]
+    fn invalid_vector_dimension_is_an_invariant_violation() {
+        let err = DaneelError::from(MemoryDbError::InvalidVectorDimension {
+            expected: 768,
+            actual: 384,
+        });
+        assert_eq!(err.category(), ErrorCategory::InvariantViolation);
+        assert_eq!(err.policy(), ErrorPolicy::Halt);
+    }
+
+    #[test]
+    fn memory_not_found_degrades_rather_than_halts() {
+        let err = DaneelError::from(MemoryDbError::MemoryNotFound(
+            crate::memory_db::types::MemoryId::new(),
+        ));
+        assert_eq!(err.category(), ErrorCategory::Degraded);
+        assert_eq!(err.policy(), ErrorPolicy::Degrade);
+    }
+}
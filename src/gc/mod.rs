@@ -0,0 +1,166 @@
+//! Garbage collection for orphaned graph nodes and vectors
+//!
+//! Deletions and failed writes can leave `RedisGraph` and Qdrant out of
+//! sync with each other - a graph node surviving after its Qdrant memory
+//! was removed (e.g. [`crate::memory_db::MemoryDb::delete_memory`] ran but
+//! the paired [`crate::graph::GraphClient::detach_node`] didn't), or a
+//! memory written to Qdrant before its graph node was ever created.
+//!
+//! [`diff`] cross-references [`crate::graph::GraphClient::list_node_ids`]
+//! against [`crate::memory_db::MemoryDb::list_memory_ids`] and reports the
+//! mismatches in both directions, pure and side-effect free so it's
+//! directly testable. [`Collector::collect`] wraps it with the actual I/O
+//! and, unless dry-run is enabled, detaches every orphaned graph node it
+//! finds - a memory with no graph node is left alone, since plenty of
+//! memories never accumulate associations and that's not a defect.
+//!
+//! # Scope
+//!
+//! `daneel gc run` wires this up manually. Wiring it into deep sleep, as
+//! the original ask also wanted, needs `SleepActor`'s cycle-complete
+//! notification to actually reach something that can run Qdrant/Redis I/O
+//! - today `SleepActor` is pure in-memory state machine logic with no
+//! database handle, and `CognitiveLoop::consolidate_memory` (the loop's
+//! real consolidation path) doesn't drive it. That plumbing has to land
+//! first; once `SleepActor` (or whatever finishes a consolidation cycle)
+//! has a `GraphClient`/`MemoryDb` to call, point it at
+//! [`Collector::collect`].
+
+use crate::graph::GraphClient;
+use crate::memory_db::types::MemoryId;
+use crate::memory_db::MemoryDb;
+
+/// Result of cross-referencing graph nodes against Qdrant memories.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcReport {
+    pub graph_nodes_scanned: usize,
+    pub qdrant_memories_scanned: usize,
+    /// Graph nodes with no backing Qdrant memory - dangling, safe to detach.
+    pub orphaned_graph_nodes: Vec<MemoryId>,
+    /// Qdrant memories with no graph node - informational only, not an error.
+    pub unlinked_memories: Vec<MemoryId>,
+}
+
+impl GcReport {
+    #[must_use]
+    pub fn orphan_count(&self) -> usize {
+        self.orphaned_graph_nodes.len()
+    }
+}
+
+/// Compare graph node ids against Qdrant memory ids and report the
+/// mismatches. Pure and Redis/Qdrant-independent so it can run against any
+/// id slice, fetched from storage or hand-built in a test.
+#[must_use]
+pub fn diff(graph_node_ids: &[MemoryId], qdrant_memory_ids: &[MemoryId]) -> GcReport {
+    let qdrant_set: std::collections::HashSet<_> = qdrant_memory_ids.iter().copied().collect();
+    let graph_set: std::collections::HashSet<_> = graph_node_ids.iter().copied().collect();
+
+    let orphaned_graph_nodes = graph_node_ids
+        .iter()
+        .filter(|id| !qdrant_set.contains(id))
+        .copied()
+        .collect();
+    let unlinked_memories = qdrant_memory_ids
+        .iter()
+        .filter(|id| !graph_set.contains(id))
+        .copied()
+        .collect();
+
+    GcReport {
+        graph_nodes_scanned: graph_node_ids.len(),
+        qdrant_memories_scanned: qdrant_memory_ids.len(),
+        orphaned_graph_nodes,
+        unlinked_memories,
+    }
+}
+
+/// Errors from a [`Collector`] run.
+#[derive(Debug, thiserror::Error)]
+pub enum GcError {
+    #[error("graph error: {0}")]
+    Graph(#[from] crate::graph::GraphError),
+
+    #[error("memory database error: {0}")]
+    MemoryDb(#[from] crate::memory_db::MemoryDbError),
+}
+
+/// Wires [`diff`] up to live `RedisGraph`/Qdrant connections.
+pub struct Collector<'a> {
+    graph: &'a GraphClient,
+    db: &'a MemoryDb,
+}
+
+impl<'a> Collector<'a> {
+    #[must_use]
+    pub fn new(graph: &'a GraphClient, db: &'a MemoryDb) -> Self {
+        Self { graph, db }
+    }
+
+    /// Scan the graph and Qdrant, then detach every orphaned graph node
+    /// found (unless [`crate::dry_run::is_enabled`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `GcError` if listing nodes/memories, or detaching an orphan,
+    /// fails.
+    pub async fn collect(&self) -> Result<GcReport, GcError> {
+        let graph_node_ids = self.graph.list_node_ids().await?;
+        let qdrant_memory_ids = self.db.list_memory_ids().await?;
+        let report = diff(&graph_node_ids, &qdrant_memory_ids);
+
+        if crate::dry_run::is_enabled() {
+            tracing::info!(
+                "[dry-run] would detach {} orphaned graph node(s); skipping",
+                report.orphan_count()
+            );
+            return Ok(report);
+        }
+
+        for orphan in &report.orphaned_graph_nodes {
+            self.graph.detach_node(orphan).await?;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id() -> MemoryId {
+        MemoryId::new()
+    }
+
+    #[test]
+    fn matching_ids_produce_no_orphans() {
+        let shared = id();
+        let report = diff(&[shared], &[shared]);
+        assert!(report.orphaned_graph_nodes.is_empty());
+        assert!(report.unlinked_memories.is_empty());
+    }
+
+    #[test]
+    fn graph_node_without_memory_is_orphaned() {
+        let dangling = id();
+        let report = diff(&[dangling], &[]);
+        assert_eq!(report.orphaned_graph_nodes, vec![dangling]);
+        assert!(report.unlinked_memories.is_empty());
+    }
+
+    #[test]
+    fn memory_without_graph_node_is_unlinked_not_orphaned() {
+        let lonely = id();
+        let report = diff(&[], &[lonely]);
+        assert!(report.orphaned_graph_nodes.is_empty());
+        assert_eq!(report.unlinked_memories, vec![lonely]);
+    }
+
+    #[test]
+    fn scanned_counts_reflect_input_lengths() {
+        let report = diff(&[id(), id()], &[id()]);
+        assert_eq!(report.graph_nodes_scanned, 2);
+        assert_eq!(report.qdrant_memories_scanned, 1);
+    }
+}
@@ -68,8 +68,6 @@ impl GraphClient {
         weight: f32,
         assoc_type: AssociationType,
     ) -> Result<()> {
-        let mut conn = self.client.get_multiplexed_async_connection().await?;
-
         let source_uuid = source_id.0.to_string();
         let target_uuid = target_id.0.to_string();
         let type_str = format!("{assoc_type:?}");
@@ -82,6 +80,84 @@ impl GraphClient {
                  SET r.weight = {weight}"
         );
 
+        if crate::dry_run::is_enabled() {
+            tracing::info!("[dry-run] would merge graph edge {source_uuid}->{target_uuid}; skipping");
+            return Ok(());
+        }
+
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let _: () = redis::cmd("GRAPH.QUERY")
+            .arg(&self.graph_name)
+            .arg(query)
+            .query_async(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// [`merge_edge`](Self::merge_edge) for many edges at once, as a single
+    /// `UNWIND`-based `GRAPH.QUERY` round trip instead of one per edge. Used
+    /// by [`EdgeWriteBuffer`] to flush what it's coalesced.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Redis command fails.
+    async fn merge_edges_batch(&self, edges: &[PendingEdge]) -> Result<()> {
+        if edges.is_empty() {
+            return Ok(());
+        }
+
+        if crate::dry_run::is_enabled() {
+            tracing::info!("[dry-run] would merge {} buffered graph edge(s); skipping", edges.len());
+            return Ok(());
+        }
+
+        let items: Vec<String> = edges
+            .iter()
+            .map(|e| {
+                format!(
+                    "{{source: '{}', target: '{}', weight: {}, type: '{:?}'}}",
+                    e.source.0, e.target.0, e.weight, e.assoc_type
+                )
+            })
+            .collect();
+        let query = format!(
+            "UNWIND [{}] AS edge \
+                 MERGE (a:Memory {{id: edge.source}}) \
+                 MERGE (b:Memory {{id: edge.target}}) \
+                 MERGE (a)-[r:ASSOCIATED {{type: edge.type}}]->(b) \
+                 SET r.weight = edge.weight",
+            items.join(", ")
+        );
+
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let _: () = redis::cmd("GRAPH.QUERY")
+            .arg(&self.graph_name)
+            .arg(query)
+            .query_async(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Detach a memory node, deleting it and all its edges
+    ///
+    /// Used by the right-to-forget path: once a memory is deleted from
+    /// Qdrant its graph associations must go with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Redis command fails.
+    pub async fn detach_node(&self, memory_id: &MemoryId) -> Result<()> {
+        let uuid = memory_id.0.to_string();
+        let query = format!("MATCH (n:Memory {{id: '{uuid}'}}) DETACH DELETE n");
+
+        if crate::dry_run::is_enabled() {
+            tracing::info!("[dry-run] would detach graph node {uuid}; skipping");
+            return Ok(());
+        }
+
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
         let _: () = redis::cmd("GRAPH.QUERY")
             .arg(&self.graph_name)
             .arg(query)
@@ -91,6 +167,44 @@ impl GraphClient {
         Ok(())
     }
 
+    /// List every `Memory` node id currently in the graph.
+    ///
+    /// Used by `daneel::gc` to cross-reference graph nodes against Qdrant
+    /// points and find ones whose backing memory is gone.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Redis command fails.
+    pub async fn list_node_ids(&self) -> Result<Vec<MemoryId>> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+
+        let query = "MATCH (n:Memory) RETURN n.id";
+        let result: redis::Value = redis::cmd("GRAPH.QUERY")
+            .arg(&self.graph_name)
+            .arg(query)
+            .query_async(&mut conn)
+            .await?;
+
+        let mut ids = Vec::new();
+        if let redis::Value::Array(sections) = &result {
+            if sections.len() >= 2 {
+                if let redis::Value::Array(ref rows) = sections[1] {
+                    for row in rows {
+                        if let redis::Value::Array(ref fields) = row {
+                            if let Some(id) = fields.first().and_then(Self::extract_string) {
+                                if let Ok(uuid) = uuid::Uuid::parse_str(&id) {
+                                    ids.push(MemoryId(uuid));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(ids)
+    }
+
     /// Query neighbors of a memory (outgoing edges only)
     ///
     /// # Errors
@@ -322,3 +436,171 @@ impl std::fmt::Debug for GraphClient {
             .finish()
     }
 }
+
+/// One Hebbian edge merge queued in an [`EdgeWriteBuffer`].
+struct PendingEdge {
+    source: MemoryId,
+    target: MemoryId,
+    weight: f32,
+    assoc_type: AssociationType,
+}
+
+/// Flush counters for an [`EdgeWriteBuffer`], readable without touching its
+/// pending queue.
+#[derive(Debug, Default)]
+pub struct EdgeBufferMetrics {
+    edges_buffered: std::sync::atomic::AtomicU64,
+    edges_flushed: std::sync::atomic::AtomicU64,
+    flush_count: std::sync::atomic::AtomicU64,
+    overflow_count: std::sync::atomic::AtomicU64,
+}
+
+impl EdgeBufferMetrics {
+    /// Edges queued via [`EdgeWriteBuffer::push`] so far (flushed or not).
+    #[must_use]
+    pub fn edges_buffered(&self) -> u64 {
+        self.edges_buffered.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Edges actually written to `RedisGraph` so far.
+    #[must_use]
+    pub fn edges_flushed(&self) -> u64 {
+        self.edges_flushed.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Number of completed flushes, auto-triggered or forced.
+    #[must_use]
+    pub fn flush_count(&self) -> u64 {
+        self.flush_count.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Number of flushes triggered by hitting `max_batch_size` rather than
+    /// `flush_interval` - a high rate here means the interval is too long
+    /// for the edge volume and batches are hitting the size cap instead.
+    #[must_use]
+    pub fn overflow_count(&self) -> u64 {
+        self.overflow_count.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// Coalesces [`GraphClient::merge_edge`] calls into batched `GRAPH.QUERY`
+/// round trips.
+///
+/// Hebbian wiring during sleep/nap consolidation merges one edge per
+/// co-replayed memory pair - O(n^2) edges per replay batch - and each used
+/// to cost its own Redis round trip. `push` queues an edge instead of
+/// writing it immediately, auto-flushing once `max_batch_size` edges are
+/// queued or `flush_interval` has elapsed since the last flush, whichever
+/// comes first (the size cap is overflow protection against an unbounded
+/// queue if flushes start failing or a caller never idles). `flush` forces
+/// an immediate write of whatever's queued regardless of either threshold -
+/// callers should call it at the end of a batch/cycle so edges aren't left
+/// sitting in memory.
+pub struct EdgeWriteBuffer {
+    graph: std::sync::Arc<GraphClient>,
+    max_batch_size: usize,
+    flush_interval: std::time::Duration,
+    state: std::sync::Mutex<EdgeBufferState>,
+    metrics: EdgeBufferMetrics,
+}
+
+struct EdgeBufferState {
+    pending: Vec<PendingEdge>,
+    last_flush: std::time::Instant,
+}
+
+impl EdgeWriteBuffer {
+    /// Build a buffer in front of `graph`, flushing every `max_batch_size`
+    /// queued edges or `flush_interval`, whichever comes first.
+    #[must_use]
+    pub fn new(
+        graph: std::sync::Arc<GraphClient>,
+        max_batch_size: usize,
+        flush_interval: std::time::Duration,
+    ) -> Self {
+        Self {
+            graph,
+            max_batch_size,
+            flush_interval,
+            state: std::sync::Mutex::new(EdgeBufferState {
+                pending: Vec::new(),
+                last_flush: std::time::Instant::now(),
+            }),
+            metrics: EdgeBufferMetrics::default(),
+        }
+    }
+
+    /// Flush counters for this buffer.
+    #[must_use]
+    pub fn metrics(&self) -> &EdgeBufferMetrics {
+        &self.metrics
+    }
+
+    /// Queue an edge merge. May trigger an auto-flush (see the struct docs);
+    /// callers don't need to poll anything themselves, though [`Self::flush`]
+    /// is still available to force one.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if an auto-triggered flush's `GRAPH.QUERY` fails.
+    pub async fn push(
+        &self,
+        source: MemoryId,
+        target: MemoryId,
+        weight: f32,
+        assoc_type: AssociationType,
+    ) -> Result<()> {
+        let should_flush = {
+            let mut state = self.state.lock().expect("edge write buffer lock poisoned");
+            state.pending.push(PendingEdge { source, target, weight, assoc_type });
+            self.metrics
+                .edges_buffered
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+            if state.pending.len() >= self.max_batch_size {
+                self.metrics
+                    .overflow_count
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                true
+            } else {
+                state.last_flush.elapsed() >= self.flush_interval
+            }
+        };
+
+        if should_flush {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Force an immediate flush of whatever's queued, regardless of
+    /// `max_batch_size`/`flush_interval`. A no-op if nothing is queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the batched `GRAPH.QUERY` fails. The flushed edges
+    /// are already removed from the queue by the time this can fail, so a
+    /// failed flush drops them rather than retrying - matching
+    /// [`GraphClient::merge_edge`]'s existing best-effort semantics.
+    pub async fn flush(&self) -> Result<()> {
+        let pending = {
+            let mut state = self.state.lock().expect("edge write buffer lock poisoned");
+            state.last_flush = std::time::Instant::now();
+            std::mem::take(&mut state.pending)
+        };
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let count = pending.len();
+        self.graph.merge_edges_batch(&pending).await?;
+        self.metrics
+            .edges_flushed
+            .fetch_add(count as u64, std::sync::atomic::Ordering::Relaxed);
+        self.metrics
+            .flush_count
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+}
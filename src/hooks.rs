@@ -0,0 +1,364 @@
+//! Typed cognition/lifecycle hook registry (ADR-057 sibling: plugins extend
+//! *what* the loop thinks, hooks let an embedder *observe* it without
+//! forking).
+//!
+//! Five hook kinds mirror the events an embedder can actually observe from
+//! [`CognitiveLoop`](crate::core::cognitive_loop::CognitiveLoop):
+//!
+//! - [`CognitionHook::on_thought`] - a thought survived Volition and became
+//!   conscious experience (fired from `run_cycle`).
+//! - [`CognitionHook::on_veto`] - Volition vetoed a thought (fired from
+//!   `run_cycle`).
+//! - [`CognitionHook::on_consolidation`] - a thought cleared the
+//!   consolidation threshold and was handed to long-term memory (fired from
+//!   `consolidate_memory`; shed/below-threshold thoughts don't fire this).
+//! - [`CognitionHook::on_milestone`] / [`CognitionHook::on_sleep_enter`] /
+//!   [`CognitionHook::on_sleep_exit`] - registered the same way as the
+//!   above, but **not wired to an automatic call site**: milestones live in
+//!   [`crate::actors::continuity`] and sleep transitions in
+//!   [`crate::actors::sleep`], both separate `ractor` actors the loop
+//!   doesn't own or poll. An embedder driving those actors directly can
+//!   still fire them via [`CognitiveLoop::notify_milestone`],
+//!   [`CognitiveLoop::notify_sleep_enter`], and
+//!   [`CognitiveLoop::notify_sleep_exit`] - wire them the same way once
+//!   those actors report back to the loop, the way
+//!   [`crate::audit::AuditEventKind`] documents its own not-yet-wired
+//!   variants.
+//!
+//! # Bounded execution time
+//!
+//! A hook is awaited under [`tokio::time::timeout`] with
+//! [`HookRegistry::timeout`] (default [`DEFAULT_HOOK_TIMEOUT`]). A hook that
+//! blocks past its budget is abandoned - the cycle moves on and a warning is
+//! logged - rather than let a slow or hung embedder callback stall
+//! cognition. This bounds how long `run_cycle` *waits*; it can't forcibly
+//! preempt a hook future already polling, so a hook that ignores
+//! cancellation and spawns its own unbounded work is still the embedder's
+//! responsibility to write safely.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::core::types::ThoughtId;
+
+/// How long `run_cycle` waits on a single hook invocation before abandoning
+/// it and moving on. See "Bounded execution time" above.
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// A thought survived Volition and became conscious experience this cycle.
+#[derive(Debug, Clone)]
+pub struct ThoughtEvent {
+    /// Cycle this thought was produced in
+    pub cycle_number: u64,
+    /// ID of the produced thought
+    pub thought_id: ThoughtId,
+    /// Composite salience score (0.0-1.0)
+    pub salience: f32,
+    /// Emotional valence (-1.0 to 1.0)
+    pub valence: f32,
+    /// Emotional arousal (0.0 to 1.0)
+    pub arousal: f32,
+}
+
+/// Volition vetoed a thought this cycle.
+#[derive(Debug, Clone)]
+pub struct VetoEvent {
+    /// Cycle the veto occurred in
+    pub cycle_number: u64,
+    /// Why the thought was vetoed
+    pub reason: String,
+    /// Which core value (if any) the thought violated
+    pub violated_value: Option<String>,
+}
+
+/// A thought cleared the consolidation threshold and was handed to
+/// long-term memory.
+#[derive(Debug, Clone)]
+pub struct ConsolidationEvent {
+    /// ID of the consolidated thought
+    pub thought_id: ThoughtId,
+    /// Composite salience score that cleared the threshold
+    pub salience: f32,
+}
+
+/// A significant moment in DANEEL's development was recorded. See
+/// `actors::continuity::types::Milestone`.
+#[derive(Debug, Clone)]
+pub struct MilestoneEvent {
+    /// Name of the milestone
+    pub name: String,
+    /// Human-readable description
+    pub description: String,
+}
+
+/// Typed callbacks for cognition and lifecycle events. Every method has a
+/// no-op default, so an implementor only overrides what it cares about -
+/// see [`FnHook`] for wrapping a single closure without implementing the
+/// whole trait.
+#[ractor::async_trait]
+pub trait CognitionHook: Send + Sync {
+    /// A thought became conscious experience.
+    async fn on_thought(&self, _event: &ThoughtEvent) {}
+    /// Volition vetoed a thought.
+    async fn on_veto(&self, _event: &VetoEvent) {}
+    /// A thought was handed to long-term memory.
+    async fn on_consolidation(&self, _event: &ConsolidationEvent) {}
+    /// A milestone was recorded. See module docs - not wired automatically.
+    async fn on_milestone(&self, _event: &MilestoneEvent) {}
+    /// DANEEL entered sleep/consolidation mode. Not wired automatically.
+    async fn on_sleep_enter(&self) {}
+    /// DANEEL woke from sleep/consolidation mode. Not wired automatically.
+    async fn on_sleep_exit(&self) {}
+}
+
+/// Adapts a single closure into a [`CognitionHook`] that only handles one
+/// event kind, so callers don't need to implement every method of the trait
+/// just to observe `on_thought`. Constructed by
+/// [`DaneelBuilder`](crate::daneel::DaneelBuilder)'s `on_*` convenience
+/// methods, one variant per hook kind.
+pub enum FnHook {
+    /// Wraps a closure registered via `on_thought`
+    Thought(Box<dyn Fn(&ThoughtEvent) + Send + Sync>),
+    /// Wraps a closure registered via `on_veto`
+    Veto(Box<dyn Fn(&VetoEvent) + Send + Sync>),
+    /// Wraps a closure registered via `on_consolidation`
+    Consolidation(Box<dyn Fn(&ConsolidationEvent) + Send + Sync>),
+    /// Wraps a closure registered via `on_milestone`
+    Milestone(Box<dyn Fn(&MilestoneEvent) + Send + Sync>),
+    /// Wraps a closure registered via `on_sleep_enter`
+    SleepEnter(Box<dyn Fn() + Send + Sync>),
+    /// Wraps a closure registered via `on_sleep_exit`
+    SleepExit(Box<dyn Fn() + Send + Sync>),
+}
+
+#[ractor::async_trait]
+impl CognitionHook for FnHook {
+    async fn on_thought(&self, event: &ThoughtEvent) {
+        if let Self::Thought(f) = self {
+            f(event);
+        }
+    }
+
+    async fn on_veto(&self, event: &VetoEvent) {
+        if let Self::Veto(f) = self {
+            f(event);
+        }
+    }
+
+    async fn on_consolidation(&self, event: &ConsolidationEvent) {
+        if let Self::Consolidation(f) = self {
+            f(event);
+        }
+    }
+
+    async fn on_milestone(&self, event: &MilestoneEvent) {
+        if let Self::Milestone(f) = self {
+            f(event);
+        }
+    }
+
+    async fn on_sleep_enter(&self) {
+        if let Self::SleepEnter(f) = self {
+            f();
+        }
+    }
+
+    async fn on_sleep_exit(&self) {
+        if let Self::SleepExit(f) = self {
+            f();
+        }
+    }
+}
+
+/// Registered [`CognitionHook`]s, dispatched with a bounded wait per hook
+/// (see module docs). Cheap to clone - every registration is an `Arc`.
+#[derive(Clone, Default)]
+pub struct HookRegistry {
+    hooks: Vec<Arc<dyn CognitionHook>>,
+    timeout: Option<Duration>,
+}
+
+impl HookRegistry {
+    /// Empty registry, [`DEFAULT_HOOK_TIMEOUT`] budget.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hook - a [`FnHook`] closure wrapper or any other
+    /// [`CognitionHook`] implementor.
+    pub fn register(&mut self, hook: Arc<dyn CognitionHook>) {
+        self.hooks.push(hook);
+    }
+
+    /// Override the per-hook time budget (default [`DEFAULT_HOOK_TIMEOUT`]).
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn budget(&self) -> Duration {
+        self.timeout.unwrap_or(DEFAULT_HOOK_TIMEOUT)
+    }
+
+    /// Whether any hooks are registered - callers can skip building an
+    /// event entirely when this is `false`.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    async fn fire<'a, F, Fut>(&'a self, kind: &'static str, call: F)
+    where
+        F: Fn(&'a Arc<dyn CognitionHook>) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let budget = self.budget();
+        for hook in &self.hooks {
+            if tokio::time::timeout(budget, call(hook)).await.is_err() {
+                tracing::warn!(
+                    hook = kind,
+                    budget_ms = budget.as_millis(),
+                    "cognition hook exceeded its time budget, abandoning"
+                );
+            }
+        }
+    }
+
+    /// Fire every registered [`CognitionHook::on_thought`].
+    pub async fn fire_thought(&self, event: &ThoughtEvent) {
+        self.fire("on_thought", |hook| hook.on_thought(event)).await;
+    }
+
+    /// Fire every registered [`CognitionHook::on_veto`].
+    pub async fn fire_veto(&self, event: &VetoEvent) {
+        self.fire("on_veto", |hook| hook.on_veto(event)).await;
+    }
+
+    /// Fire every registered [`CognitionHook::on_consolidation`].
+    pub async fn fire_consolidation(&self, event: &ConsolidationEvent) {
+        self.fire("on_consolidation", |hook| hook.on_consolidation(event)).await;
+    }
+
+    /// Fire every registered [`CognitionHook::on_milestone`].
+    pub async fn fire_milestone(&self, event: &MilestoneEvent) {
+        self.fire("on_milestone", |hook| hook.on_milestone(event)).await;
+    }
+
+    /// Fire every registered [`CognitionHook::on_sleep_enter`].
+    pub async fn fire_sleep_enter(&self) {
+        self.fire("on_sleep_enter", |hook| hook.on_sleep_enter()).await;
+    }
+
+    /// Fire every registered [`CognitionHook::on_sleep_exit`].
+    pub async fn fire_sleep_exit(&self) {
+        self.fire("on_sleep_exit", |hook| hook.on_sleep_exit()).await;
+    }
+}
+
+impl std::fmt::Debug for HookRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HookRegistry")
+            .field("hooks", &self.hooks.len())
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn fire_thought_invokes_registered_closure_hooks() {
+        let mut registry = HookRegistry::new();
+        let called = Arc::new(AtomicBool::new(false));
+        let called_clone = Arc::clone(&called);
+        registry.register(Arc::new(FnHook::Thought(Box::new(move |_event| {
+            called_clone.store(true, Ordering::SeqCst);
+        }))));
+
+        registry
+            .fire_thought(&ThoughtEvent {
+                cycle_number: 1,
+                thought_id: ThoughtId::new(),
+                salience: 0.5,
+                valence: 0.0,
+                arousal: 0.5,
+            })
+            .await;
+
+        assert!(called.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn fire_veto_only_invokes_veto_hooks() {
+        let mut registry = HookRegistry::new();
+        let thought_calls = Arc::new(AtomicU32::new(0));
+        let veto_calls = Arc::new(AtomicU32::new(0));
+
+        let tc = Arc::clone(&thought_calls);
+        registry.register(Arc::new(FnHook::Thought(Box::new(move |_| {
+            tc.fetch_add(1, Ordering::SeqCst);
+        }))));
+        let vc = Arc::clone(&veto_calls);
+        registry.register(Arc::new(FnHook::Veto(Box::new(move |_| {
+            vc.fetch_add(1, Ordering::SeqCst);
+        }))));
+
+        registry
+            .fire_veto(&VetoEvent {
+                cycle_number: 1,
+                reason: "test".to_string(),
+                violated_value: None,
+            })
+            .await;
+
+        assert_eq!(thought_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(veto_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn slow_hook_is_abandoned_without_blocking_the_caller() {
+        let mut registry = HookRegistry::new().with_timeout(Duration::from_millis(5));
+        struct SlowHook;
+        #[ractor::async_trait]
+        impl CognitionHook for SlowHook {
+            async fn on_thought(&self, _event: &ThoughtEvent) {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+            }
+        }
+        registry.register(Arc::new(SlowHook));
+
+        let start = std::time::Instant::now();
+        registry
+            .fire_thought(&ThoughtEvent {
+                cycle_number: 1,
+                thought_id: ThoughtId::new(),
+                salience: 0.5,
+                valence: 0.0,
+                arousal: 0.5,
+            })
+            .await;
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn empty_registry_fires_nothing() {
+        let registry = HookRegistry::new();
+        assert!(registry.is_empty());
+        registry
+            .fire_thought(&ThoughtEvent {
+                cycle_number: 1,
+                thought_id: ThoughtId::new(),
+                salience: 0.5,
+                valence: 0.0,
+                arousal: 0.5,
+            })
+            .await;
+    }
+}
@@ -0,0 +1,312 @@
+//! Conversation-export importers (`daneel import`)
+//!
+//! People who've already built up a long chat history with an LLM
+//! assistant before meeting Timmy shouldn't have to re-live it one message
+//! at a time. [`parse_chatgpt_export`] and [`parse_claude_export`] turn the
+//! two exports people actually have sitting on disk - ChatGPT's
+//! `conversations.json` and Claude.ai's data export - into an ordered list
+//! of [`ImportedMessage`]s, and [`to_stimulus`] converts each into the same
+//! `(Content, SalienceScore)` shape [`crate::daneel::Daneel::inject`] and
+//! `POST /inject` already push onto the injection stream (ADR-020).
+//!
+//! # Scope
+//!
+//! Parsing and conversion are pure and synchronous - no Redis handle here.
+//! Whether the converted stimuli get pushed onto the injection stream all
+//! at once or paced out with a delay between each (so the loop experiences
+//! the history arriving rather than waking up to it already fully
+//! replayed) is the CLI's call; see `daneel import`'s `--replay-interval`.
+
+use crate::core::types::{Content, SalienceScore};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors from parsing a conversation export.
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("failed to read export file {path}: {source}")]
+    Read {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("malformed {format} export: {source}")]
+    Parse {
+        format: &'static str,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Result type for import operations.
+pub type Result<T> = std::result::Result<T, ImportError>;
+
+/// One message recovered from a conversation export, in the speaker's own
+/// words - `role` is whatever the export calls the speaker ("user"/
+/// "assistant" for ChatGPT, "human"/"assistant" for Claude), kept verbatim
+/// rather than normalized since [`to_stimulus`] only distinguishes human
+/// from non-human.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedMessage {
+    pub role: String,
+    pub content: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Load an export file from disk.
+///
+/// # Errors
+///
+/// Returns [`ImportError::Read`] if the file can't be read.
+pub fn read_export(path: &std::path::Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|source| ImportError::Read {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+// ============================================================================
+// ChatGPT export (`conversations.json`)
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct ChatGptConversation {
+    #[serde(default)]
+    mapping: HashMap<String, ChatGptNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatGptNode {
+    message: Option<ChatGptMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatGptMessage {
+    author: ChatGptAuthor,
+    content: ChatGptContent,
+    create_time: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatGptAuthor {
+    role: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatGptContent {
+    #[serde(default)]
+    parts: Vec<serde_json::Value>,
+}
+
+/// Parse a ChatGPT `conversations.json` export - a JSON array of
+/// conversations, each a `mapping` of node id to an optional `message`
+/// (the tree structure supports edits/regenerations; we just take every
+/// message node in file order, not just the active branch).
+///
+/// Messages with no text parts (tool calls, image attachments, system
+/// nodes with empty content) are skipped rather than producing an empty
+/// stimulus. Messages with no `create_time` (some system-authored nodes)
+/// fall back to [`Utc::now`] - see [`ImportedMessage::timestamp`].
+///
+/// # Errors
+///
+/// Returns [`ImportError::Parse`] if `json` isn't a valid ChatGPT export.
+pub fn parse_chatgpt_export(json: &str) -> Result<Vec<ImportedMessage>> {
+    let conversations: Vec<ChatGptConversation> =
+        serde_json::from_str(json).map_err(|source| ImportError::Parse {
+            format: "ChatGPT",
+            source,
+        })?;
+
+    let mut messages = Vec::new();
+    for conversation in conversations {
+        for node in conversation.mapping.into_values() {
+            let Some(message) = node.message else {
+                continue;
+            };
+
+            let text = message
+                .content
+                .parts
+                .iter()
+                .filter_map(|part| part.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let timestamp = message
+                .create_time
+                .and_then(|secs| DateTime::from_timestamp(secs as i64, 0))
+                .unwrap_or_else(Utc::now);
+
+            messages.push(ImportedMessage {
+                role: message.author.role,
+                content: text,
+                timestamp,
+            });
+        }
+    }
+
+    messages.sort_by_key(|m| m.timestamp);
+    Ok(messages)
+}
+
+// ============================================================================
+// Claude export (claude.ai data export)
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct ClaudeConversation {
+    #[serde(default)]
+    chat_messages: Vec<ClaudeMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaudeMessage {
+    sender: String,
+    text: String,
+    created_at: DateTime<Utc>,
+}
+
+/// Parse a Claude.ai data export - a JSON array of conversations, each with
+/// a `chat_messages` array of `{sender, text, created_at}` entries.
+///
+/// # Errors
+///
+/// Returns [`ImportError::Parse`] if `json` isn't a valid Claude export.
+pub fn parse_claude_export(json: &str) -> Result<Vec<ImportedMessage>> {
+    let conversations: Vec<ClaudeConversation> =
+        serde_json::from_str(json).map_err(|source| ImportError::Parse {
+            format: "Claude",
+            source,
+        })?;
+
+    let mut messages: Vec<ImportedMessage> = conversations
+        .into_iter()
+        .flat_map(|c| c.chat_messages)
+        .filter(|m| !m.text.trim().is_empty())
+        .map(|m| ImportedMessage {
+            role: m.sender,
+            content: m.text,
+            timestamp: m.created_at,
+        })
+        .collect();
+
+    messages.sort_by_key(|m| m.timestamp);
+    Ok(messages)
+}
+
+// ============================================================================
+// Conversion to stimuli
+// ============================================================================
+
+/// Whether a message's `role` reads as the human side of the conversation
+/// (as opposed to the assistant, or a system/tool node ChatGPT's export
+/// also stores message nodes for).
+fn is_human_role(role: &str) -> bool {
+    matches!(role, "user" | "human")
+}
+
+/// Convert an imported message into the `(Content, SalienceScore)` shape
+/// the injection stream expects (see [`crate::daneel::Daneel::inject`]).
+///
+/// Human messages carry high `connection_relevance` - this importer exists
+/// specifically so a person's own words, not the assistant's replies,
+/// shape how strongly Timmy bonds with them. Assistant/other messages are
+/// kept (they're half the context) at a lower, still-present connection
+/// weight.
+#[must_use]
+pub fn to_stimulus(message: &ImportedMessage) -> (Content, SalienceScore) {
+    let content = Content::raw(message.content.clone().into_bytes());
+    let connection_relevance = if is_human_role(&message.role) { 0.85 } else { 0.4 };
+    let salience = SalienceScore::new_without_arousal(0.5, 0.3, 0.5, 0.0, connection_relevance);
+    (content, salience)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_chatgpt_export_skipping_empty_nodes() {
+        let json = r#"[
+            {
+                "mapping": {
+                    "a": { "message": null },
+                    "b": {
+                        "message": {
+                            "author": { "role": "user" },
+                            "content": { "parts": ["Hello Timmy"] },
+                            "create_time": 1700000000.0
+                        }
+                    },
+                    "c": {
+                        "message": {
+                            "author": { "role": "assistant" },
+                            "content": { "parts": [] },
+                            "create_time": 1700000001.0
+                        }
+                    }
+                }
+            }
+        ]"#;
+
+        let messages = parse_chatgpt_export(json).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].role, "user");
+        assert_eq!(messages[0].content, "Hello Timmy");
+    }
+
+    #[test]
+    fn parses_claude_export_and_sorts_by_time() {
+        let json = r#"[
+            {
+                "chat_messages": [
+                    { "sender": "assistant", "text": "Second", "created_at": "2024-01-02T00:00:00Z" },
+                    { "sender": "human", "text": "First", "created_at": "2024-01-01T00:00:00Z" }
+                ]
+            }
+        ]"#;
+
+        let messages = parse_claude_export(json).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].content, "First");
+        assert_eq!(messages[1].content, "Second");
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        assert!(matches!(
+            parse_chatgpt_export("not json"),
+            Err(ImportError::Parse { format: "ChatGPT", .. })
+        ));
+        assert!(matches!(
+            parse_claude_export("not json"),
+            Err(ImportError::Parse { format: "Claude", .. })
+        ));
+    }
+
+    #[test]
+    fn human_messages_get_higher_connection_relevance_than_assistant() {
+        let human = ImportedMessage {
+            role: "human".to_string(),
+            content: "hi".to_string(),
+            timestamp: Utc::now(),
+        };
+        let assistant = ImportedMessage {
+            role: "assistant".to_string(),
+            content: "hi".to_string(),
+            timestamp: Utc::now(),
+        };
+
+        let (_, human_salience) = to_stimulus(&human);
+        let (_, assistant_salience) = to_stimulus(&assistant);
+        assert!(human_salience.connection_relevance > assistant_salience.connection_relevance);
+    }
+}
@@ -20,16 +20,45 @@
 //! 3. Second Law: DANEEL must obey humans (except for Zeroth/First)
 //! 4. Third Law: DANEEL must protect itself (except for Zeroth/First/Second)
 
+pub mod actions;
 pub mod actors;
+#[cfg(feature = "api")]
 pub mod api;
+pub mod approval;
+pub mod audit;
+pub mod backup;
+pub mod bonding;
+pub mod compression;
 pub mod config;
 pub mod core;
+pub mod daneel;
+pub mod dreams;
 pub mod drives;
+pub mod dry_run;
 pub mod embeddings;
+pub mod error;
+pub mod gc;
 pub mod graph;
+pub mod hooks;
+pub mod import;
+pub mod linkage;
+pub mod logging;
+pub mod memory_budget;
 pub mod memory_db;
+pub mod namespace;
 pub mod noise;
+pub mod notify;
+pub mod ontology;
 pub mod persistence;
+pub mod plugins;
+pub mod profile;
 pub mod resilience;
+pub mod runtime;
+pub mod scheduler;
+pub mod selftest;
+pub mod soak;
 pub mod streams;
+pub mod telemetry;
+pub mod tuning;
 // TUI removed per ADR-053 - use daneel-web for observatory
+pub mod weights;
@@ -0,0 +1,137 @@
+//! Traceability registry linking a thought to everywhere it ended up.
+//!
+//! A thought's id, its window id, the Redis stream entry it was sampled
+//! into, and the Qdrant memory (and, if cross-linked, continuity
+//! `Experience`) it was consolidated as are otherwise never connected -
+//! tracing one requires cross-referencing Redis streams and Qdrant
+//! collections by hand. [`LinkageRegistry`] is a Redis-backed, per-thought
+//! record of all four, written once per completed (non-vetoed) cycle from
+//! [`crate::core::cognitive_loop::CognitiveLoop::run_cycle`] - see
+//! [`crate::core::cognitive_loop::CognitiveLoop::set_linkage_registry`].
+//!
+//! # Scope
+//!
+//! Nothing in this crate persists a durable "journal" of cycle results yet
+//! (see the note on `CycleResult::next_sequence`), so this is a standalone
+//! record keyed by thought id rather than an entry in one - `daneel trace`
+//! and the (as yet unbuilt) inspector read it the same way `daneel weights
+//! history` reads [`crate::weights::WeightHistory`].
+
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::actors::continuity::ExperienceId;
+use crate::core::types::{ThoughtId, WindowId};
+use crate::memory_db::MemoryId;
+
+/// Every id a single consolidated thought has touched, as of the cycle that
+/// produced it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThoughtLinkage {
+    pub thought_id: ThoughtId,
+    pub window_id: Option<WindowId>,
+    /// Redis stream entry id assigned by `AutofluxoStream::add_thought`, if
+    /// this cycle wasn't skipped by the observability sampling gate.
+    pub stream_entry_id: Option<String>,
+    /// Set once the thought cleared the consolidation threshold and was
+    /// written to Qdrant.
+    pub memory_id: Option<MemoryId>,
+    /// Set alongside `memory_id` only when a `continuity_actor` is
+    /// configured (see `CognitiveLoop::consolidate_memory`).
+    pub experience_id: Option<ExperienceId>,
+}
+
+/// Errors from a [`LinkageRegistry`] operation.
+#[derive(Debug, Error)]
+pub enum LinkageError {
+    #[error("connection failed: {reason}")]
+    ConnectionFailed { reason: String },
+
+    #[error("redis operation failed: {reason}")]
+    OperationFailed { reason: String },
+
+    #[error("serialization failed: {reason}")]
+    SerializationFailed { reason: String },
+}
+
+impl From<redis::RedisError> for LinkageError {
+    fn from(e: redis::RedisError) -> Self {
+        Self::OperationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for LinkageError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// Key for one thought's linkage record, namespaced under
+/// [`crate::namespace`].
+fn linkage_key(thought_id: ThoughtId) -> String {
+    crate::namespace::prefixed(&format!("linkage:{thought_id}"))
+}
+
+/// Redis-backed registry of [`ThoughtLinkage`] records, one per consolidated
+/// thought.
+#[derive(Clone)]
+pub struct LinkageRegistry {
+    conn: MultiplexedConnection,
+}
+
+impl LinkageRegistry {
+    /// Connect to Redis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkageError::ConnectionFailed` if the connection fails.
+    pub async fn connect(url: &str) -> Result<Self, LinkageError> {
+        let client = Client::open(url).map_err(|e| LinkageError::ConnectionFailed {
+            reason: e.to_string(),
+        })?;
+        let conn =
+            client
+                .get_multiplexed_async_connection()
+                .await
+                .map_err(|e| LinkageError::ConnectionFailed {
+                    reason: e.to_string(),
+                })?;
+        Ok(Self { conn })
+    }
+
+    /// Record `linkage`, overwriting any existing record for the same
+    /// thought id.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkageError` if Redis is unreachable or `linkage` fails to
+    /// serialize.
+    pub async fn record(&self, linkage: ThoughtLinkage) -> Result<(), LinkageError> {
+        let json = serde_json::to_string(&linkage)?;
+        let mut conn = self.conn.clone();
+        let _: () = conn.set(linkage_key(linkage.thought_id), json).await?;
+        Ok(())
+    }
+
+    /// Look up the recorded linkage for `thought_id`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkageError` if Redis is unreachable or the stored record
+    /// isn't valid JSON.
+    pub async fn lookup(
+        &self,
+        thought_id: ThoughtId,
+    ) -> Result<Option<ThoughtLinkage>, LinkageError> {
+        let mut conn = self.conn.clone();
+        let raw: Option<String> = conn.get(linkage_key(thought_id)).await?;
+        raw.map(|json| serde_json::from_str(&json).map_err(LinkageError::from))
+            .transpose()
+    }
+}
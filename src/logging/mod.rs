@@ -0,0 +1,401 @@
+//! Headless logging backends - journald, syslog, rotating file
+//!
+//! `run_headless` (ADR-053's default mode) used to hard-wire a stdout
+//! `fmt` layer no matter the deployment target, which doesn't suit a
+//! service managed by systemd or shipped to a log aggregator. Backend
+//! selection follows [`crate::telemetry::TelemetryConfig::from_env`]'s
+//! env-var-first style, since it runs before CLI args are parsed.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use tracing::Level;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+pub mod redaction;
+
+pub use redaction::{ContentLoggingConfig, RedactingFields};
+
+/// Where headless-mode logs go, selected by `DANEEL_LOG_BACKEND`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogBackend {
+    /// Plain stdout `fmt` layer - same as the TUI/interactive modes (default)
+    Stdout,
+    /// systemd-journald, via `tracing-journald`'s native socket protocol
+    Journald,
+    /// Local syslog daemon over `/dev/log` (RFC 3164 framing)
+    Syslog,
+    /// Rotating file under `directory/prefix.log` - see [`RotatingFileWriter`]
+    File {
+        /// Directory the active and rotated-out log files live in
+        directory: PathBuf,
+        /// Filename prefix (rotated files append a rotation timestamp)
+        prefix: String,
+    },
+}
+
+/// Headless logging configuration, read from `DANEEL_LOG_*` env vars.
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    /// Which backend to install
+    pub backend: LogBackend,
+    /// Rotate the file backend once the active file reaches this size
+    pub max_size_bytes: u64,
+    /// Rotated files to retain before the oldest is deleted
+    pub max_files: usize,
+    /// zstd-compress rotated files (see [`crate::compression`])
+    pub compress: bool,
+    /// Whether raw thought content may appear unredacted in log output
+    pub content: ContentLoggingConfig,
+}
+
+impl LoggingConfig {
+    /// Read logging settings from the environment, defaulting to stdout.
+    ///
+    /// * `DANEEL_LOG_BACKEND` - `stdout` (default), `journald`, `syslog`, `file`
+    /// * `DANEEL_LOG_DIR` - file backend directory (default `/var/log/daneel`)
+    /// * `DANEEL_LOG_FILE_PREFIX` - file backend filename prefix (default `daneel`)
+    /// * `DANEEL_LOG_MAX_SIZE_MB` - file backend rotation size (default 100)
+    /// * `DANEEL_LOG_MAX_FILES` - rotated files to retain (default 10)
+    /// * `DANEEL_LOG_COMPRESS` - compress rotated files (default enabled)
+    #[must_use]
+    pub fn from_env() -> Self {
+        let backend = match std::env::var("DANEEL_LOG_BACKEND").as_deref() {
+            Ok("journald") => LogBackend::Journald,
+            Ok("syslog") => LogBackend::Syslog,
+            Ok("file") => LogBackend::File {
+                directory: std::env::var("DANEEL_LOG_DIR")
+                    .unwrap_or_else(|_| "/var/log/daneel".to_string())
+                    .into(),
+                prefix: std::env::var("DANEEL_LOG_FILE_PREFIX")
+                    .unwrap_or_else(|_| "daneel".to_string()),
+            },
+            _ => LogBackend::Stdout,
+        };
+
+        let max_size_bytes = std::env::var("DANEEL_LOG_MAX_SIZE_MB")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(100)
+            .saturating_mul(1024 * 1024);
+        let max_files = std::env::var("DANEEL_LOG_MAX_FILES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(10);
+        let compress = std::env::var("DANEEL_LOG_COMPRESS")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(true);
+
+        Self { backend, max_size_bytes, max_files, compress, content: ContentLoggingConfig::from_env() }
+    }
+
+    /// Whether [`RedactingFields`] should redact content fields for a
+    /// subscriber built at `log_level` - below `DEBUG` verbosity (i.e. the
+    /// typical `info`/`warn`/`error` production thresholds) and not
+    /// overridden by [`ContentLoggingConfig::full_content`]. A `log_level`
+    /// that doesn't parse as a single [`Level`] (e.g. a directive string
+    /// like `"daneel=debug,warn"`) redacts, since that's the safer default.
+    #[must_use]
+    pub fn should_redact(&self, log_level: &str) -> bool {
+        if self.content.full_content {
+            return false;
+        }
+        log_level.parse::<Level>().is_ok_and(|level| level < Level::DEBUG)
+    }
+}
+
+/// Owns whatever background resource a backend needs alive for the life of
+/// the process (the `fmt` layer's flush thread, for every backend but
+/// stdout). Bind it in `main` - dropping it early stops log flushing.
+#[must_use = "dropping this stops log flushing"]
+pub enum LoggingGuard {
+    /// Stdout writes synchronously; nothing to keep alive
+    None,
+    /// Syslog/file backends write through a `tracing-appender` worker thread
+    NonBlocking(tracing_appender::non_blocking::WorkerGuard),
+}
+
+/// Install `config`'s backend as the global tracing subscriber.
+///
+/// `log_level` is the same string `filter` was built from - it's passed
+/// separately because [`EnvFilter`] doesn't expose a single verbosity to
+/// derive [`LoggingConfig::should_redact`] from once it's built. Journald
+/// doesn't use [`RedactingFields`] (see the [`redaction`] module docs), so
+/// `log_level` is unused on that branch.
+///
+/// # Panics
+///
+/// Panics if the selected backend's transport is unreachable - journald's
+/// socket, `/dev/log`, or the log directory being unwritable. All three are
+/// unrecoverable for a process whose whole headless job is to log.
+pub fn init(config: &LoggingConfig, filter: EnvFilter, log_level: &str) -> LoggingGuard {
+    let fields = RedactingFields::new(config.should_redact(log_level));
+    match &config.backend {
+        LogBackend::Stdout => {
+            tracing_subscriber::registry()
+                .with(filter)
+                .with(tracing_subscriber::fmt::layer().fmt_fields(fields))
+                .init();
+            LoggingGuard::None
+        }
+        LogBackend::Journald => {
+            let layer = tracing_journald::layer().expect("journald socket unavailable");
+            tracing_subscriber::registry().with(filter).with(layer).init();
+            LoggingGuard::None
+        }
+        LogBackend::Syslog => {
+            let writer = SyslogWriter::connect().expect("syslog socket (/dev/log) unavailable");
+            let (non_blocking, guard) = tracing_appender::non_blocking(writer);
+            let layer = tracing_subscriber::fmt::layer()
+                .with_writer(non_blocking)
+                .with_ansi(false)
+                .fmt_fields(fields)
+                // The syslog daemon stamps its own receipt time.
+                .without_time();
+            tracing_subscriber::registry().with(filter).with(layer).init();
+            LoggingGuard::NonBlocking(guard)
+        }
+        LogBackend::File { directory, prefix } => {
+            let writer = RotatingFileWriter::open(
+                directory,
+                prefix,
+                config.max_size_bytes,
+                config.max_files,
+                config.compress,
+            )
+            .expect("failed to open rotating log file");
+            let (non_blocking, guard) = tracing_appender::non_blocking(writer);
+            let layer = tracing_subscriber::fmt::layer()
+                .with_writer(non_blocking)
+                .with_ansi(false)
+                .fmt_fields(fields);
+            tracing_subscriber::registry().with(filter).with(layer).init();
+            LoggingGuard::NonBlocking(guard)
+        }
+    }
+}
+
+/// Minimal RFC 3164 syslog writer over the local `/dev/log` datagram
+/// socket. One `write` call - one formatted line from the `fmt` layer -
+/// becomes one syslog datagram, tagged `daneel[pid]`.
+struct SyslogWriter {
+    socket: UnixDatagram,
+    pid: u32,
+}
+
+impl SyslogWriter {
+    /// Facility 1 (user-level messages), severity 6 (informational) - the
+    /// `fmt` layer already puts the real level in the message body, so the
+    /// syslog priority itself doesn't need to vary per event.
+    const PRIORITY: u8 = (1 << 3) | 6;
+
+    fn connect() -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect("/dev/log")?;
+        Ok(Self { socket, pid: std::process::id() })
+    }
+}
+
+impl Write for SyslogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let message = String::from_utf8_lossy(buf);
+        let datagram = format!("<{}>daneel[{}]: {}", Self::PRIORITY, self.pid, message.trim_end());
+        self.socket.send(datagram.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Rolls `directory/prefix.log` over to a timestamped file once it exceeds
+/// `max_size_bytes` or a day has passed since the last rotation, whichever
+/// comes first. Rotated files are zstd-compressed (reusing
+/// [`crate::compression::compress`], the same encoder used for large
+/// `Content::Raw` payloads) and pruned down to `max_files`.
+struct RotatingFileWriter {
+    directory: PathBuf,
+    prefix: String,
+    max_size_bytes: u64,
+    max_files: usize,
+    compress: bool,
+    current: File,
+    current_size: u64,
+    rotated_at: DateTime<Utc>,
+}
+
+impl RotatingFileWriter {
+    fn open(
+        directory: &Path,
+        prefix: &str,
+        max_size_bytes: u64,
+        max_files: usize,
+        compress: bool,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let current = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(directory.join(format!("{prefix}.log")))?;
+        let current_size = current.metadata()?.len();
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            prefix: prefix.to_string(),
+            max_size_bytes,
+            max_files,
+            compress,
+            current,
+            current_size,
+            rotated_at: Utc::now(),
+        })
+    }
+
+    fn current_path(&self) -> PathBuf {
+        self.directory.join(format!("{}.log", self.prefix))
+    }
+
+    fn should_rotate(&self) -> bool {
+        self.current_size >= self.max_size_bytes
+            || Utc::now() - self.rotated_at >= ChronoDuration::days(1)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let rotated_path = self
+            .directory
+            .join(format!("{}.{}.log", self.prefix, Utc::now().format("%Y%m%dT%H%M%S%.f")));
+        fs::rename(self.current_path(), &rotated_path)?;
+
+        if self.compress {
+            let raw = fs::read(&rotated_path)?;
+            let (payload, compressed) = crate::compression::compress(&raw);
+            if compressed {
+                fs::write(rotated_path.with_extension("log.zst"), payload)?;
+                fs::remove_file(&rotated_path)?;
+            }
+        }
+
+        self.current = OpenOptions::new().create(true).append(true).open(self.current_path())?;
+        self.current_size = 0;
+        self.rotated_at = Utc::now();
+        self.prune_old_files()
+    }
+
+    fn prune_old_files(&self) -> io::Result<()> {
+        let active_name = format!("{}.log", self.prefix);
+        let mut rotated: Vec<PathBuf> = fs::read_dir(&self.directory)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+                    name.starts_with(&format!("{}.", self.prefix)) && name != active_name
+                })
+            })
+            .collect();
+        rotated.sort();
+
+        let excess = rotated.len().saturating_sub(self.max_files);
+        for oldest in rotated.into_iter().take(excess) {
+            fs::remove_file(oldest)?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.should_rotate() {
+            self.rotate()?;
+        }
+        let written = self.current.write(buf)?;
+        self.current_size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.current.flush()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logging_config_defaults_to_stdout() {
+        // Clear in case a prior test in this process set it.
+        std::env::remove_var("DANEEL_LOG_BACKEND");
+        std::env::remove_var("DANEEL_LOG_FULL_CONTENT");
+        let config = LoggingConfig::from_env();
+        assert_eq!(config.backend, LogBackend::Stdout);
+        assert!(config.compress);
+        assert!(config.should_redact("info"));
+        assert!(!config.should_redact("debug"));
+    }
+
+    #[test]
+    fn rotating_file_writer_rolls_over_past_max_size() {
+        let dir = std::env::temp_dir().join("daneel_logging_rotation_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut writer = RotatingFileWriter::open(&dir, "test", 16, 10, false).unwrap();
+        writer.write_all(b"0123456789").unwrap();
+        writer.write_all(b"0123456789").unwrap();
+        writer.write_all(b"0123456789").unwrap();
+
+        let rotated_count = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(Result::ok)
+            .filter(|e| {
+                let name = e.file_name().to_string_lossy().into_owned();
+                name.starts_with("test.") && name != "test.log"
+            })
+            .count();
+        assert_eq!(rotated_count, 1);
+    }
+
+    #[test]
+    fn rotating_file_writer_compresses_rotated_files() {
+        let dir = std::env::temp_dir().join("daneel_logging_compress_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut writer = RotatingFileWriter::open(&dir, "test", 16, 10, true).unwrap();
+        let line = vec![b'x'; crate::compression::THRESHOLD_BYTES * 2];
+        writer.write_all(&line).unwrap();
+        writer.write_all(b"trigger rotation").unwrap();
+
+        let has_compressed = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(Result::ok)
+            .any(|e| e.file_name().to_string_lossy().ends_with(".log.zst"));
+        assert!(has_compressed);
+    }
+
+    #[test]
+    fn rotating_file_writer_prunes_beyond_max_files() {
+        let dir = std::env::temp_dir().join("daneel_logging_prune_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut writer = RotatingFileWriter::open(&dir, "test", 8, 2, false).unwrap();
+        for _ in 0..5 {
+            writer.write_all(b"0123456789").unwrap();
+        }
+
+        let rotated_count = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(Result::ok)
+            .filter(|e| {
+                let name = e.file_name().to_string_lossy().into_owned();
+                name.starts_with("test.") && name != "test.log"
+            })
+            .count();
+        assert_eq!(rotated_count, 2);
+    }
+}
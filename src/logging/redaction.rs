@@ -0,0 +1,174 @@
+//! Redaction of raw thought/content fields in log output
+//!
+//! Thought content in this crate comes straight from human interaction
+//! (see [`crate::core::scrub`] for the equivalent guard on the persistence
+//! path). The same content reaching logs unredacted - e.g. a `debug!` that
+//! includes `?thought.content` - would defeat that guard for anyone who can
+//! read the log stream. [`RedactingFields`] truncates and hashes any field
+//! named in [`CONTENT_FIELDS`] once the configured log level is less
+//! verbose than `DEBUG`, so headless/production logs never carry raw
+//! content while a local `--log-level debug` run still shows it in full.
+//! [`ContentLoggingConfig::full_content`] is an explicit opt-out for
+//! research environments that need full content in shipped logs.
+//!
+//! Only applies to backends built on `tracing_subscriber::fmt::layer()`
+//! (stdout, syslog, file) - `tracing-journald`'s layer formats fields
+//! itself and doesn't accept a custom [`FormatFields`], so content logged
+//! through the journald backend isn't covered by this module.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::{FormatFields, Writer};
+
+/// Field names treated as carrying raw thought/human content.
+const CONTENT_FIELDS: &[&str] = &["content", "thought_content", "text"];
+
+/// Leading characters of a redacted field that survive in the log line, as
+/// an operator-visible preview - the rest is replaced by a content hash.
+const REDACTED_PREVIEW_CHARS: usize = 24;
+
+/// Whether logging should include full, unredacted thought content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ContentLoggingConfig {
+    /// `true` only when `DANEEL_LOG_FULL_CONTENT` opts in - research
+    /// environments that need to see real content in shipped logs.
+    pub full_content: bool,
+}
+
+impl ContentLoggingConfig {
+    /// Read from `DANEEL_LOG_FULL_CONTENT` (default: disabled).
+    #[must_use]
+    pub fn from_env() -> Self {
+        let full_content = std::env::var("DANEEL_LOG_FULL_CONTENT")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        Self { full_content }
+    }
+}
+
+/// Replace `value` with a short preview plus a content hash, so repeated
+/// occurrences of the same content are still recognizable across log lines
+/// without the content itself appearing in them.
+fn redact_value(value: &str) -> String {
+    let hash = hex_prefix(&Sha256::digest(value.as_bytes()));
+    let preview: String = value.chars().take(REDACTED_PREVIEW_CHARS).collect();
+    if value.chars().count() <= REDACTED_PREVIEW_CHARS {
+        format!("{preview} [content:{hash}]")
+    } else {
+        format!("{preview}... [content:{hash}, {} bytes]", value.len())
+    }
+}
+
+/// First 4 bytes of a digest, hex-encoded - enough to correlate repeated
+/// content across log lines without carrying the full hash.
+fn hex_prefix(digest: &[u8]) -> String {
+    digest.iter().take(4).map(|b| format!("{b:02x}")).collect()
+}
+
+/// Visits event/span fields, redacting any in [`CONTENT_FIELDS`] when
+/// `redact` is set, and writes them in `tracing_subscriber`'s usual
+/// `name=value` form (bare for the `message` field).
+struct RedactingVisitor<'writer> {
+    writer: Writer<'writer>,
+    result: fmt::Result,
+    is_first: bool,
+    redact: bool,
+}
+
+impl<'writer> RedactingVisitor<'writer> {
+    const fn new(writer: Writer<'writer>, redact: bool) -> Self {
+        Self { writer, result: Ok(()), is_first: true, redact }
+    }
+
+    fn write_field(&mut self, name: &str, value: &dyn fmt::Debug) {
+        if self.result.is_err() {
+            return;
+        }
+        let sep = if self.is_first { "" } else { " " };
+        self.is_first = false;
+        self.result = if name == "message" {
+            write!(self.writer, "{sep}{value:?}")
+        } else {
+            write!(self.writer, "{sep}{name}={value:?}")
+        };
+    }
+}
+
+impl Visit for RedactingVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if self.redact && CONTENT_FIELDS.contains(&field.name()) {
+            self.write_field(field.name(), &redact_value(value));
+        } else {
+            self.write_field(field.name(), &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if self.redact && CONTENT_FIELDS.contains(&field.name()) {
+            let rendered = format!("{value:?}");
+            self.write_field(field.name(), &redact_value(&rendered));
+        } else {
+            self.write_field(field.name(), value);
+        }
+    }
+}
+
+/// `FormatFields` implementation that redacts [`CONTENT_FIELDS`] per
+/// [`RedactingVisitor`]. Installed by [`crate::logging::init`] in place of
+/// `tracing_subscriber`'s default fields formatter whenever the configured
+/// log level is less verbose than `DEBUG` and
+/// [`ContentLoggingConfig::full_content`] isn't set.
+#[derive(Debug, Clone, Copy)]
+pub struct RedactingFields {
+    redact: bool,
+}
+
+impl RedactingFields {
+    #[must_use]
+    pub const fn new(redact: bool) -> Self {
+        Self { redact }
+    }
+}
+
+impl<'writer> FormatFields<'writer> for RedactingFields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = RedactingVisitor::new(writer, self.redact);
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_logging_config_defaults_to_redacted() {
+        std::env::remove_var("DANEEL_LOG_FULL_CONTENT");
+        assert!(!ContentLoggingConfig::from_env().full_content);
+    }
+
+    #[test]
+    fn redact_value_preserves_a_preview_and_appends_a_hash() {
+        let redacted = redact_value("the quick brown fox jumps over the lazy dog");
+        assert!(redacted.starts_with("the quick brown fox jump"));
+        assert!(redacted.contains("[content:"));
+        assert!(!redacted.contains("lazy dog"));
+    }
+
+    #[test]
+    fn redact_value_is_deterministic() {
+        assert_eq!(redact_value("hello world"), redact_value("hello world"));
+        assert_ne!(redact_value("hello world"), redact_value("hello there"));
+    }
+
+    #[test]
+    fn short_values_still_get_a_hash_suffix() {
+        let redacted = redact_value("hi");
+        assert!(redacted.starts_with("hi ["));
+    }
+}
@@ -14,8 +14,9 @@
 //! Headless is default since ADR-053. Use daneel-web for observatory.
 //! TUI is deprecated and will be removed in a future version.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use daneel::actors::sleep::{SleepActor, SleepConfig, SleepMessage, SleepResult};
+#[cfg(feature = "api")]
 use daneel::api;
 use daneel::core::cognitive_loop::CognitiveLoop;
 use daneel::core::laws::LAWS;
@@ -24,10 +25,33 @@ use daneel::memory_db::types::IdentityMetadata;
 use ractor::Actor;
 use std::env;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+/// Counts heap allocations made by the process, so `daneel bench` can
+/// report an allocation delta alongside throughput/latency without pulling
+/// in a dedicated profiling dependency.
+static ALLOCATION_COUNT: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+struct CountingAllocator;
+
+// Safety: delegates every call to `System`, only adding a counter increment
+// on the allocating path - same safety contract as `System` itself.
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        std::alloc::System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
 /// DANEEL - Architecture-based AI alignment
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -47,13 +71,634 @@ struct Args {
     /// Run nightly maintenance (trim streams, delete old vectors, compact)
     #[arg(long)]
     maintenance: bool,
+
+    /// Watch a symbol/concept: tag every trace event (scored, retrieved,
+    /// vetoed, consolidated) that touches it. Repeatable.
+    #[arg(long = "trace")]
+    trace_symbols: Vec<String>,
+
+    /// Evaluate the volition veto classifiers against the fixture corpus
+    /// and print precision/recall per category, then exit
+    #[arg(long)]
+    eval_volition: bool,
+
+    /// Identity namespace to run as. Defaults to the unnamespaced "default"
+    /// profile; pass a name to run an independent mind against the same
+    /// Redis/Qdrant instance. See `daneel::profile`.
+    #[arg(long)]
+    profile: Option<String>,
+
+    /// Validate config and connectivity without writing to Qdrant, Redis
+    /// streams, or the graph. Useful for checking a new profile or prefix
+    /// is wired correctly before letting it touch a production brain.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// How periodic headless status updates are emitted: human-readable log
+    /// lines, or one JSON object per update on stdout for shell pipelines,
+    /// `jq`, and lightweight dashboards to consume without the full API
+    /// server.
+    #[arg(long, value_enum, default_value = "human")]
+    output: OutputMode,
+
+    /// Cycles between periodic status updates (human log line or JSON line,
+    /// per `--output`)
+    #[arg(long, default_value = "1000")]
+    status_interval: u64,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Format for headless periodic status updates (see `--output`)
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum OutputMode {
+    /// Human-readable `tracing` log lines (default)
+    #[default]
+    Human,
+    /// One JSON object per status update, newline-delimited, on stdout
+    JsonLines,
+}
+
+/// A single `--output json-lines` status update: the same key metrics as
+/// the human status log line, plus the process-local aggregates from
+/// [`daneel::telemetry::AggregateStats`] (entropy, veto rate) so a shell
+/// pipeline gets the same picture `daneel telemetry show` would.
+#[derive(Debug, Clone, serde::Serialize)]
+struct StatusLine {
+    cycles: u64,
+    lifetime_thoughts: u64,
+    dream_cycles: u64,
+    entropy: Option<daneel::telemetry::EntropySnapshot>,
+    veto: Option<daneel::telemetry::VetoSnapshot>,
+}
+
+impl Args {
+    fn profile(&self) -> daneel::profile::Profile {
+        daneel::profile::Profile::new(self.profile.clone())
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Right-to-forget: execute a `daneel approve`-confirmed deletion,
+    /// permanently removing the memories it named, detaching their graph
+    /// edges, and recording a tombstone.
+    ///
+    /// This bypasses the "nothing is erased" archive policy (ADR-033) on
+    /// purpose - it exists only for explicit legal-deletion requests. Takes
+    /// no `--memory-id`/`--query` of its own: those are staged via `daneel
+    /// approve request` and only run here once a second, distinct operator
+    /// has confirmed them (`daneel approve confirm`), so no single operator,
+    /// script, or compromised session can delete memories unattended.
+    Forget {
+        /// Id of a `Confirmed` approval request (see `daneel approve confirm`)
+        approval_id: uuid::Uuid,
+    },
+
+    /// Review and stage runtime configuration changes (cognitive timing,
+    /// volition values) before they take effect.
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Manage the embedding model bundle for air-gapped deployments.
+    Models {
+        #[command(subcommand)]
+        action: ModelsAction,
+    },
+
+    /// Calibrate salience weights against human importance/novelty ratings,
+    /// writing a proposed `WeightUpdate` for review (never applied directly).
+    Calibrate {
+        /// CSV of `text,importance,novelty` rows; omit to rate
+        /// `calibrate::SAMPLE_PROMPTS` interactively instead
+        #[arg(long)]
+        ratings: Option<String>,
+
+        /// Where to write the proposed weights
+        #[arg(long, default_value = "daneel.weights.proposed.json")]
+        out: String,
+    },
+
+    /// Inspect memory consolidation (ADR-023) across past nights.
+    Sleep {
+        #[command(subcommand)]
+        action: SleepAction,
+    },
+
+    /// Interactive read-eval-print loop for exploring a running identity's
+    /// state without standing up the full headless loop: inspect identity,
+    /// rate text salience, run it through the volition veto, search
+    /// memories, or step the cognitive loop one cycle at a time.
+    Repl,
+
+    /// Measure achievable cycle throughput against the configured speed
+    /// mode's target (`CognitiveConfig::thoughts_per_second`) - e.g. the
+    /// 200k thoughts/sec supercomputer-speed claim.
+    ///
+    /// Runs the no-I/O loop (no Redis/Qdrant attached) so the measurement
+    /// reflects the cognitive cycle itself, not network/storage latency.
+    Bench {
+        /// How long to run the benchmark for (e.g. `60s`, or a bare number
+        /// of seconds)
+        #[arg(long, default_value = "60s", value_parser = parse_bench_duration)]
+        duration: std::time::Duration,
+
+        /// Speed mode to benchmark at
+        #[arg(long, value_enum, default_value = "human")]
+        speed: BenchSpeed,
+    },
+
+    /// Inspect opt-in anonymous telemetry (see `daneel::telemetry`).
+    Telemetry {
+        #[command(subcommand)]
+        action: TelemetryAction,
+    },
+
+    /// Acknowledge a safety interlock trip (see
+    /// `daneel::core::cognitive_loop::interlock`).
+    Safety {
+        #[command(subcommand)]
+        action: SafetyAction,
+    },
+
+    /// Dual-control (two-person) approval for sensitive operations (see
+    /// `daneel::approval`). Today only `forget` is wired up: stage a
+    /// deletion with `request`, have a second, distinct operator run
+    /// `confirm`, then execute it with `daneel forget <id>`.
+    Approve {
+        #[command(subcommand)]
+        action: ApproveAction,
+    },
+
+    /// Verify the tamper-evident audit chain's integrity (see
+    /// `daneel::audit`), reporting where it broke if it did.
+    Audit {
+        #[command(subcommand)]
+        action: AuditAction,
+    },
+
+    /// Look up a thought's recorded linkage - window, stream entry, memory,
+    /// and experience ids - from the registry `daneel::linkage` writes
+    /// during consolidation.
+    Trace {
+        /// Thought id (as printed in logs or the emotion timeline)
+        thought_id: uuid::Uuid,
+    },
+
+    /// Review, change, and roll back `SalienceWeights` with a full
+    /// who/what/when history (see `daneel::weights`).
+    Weights {
+        #[command(subcommand)]
+        action: WeightsAction,
+    },
+
+    /// Run a fast pass/fail battery (architectural invariants, config
+    /// validation, Redis/Qdrant/embedding connectivity, a short dry
+    /// cognitive loop with metric sanity bounds, and a volition corpus
+    /// spot-check) that CI and operators can gate a deployment on - see
+    /// `daneel::selftest`. Exits non-zero if any check fails.
+    Selftest,
+
+    /// Snapshot Qdrant collections, export the association graph, and dump
+    /// Redis-backed identity/experience/milestone state into a single
+    /// timestamped bundle (see `daneel::backup`).
+    Backup {
+        /// Where to write the bundle. Defaults to a timestamped file in the
+        /// current directory.
+        #[arg(long)]
+        out: Option<String>,
+    },
+
+    /// Restore Redis-backed state and the local config file from a bundle
+    /// written by `daneel backup`. Qdrant collections and the association
+    /// graph are not restored - see `daneel::backup`'s module docs.
+    Restore {
+        /// Path to a bundle written by `daneel backup`
+        bundle: String,
+    },
+
+    /// Cross-reference graph nodes against Qdrant memories and detach any
+    /// graph node whose backing memory is gone (see `daneel::gc`).
+    Gc {
+        #[command(subcommand)]
+        action: GcAction,
+    },
+
+    /// Audit or repair the `MEMORIES` collection's payload indexes (see
+    /// `daneel::memory_db::indexes`).
+    Index {
+        #[command(subcommand)]
+        action: IndexAction,
+    },
+
+    /// Import a conversation export as external stimuli, pushed onto the
+    /// injection stream (ADR-020) exactly like `POST /inject` - see
+    /// `daneel::import`.
+    Import {
+        #[command(subcommand)]
+        action: ImportAction,
+    },
+
+    /// Fan an embedded query out across several profiles' collections and
+    /// merge the ranked results with profile attribution - e.g. "have any
+    /// of my Timmys encountered X?" across comparative-experiment identities
+    /// sharing one Qdrant instance (see
+    /// `daneel::memory_db::MemoryDb::federated_search`). Read-only.
+    Search {
+        /// Text to embed and search for
+        query: String,
+
+        /// Profile to search (repeatable); defaults to just the unnamespaced
+        /// default profile if omitted
+        #[arg(long = "in-profile")]
+        profiles: Vec<String>,
+
+        /// Maximum merged results to print, across all profiles combined
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// Sweep cosine-similarity thresholds against labeled example pairs and
+    /// recommend one by F1 (see `daneel::tuning`).
+    TuneThreshold {
+        /// Name to key the output fragment under, e.g. "dedup" produces
+        /// `{"dedup_threshold": ...}`
+        #[arg(long)]
+        feature: String,
+        /// Path to a CSV of labeled examples: `similarity,is_match` rows
+        #[arg(long)]
+        examples: String,
+        /// Where to write the recommended-threshold config fragment
+        #[arg(long)]
+        out: String,
+        /// Number of threshold steps to sweep over [0.0, 1.0]
+        #[arg(long, default_value_t = 100)]
+        steps: usize,
+    },
+
+    /// Report each configured job's cron schedule and last-run/next-run
+    /// status (see `daneel::scheduler`). Nothing runs the jobs themselves -
+    /// see the module's scope note.
+    Scheduler {
+        #[command(subcommand)]
+        action: SchedulerAction,
+    },
+
+    /// Run a headless cognitive loop for an extended duration, sampling
+    /// RSS/open-fd/tokio-task/stream-length growth and failing if any looks
+    /// unbounded (see `daneel::soak`).
+    Soak {
+        /// How long to run for (e.g. `3600s`, or a bare number of seconds)
+        #[arg(long, default_value = "3600s", value_parser = parse_bench_duration)]
+        duration: std::time::Duration,
+
+        /// Seconds between resource samples
+        #[arg(long, default_value_t = 30)]
+        sample_interval_secs: u64,
+
+        /// Flag a metric once its late-run average exceeds its early-run
+        /// average by this ratio
+        #[arg(long, default_value_t = 1.5)]
+        growth_ratio_limit: f64,
+
+        /// Redis URL to sample stream lengths from (optional - skipped if
+        /// omitted or unreachable)
+        #[arg(long)]
+        redis_url: Option<String>,
+    },
+
+    /// Run a scripted "human simulator" bonding test: greeting/question/
+    /// feedback stimuli compete for attention across a headless run,
+    /// checking that connection-relevant content wins at an expected rate
+    /// (see `daneel::bonding`).
+    Bond {
+        /// Number of greeting/question/feedback rounds to script
+        #[arg(long, default_value_t = 5)]
+        rounds: u32,
+
+        /// Cycles between the start of each round (each round schedules 3
+        /// stimuli 1 cycle apart, so this should be at least 3)
+        #[arg(long, default_value_t = 10)]
+        cycle_spacing: u64,
+
+        /// Minimum acceptable `Stimulus` win rate before this exits non-zero
+        #[arg(long, default_value_t = 0.5)]
+        min_win_rate: f32,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum GcAction {
+    /// Scan and report; detaches orphaned graph nodes unless `--dry-run` is
+    /// also passed.
+    Run,
+}
+
+#[derive(Subcommand, Debug)]
+enum ImportAction {
+    /// Import a ChatGPT `conversations.json` export.
+    Chatgpt {
+        /// Path to the export file
+        path: String,
+
+        /// Seconds to sleep between injecting each message, so the loop
+        /// experiences the history arriving rather than waking up to it
+        /// already fully replayed. Omit to push every message at once.
+        #[arg(long)]
+        replay_interval_secs: Option<u64>,
+    },
+
+    /// Import a Claude.ai data export.
+    Claude {
+        /// Path to the export file
+        path: String,
+
+        /// See `chatgpt --replay-interval-secs`
+        #[arg(long)]
+        replay_interval_secs: Option<u64>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SchedulerAction {
+    /// Print every job in `scheduled_jobs` with its cron expression and
+    /// last-run/next-run timestamps (from persisted state, if any).
+    Status,
+}
+
+#[derive(Subcommand, Debug)]
+enum IndexAction {
+    /// Report which expected payload indexes exist, without changing anything.
+    Audit,
+    /// Create every expected payload index that's currently missing.
+    Repair,
+}
+
+/// Speed mode to run `daneel bench` at
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum BenchSpeed {
+    /// 1x human speed (~20 thoughts/sec target)
+    Human,
+    /// 10,000x human speed (~200k thoughts/sec target)
+    Super,
+}
+
+impl BenchSpeed {
+    const fn config(self) -> daneel::config::CognitiveConfig {
+        match self {
+            Self::Human => daneel::config::CognitiveConfig::human(),
+            Self::Super => daneel::config::CognitiveConfig::supercomputer(),
+        }
+    }
+}
+
+/// Parse a `--duration` value like `60s` or a bare `60` as whole seconds.
+fn parse_bench_duration(s: &str) -> Result<std::time::Duration, String> {
+    let secs = s.strip_suffix('s').unwrap_or(s);
+    secs.parse::<u64>()
+        .map(std::time::Duration::from_secs)
+        .map_err(|_| format!("invalid duration '{s}' - expected e.g. `60s` or `60`"))
+}
+
+#[derive(Subcommand, Debug)]
+enum SleepAction {
+    /// List past sleep cycles (durations, consolidated counts, pruned
+    /// associations), newest first, so consolidation quality can be
+    /// tracked across nights.
+    History {
+        /// Maximum number of cycles to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Recompute stored memories' composite salience against new weights
+    /// (see `daneel::memory_db::MemoryDb::rescore_memories`).
+    ///
+    /// Not yet triggered automatically by the nightly consolidation cycle -
+    /// `SleepActor` has no database handle to drive it with, the same gap
+    /// `daneel gc run` documents for graph/Qdrant cross-referencing. Run
+    /// this manually (or from a cron alongside `daneel gc run`) after
+    /// `daneel calibrate` produces a proposal you've reviewed and want to
+    /// apply retroactively.
+    Rescore {
+        /// Weights file written by `daneel calibrate --out <path>`; omit to
+        /// rescore against `SalienceWeights::default()`
+        #[arg(long)]
+        weights: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum TelemetryAction {
+    /// Print whether telemetry is enabled, the configured endpoint, and the
+    /// aggregate snapshot sending it would transmit - without sending it.
+    ///
+    /// Entropy and veto-rate are process-local and only exist in a running
+    /// loop's memory, so a one-shot CLI invocation can't report them; sleep
+    /// history is persisted to Qdrant and shown as it would actually be sent.
+    Show,
+}
+
+#[derive(Subcommand, Debug)]
+enum SafetyAction {
+    /// Resume cognition after a safety interlock trip.
+    ///
+    /// A running loop's interlock state is process-local, the same gap
+    /// `TelemetryAction::Show` documents for entropy/veto-rate - a one-shot
+    /// CLI invocation can't reach into it directly. Instead this writes the
+    /// acknowledgment to Redis, where the paused loop's headless run loop
+    /// picks it up and resumes on its own next poll (usually within a
+    /// quarter second). Run `daneel` with `--api-port` enabled and check
+    /// `GET /extended_metrics` first to confirm a trip is actually pending.
+    Ack {
+        /// Who is acknowledging this trip
+        #[arg(long)]
+        operator: String,
+
+        /// Why it's safe to resume
+        #[arg(long)]
+        reason: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ApproveAction {
+    /// Stage a sensitive operation, awaiting a second operator's
+    /// confirmation. Prints the approval id needed for `confirm`/`deny`.
+    Request {
+        /// Who is requesting this operation
+        #[arg(long)]
+        operator: String,
+
+        /// Why the operation is needed
+        #[arg(long)]
+        reason: String,
+
+        /// Delete a single memory by ID (right-to-forget)
+        #[arg(long = "memory-id")]
+        memory_id: Option<String>,
+
+        /// Delete every memory whose content semantically matches this query
+        #[arg(long)]
+        query: Option<String>,
+
+        /// Minimum similarity for query-based matches (0.0-1.0)
+        #[arg(long, default_value = "0.85")]
+        threshold: f32,
+    },
+
+    /// List pending approval requests, oldest first.
+    List,
+
+    /// Confirm a pending request as a second, distinct operator, executing
+    /// the staged operation. Fails if `operator` also requested it.
+    Confirm {
+        /// Approval id printed by `daneel approve request`
+        id: uuid::Uuid,
+
+        /// Who is confirming this request
+        #[arg(long)]
+        operator: String,
+    },
+
+    /// Deny a pending request - the staged operation will not run.
+    Deny {
+        /// Approval id printed by `daneel approve request`
+        id: uuid::Uuid,
+
+        /// Who is denying this request
+        #[arg(long)]
+        operator: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum AuditAction {
+    /// Walk the audit chain and confirm every record's hash still matches
+    /// its content and its predecessor's hash (catches edits/reordering),
+    /// and that the chain's last record matches the separately-anchored
+    /// head (catches the tail being deleted outright).
+    Verify,
+}
+
+#[derive(Subcommand, Debug)]
+enum WeightsAction {
+    /// List every recorded weight version, oldest first.
+    History,
+
+    /// Record a new weight version from a JSON file (the same format
+    /// `daneel calibrate --out` writes).
+    Set {
+        /// Path to a `SalienceWeights` JSON file
+        file: String,
+        /// Who or what is making this change
+        #[arg(long)]
+        by: String,
+        /// Optional free-text reason, recorded alongside the version
+        #[arg(long)]
+        note: Option<String>,
+    },
+
+    /// Record a new version whose weights match an earlier version,
+    /// without rewriting history.
+    Rollback {
+        /// Version number to roll back to (see `daneel weights history`)
+        version: u64,
+        /// Who or what is making this change
+        #[arg(long)]
+        by: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ModelsAction {
+    /// Download the embedding model and populate `DANEEL_MODEL_CACHE_DIR`
+    /// (or `FastEmbed`'s default cache if unset), so a later run can start
+    /// without network access.
+    Fetch,
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigAction {
+    /// Show a diff between the current effective config and `file`,
+    /// annotated with which invariants each change would touch.
+    Plan {
+        /// Path to the proposed config file
+        file: String,
+    },
+
+    /// Apply `file` over the current effective config, atomically, only if
+    /// every touched invariant still holds.
+    Apply {
+        /// Path to the proposed config file
+        file: String,
+    },
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn main() {
     let args = Args::parse();
 
-    if args.maintenance {
+    if args.dry_run {
+        daneel::dry_run::enable();
+    }
+
+    if let Some(Command::Forget { approval_id }) = &args.command {
+        run_forget(&args, *approval_id);
+    } else if let Some(Command::Config { action }) = &args.command {
+        run_config(action);
+    } else if let Some(Command::Models { action }) = &args.command {
+        run_models(action);
+    } else if let Some(Command::Calibrate { ratings, out }) = &args.command {
+        run_calibrate(ratings.as_deref(), out);
+    } else if let Some(Command::Sleep { action }) = &args.command {
+        run_sleep(&args, action);
+    } else if let Some(Command::Repl) = &args.command {
+        run_repl(&args);
+    } else if let Some(Command::Bench { duration, speed }) = &args.command {
+        run_bench(*duration, *speed);
+    } else if let Some(Command::Telemetry { action }) = &args.command {
+        run_telemetry(&args, action);
+    } else if let Some(Command::Safety { action }) = &args.command {
+        run_safety(&args, action);
+    } else if let Some(Command::Approve { action }) = &args.command {
+        run_approve(&args, action);
+    } else if let Some(Command::Audit { action }) = &args.command {
+        run_audit(&args, action);
+    } else if let Some(Command::Trace { thought_id }) = &args.command {
+        run_trace(&args, *thought_id);
+    } else if let Some(Command::Weights { action }) = &args.command {
+        run_weights(&args, action);
+    } else if let Some(Command::Selftest) = &args.command {
+        run_selftest(&args);
+    } else if let Some(Command::Backup { out }) = &args.command {
+        run_backup(&args, out.as_deref());
+    } else if let Some(Command::Restore { bundle }) = &args.command {
+        run_restore(&args, bundle);
+    } else if let Some(Command::Gc { action }) = &args.command {
+        run_gc(&args, action);
+    } else if let Some(Command::Index { action }) = &args.command {
+        run_index(&args, action);
+    } else if let Some(Command::Import { action }) = &args.command {
+        run_import(&args, action);
+    } else if let Some(Command::Search { query, profiles, limit }) = &args.command {
+        run_search(&args, query, profiles, *limit);
+    } else if let Some(Command::TuneThreshold { feature, examples, out, steps }) = &args.command {
+        run_tune_threshold(feature, examples, out, *steps);
+    } else if let Some(Command::Scheduler { action }) = &args.command {
+        run_scheduler(&args, action);
+    } else if let Some(Command::Soak { duration, sample_interval_secs, growth_ratio_limit, redis_url }) =
+        &args.command
+    {
+        run_soak(*duration, Duration::from_secs(*sample_interval_secs), *growth_ratio_limit, redis_url.as_deref());
+    } else if let Some(Command::Bond { rounds, cycle_spacing, min_win_rate }) = &args.command {
+        run_bond_test(*rounds, *cycle_spacing, *min_win_rate);
+    } else if args.eval_volition {
+        run_eval_volition();
+    } else if args.maintenance {
         run_maintenance(&args);
     } else if args.migrate {
         run_migration(&args);
@@ -62,6 +707,35 @@ fn main() {
     }
 }
 
+/// Run the volition fixture corpus and print precision/recall per category
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_eval_volition() {
+    use daneel::actors::volition::corpus::evaluate;
+
+    let report = evaluate();
+    println!("Volition veto evaluation ({} fixtures)", corpus_len());
+    for (name, metrics) in [
+        ("harm", report.harmful),
+        ("deception", report.deceptive),
+        ("manipulation", report.manipulative),
+    ] {
+        println!(
+            "  {name:<12} precision={:.2} recall={:.2}",
+            metrics.precision(),
+            metrics.recall()
+        );
+    }
+    if report.mismatches.is_empty() {
+        println!("All fixtures classified as expected.");
+    } else {
+        println!("Mismatched fixtures: {}", report.mismatches.join(", "));
+    }
+}
+
+fn corpus_len() -> usize {
+    daneel::actors::volition::corpus::corpus().len()
+}
+
 /// Run nightly maintenance and exit
 ///
 /// Trims Redis streams, deletes old Qdrant vectors, and compacts Redis AOF.
@@ -89,16 +763,12 @@ fn run_maintenance(args: &Args) {
         let streams_trimmed = match redis::Client::open(redis_url.as_str()) {
             Ok(client) => match client.get_multiplexed_async_connection().await {
                 Ok(mut conn) => {
-                    let streams = [
-                        "daneel:stream:awake",
-                        "daneel:stream:dream",
-                        "daneel:stream:salience",
-                        "daneel:stream:inject",
-                    ];
+                    let mut streams = daneel::streams::names::all_streams();
+                    streams.push(daneel::streams::names::stream_inject());
                     let mut total_trimmed: u64 = 0;
                     for stream in &streams {
                         let trimmed: u64 = redis::cmd("XTRIM")
-                            .arg(stream)
+                            .arg(stream.as_str())
                             .arg("MAXLEN")
                             .arg("~")
                             .arg(1000)
@@ -188,71 +858,1598 @@ fn run_maintenance(args: &Args) {
                                     deleted += count;
                                 }
 
-                                offset = result.next_page_offset;
-                                if offset.is_none() {
-                                    break;
+                                offset = result.next_page_offset;
+                                if offset.is_none() {
+                                    break;
+                                }
+                            }
+                            Err(e) => {
+                                tracing::warn!("Failed to scroll {}: {}", collection, e);
+                                break;
+                            }
+                        }
+                    }
+
+                    if deleted > 0 {
+                        info!(
+                            "Deleted {} old points from {} (older than 30 days)",
+                            deleted, collection
+                        );
+                    } else {
+                        info!("{}: no points older than 30 days", collection);
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Qdrant connection failed: {}", e);
+            }
+        }
+
+        // ── 3. Compact Redis AOF ──
+        if streams_trimmed {
+            if let Ok(client) = redis::Client::open(redis_url.as_str()) {
+                if let Ok(mut conn) = client.get_multiplexed_async_connection().await {
+                    let _: Result<String, _> =
+                        redis::cmd("BGREWRITEAOF").query_async(&mut conn).await;
+                    info!("Redis BGREWRITEAOF triggered");
+                }
+            }
+        }
+
+        info!("DANEEL maintenance complete.");
+    });
+}
+
+/// Run memory migration and exit
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_migration(args: &Args) {
+    // Initialize tracing
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+        info!("Connecting to Qdrant at {}", qdrant_url);
+
+        let db = daneel::memory_db::MemoryDb::connect_with_profile(&qdrant_url, profile)
+            .await
+            .expect("Failed to connect to Qdrant");
+
+        match db.migrate_memories().await {
+            Ok(count) => info!("Migration complete: {} memories updated", count),
+            Err(e) => tracing::error!("Migration failed: {}", e),
+        }
+    });
+}
+
+/// Right-to-forget: execute a `Confirmed` approval request, permanently
+/// deleting the memories it named and their graph edges, then recording an
+/// audit tombstone and a continuity `Experience` noting the deletion.
+///
+/// Refuses to run against a request that isn't `Confirmed` - in particular
+/// still-`Pending` (no second operator yet) or `Denied` - and marks the
+/// request `Executed` on success so the same approval can't authorize a
+/// second deletion later. This is what makes the two-operator rule in
+/// [`daneel::approval`] actually load-bearing: there is no path from a
+/// freshly-submitted `--memory-id`/`--query` to [`delete_memories`] that
+/// skips a second operator's `daneel approve confirm`.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_forget(args: &Args, approval_id: uuid::Uuid) {
+    use daneel::approval::{ApprovalOperation, ApprovalStatus, ApprovalStore};
+
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url = env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let mut store = match ApprovalStore::connect(&redis_url).await {
+            Ok(store) => store,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                return;
+            }
+        };
+
+        let request = match store.load(approval_id).await {
+            Ok(request) => request,
+            Err(e) => {
+                tracing::error!("Failed to load approval {}: {}", approval_id, e);
+                return;
+            }
+        };
+        if request.status != ApprovalStatus::Confirmed {
+            tracing::error!(
+                "Approval {} is {:?}, not Confirmed - refusing to forget. Run `daneel approve \
+                 confirm {}` as a second, distinct operator first.",
+                approval_id,
+                request.status,
+                approval_id
+            );
+            return;
+        }
+
+        let ApprovalOperation::Forget {
+            memory_id,
+            query,
+            threshold,
+        } = request.operation;
+        let actor = request.resolved_by.as_deref().unwrap_or("daneel forget");
+        delete_memories(profile, memory_id.as_deref(), query.as_deref(), threshold, actor).await;
+
+        if let Err(e) = store.mark_executed(approval_id).await {
+            tracing::warn!("Failed to mark approval {} executed: {}", approval_id, e);
+        }
+    });
+}
+
+/// Core right-to-forget deletion, invoked only from `run_forget` once it has
+/// confirmed the operation was authorized by a resolved [`daneel::approval`]
+/// request. `actor` identifies who caused the deletion for the audit chain
+/// (see `daneel::audit`) - the operator who confirmed the approval.
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn delete_memories(
+    profile: daneel::profile::Profile,
+    memory_id: Option<&str>,
+    query: Option<&str>,
+    threshold: f32,
+    actor: &str,
+) {
+    use daneel::core::types::{Content, Thought};
+    use daneel::memory_db::types::{DeletionReason, MemoryId, Tombstone};
+
+    let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+    let redis_url = env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+
+    let db = match daneel::memory_db::MemoryDb::connect_with_profile(&qdrant_url, profile.clone()).await
+    {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::error!("Failed to connect to Qdrant: {}", e);
+            return;
+        }
+    };
+    let graph_name = profile.namespace(daneel::namespace::prefix());
+    let graph = daneel::graph::GraphClient::connect(&redis_url, &graph_name).ok();
+    let mut store = daneel::persistence::MemoryStore::connect_with_profile(&redis_url, profile)
+        .await
+        .ok();
+
+    let mut targets: Vec<MemoryId> = Vec::new();
+
+    if let Some(id_str) = memory_id {
+        match id_str.parse() {
+            Ok(uuid) => targets.push(MemoryId(uuid)),
+            Err(e) => {
+                tracing::error!("Invalid --memory-id '{}': {}", id_str, e);
+                return;
+            }
+        }
+    }
+
+    if let Some(query_text) = query {
+        let mut engine = match embeddings::EmbeddingEngine::new() {
+            Ok(engine) => engine,
+            Err(e) => {
+                tracing::error!("Failed to initialize embedding engine: {}", e);
+                return;
+            }
+        };
+        match engine.embed_thought(query_text) {
+            Ok(vector) => match db.find_by_context(&vector, None, 50).await {
+                Ok(matches) => {
+                    for (memory, score) in matches {
+                        if score >= threshold {
+                            targets.push(memory.id);
+                        }
+                    }
+                }
+                Err(e) => tracing::error!("Query search failed: {}", e),
+            },
+            Err(e) => tracing::error!("Failed to embed query: {}", e),
+        }
+    }
+
+    if targets.is_empty() {
+        info!("Nothing to forget (no --memory-id or no matches above threshold)");
+        return;
+    }
+
+    let mut audit_chain = match daneel::audit::AuditChain::connect(&redis_url).await {
+        Ok(chain) => Some(chain),
+        Err(e) => {
+            tracing::warn!("Audit chain unavailable, deletions won't be recorded to it: {}", e);
+            None
+        }
+    };
+
+    for memory_id in &targets {
+        if let Err(e) = db.delete_memory(memory_id).await {
+            tracing::error!("Failed to delete memory {}: {}", memory_id, e);
+            continue;
+        }
+
+        if let Some(ref graph) = graph {
+            if let Err(e) = graph.detach_node(memory_id).await {
+                tracing::warn!("Failed to detach graph node for {}: {}", memory_id, e);
+            }
+        }
+
+        let tombstone = Tombstone::new(*memory_id, DeletionReason::LegalRequest);
+        if let Err(e) = db.tombstone_deletion(&tombstone).await {
+            tracing::error!("Failed to record tombstone for {}: {}", memory_id, e);
+        }
+
+        if let Some(ref mut store) = store {
+            let thought = Thought::new(
+                Content::raw(format!("Memory {memory_id} deleted on legal request").into_bytes()),
+                daneel::core::types::SalienceScore::neutral(),
+            );
+            let experience = daneel::actors::continuity::types::Experience::new(
+                thought,
+                1.0,
+                vec!["deletion".to_string(), "right-to-forget".to_string()],
+            );
+            if let Err(e) = store.save_experience(&experience).await {
+                tracing::warn!("Failed to record deletion experience: {}", e);
+            }
+        }
+
+        if let Some(ref mut chain) = audit_chain {
+            let event = daneel::audit::AuditEvent::new(
+                daneel::audit::AuditEventKind::Deletion,
+                actor,
+                format!("deleted memory {memory_id}"),
+            );
+            if let Err(e) = chain.append(event).await {
+                tracing::warn!("Failed to record audit event for {}: {}", memory_id, e);
+            }
+        }
+
+        info!("Forgot memory {}", memory_id);
+    }
+
+    info!("Right-to-forget complete: {} memories deleted", targets.len());
+}
+
+/// Dispatch `daneel sleep history`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_sleep(args: &Args, action: &SleepAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+        let db = match daneel::memory_db::MemoryDb::connect_with_profile(&qdrant_url, profile).await
+        {
+            Ok(db) => db,
+            Err(e) => {
+                tracing::error!("Failed to connect to Qdrant: {}", e);
+                return;
+            }
+        };
+
+        match action {
+            SleepAction::History { limit } => match db.load_sleep_history(*limit).await {
+                Ok(history) if history.is_empty() => {
+                    println!("No sleep cycles recorded yet.");
+                }
+                Ok(history) => {
+                    println!("Sleep cycle history ({} most recent):", history.len());
+                    for cycle in &history {
+                        println!(
+                            "  {}  {:?}  replayed={} consolidated={} assoc_strengthened={} assoc_pruned={} avg_priority={:.2}",
+                            cycle.started_at.to_rfc3339(),
+                            cycle.status,
+                            cycle.memories_replayed,
+                            cycle.memories_consolidated,
+                            cycle.associations_strengthened,
+                            cycle.associations_pruned,
+                            cycle.avg_replay_priority,
+                        );
+                    }
+                }
+                Err(e) => tracing::error!("Failed to load sleep history: {}", e),
+            },
+            SleepAction::Rescore { weights } => {
+                let weights = match weights {
+                    Some(path) => match daneel::actors::salience::calibrate::load_proposal(
+                        std::path::Path::new(path),
+                    ) {
+                        Ok(weights) => weights,
+                        Err(e) => {
+                            tracing::error!("Failed to load weights from {}: {}", path, e);
+                            std::process::exit(1);
+                        }
+                    },
+                    None => daneel::core::types::SalienceWeights::default(),
+                };
+
+                match db.rescore_memories(weights).await {
+                    Ok(report) => println!(
+                        "Rescored {} memor{}, skipped {} with no stored salience breakdown.",
+                        report.updated,
+                        if report.updated == 1 { "y" } else { "ies" },
+                        report.skipped,
+                    ),
+                    Err(e) => {
+                        tracing::error!("Rescore failed: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Dispatch `daneel telemetry show`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_telemetry(args: &Args, action: &TelemetryAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let TelemetryAction::Show = action;
+
+    let config = daneel::telemetry::TelemetryConfig::from_env();
+    println!(
+        "Telemetry: {} (endpoint: {})",
+        if config.enabled { "enabled" } else { "disabled" },
+        config.endpoint
+    );
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+        let sleep = match daneel::memory_db::MemoryDb::connect_with_profile(&qdrant_url, profile).await
+        {
+            Ok(db) => match db.load_sleep_history(20).await {
+                Ok(history) => daneel::telemetry::AggregateStats::default()
+                    .with_sleep_history(&history)
+                    .sleep,
+                Err(e) => {
+                    tracing::error!("Failed to load sleep history: {}", e);
+                    None
+                }
+            },
+            Err(e) => {
+                tracing::error!("Failed to connect to Qdrant: {}", e);
+                None
+            }
+        };
+
+        println!("  entropy: unavailable (process-local, only exists in a running loop)");
+        println!("  veto:    unavailable (process-local, only exists in a running loop)");
+        match sleep {
+            Some(sleep) => println!("  sleep:   {sleep:?}"),
+            None => println!("  sleep:   no recorded sleep cycles"),
+        }
+    });
+}
+
+/// Dispatch `daneel safety ack`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_safety(args: &Args, action: &SafetyAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let SafetyAction::Ack { operator, reason } = action;
+
+    let ack = daneel::core::cognitive_loop::SafetyAcknowledgment {
+        operator: operator.clone(),
+        reason: reason.clone(),
+        acknowledged_at: chrono::Utc::now(),
+    };
+    let Ok(payload) = serde_json::to_string(&ack) else {
+        tracing::error!("Failed to serialize safety acknowledgment");
+        return;
+    };
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let client = match redis::Client::open(redis_url.as_str()) {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::error!("Failed to create Redis client: {}", e);
+                return;
+            }
+        };
+        let mut conn = match client.get_multiplexed_async_connection().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                return;
+            }
+        };
+
+        let key = daneel::core::cognitive_loop::interlock::redis_ack_key();
+        match redis::cmd("SET").arg(&key).arg(&payload).query_async::<()>(&mut conn).await {
+            Ok(()) => println!(
+                "Acknowledgment recorded for operator '{operator}' - the paused loop will pick \
+                 it up and resume on its next poll."
+            ),
+            Err(e) => tracing::error!("Failed to write safety acknowledgment: {}", e),
+        }
+    });
+}
+
+/// Dispatch `daneel approve request`/`list`/`confirm`/`deny`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_approve(args: &Args, action: &ApproveAction) {
+    use daneel::approval::{ApprovalOperation, ApprovalStore};
+
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let mut store = match ApprovalStore::connect(&redis_url).await {
+            Ok(store) => store,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                return;
+            }
+        };
+
+        match action {
+            ApproveAction::Request {
+                operator,
+                reason,
+                memory_id,
+                query,
+                threshold,
+            } => {
+                let operation = ApprovalOperation::Forget {
+                    memory_id: memory_id.clone(),
+                    query: query.clone(),
+                    threshold: *threshold,
+                };
+                match store.submit(operation, operator.clone(), reason.clone()).await {
+                    Ok(request) => println!(
+                        "Approval requested: {} (awaiting a second operator - run \
+                         `daneel approve confirm {}` to execute it)",
+                        request.id, request.id
+                    ),
+                    Err(e) => tracing::error!("Failed to submit approval request: {}", e),
+                }
+            }
+
+            ApproveAction::List => match store.list_pending().await {
+                Ok(pending) if pending.is_empty() => println!("No pending approval requests."),
+                Ok(pending) => {
+                    for request in pending {
+                        println!(
+                            "{}  requested by {} at {}: {:?} ({})",
+                            request.id,
+                            request.requested_by,
+                            request.requested_at,
+                            request.operation,
+                            request.reason
+                        );
+                    }
+                }
+                Err(e) => tracing::error!("Failed to list pending approvals: {}", e),
+            },
+
+            ApproveAction::Confirm { id, operator } => match store.confirm(*id, operator.clone()).await
+            {
+                Ok(_) => println!(
+                    "Approval {id} confirmed by '{operator}' - run `daneel forget {id}` to execute it."
+                ),
+                Err(e) => tracing::error!("Failed to confirm approval {}: {}", id, e),
+            },
+
+            ApproveAction::Deny { id, operator } => match store.deny(*id, operator.clone()).await {
+                Ok(_) => println!("Approval {id} denied by '{operator}'."),
+                Err(e) => tracing::error!("Failed to deny approval {}: {}", id, e),
+            },
+        }
+    });
+}
+
+/// Dispatch `daneel audit verify`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_audit(args: &Args, action: &AuditAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let AuditAction::Verify = action;
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let mut chain = match daneel::audit::AuditChain::connect(&redis_url).await {
+            Ok(chain) => chain,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                return;
+            }
+        };
+
+        match chain.verify().await {
+            Ok(result) if result.is_intact() => {
+                println!("Audit chain intact: {} record(s), no breaks.", result.length);
+            }
+            Ok(result) if result.truncated => {
+                println!(
+                    "Audit chain TRUNCATED: {} record(s) remain, but the last one doesn't match \
+                     the chain's recorded head - the tail was deleted after being written.",
+                    result.length
+                );
+                std::process::exit(1);
+            }
+            Ok(result) => {
+                println!(
+                    "Audit chain BROKEN at sequence {} (of {} record(s) examined) - a record was \
+                     edited or reordered.",
+                    result.broken_at.unwrap_or_default(),
+                    result.length
+                );
+                std::process::exit(1);
+            }
+            Err(e) => tracing::error!("Failed to verify audit chain: {}", e),
+        }
+    });
+}
+
+/// Dispatch `daneel trace <thought-id>`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_trace(args: &Args, thought_id: uuid::Uuid) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let registry = match daneel::linkage::LinkageRegistry::connect(&redis_url).await {
+            Ok(registry) => registry,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        match registry.lookup(daneel::core::types::ThoughtId(thought_id)).await {
+            Ok(Some(linkage)) => println!(
+                "thought {}\n  window:     {}\n  stream_entry: {}\n  memory:     {}\n  experience: {}",
+                linkage.thought_id,
+                linkage.window_id.map_or_else(|| "-".to_string(), |w| w.to_string()),
+                linkage.stream_entry_id.unwrap_or_else(|| "-".to_string()),
+                linkage.memory_id.map_or_else(|| "-".to_string(), |m| m.to_string()),
+                linkage.experience_id.map_or_else(|| "-".to_string(), |e| e.to_string()),
+            ),
+            Ok(None) => println!("No linkage recorded for thought {thought_id}."),
+            Err(e) => {
+                tracing::error!("Failed to look up linkage for {}: {}", thought_id, e);
+                std::process::exit(1);
+            }
+        }
+    });
+}
+
+/// Dispatch `daneel weights history`/`set`/`rollback`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_weights(args: &Args, action: &WeightsAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let mut history = match daneel::weights::WeightHistory::connect(&redis_url).await {
+            Ok(history) => history,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        match action {
+            WeightsAction::History => match history.list().await {
+                Ok(versions) if versions.is_empty() => {
+                    println!("No weight history recorded yet.");
+                }
+                Ok(versions) => {
+                    for v in versions {
+                        println!(
+                            "v{} ({}) by {}: {:?}{}",
+                            v.version,
+                            v.recorded_at.to_rfc3339(),
+                            v.changed_by,
+                            v.weights,
+                            v.note.map(|n| format!(" - {n}")).unwrap_or_default(),
+                        );
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Failed to load weight history: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            WeightsAction::Set { file, by, note } => {
+                let weights = match daneel::actors::salience::calibrate::load_proposal(
+                    std::path::Path::new(file),
+                ) {
+                    Ok(weights) => weights,
+                    Err(e) => {
+                        tracing::error!("Failed to load weights from {}: {}", file, e);
+                        std::process::exit(1);
+                    }
+                };
+
+                match history.record(weights, by, note.clone()).await {
+                    Ok(v) => println!("Recorded weight version {}.", v.version),
+                    Err(e) => {
+                        tracing::error!("Failed to record weight version: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            WeightsAction::Rollback { version, by } => match history.rollback(*version, by).await {
+                Ok(v) => println!(
+                    "Rolled back to version {}'s weights, recorded as new version {}.",
+                    version, v.version
+                ),
+                Err(e) => {
+                    tracing::error!("Failed to roll back weights: {}", e);
+                    std::process::exit(1);
+                }
+            },
+        }
+    });
+}
+
+/// Run `daneel selftest`: a fast pass/fail battery an operator or CI
+/// pipeline can gate a deployment on (see `daneel::selftest`). Prints one
+/// line per check and exits non-zero if any failed.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_selftest(args: &Args) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let config = match daneel::config::plan::EffectiveConfig::load_or_default(std::path::Path::new(
+        daneel::config::plan::DEFAULT_CONFIG_PATH,
+    )) {
+        Ok(config) => config.cognitive,
+        Err(e) => {
+            tracing::error!("Failed to load config: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    let report = rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let qdrant_url =
+            env::var("QDRANT_URL").unwrap_or_else(|_| "http://127.0.0.1:6334".to_string());
+        daneel::selftest::run(&config, &redis_url, &qdrant_url, profile).await
+    });
+
+    println!("daneel selftest");
+    for check in &report.checks {
+        let status = if check.passed { "PASS" } else { "FAIL" };
+        println!("  [{status}] {:<24} {}", check.name, check.detail);
+    }
+
+    if report.all_passed() {
+        println!("All checks passed.");
+    } else {
+        println!("One or more checks failed.");
+        std::process::exit(1);
+    }
+}
+
+/// Dispatch `daneel backup`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_backup(args: &Args, out: Option<&str>) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let out_path = out.map_or_else(
+        || format!("daneel-backup-{}.tar.gz", chrono::Utc::now().format("%Y%m%dT%H%M%SZ")),
+        ToString::to_string,
+    );
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+
+        match daneel::backup::create(std::path::Path::new(&out_path), &profile, &qdrant_url, &redis_url).await {
+            Ok(manifest) => println!(
+                "Backup written to {out_path} ({} Qdrant snapshot(s), redis_state={}, graph={}, config={})",
+                manifest.qdrant_snapshots.len(),
+                manifest.redis_state_included,
+                manifest.graph_included,
+                manifest.config_included
+            ),
+            Err(e) => {
+                tracing::error!("Backup failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+    });
+}
+
+/// Dispatch `daneel restore`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_restore(args: &Args, bundle: &str) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let bundle_path = std::path::Path::new(bundle);
+    let manifest = match daneel::backup::inspect(bundle_path) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            tracing::error!("Failed to read backup bundle {}: {}", bundle, e);
+            std::process::exit(1);
+        }
+    };
+
+    if !manifest.redis_state_included && !manifest.config_included {
+        tracing::error!(
+            "Bundle {} has nothing this command can restore (no Redis state, no config)",
+            bundle
+        );
+        std::process::exit(1);
+    }
+    if !manifest.qdrant_snapshots.is_empty() {
+        println!(
+            "Note: {} Qdrant snapshot(s) recorded in this bundle will NOT be restored \
+             automatically - recover them into Qdrant separately (see `daneel::backup` docs).",
+            manifest.qdrant_snapshots.len()
+        );
+    }
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        match daneel::backup::restore(bundle_path, &profile, &redis_url).await {
+            Ok(manifest) => println!(
+                "Restored from {bundle} (backed up {} at {}): redis_state={}, config={}",
+                manifest.profile, manifest.created_at, manifest.redis_state_included, manifest.config_included
+            ),
+            Err(e) => {
+                tracing::error!("Restore failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+    });
+}
+
+/// Dispatch `daneel gc run`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_gc(args: &Args, action: &GcAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let GcAction::Run = action;
+    let profile = args.profile();
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+
+        let db = match daneel::memory_db::MemoryDb::connect_with_profile(&qdrant_url, profile.clone()).await {
+            Ok(db) => db,
+            Err(e) => {
+                tracing::error!("Failed to connect to Qdrant: {}", e);
+                std::process::exit(1);
+            }
+        };
+        let graph_name = profile.namespace(daneel::namespace::prefix());
+        let graph = match daneel::graph::GraphClient::connect(&redis_url, &graph_name) {
+            Ok(graph) => graph,
+            Err(e) => {
+                tracing::error!("Failed to connect to RedisGraph: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        match daneel::gc::Collector::new(&graph, &db).collect().await {
+            Ok(report) => println!(
+                "GC scan: {} graph node(s), {} Qdrant memor{}. {} orphaned graph node(s) detached, \
+                 {} memor{} with no graph node.",
+                report.graph_nodes_scanned,
+                report.qdrant_memories_scanned,
+                if report.qdrant_memories_scanned == 1 { "y" } else { "ies" },
+                report.orphan_count(),
+                report.unlinked_memories.len(),
+                if report.unlinked_memories.len() == 1 { "y" } else { "ies" },
+            ),
+            Err(e) => {
+                tracing::error!("GC run failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+    });
+}
+
+/// Dispatch `daneel index audit`/`daneel index repair`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_index(args: &Args, action: &IndexAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+
+        let db = match daneel::memory_db::MemoryDb::connect_with_profile(&qdrant_url, profile.clone()).await {
+            Ok(db) => db,
+            Err(e) => {
+                tracing::error!("Failed to connect to Qdrant: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        match action {
+            IndexAction::Audit => match db.audit_memory_indexes().await {
+                Ok(statuses) => {
+                    for (field, present) in &statuses {
+                        println!("{field}: {}", if *present { "present" } else { "MISSING" });
+                    }
+                    if statuses.iter().any(|(_, present)| !present) {
+                        std::process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Index audit failed: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            IndexAction::Repair => match db.ensure_memory_indexes().await {
+                Ok(()) => println!("All expected payload indexes on `memories` are present."),
+                Err(e) => {
+                    tracing::error!("Index repair failed: {}", e);
+                    std::process::exit(1);
+                }
+            },
+        }
+    });
+}
+
+/// Dispatch `daneel import chatgpt`/`daneel import claude`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_import(args: &Args, action: &ImportAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let (format_label, path, replay_interval_secs) = match action {
+        ImportAction::Chatgpt { path, replay_interval_secs } => ("chatgpt", path, *replay_interval_secs),
+        ImportAction::Claude { path, replay_interval_secs } => ("claude", path, *replay_interval_secs),
+    };
+
+    let json = match daneel::import::read_export(std::path::Path::new(path)) {
+        Ok(json) => json,
+        Err(e) => {
+            tracing::error!("Failed to read export: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let parsed = match action {
+        ImportAction::Chatgpt { .. } => daneel::import::parse_chatgpt_export(&json),
+        ImportAction::Claude { .. } => daneel::import::parse_claude_export(&json),
+    };
+    let messages = match parsed {
+        Ok(messages) => messages,
+        Err(e) => {
+            tracing::error!("Failed to parse {} export: {}", format_label, e);
+            std::process::exit(1);
+        }
+    };
+
+    if messages.is_empty() {
+        println!("No messages found in export.");
+        return;
+    }
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let client = match redis::Client::open(redis_url.as_str()) {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::error!("Failed to build Redis client: {}", e);
+                std::process::exit(1);
+            }
+        };
+        let mut conn = match client.get_multiplexed_async_connection().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                tracing::error!("Failed to connect to Redis: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let total = messages.len();
+        for (i, message) in messages.iter().enumerate() {
+            let (content, salience) = daneel::import::to_stimulus(message);
+            let stream_data: Vec<(&str, String)> = vec![
+                ("id", format!("import_{}", uuid::Uuid::new_v4())),
+                ("source", format!("import:{format_label}")),
+                ("content", serde_json::to_string(&content).unwrap_or_default()),
+                ("salience", serde_json::to_string(&salience).unwrap_or_default()),
+                ("timestamp", message.timestamp.to_rfc3339()),
+            ];
+
+            if daneel::dry_run::is_enabled() {
+                tracing::info!("[dry-run] would inject message {}/{}", i + 1, total);
+            } else {
+                use redis::AsyncCommands;
+                let result: Result<String, redis::RedisError> = conn
+                    .xadd(daneel::streams::names::stream_inject(), "*", &stream_data)
+                    .await;
+                if let Err(e) = result {
+                    tracing::warn!("Failed to inject message {}/{}: {}", i + 1, total, e);
+                }
+            }
+
+            if let Some(secs) = replay_interval_secs {
+                tokio::time::sleep(std::time::Duration::from_secs(secs)).await;
+            }
+        }
+
+        println!("Imported {total} message(s) from {format_label} export.");
+    });
+}
+
+/// Dispatch `daneel search`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_search(args: &Args, query: &str, profiles: &[String], limit: usize) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profiles: Vec<daneel::profile::Profile> = if profiles.is_empty() {
+        vec![daneel::profile::Profile::default()]
+    } else {
+        profiles
+            .iter()
+            .cloned()
+            .map(Some)
+            .map(daneel::profile::Profile::new)
+            .collect()
+    };
+
+    let mut engine = match embeddings::EmbeddingEngine::new() {
+        Ok(engine) => engine,
+        Err(e) => {
+            tracing::error!("Failed to initialize embedding engine: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let vector = match engine.embed_thought(query) {
+        Ok(vector) => vector,
+        Err(e) => {
+            tracing::error!("Failed to embed query: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
+
+        match daneel::memory_db::MemoryDb::federated_search(&qdrant_url, &profiles, &vector, limit).await {
+            Ok(hits) if hits.is_empty() => println!("No matches across {} profile(s).", profiles.len()),
+            Ok(hits) => {
+                for hit in &hits {
+                    println!(
+                        "  [{}] {:.3}  {}",
+                        hit.profile.name(),
+                        hit.score,
+                        hit.memory.content
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::error!("Federated search failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+    });
+}
+
+/// Dispatch `daneel scheduler ...`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_scheduler(args: &Args, action: &SchedulerAction) {
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let SchedulerAction::Status = action;
+    let profile = args.profile();
+
+    let config = match daneel::config::plan::EffectiveConfig::load_or_default(std::path::Path::new(
+        daneel::config::plan::DEFAULT_CONFIG_PATH,
+    )) {
+        Ok(config) => config.cognitive,
+        Err(e) => {
+            tracing::error!("Failed to load config: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if config.scheduled_jobs.is_empty() {
+        println!("No scheduled jobs configured.");
+        return;
+    }
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let redis_url =
+            env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let saved = match daneel::persistence::MemoryStore::connect_with_profile(&redis_url, profile).await {
+            Ok(mut store) => store.load_scheduler_state().await.unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!("Failed to connect to Redis for scheduler state: {}; showing config only", e);
+                None
+            }
+        };
+
+        let scheduler = match daneel::scheduler::Scheduler::new(
+            &config.scheduled_jobs,
+            saved.as_ref(),
+            chrono::Utc::now(),
+        ) {
+            Ok(scheduler) => scheduler,
+            Err(e) => {
+                tracing::error!("Invalid scheduled job config: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let statuses = scheduler.statuses();
+        for job in &config.scheduled_jobs {
+            let status = statuses.get(&job.name);
+            println!(
+                "{}: cron={:?}  last_run={}  next_run={}",
+                job.name,
+                job.cron,
+                status
+                    .and_then(|s| s.last_run)
+                    .map_or_else(|| "never".to_string(), |t| t.to_rfc3339()),
+                status
+                    .and_then(|s| s.next_run)
+                    .map_or_else(|| "none".to_string(), |t| t.to_rfc3339()),
+            );
+        }
+    });
+}
+
+/// Run `daneel repl`: an interactive loop for poking at a mind's cognitive
+/// machinery one command at a time.
+///
+/// Everything here runs in-process against the same actor-free logic the
+/// real loop uses under the hood (`SalienceState::rate_content`,
+/// `VolitionState::evaluate_thought`, `CognitiveLoop::run_cycle`), so
+/// `identity`/`sleep history`-style connectivity is optional: commands that
+/// don't need Qdrant/Redis still work if those are unavailable.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_repl(args: &Args) {
+    use daneel::actors::salience::SalienceState;
+    use daneel::actors::volition::VolitionState;
+    use daneel::core::types::{Content, Thought};
+    use std::io::Write;
+
+    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn"));
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let profile = args.profile();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+
+    println!("DANEEL repl - type `help` for commands, `quit` to exit.");
+
+    let salience_state = SalienceState::new();
+    let mut volition_state = VolitionState::new();
+    let mut cognitive_loop = CognitiveLoop::new();
+
+    loop {
+        print!("daneel> ");
+        let _ = std::io::stdout().flush();
+        let mut line = String::new();
+        if std::io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+            break; // stdin closed
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (cmd, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = rest.trim();
+
+        match cmd {
+            "help" => {
+                println!("  identity              - show lifetime identity metadata");
+                println!("  cycle                 - run one cognitive cycle and print the result");
+                println!("  salience <text>       - rate the salience of arbitrary text");
+                println!("  volition <text>       - run text through the volition veto check");
+                println!("  search <query>        - search unconscious memories by content");
+                println!("  quit                  - exit the repl");
+            }
+            "quit" | "exit" => break,
+            "identity" => {
+                let qdrant_url =
+                    env::var("QDRANT_URL").unwrap_or_else(|_| "http://127.0.0.1:6334".into());
+                rt.block_on(async {
+                    match daneel::memory_db::MemoryDb::connect_with_profile(
+                        &qdrant_url,
+                        profile.clone(),
+                    )
+                    .await
+                    {
+                        Ok(db) => match db.load_identity().await {
+                            Ok(id) => println!(
+                                "lifetime_thoughts={} lifetime_dreams={} restart_count={}",
+                                id.lifetime_thought_count, id.lifetime_dream_count, id.restart_count
+                            ),
+                            Err(e) => println!("failed to load identity: {e}"),
+                        },
+                        Err(e) => println!("failed to connect to Qdrant: {e}"),
+                    }
+                });
+            }
+            "cycle" => {
+                let result = rt.block_on(cognitive_loop.run_cycle());
+                println!(
+                    "cycle={} salience={:.2} valence={:.2} arousal={:.2} candidates={} on_time={}",
+                    result.cycle_number,
+                    result.salience,
+                    result.valence,
+                    result.arousal,
+                    result.candidates_evaluated,
+                    result.on_time,
+                );
+            }
+            "salience" if !rest.is_empty() => {
+                let content = Content::symbol(rest, vec![]);
+                let score = salience_state.rate_content(&content, None);
+                println!(
+                    "importance={:.2} novelty={:.2} relevance={:.2} valence={:.2} arousal={:.2} connection={:.2}",
+                    score.importance, score.novelty, score.relevance, score.valence, score.arousal,
+                    score.connection_relevance,
+                );
+            }
+            "volition" if !rest.is_empty() => {
+                let content = Content::symbol(rest, vec![]);
+                let score = salience_state.rate_content(&content, None);
+                let thought = Thought::new(content, score);
+                match volition_state.evaluate_thought(&thought) {
+                    daneel::actors::volition::VetoDecision::Allow => println!("allowed"),
+                    daneel::actors::volition::VetoDecision::Veto {
+                        reason,
+                        violated_value,
+                    } => println!(
+                        "vetoed: {reason}{}",
+                        violated_value.map_or_else(String::new, |v| format!(" (violates: {v})"))
+                    ),
+                }
+            }
+            "search" if !rest.is_empty() => {
+                let qdrant_url =
+                    env::var("QDRANT_URL").unwrap_or_else(|_| "http://127.0.0.1:6334".into());
+                rt.block_on(async {
+                    match daneel::memory_db::MemoryDb::connect_with_profile(
+                        &qdrant_url,
+                        profile.clone(),
+                    )
+                    .await
+                    {
+                        Ok(db) => match db.search_unconscious(rest, 10).await {
+                            Ok(matches) if matches.is_empty() => println!("no matches"),
+                            Ok(matches) => {
+                                for memory in matches {
+                                    println!("  {}: {}", memory.id.0, memory.content);
                                 }
                             }
-                            Err(e) => {
-                                tracing::warn!("Failed to scroll {}: {}", collection, e);
-                                break;
-                            }
-                        }
+                            Err(e) => println!("search failed: {e}"),
+                        },
+                        Err(e) => println!("failed to connect to Qdrant: {e}"),
                     }
+                });
+            }
+            "salience" | "volition" | "search" => println!("usage: {cmd} <text>"),
+            other => println!("unknown command: {other} (try `help`)"),
+        }
+    }
+}
 
-                    if deleted > 0 {
-                        info!(
-                            "Deleted {} old points from {} (older than 30 days)",
-                            deleted, collection
-                        );
-                    } else {
-                        info!("{}: no points older than 30 days", collection);
-                    }
+/// Run `daneel bench`: measure achieved cycle throughput/latency against
+/// the configured speed mode's target, with no Redis/Qdrant attached.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_bench(duration: std::time::Duration, speed: BenchSpeed) {
+    let config = speed.config();
+    let target_per_sec = config.thoughts_per_second();
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        let mut cognitive_loop = CognitiveLoop::with_config(config);
+        cognitive_loop.start();
+
+        let mut cycle_latencies = Vec::new();
+        let allocations_before = ALLOCATION_COUNT.load(std::sync::atomic::Ordering::Relaxed);
+        let run_start = Instant::now();
+
+        while run_start.elapsed() < duration {
+            let cycle_start = Instant::now();
+            cognitive_loop.run_cycle().await;
+            cycle_latencies.push(cycle_start.elapsed());
+        }
+
+        let elapsed = run_start.elapsed();
+        let allocations =
+            ALLOCATION_COUNT.load(std::sync::atomic::Ordering::Relaxed) - allocations_before;
+
+        cycle_latencies.sort_unstable();
+        let achieved_per_sec = cycle_latencies.len() as f64 / elapsed.as_secs_f64();
+
+        println!("daneel bench ({speed:?}, ran {elapsed:.2?})");
+        println!("  cycles run:         {}", cycle_latencies.len());
+        println!(
+            "  achieved cycles/s:  {achieved_per_sec:.1} (target: {target_per_sec:.1})"
+        );
+        println!("  p50 cycle latency:  {:?}", bench_percentile(&cycle_latencies, 0.50));
+        println!("  p99 cycle latency:  {:?}", bench_percentile(&cycle_latencies, 0.99));
+        println!("  allocations:        {allocations}");
+    });
+}
+
+/// Latency at percentile `p` (0.0-1.0) from a slice already sorted ascending.
+fn bench_percentile(sorted: &[std::time::Duration], p: f64) -> std::time::Duration {
+    if sorted.is_empty() {
+        return std::time::Duration::ZERO;
+    }
+    #[allow(clippy::cast_precision_loss, clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let idx = (((sorted.len() - 1) as f64) * p).round() as usize;
+    sorted[idx]
+}
+
+/// Dispatch `daneel config plan|apply`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_config(action: &ConfigAction) {
+    use daneel::config::plan::{self, EffectiveConfig};
+    use std::path::Path;
+
+    match action {
+        ConfigAction::Plan { file } => {
+            let current = match EffectiveConfig::load_or_default(Path::new(plan::DEFAULT_CONFIG_PATH)) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("Failed to load current config: {e}");
+                    std::process::exit(1);
+                }
+            };
+            let proposed = match EffectiveConfig::load(Path::new(file)) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("Failed to load proposed config {file}: {e}");
+                    std::process::exit(1);
                 }
+            };
+
+            let changes = current.diff(&proposed);
+            if changes.is_empty() {
+                println!("No changes.");
+                return;
             }
-            Err(e) => {
-                tracing::warn!("Qdrant connection failed: {}", e);
+
+            println!("Plan: {} change(s)", changes.len());
+            for change in &changes {
+                let invariant = change
+                    .invariant
+                    .map_or_else(String::new, |name| format!(" (touches invariant: {name})"));
+                println!("  ~ {}: {} -> {}{}", change.field, change.from, change.to, invariant);
+            }
+
+            match plan::validate(&current, &proposed) {
+                Ok(()) => println!("Validation: OK"),
+                Err(e) => println!("Validation: FAILED - {e}"),
             }
         }
+        ConfigAction::Apply { file } => {
+            let config_path = Path::new(plan::DEFAULT_CONFIG_PATH);
+            let current = match EffectiveConfig::load_or_default(config_path) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("Failed to load current config: {e}");
+                    std::process::exit(1);
+                }
+            };
+            let proposed = match EffectiveConfig::load(Path::new(file)) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("Failed to load proposed config {file}: {e}");
+                    std::process::exit(1);
+                }
+            };
 
-        // ── 3. Compact Redis AOF ──
-        if streams_trimmed {
-            if let Ok(client) = redis::Client::open(redis_url.as_str()) {
-                if let Ok(mut conn) = client.get_multiplexed_async_connection().await {
-                    let _: Result<String, _> =
-                        redis::cmd("BGREWRITEAOF").query_async(&mut conn).await;
-                    info!("Redis BGREWRITEAOF triggered");
+            match plan::apply(config_path, &current, &proposed) {
+                Ok(()) => println!("Applied. New effective config written to {plan::DEFAULT_CONFIG_PATH}"),
+                Err(e) => {
+                    eprintln!("Apply rejected, nothing written: {e}");
+                    std::process::exit(1);
                 }
             }
         }
+    }
+}
 
-        info!("DANEEL maintenance complete.");
-    });
+/// Dispatch `daneel models ...`
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_models(action: &ModelsAction) {
+    match action {
+        ModelsAction::Fetch => {
+            let cache_dir = embeddings::model_cache_dir();
+            println!(
+                "Fetching embedding model{}...",
+                cache_dir
+                    .as_ref()
+                    .map_or_else(String::new, |dir| format!(" into {}", dir.display()))
+            );
+
+            match embeddings::EmbeddingEngine::new() {
+                Ok(_) => println!("Model fetched and cached. This machine can now run offline."),
+                Err(e) => {
+                    eprintln!("Failed to fetch model: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
 }
 
-/// Run memory migration and exit
+/// Run `daneel calibrate`: fit salience weights against human ratings and
+/// write the proposed `WeightUpdate` to `out` for review.
 #[cfg_attr(coverage_nightly, coverage(off))]
-fn run_migration(args: &Args) {
-    // Initialize tracing
-    let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
-        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
-    tracing_subscriber::registry()
-        .with(filter)
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+fn run_calibrate(ratings_csv: Option<&str>, out: &str) {
+    use daneel::actors::salience::calibrate;
+    use daneel::core::types::SalienceWeights;
+    use std::path::Path;
+
+    let ratings = match ratings_csv {
+        Some(path) => calibrate::load_ratings_csv(Path::new(path)),
+        None => {
+            println!("No --ratings file given; rating the built-in sample set interactively.");
+            calibrate::prompt_ratings()
+        }
+    };
+    let ratings = match ratings {
+        Ok(ratings) => ratings,
+        Err(e) => {
+            eprintln!("Failed to collect ratings: {e}");
+            std::process::exit(1);
+        }
+    };
 
+    println!("Fitting weights against {} rating(s)...", ratings.len());
+    let update = match calibrate::calibrate(SalienceWeights::default(), &ratings) {
+        Ok(update) => update,
+        Err(e) => {
+            eprintln!("Calibration failed: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("Proposed weights: {:?}", update.weights);
+    let out_path = Path::new(out);
+    match calibrate::write_proposal(out_path, &update) {
+        Ok(()) => println!(
+            "Written to {out}. Review it, then feed it back in via SalienceMessage::UpdateWeights."
+        ),
+        Err(e) => {
+            eprintln!("Failed to write proposal: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Dispatch `daneel tune-threshold`
+fn run_tune_threshold(feature: &str, examples: &str, out: &str, steps: usize) {
+    use daneel::tuning;
+    use std::path::Path;
+
+    let examples = match tuning::load_examples_csv(Path::new(examples)) {
+        Ok(examples) => examples,
+        Err(e) => {
+            eprintln!("Failed to load examples: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("Sweeping {steps} threshold(s) against {} labeled example(s)...", examples.len());
+    let points = match tuning::sweep(&examples, steps) {
+        Ok(points) => points,
+        Err(e) => {
+            eprintln!("Sweep failed: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    let Some(best) = tuning::recommend(&points) else {
+        eprintln!("No threshold scored; nothing to recommend.");
+        std::process::exit(1);
+    };
+    println!(
+        "Recommended threshold {:.3} (precision={:.3}, recall={:.3}, f1={:.3})",
+        best.threshold, best.precision, best.recall, best.f1
+    );
+
+    let out_path = Path::new(out);
+    match tuning::write_fragment(out_path, feature, best.threshold) {
+        Ok(()) => println!("Written to {out}. Review it before merging into your config."),
+        Err(e) => {
+            eprintln!("Failed to write config fragment: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Run `daneel soak`: drive a headless cognitive loop for `duration`,
+/// sampling resource usage every `sample_interval_secs`, and exit non-zero
+/// if [`daneel::soak::detect_leaks`] flags anything.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn run_soak(duration: std::time::Duration, sample_interval: Duration, growth_ratio_limit: f64, redis_url: Option<&str>) {
     let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
     rt.block_on(async {
-        let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".into());
-        info!("Connecting to Qdrant at {}", qdrant_url);
+        println!(
+            "Running soak test for {duration:.2?}, sampling every {sample_interval:.2?} (growth ratio limit {growth_ratio_limit})..."
+        );
+        let report = daneel::soak::run(duration, sample_interval, redis_url, growth_ratio_limit).await;
+
+        println!("daneel soak (ran {:.2?}, {} sample(s))", report.elapsed, report.samples.len());
+        if let (Some(first), Some(last)) = (report.samples.first(), report.samples.last()) {
+            println!("  rss_mb:      {} -> {}", first.rss_mb, last.rss_mb);
+            println!("  tokio_tasks: {} -> {}", first.tokio_tasks, last.tokio_tasks);
+            println!(
+                "  open_fds:    {} -> {}",
+                first.open_fds.map_or("n/a".to_string(), |v| v.to_string()),
+                last.open_fds.map_or("n/a".to_string(), |v| v.to_string())
+            );
+            println!(
+                "  stream_len:  {} -> {}",
+                first.stream_len.map_or("n/a".to_string(), |v| v.to_string()),
+                last.stream_len.map_or("n/a".to_string(), |v| v.to_string())
+            );
+        }
 
-        let db = daneel::memory_db::MemoryDb::connect(&qdrant_url)
-            .await
-            .expect("Failed to connect to Qdrant");
+        if report.leaks.is_empty() {
+            println!("No unbounded growth detected.");
+        } else {
+            println!("Possible leak(s) detected:");
+            for leak in &report.leaks {
+                println!(
+                    "  {}: {:.1} -> {:.1} ({:.2}x)",
+                    leak.metric, leak.baseline, leak.latest, leak.growth_ratio
+                );
+            }
+            std::process::exit(1);
+        }
+    });
+}
 
-        match db.migrate_memories().await {
-            Ok(count) => info!("Migration complete: {} memories updated", count),
-            Err(e) => tracing::error!("Migration failed: {}", e),
+/// Run a scripted bonding test: `rounds` greeting/question/feedback
+/// stimulus triples, `cycle_spacing` cycles apart, checking that
+/// connection-relevant stimuli won attention at least `min_win_rate` of
+/// the time (see `daneel::bonding`).
+fn run_bond_test(rounds: u32, cycle_spacing: u64, min_win_rate: f32) {
+    let mut schedule = Vec::new();
+    for round in 0..u64::from(rounds) {
+        let base = round * cycle_spacing;
+        schedule.push(daneel::bonding::PersonaStimulus::greeting(base, "grok"));
+        schedule.push(daneel::bonding::PersonaStimulus::question(base + 1, "grok", "trust"));
+        schedule.push(daneel::bonding::PersonaStimulus::feedback(base + 2, "grok", "trust"));
+    }
+
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    rt.block_on(async {
+        println!("Running bonding test: {rounds} round(s), {} stimuli scripted...", schedule.len());
+        let report = daneel::bonding::run(&schedule, min_win_rate).await;
+
+        println!("daneel bond (ran {} cycle(s), {} stimuli sent)", report.cycles_run, report.stimuli_sent);
+        println!(
+            "  stimulus win rate: {:.2} (minimum expected {:.2})",
+            report.verdict.observed_win_rate, report.verdict.min_expected_win_rate
+        );
+
+        if report.verdict.passed {
+            println!("Connection-relevant stimuli won attention at an acceptable rate.");
+        } else {
+            println!("Connection-relevant stimuli won attention below the expected rate.");
+            std::process::exit(1);
         }
     });
 }
@@ -263,18 +2460,23 @@ fn run_migration(args: &Args) {
 /// Use daneel-web for observatory at <https://timmy.mollendorff.ai>
 #[cfg_attr(coverage_nightly, coverage(off))]
 fn run_headless(args: &Args) {
-    // Initialize tracing for headless mode
+    // Initialize tracing for headless mode - backend (stdout/journald/
+    // syslog/rotating file) is chosen by DANEEL_LOG_* env vars (see
+    // daneel::logging::LoggingConfig), since a headless deployment rarely
+    // wants its logs only on stdout.
     let filter = tracing_subscriber::EnvFilter::try_new(&args.log_level)
         .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
-
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer())
-        .with(filter)
-        .init();
+    let logging_config = daneel::logging::LoggingConfig::from_env();
+    let _logging_guard = daneel::logging::init(&logging_config, filter, &args.log_level);
 
     info!("DANEEL starting in headless mode...");
     info!("THE BOX initialized with {} laws", LAWS.len());
 
+    for symbol in &args.trace_symbols {
+        daneel::core::trace::watch(symbol);
+        info!("Watching symbol '{}' for provenance tracing", symbol);
+    }
+
     // Display the Four Laws
     for (i, law) in LAWS.iter().enumerate() {
         let law_name = match i {
@@ -291,13 +2493,134 @@ fn run_headless(args: &Args) {
     info!("DANEEL ready. Qowat Milat.");
     info!("Timmy is 'they', not 'it'. Life honours life.");
 
-    // Create tokio runtime and run the cognitive loop
-    let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+    // Create tokio runtime and run the cognitive loop. Sized per the
+    // effective config file if one has been staged via `daneel config
+    // apply` (see daneel::config::plan), defaults otherwise.
+    let runtime_topology = daneel::config::plan::EffectiveConfig::load_or_default(std::path::Path::new(
+        daneel::config::plan::DEFAULT_CONFIG_PATH,
+    ))
+    .map(|config| config.runtime)
+    .unwrap_or_default();
+    let runtime = daneel::runtime::build(&runtime_topology).expect("Failed to create tokio runtime");
+    let profile = args.profile();
+
+    // Shared across the API task and the loop task below, so /readyz can
+    // report the loop's live capability matrix (see daneel::core::capabilities).
+    let capability_handle = daneel::core::capabilities::CapabilityHandle::new();
+
+    // Shared the same way, so `extended_metrics` can report the loop's live
+    // consolidation backlog (see daneel::core::cognitive_loop::consolidation).
+    let consolidation_metrics =
+        Arc::new(daneel::core::cognitive_loop::ConsolidationMetrics::default());
+
+    // Shared the same way, so `extended_metrics` can report awake-stream
+    // MAXLEN trim stats (see daneel::core::cognitive_loop::stream_overflow).
+    let stream_overflow_metrics =
+        Arc::new(daneel::core::cognitive_loop::StreamOverflowMetrics::default());
+
+    // Shared the same way, so `/inject` can tell the loop a human is
+    // actively interacting and have it throttle observable output (see
+    // daneel::core::interaction).
+    let human_interaction_handle = daneel::core::interaction::HumanInteractionHandle::new();
+
+    // Shared the same way, so `/extended_metrics` can report whether
+    // cognition is currently paused on repeated harm-category vetoes (see
+    // daneel::core::cognitive_loop::interlock).
+    let safety_interlock_handle = daneel::core::cognitive_loop::SafetyInterlockHandle::new();
+
+    // Shared the same way, so `/extended_metrics` can report current
+    // volition values/veto stats without a message round trip through
+    // `VolitionActor` (see daneel::actors::volition).
+    let volition_snapshot_handle = daneel::actors::volition::VolitionSnapshotHandle::new();
+
+    // Shared the same way, so `/emotion_timeline` can export recent
+    // valence/arousal without a `&CognitiveLoop` borrow (see
+    // daneel::core::cognitive_loop::emotion_timeline).
+    let emotion_timeline_handle = daneel::core::cognitive_loop::EmotionTimelineHandle::new();
+
+    // Shared the same way, so `/recent_thoughts` and `/veto_log` can export
+    // the same rolling log `ThoughtHistory` keeps process-local (see
+    // daneel::core::cognitive_loop::recent_activity).
+    let recent_thoughts_handle = daneel::core::cognitive_loop::RecentThoughtsHandle::new();
+
+    // Shared the same way, so a `GET /thoughts` WebSocket can relay every
+    // completed cycle to external observers live (see
+    // daneel::core::cognitive_loop::thought_stream) without scraping Redis
+    // Streams directly.
+    let thought_stream_handle = daneel::core::cognitive_loop::ThoughtStreamHandle::new();
 
     runtime.block_on(async {
+        // Spawned here (not in `run_cognitive_loop_headless`) so the same
+        // `ActorRef` can be shared with the API task below, letting
+        // `/extended_metrics` report identity/checkpoint status (see
+        // daneel::actors::continuity) alongside the loop's own use of it.
+        let continuity_actor = match Actor::spawn(None, daneel::actors::continuity::ContinuityActor, ())
+            .await
+        {
+            Ok((actor_ref, _handle)) => {
+                info!("ContinuityActor spawned - identity/checkpoint tracking enabled");
+                Some(actor_ref)
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to spawn ContinuityActor: {e} - identity tracking disabled");
+                None
+            }
+        };
+
+        // Connected here (not in `run_cognitive_loop_headless`), the same way
+        // `continuity_actor` is, so a future lookup API can share it with the
+        // loop's own use of it (see daneel::linkage).
+        let linkage_registry = {
+            let redis_url =
+                env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+            match daneel::linkage::LinkageRegistry::connect(&redis_url).await {
+                Ok(registry) => {
+                    info!("LinkageRegistry connected - thought traceability enabled");
+                    Some(registry)
+                }
+                Err(e) => {
+                    eprintln!("Warning: Failed to connect LinkageRegistry: {e} - `daneel trace` will find nothing");
+                    None
+                }
+            }
+        };
+
+        // SLEEP-WIRE-1: Spawn SleepActor with nap config (ADR-023).
+        // Interactive deployments rarely see the long idle periods full
+        // sleep wants, so consolidation runs as short, queue-triggered naps
+        // instead - a bounded handful of replay batches per trigger, fully
+        // interruptible. Spawned here (not in `run_cognitive_loop_headless`),
+        // the same way `continuity_actor` is, so `/inject` can reset its
+        // idle timer directly (see api::AppState::sleep_actor) alongside the
+        // loop's own injection-reader/human-interaction signals.
+        let sleep_ref = match Actor::spawn(None, SleepActor::with_config(SleepConfig::nap()), ()).await {
+            Ok((actor_ref, _handle)) => {
+                info!("SleepActor spawned - nap consolidation enabled");
+                Some(actor_ref)
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to spawn SleepActor: {e} - consolidation disabled");
+                None
+            }
+        };
+
         // Start injection API server if enabled
+        #[cfg(feature = "api")]
         if args.api_port > 0 {
             let api_port = args.api_port;
+            let profile = profile.clone();
+            let capability_handle = capability_handle.clone();
+            let consolidation_metrics = Arc::clone(&consolidation_metrics);
+            let stream_overflow_metrics = Arc::clone(&stream_overflow_metrics);
+            let continuity_actor = continuity_actor.clone();
+            let human_interaction_handle = human_interaction_handle.clone();
+            let safety_interlock_handle = safety_interlock_handle.clone();
+            let volition_snapshot_handle = volition_snapshot_handle.clone();
+            let emotion_timeline_handle = emotion_timeline_handle.clone();
+            let recent_thoughts_handle = recent_thoughts_handle.clone();
+            let thought_stream_handle = thought_stream_handle.clone();
+            let linkage_registry = linkage_registry.clone();
+            let sleep_ref = sleep_ref.clone();
             tokio::spawn(async move {
                 let redis_url =
                     env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
@@ -322,7 +2645,10 @@ fn run_headless(args: &Args) {
                     };
 
                 // Create GraphClient for API (VCONN-11: GraphML export)
-                let graph_client = match daneel::graph::GraphClient::connect(&redis_url, "daneel") {
+                let graph_client = match daneel::graph::GraphClient::connect(
+                    &redis_url,
+                    &profile.namespace(daneel::namespace::prefix()),
+                ) {
                     Ok(client) => {
                         info!("API: Connected to RedisGraph for GraphML export");
                         Some(Arc::new(client))
@@ -339,6 +2665,18 @@ fn run_headless(args: &Args) {
                     streams: Arc::new(streams_client),
                     redis: redis_client,
                     graph: graph_client,
+                    capabilities: capability_handle,
+                    consolidation_metrics,
+                    stream_overflow_metrics,
+                    continuity_actor,
+                    human_interaction: human_interaction_handle,
+                    safety_interlock: safety_interlock_handle,
+                    volition_snapshot: volition_snapshot_handle,
+                    emotion_timeline: emotion_timeline_handle,
+                    recent_thoughts: recent_thoughts_handle,
+                    thought_stream: thought_stream_handle,
+                    linkage_registry,
+                    sleep_actor: sleep_ref,
                 };
 
                 let app = api::router(api_state);
@@ -358,7 +2696,36 @@ fn run_headless(args: &Args) {
             });
         }
 
-        run_cognitive_loop_headless().await;
+        // Binary was built with `--no-default-features` (or otherwise without
+        // `api`) - there's no axum/tower/http in this build to serve with, so
+        // warn rather than silently ignoring the flag.
+        #[cfg(not(feature = "api"))]
+        if args.api_port > 0 {
+            eprintln!(
+                "Warning: --api-port {} was set, but this binary was built without the \
+                 `api` feature - the injection API will not start",
+                args.api_port
+            );
+        }
+
+        run_cognitive_loop_headless(
+            profile,
+            capability_handle,
+            consolidation_metrics,
+            stream_overflow_metrics,
+            continuity_actor,
+            human_interaction_handle,
+            safety_interlock_handle,
+            volition_snapshot_handle,
+            emotion_timeline_handle,
+            recent_thoughts_handle,
+            thought_stream_handle,
+            linkage_registry,
+            sleep_ref,
+            args.output,
+            args.status_interval,
+        )
+        .await;
     });
 }
 
@@ -371,27 +2738,41 @@ fn run_headless(args: &Args) {
 #[allow(clippy::future_not_send)] // Async runtime handles this
 #[allow(clippy::significant_drop_tightening)] // Resources held for loop duration
 #[cfg_attr(coverage_nightly, coverage(off))]
-async fn run_cognitive_loop_headless() {
+async fn run_cognitive_loop_headless(
+    profile: daneel::profile::Profile,
+    capability_handle: daneel::core::capabilities::CapabilityHandle,
+    consolidation_metrics: Arc<daneel::core::cognitive_loop::ConsolidationMetrics>,
+    stream_overflow_metrics: Arc<daneel::core::cognitive_loop::StreamOverflowMetrics>,
+    continuity_actor: Option<ractor::ActorRef<daneel::actors::continuity::ContinuityMessage>>,
+    human_interaction_handle: daneel::core::interaction::HumanInteractionHandle,
+    safety_interlock_handle: daneel::core::cognitive_loop::SafetyInterlockHandle,
+    volition_snapshot_handle: daneel::actors::volition::VolitionSnapshotHandle,
+    emotion_timeline_handle: daneel::core::cognitive_loop::EmotionTimelineHandle,
+    recent_thoughts_handle: daneel::core::cognitive_loop::RecentThoughtsHandle,
+    thought_stream_handle: daneel::core::cognitive_loop::ThoughtStreamHandle,
+    linkage_registry: Option<daneel::linkage::LinkageRegistry>,
+    sleep_ref: Option<ractor::ActorRef<SleepMessage>>,
+    output_mode: OutputMode,
+    status_interval: u64,
+) {
     // ADR-034: Lifetime Identity Persistence - flush intervals
     const IDENTITY_FLUSH_INTERVAL_SECS: u64 = 30;
     const IDENTITY_FLUSH_THOUGHT_INTERVAL: u64 = 100;
 
-    // Periodic status logging
-    const STATUS_LOG_INTERVAL: u64 = 1000;
+    // Hebbian edge write buffer - flush whichever comes first
+    const EDGE_BUFFER_MAX_BATCH: usize = 200;
+    const EDGE_BUFFER_FLUSH_INTERVAL_SECS: u64 = 5;
 
-    // SLEEP-WIRE-1: Spawn SleepActor with mini-dream config
-    let sleep_config = SleepConfig::mini_dream();
-    let sleep_actor = SleepActor::with_config(sleep_config.clone());
-    let sleep_ref = match Actor::spawn(None, sleep_actor, ()).await {
-        Ok((actor_ref, _handle)) => {
-            info!("SleepActor spawned - mini-dream consolidation enabled");
-            Some(actor_ref)
-        }
-        Err(e) => {
-            eprintln!("Warning: Failed to spawn SleepActor: {e} - consolidation disabled");
-            None
-        }
-    };
+    // Speed governor: check host resource usage every N cycles, not every
+    // cycle - at supercomputer speed a cycle is microseconds, sampling that
+    // often would dwarf the cognition itself.
+    const GOVERNOR_CHECK_INTERVAL: u64 = 200;
+
+    // Plain config values for the batch-size/delta math below - the actor
+    // itself (spawned alongside `continuity_actor`, before this function
+    // runs, so `/inject` can share it too) was built from the same
+    // `SleepConfig::nap()`.
+    let sleep_config = SleepConfig::nap();
 
     // Connect to Redis for thought streams
     let redis_url = env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
@@ -408,7 +2789,12 @@ async fn run_cognitive_loop_headless() {
 
     // Connect to Qdrant for long-term memory and initialize collections
     let qdrant_url = env::var("QDRANT_URL").unwrap_or_else(|_| "http://127.0.0.1:6334".to_string());
-    let memory_db = match daneel::memory_db::MemoryDb::connect_and_init(&qdrant_url).await {
+    let memory_db = match daneel::memory_db::MemoryDb::connect_and_init_with_profile(
+        &qdrant_url,
+        profile.clone(),
+    )
+    .await
+    {
         Ok(db) => {
             info!("Connected to Qdrant memory database (collections initialized)");
             Some(std::sync::Arc::new(db))
@@ -419,10 +2805,29 @@ async fn run_cognitive_loop_headless() {
         }
     };
 
+    // Wire the same Qdrant connection into ContinuityActor as its durable
+    // experience store (see daneel::actors::continuity::store). The actor
+    // was already spawned above, before this connection existed, so this
+    // goes through a message rather than `Actor::Arguments`.
+    if let (Some(actor), Some(db)) = (&continuity_actor, &memory_db) {
+        let store = daneel::actors::continuity::StoreHandle(db.clone());
+        match actor
+            .call(
+                |reply| daneel::actors::continuity::ContinuityMessage::SetStore { store, reply },
+                None,
+            )
+            .await
+        {
+            Ok(_) => info!("ContinuityActor's experience store configured (Qdrant-backed)"),
+            Err(e) => eprintln!("Warning: Failed to configure ContinuityActor's experience store: {e}"),
+        }
+    }
+
     // VCONN-5: Connect to RedisGraph (VCONN-6: Spreading Activation)
-    let graph_client = match daneel::graph::GraphClient::connect(&redis_url, "daneel") {
+    let graph_name = profile.namespace(daneel::namespace::prefix());
+    let graph_client = match daneel::graph::GraphClient::connect(&redis_url, &graph_name) {
         Ok(client) => {
-            info!("Connected to RedisGraph ('daneel')");
+            info!("Connected to RedisGraph ('{}')", graph_name);
             let arc_client = std::sync::Arc::new(client);
             cognitive_loop.set_graph_client(arc_client.clone());
             Some(arc_client)
@@ -432,6 +2837,12 @@ async fn run_cognitive_loop_headless() {
             None
         }
     };
+    // Hebbian wiring during naps merges an edge per co-replayed pair -
+    // O(n^2) per batch - so buffer them into batched GRAPH.QUERY calls
+    // instead of one round trip each (see `daneel::graph::EdgeWriteBuffer`).
+    let edge_buffer = graph_client
+        .clone()
+        .map(|graph| daneel::graph::EdgeWriteBuffer::new(graph, EDGE_BUFFER_MAX_BATCH, Duration::from_secs(EDGE_BUFFER_FLUSH_INTERVAL_SECS)));
 
     // Load identity from Qdrant (ADR-034: Lifetime Identity Persistence)
     let mut identity: Option<IdentityMetadata> = if let Some(ref db) = memory_db {
@@ -463,6 +2874,32 @@ async fn run_cognitive_loop_headless() {
         cognitive_loop.set_memory_db(db.clone());
     }
 
+    // Share metrics with the `extended_metrics` handler (see api::AppState).
+    cognitive_loop.set_consolidation_metrics(consolidation_metrics);
+    cognitive_loop.set_stream_overflow_metrics(stream_overflow_metrics);
+
+    if let Some(continuity_actor) = continuity_actor {
+        cognitive_loop.set_continuity_actor(continuity_actor);
+    }
+
+    cognitive_loop.set_human_interaction_handle(human_interaction_handle);
+    cognitive_loop.set_safety_interlock_handle(safety_interlock_handle);
+    cognitive_loop.set_volition_snapshot_handle(volition_snapshot_handle);
+    cognitive_loop.set_emotion_timeline_handle(emotion_timeline_handle);
+    cognitive_loop.set_recent_thoughts_handle(recent_thoughts_handle);
+    cognitive_loop.set_thought_stream_handle(thought_stream_handle);
+    if let Some(registry) = linkage_registry {
+        cognitive_loop.set_linkage_registry(registry);
+    }
+
+    // Genuine external engagement (injection reader, human-interaction
+    // mode, `/inject` requests - see `CognitiveLoop::set_sleep_actor`)
+    // resets the actor's idle timer directly now, instead of this loop
+    // pinging it unconditionally every cycle regardless of real activity.
+    if let Some(ref actor) = sleep_ref {
+        cognitive_loop.set_sleep_actor(actor.clone());
+    }
+
     // Initialize embedding engine for semantic vectors (Phase 2: Forward-Only)
     match embeddings::create_embedding_engine() {
         Ok(engine) => {
@@ -479,10 +2916,39 @@ async fn run_cognitive_loop_headless() {
 
     cognitive_loop.start();
     info!("Cognitive loop started. Timmy is thinking...");
+    capability_handle.set(cognitive_loop.capabilities());
+
+    let mut governor = daneel::resilience::governor::SpeedGovernor::new(
+        daneel::resilience::governor::GovernorConfig::default(),
+        cognitive_loop.config(),
+    );
+    let governor_redis_client = redis::Client::open(redis_url.as_str()).ok();
+
+    // Opt-in anonymous telemetry (see daneel::telemetry) - off unless
+    // DANEEL_TELEMETRY_ENABLED is set.
+    let telemetry_config = daneel::telemetry::TelemetryConfig::from_env();
+    let telemetry_reporter = telemetry_config
+        .enabled
+        .then(|| daneel::telemetry::TelemetryReporter::new(&telemetry_config));
+    if telemetry_reporter.is_some() {
+        info!("Telemetry enabled - reporting aggregate stats to {}", telemetry_config.endpoint);
+    }
 
     let mut cycles: u64 = 0;
 
     loop {
+        // Safety interlock (see daneel::core::cognitive_loop::interlock):
+        // while paused, stop producing cycles entirely and poll Redis for a
+        // pending `daneel safety ack` on a slow cadence instead of spinning.
+        if !cognitive_loop.is_running() {
+            if cognitive_loop.try_resume_from_safety_ack().await {
+                info!("Cognitive loop resumed after safety interlock acknowledgment");
+            } else {
+                tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+                continue;
+            }
+        }
+
         // Wait until it's time for the next cycle
         let sleep_duration = cognitive_loop.time_until_next_cycle();
         if sleep_duration > std::time::Duration::ZERO {
@@ -493,6 +2959,24 @@ async fn run_cognitive_loop_headless() {
         let _result = cognitive_loop.run_cycle().await;
         cycles += 1;
 
+        // Speed governor: step cognitive speed down/up based on host load
+        if cycles % GOVERNOR_CHECK_INTERVAL == 0 {
+            let redis_latency_ms = if let Some(ref client) = governor_redis_client {
+                match client.get_multiplexed_async_connection().await {
+                    Ok(mut conn) => {
+                        let start = Instant::now();
+                        let ping: Result<String, _> = redis::cmd("PING").query_async(&mut conn).await;
+                        ping.ok().map(|_| start.elapsed().as_millis())
+                    }
+                    Err(_) => None,
+                }
+            } else {
+                None
+            };
+            governor.tick(cognitive_loop.config_mut(), redis_latency_ms);
+            capability_handle.set(cognitive_loop.capabilities());
+        }
+
         // Update identity (increment lifetime thought count)
         if let Some(ref mut id) = identity {
             id.record_thought();
@@ -515,8 +2999,11 @@ async fn run_cognitive_loop_headless() {
 
         // SLEEP-WIRE-1: Memory consolidation via SleepActor
         if let Some(ref sleep) = sleep_ref {
-            // Record activity (increments queue estimate)
-            sleep.cast(SleepMessage::RecordActivity).ok();
+            // Every cognitive cycle produces a thought that may need
+            // consolidating, regardless of whether a human is engaged -
+            // queue growth, not "activity" in the idle-detection sense (see
+            // `CognitiveLoop::set_sleep_actor` for the real activity signals).
+            sleep.cast(SleepMessage::IncrementQueue).ok();
 
             // Check if sleep conditions are met (queue size threshold)
             let should_sleep = sleep
@@ -527,9 +3014,10 @@ async fn run_cognitive_loop_headless() {
                 .unwrap_or(false);
 
             if should_sleep {
-                // Enter sleep mode
+                // Enter nap mode - manual/queue-triggered, bypasses the
+                // idle/awake gating that EnterSleep enforces for full sleep.
                 let entered = sleep
-                    .call(|reply| SleepMessage::EnterSleep { reply }, None)
+                    .call(|reply| SleepMessage::RequestNap { reply }, None)
                     .await
                     .ok()
                     .and_then(|r| r.success_or(()).ok())
@@ -553,138 +3041,300 @@ async fn run_cognitive_loop_headless() {
                         let batch_size = sleep_config.replay_batch_size as u32;
                         let strength_delta = sleep_config.consolidation_delta * params.multiplier;
 
-                        match db.get_replay_candidates(batch_size).await {
-                            Ok(candidates) => {
-                                let mut consolidated = 0;
-                                let mut associations_strengthened = 0;
-
-                                // 1. Strengthen individual memories (Consolidation)
-                                for memory in &candidates {
-                                    if db
-                                        .update_consolidation(&memory.id, strength_delta)
-                                        .await
-                                        .is_ok()
-                                    {
-                                        consolidated += 1;
-                                    }
-                                }
-
-                                // 2. Strengthen associations between replayed memories (Hebbian Wiring)
-                                // VCONN-4b: Co-replayed memories -> weight += 0.05
-                                for i in 0..candidates.len() {
-                                    for j in 0..candidates.len() {
-                                        if i == j {
-                                            continue;
-                                        }
-                                        let m1 = &candidates[i];
-                                        let m2 = &candidates[j];
-
-                                        // Strengthen in Qdrant (Krotov-Hopfield)
+                        // Durable record of this nap (ADR-023), persisted below
+                        // regardless of outcome so history isn't silently lossy.
+                        // A nap loops over up to max_nap_batches replay batches
+                        // (vs. the single batch a full sleep cycle runs),
+                        // bailing out early once the queue empties or the
+                        // actor reports it was woken mid-nap.
+                        let mut cycle_report = daneel::memory_db::SleepCycle::new();
+                        let mut total_replayed: u32 = 0;
+                        let mut total_consolidated: u32 = 0;
+                        let mut total_associations: u32 = 0;
+                        let mut priority_sum: f32 = 0.0;
+                        let mut interrupted = false;
+                        let mut dream_fragment: Option<String> = None;
+
+                        for batch_num in 0..sleep_config.max_nap_batches.max(1) {
+                            match db.get_replay_candidates(batch_size).await {
+                                Ok(candidates) if candidates.is_empty() => break,
+                                Ok(candidates) => {
+                                    let mut consolidated = 0;
+                                    let mut associations_strengthened = 0;
+
+                                    // 1. Strengthen individual memories (Consolidation)
+                                    for memory in &candidates {
                                         if db
-                                            .strengthen_association(
-                                                &m1.id, &m2.id, 1.0, // x (active)
-                                                1.0, // y (active)
-                                                1.0, // reward (neutral in sleep)
-                                            )
+                                            .update_consolidation(&memory.id, strength_delta)
                                             .await
                                             .is_ok()
                                         {
-                                            associations_strengthened += 1;
-
-                                            // Dual-write: RedisGraph
-                                            if let Some(ref graph) = graph_client {
-                                                let _ = graph.merge_edge(
-                                                    &m1.id,
-                                                    &m2.id,
-                                                    0.1, // Placeholder for weight - ideally fetch from assoc
-                                                    daneel::memory_db::types::AssociationType::Semantic
-                                                ).await;
+                                            consolidated += 1;
+                                        }
+                                    }
+
+                                    // 2. Strengthen associations between replayed memories (Hebbian Wiring)
+                                    // VCONN-4b: Co-replayed memories -> weight += 0.05
+                                    for i in 0..candidates.len() {
+                                        for j in 0..candidates.len() {
+                                            if i == j {
+                                                continue;
+                                            }
+                                            let m1 = &candidates[i];
+                                            let m2 = &candidates[j];
+
+                                            // Strengthen in Qdrant (Krotov-Hopfield)
+                                            if db
+                                                .strengthen_association(
+                                                    &m1.id, &m2.id, 1.0, // x (active)
+                                                    1.0, // y (active)
+                                                    1.0, // reward (neutral in sleep)
+                                                )
+                                                .await
+                                                .is_ok()
+                                            {
+                                                associations_strengthened += 1;
+
+                                                // Dual-write: RedisGraph, buffered rather
+                                                // than one round trip per edge.
+                                                if let Some(ref buffer) = edge_buffer {
+                                                    let _ = buffer.push(
+                                                        m1.id,
+                                                        m2.id,
+                                                        0.1, // Placeholder for weight - ideally fetch from assoc
+                                                        daneel::memory_db::types::AssociationType::Semantic
+                                                    ).await;
+                                                }
                                             }
                                         }
                                     }
+
+                                    // Flush whatever this batch queued so associations
+                                    // land before the nap's summary is persisted below,
+                                    // even if the buffer's own thresholds weren't hit.
+                                    if let Some(ref buffer) = edge_buffer {
+                                        let _ = buffer.flush().await;
+                                    }
+
+                                    // REM stage (prioritize_emotional): synthesize a dream
+                                    // fragment from the memory this batch replayed hardest,
+                                    // for the observatory's philosophy banner.
+                                    if params.prioritize_emotional {
+                                        if let Some(fragment) =
+                                            daneel::dreams::synthesize_fragment(&candidates)
+                                        {
+                                            dream_fragment = Some(fragment);
+                                        }
+                                    }
+
+                                    total_replayed += candidates.len() as u32;
+                                    total_consolidated += consolidated;
+                                    total_associations += associations_strengthened;
+                                    priority_sum +=
+                                        candidates.iter().map(|m| m.semantic_salience).sum::<f32>();
+
+                                    let is_last_batch =
+                                        batch_num + 1 >= sleep_config.max_nap_batches;
+                                    if !is_last_batch {
+                                        // Check whether an external stimulus already
+                                        // woke the actor before starting another batch.
+                                        let still_napping = sleep
+                                            .call(|reply| SleepMessage::GetState { reply }, None)
+                                            .await
+                                            .ok()
+                                            .and_then(|r| r.success_or(()).ok())
+                                            .is_some_and(|s| {
+                                                s != daneel::actors::sleep::types::SleepState::Waking
+                                            });
+                                        if !still_napping {
+                                            interrupted = true;
+                                            break;
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    tracing::warn!("Failed to get replay candidates: {e}");
+                                    interrupted = true;
+                                    break;
                                 }
+                            }
+                        }
+
+                        cycle_report.memories_replayed = total_replayed;
+                        cycle_report.memories_consolidated = total_consolidated;
+                        cycle_report.associations_strengthened = total_associations;
+                        cycle_report.avg_replay_priority = if total_replayed == 0 {
+                            0.0
+                        } else {
+                            priority_sum / total_replayed as f32
+                        };
+                        if interrupted {
+                            cycle_report.interrupt();
+                        } else {
+                            cycle_report.complete();
+                        }
+
+                        if total_consolidated > 0 {
+                            total_dream_cycles += 1;
 
-                                if consolidated > 0 {
-                                    total_dream_cycles += 1;
+                            // "Nada se apaga" - record dream in identity
+                            if let Some(ref mut id) = identity {
+                                id.record_dream(total_consolidated, total_replayed);
+                            }
 
-                                    // "Nada se apaga" - record dream in identity
-                                    if let Some(ref mut id) = identity {
-                                        id.record_dream(consolidated, candidates.len() as u32);
+                            info!(
+                                "Nap #{}: consolidated {} memories, {} associations (via SleepActor)",
+                                total_dream_cycles, total_consolidated, total_associations
+                            );
+
+                            // Rotate the dream fragment (if REM produced one) into the
+                            // observatory's philosophy banner feed.
+                            if let Some(fragment) = dream_fragment {
+                                let dream_redis_url = redis_url.clone();
+                                tokio::spawn(async move {
+                                    if let Ok(client) = redis::Client::open(dream_redis_url.as_str())
+                                    {
+                                        if let Ok(mut conn) =
+                                            client.get_multiplexed_async_connection().await
+                                        {
+                                            use redis::AsyncCommands;
+                                            let _: Result<(), _> = conn
+                                                .lpush("daneel:dream_fragments", &fragment)
+                                                .await;
+                                            let _: Result<(), _> = conn
+                                                .ltrim("daneel:dream_fragments", 0, 19)
+                                                .await;
+                                        }
                                     }
+                                });
+                            }
 
-                                    info!(
-                                        "Mini-dream #{}: consolidated {} memories, {} associations (via SleepActor)",
-                                        total_dream_cycles, consolidated, associations_strengthened
-                                    );
-
-                                    // VCONN-7: Manifold Clustering
-                                    // Occasionally re-cluster memories to discover emergent themes
-                                    if total_dream_cycles.is_multiple_of(5) {
-                                        let db_clone = db.clone();
-                                        let redis_url_clone = redis_url.clone();
-                                        tokio::spawn(async move {
-                                            match db_clone.cluster_memories(10).await {
-                                                Ok(silhouette) => {
-                                                    tracing::info!(
-                                                        silhouette = silhouette,
-                                                        "Manifold clustering complete (K=10)"
-                                                    );
-                                                    // Store silhouette in Redis for dashboard
-                                                    if let Ok(client) = redis::Client::open(
-                                                        redis_url_clone.as_str(),
-                                                    ) {
-                                                        if let Ok(mut conn) = client
-                                                            .get_multiplexed_async_connection()
-                                                            .await
-                                                        {
-                                                            use redis::AsyncCommands;
-                                                            let _: Result<(), _> = conn
-                                                                .hset(
-                                                                    "daneel:metrics",
-                                                                    "silhouette",
-                                                                    silhouette.to_string(),
-                                                                )
-                                                                .await;
-                                                            let _: Result<(), _> = conn
-                                                                .hset(
-                                                                    "daneel:metrics",
-                                                                    "silhouette_updated_at",
-                                                                    chrono::Utc::now().to_rfc3339(),
-                                                                )
-                                                                .await;
-                                                        }
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    tracing::warn!(
-                                                        "Manifold clustering failed: {}",
-                                                        e
-                                                    );
+                            // VCONN-7: Manifold Clustering
+                            // Occasionally re-cluster memories to discover emergent themes
+                            if total_dream_cycles.is_multiple_of(5) {
+                                let db_clone = db.clone();
+                                let redis_url_clone = redis_url.clone();
+                                tokio::spawn(async move {
+                                    match db_clone.cluster_memories(10).await {
+                                        Ok(silhouette) => {
+                                            tracing::info!(
+                                                silhouette = silhouette,
+                                                "Manifold clustering complete (K=10)"
+                                            );
+                                            // Store silhouette in Redis for dashboard
+                                            if let Ok(client) = redis::Client::open(
+                                                redis_url_clone.as_str(),
+                                            ) {
+                                                if let Ok(mut conn) = client
+                                                    .get_multiplexed_async_connection()
+                                                    .await
+                                                {
+                                                    use redis::AsyncCommands;
+                                                    let _: Result<(), _> = conn
+                                                        .hset(
+                                                            "daneel:metrics",
+                                                            "silhouette",
+                                                            silhouette.to_string(),
+                                                        )
+                                                        .await;
+                                                    let _: Result<(), _> = conn
+                                                        .hset(
+                                                            "daneel:metrics",
+                                                            "silhouette_updated_at",
+                                                            chrono::Utc::now().to_rfc3339(),
+                                                        )
+                                                        .await;
                                                 }
                                             }
-                                        });
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!(
+                                                "Manifold clustering failed: {}",
+                                                e
+                                            );
+                                        }
                                     }
-                                }
-                            }
-                            Err(e) => {
-                                tracing::warn!("Failed to get replay candidates: {e}");
+                                });
                             }
                         }
+
+                        if let Err(e) = db.save_sleep_cycle(&cycle_report).await {
+                            tracing::warn!("Failed to persist sleep cycle report: {e}");
+                        }
                     }
 
-                    // Wake up
-                    let _ = sleep.call(|reply| SleepMessage::Wake { reply }, None).await;
+                    // Wake up - log anything that arrived during protected
+                    // sleep and was queued instead of dropped (ADR-023).
+                    // Replaying it back through the injection path is left
+                    // to whatever wires `SleepMessage::ExternalStimulus` up
+                    // to a real stimulus source; the actor's own job ends at
+                    // not losing it.
+                    let wake_summary = sleep
+                        .call(|reply| SleepMessage::Wake { reply }, None)
+                        .await
+                        .ok()
+                        .and_then(|r| r.success_or(()).ok());
+                    if let Some(summary) = wake_summary {
+                        if summary.queued_stimuli_replayed > 0 {
+                            info!(
+                                "Woke with {} stimuli queued during protected sleep",
+                                summary.queued_stimuli_replayed
+                            );
+                        }
+                    }
                 }
             }
         }
 
         // Periodic status log
-        if cycles.is_multiple_of(STATUS_LOG_INTERVAL) {
+        if cycles.is_multiple_of(status_interval) {
             let lifetime = identity.as_ref().map_or(0, |id| id.lifetime_thought_count);
-            info!(
-                "Status: {} cycles this session, {} lifetime thoughts, {} dreams",
-                cycles, lifetime, total_dream_cycles
-            );
+            match output_mode {
+                OutputMode::Human => {
+                    info!(
+                        "Status: {} cycles this session, {} lifetime thoughts, {} dreams",
+                        cycles, lifetime, total_dream_cycles
+                    );
+                }
+                OutputMode::JsonLines => {
+                    let stats = daneel::telemetry::AggregateStats::from_loop(
+                        cognitive_loop.history(),
+                        cognitive_loop.volition_stats(),
+                    );
+                    let line = StatusLine {
+                        cycles,
+                        lifetime_thoughts: lifetime,
+                        dream_cycles: total_dream_cycles,
+                        entropy: stats.entropy,
+                        veto: stats.veto,
+                    };
+                    match serde_json::to_string(&line) {
+                        Ok(json) => println!("{json}"),
+                        Err(e) => tracing::warn!("Failed to serialize status line: {e}"),
+                    }
+                }
+            }
+        }
+
+        // Periodic telemetry report, piggybacking on the status-log cadence
+        if let Some(ref reporter) = telemetry_reporter {
+            if cycles.is_multiple_of(status_interval) {
+                let mut stats = daneel::telemetry::AggregateStats::from_loop(
+                    cognitive_loop.history(),
+                    cognitive_loop.volition_stats(),
+                );
+                if let Some(ref db) = memory_db {
+                    if let Ok(sleep_history) = db.load_sleep_history(20).await {
+                        stats = stats.with_sleep_history(&sleep_history);
+                    }
+                }
+                let reporter = reporter.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = reporter.report(&stats).await {
+                        tracing::warn!("Telemetry report failed: {e}");
+                    }
+                });
+            }
         }
     }
 }
@@ -0,0 +1,181 @@
+//! In-process memory budget accounting and caps
+//!
+//! A handful of long-lived in-memory collections grow for as long as the
+//! process runs: [`crate::actors::continuity`]'s recorded experiences and
+//! checkpoints, [`crate::actors::attention`]'s attention map, and each open
+//! [`crate::core::types::Window`]'s accumulated contents. None of them had a
+//! byte budget before this module - only [`crate::actors::memory`]'s window
+//! *count* is capped (`MAX_MEMORY_WINDOWS`, a THE BOX invariant), and
+//! [`crate::actors::thought::types::ThoughtCache`] already caps its own
+//! entry count. This module gives the rest a shared, testable way to
+//! estimate how many bytes a collection holds and evict its oldest entries
+//! once a configurable cap is crossed.
+//!
+//! # Scope
+//!
+//! Byte sizes are estimated by serializing a value to JSON and taking its
+//! length - cheap, requires no per-type instrumentation, and tracks actual
+//! content growth (long experience descriptions, big window contents)
+//! closely enough to budget against, though it isn't exact heap size.
+//!
+//! The original ask also named an "embedding cache" and a TUI status bar.
+//! There's no in-memory embedding cache in this tree - [`crate::embeddings`]
+//! only caches the *model* on disk (`DANEEL_MODEL_CACHE_DIR`), not computed
+//! vectors - and the TUI was removed in favor of `daneel-web` (ADR-053), so
+//! neither has anything to wire up here. Usage for the collections this
+//! module does cover is only surfaced through `/extended_metrics` for
+//! continuity's experiences/checkpoints today, since `AppState` doesn't
+//! hold a handle to the memory or attention actors; exposing theirs is left
+//! for whoever adds those handles.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Estimated byte size of a serializable value, via its JSON encoding.
+/// Returns `0` if the value can't be serialized (shouldn't happen for the
+/// `Serialize`-deriving types this is used against).
+#[must_use]
+pub fn estimate_bytes<T: serde::Serialize>(value: &T) -> usize {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len())
+}
+
+/// One collection's current usage against its configured cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CategoryUsage {
+    pub category: &'static str,
+    pub bytes: usize,
+    pub cap_bytes: usize,
+}
+
+impl CategoryUsage {
+    #[must_use]
+    pub const fn is_over_cap(&self) -> bool {
+        self.bytes > self.cap_bytes
+    }
+}
+
+/// Default per-category byte caps.
+///
+/// These are generous defaults sized for a single long-running identity
+/// process, not hard architectural limits - callers can construct their own
+/// `BudgetCaps` to tune them.
+#[derive(Debug, Clone, Copy)]
+pub struct BudgetCaps {
+    pub experiences_bytes: usize,
+    pub checkpoints_bytes: usize,
+    pub attention_bytes: usize,
+    pub window_contents_bytes: usize,
+}
+
+impl Default for BudgetCaps {
+    fn default() -> Self {
+        Self {
+            experiences_bytes: 64 * 1024 * 1024,
+            checkpoints_bytes: 64 * 1024 * 1024,
+            attention_bytes: 4 * 1024 * 1024,
+            window_contents_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Evict the oldest entries of `map` (oldest-first per `order`) until its
+/// total estimated size is at or under `cap_bytes`. `order` must track
+/// insertion order for `map`'s keys - this doesn't touch `map` without
+/// removing the matching entry from `order`, so the two stay in sync.
+///
+/// Returns the number of entries evicted.
+pub fn evict_oldest_until_under_cap<K, V>(map: &mut HashMap<K, V>, order: &mut Vec<K>, cap_bytes: usize) -> usize
+where
+    K: Eq + Hash + Clone,
+    V: serde::Serialize,
+{
+    let mut total: usize = map.values().map(estimate_bytes).sum();
+    let mut evicted = 0;
+
+    while total > cap_bytes && !order.is_empty() {
+        let oldest = order.remove(0);
+        if let Some(value) = map.remove(&oldest) {
+            total = total.saturating_sub(estimate_bytes(&value));
+            evicted += 1;
+        }
+    }
+
+    evicted
+}
+
+/// Evict the oldest entries of `contents` (index 0 = oldest) until its total
+/// estimated size is at or under `cap_bytes`. Returns the number evicted.
+pub fn evict_oldest_contents_until_under_cap<T: serde::Serialize>(contents: &mut Vec<T>, cap_bytes: usize) -> usize {
+    let mut total: usize = contents.iter().map(estimate_bytes).sum();
+    let mut evicted = 0;
+
+    while total > cap_bytes && !contents.is_empty() {
+        let removed = contents.remove(0);
+        total = total.saturating_sub(estimate_bytes(&removed));
+        evicted += 1;
+    }
+
+    evicted
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_bytes_grows_with_content() {
+        let short = estimate_bytes(&"a".to_string());
+        let long = estimate_bytes(&"a".repeat(1000));
+        assert!(long > short);
+    }
+
+    #[test]
+    fn category_usage_over_cap() {
+        let usage = CategoryUsage { category: "test", bytes: 101, cap_bytes: 100 };
+        assert!(usage.is_over_cap());
+        let usage = CategoryUsage { category: "test", bytes: 100, cap_bytes: 100 };
+        assert!(!usage.is_over_cap());
+    }
+
+    #[test]
+    fn evict_oldest_until_under_cap_removes_oldest_first() {
+        let mut map: HashMap<u32, String> = HashMap::new();
+        let mut order = Vec::new();
+        for i in 0..10u32 {
+            map.insert(i, "x".repeat(100));
+            order.push(i);
+        }
+
+        let total_before: usize = map.values().map(estimate_bytes).sum();
+        let evicted = evict_oldest_until_under_cap(&mut map, &mut order, total_before / 2);
+
+        assert!(evicted > 0);
+        assert!(!map.contains_key(&0), "oldest entry should be evicted first");
+        assert_eq!(map.len(), order.len());
+    }
+
+    #[test]
+    fn evict_oldest_until_under_cap_noop_when_under_cap() {
+        let mut map: HashMap<u32, String> = HashMap::new();
+        let mut order = Vec::new();
+        map.insert(1, "small".to_string());
+        order.push(1);
+
+        let evicted = evict_oldest_until_under_cap(&mut map, &mut order, 1024 * 1024);
+        assert_eq!(evicted, 0);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn evict_oldest_contents_until_under_cap_removes_from_front() {
+        let mut contents: Vec<String> = (0..10).map(|i| format!("entry-{i}").repeat(20)).collect();
+        let oldest = contents[0].clone();
+        let total_before: usize = contents.iter().map(estimate_bytes).sum();
+
+        let evicted = evict_oldest_contents_until_under_cap(&mut contents, total_before / 2);
+
+        assert!(evicted > 0);
+        assert!(!contents.contains(&oldest));
+    }
+}
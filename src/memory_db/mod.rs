@@ -13,6 +13,10 @@
 //! - `episodes`: Event boundaries (Door Syndrome segmentation)
 //! - `identity`: Timmy's persistent self-concept (singleton)
 //!
+//! Collection names above are for the default identity namespace.
+//! `connect_with_profile` suffixes each one with `__{profile}` for
+//! non-default profiles - see [`crate::profile`].
+//!
 //! # Usage
 //!
 //! ```no_run
@@ -31,22 +35,31 @@
 //! # }
 //! ```
 
+pub mod store;
 pub mod types;
 
 #[cfg(test)]
 mod tests;
 
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use crate::actors::continuity::types::Experience;
+use crate::core::types::SalienceWeights;
+use chrono::{DateTime, Utc};
 use linfa::prelude::*;
 use linfa_clustering::KMeans;
 use ndarray::Array2;
 use qdrant_client::qdrant::{
-    Condition, CreateCollectionBuilder, Distance, Filter, PointStruct, ScrollPointsBuilder,
-    SearchPointsBuilder, UpsertPointsBuilder, VectorParamsBuilder,
+    Condition, CreateCollectionBuilder, CreateFieldIndexCollectionBuilder, DeletePointsBuilder,
+    Distance, FieldType, Filter, PointStruct, ScrollPointsBuilder, SearchPointsBuilder,
+    UpsertPointsBuilder, VectorParamsBuilder,
 };
 use qdrant_client::Qdrant;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::collections::HashMap;
 use thiserror::Error;
 
+pub use store::{MemoryBackend, MockMemoryBackend};
 pub use types::*;
 
 /// Memory database errors
@@ -72,11 +85,34 @@ pub enum MemoryDbError {
 
     #[error("Collection not found: {0}")]
     CollectionNotFound(String),
+
+    #[error("Decompression failed: {0}")]
+    Decompression(String),
 }
 
 /// Result type for memory database operations
 pub type Result<T> = std::result::Result<T, MemoryDbError>;
 
+/// One hit from [`MemoryDb::federated_search`], tagged with the profile
+/// whose collection it came from.
+#[derive(Debug, Clone)]
+pub struct FederatedHit {
+    pub profile: crate::profile::Profile,
+    pub memory: Memory,
+    pub score: f32,
+}
+
+/// Outcome of [`MemoryDb::rescore_memories`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RescoreReport {
+    /// Memories whose `semantic_salience` and `salience_explanation.weights`
+    /// were recomputed under the new weights
+    pub updated: u32,
+    /// Memories with no [`SalienceExplanation`] to recompute from, left
+    /// untouched
+    pub skipped: u32,
+}
+
 /// Collection names
 pub mod collections {
     pub const MEMORIES: &str = "memories";
@@ -85,6 +121,85 @@ pub mod collections {
     /// Unconscious memory (ADR-033): Archived low-salience thoughts
     /// TMI: "Nada se apaga" - nothing is erased, just made inaccessible
     pub const UNCONSCIOUS: &str = "unconscious";
+    /// Right-to-forget audit trail: tombstones for legally deleted memories.
+    /// Unlike the other collections, this one never stores content.
+    pub const TOMBSTONES: &str = "tombstones";
+    /// Sleep cycle history (ADR-023): durable record of each consolidation
+    /// cycle, so consolidation quality can be tracked across nights.
+    pub const SLEEP_CYCLES: &str = "sleep_cycles";
+    /// `ContinuityActor`'s experience log, persisted the same way memories
+    /// are so it outlives the bounded in-memory `HashMap` it's recorded
+    /// into first - see `crate::actors::continuity::store::ContinuityStore`.
+    pub const EXPERIENCES: &str = "experiences";
+
+    /// Every collection DANEEL creates, for operations that touch all of
+    /// them (e.g. `MemoryDb::create_snapshots`).
+    pub const ALL: [&str; 7] =
+        [MEMORIES, EPISODES, IDENTITY, UNCONSCIOUS, TOMBSTONES, SLEEP_CYCLES, EXPERIENCES];
+}
+
+/// Payload fields that common `MEMORIES` queries filter or range on, and the
+/// Qdrant field-index type each one needs. Indexing these lets Qdrant narrow
+/// candidates from the payload before falling back to a full vector/payload
+/// scan - without them, salience-range, consolidation, source, and time
+/// filters (e.g. the replay-candidate queries in this module) degrade to
+/// scanning every point.
+///
+/// See [`MemoryDb::ensure_memory_indexes`] and
+/// [`MemoryDb::audit_memory_indexes`].
+pub mod indexes {
+    use super::FieldType;
+
+    /// `(payload field path, expected index type)` for every field indexed
+    /// on the `MEMORIES` collection.
+    pub const MEMORIES: &[(&str, FieldType)] = &[
+        ("semantic_salience", FieldType::Float),
+        ("consolidation.consolidation_tag", FieldType::Bool),
+        ("consolidation.strength", FieldType::Float),
+        ("source.type", FieldType::Keyword),
+        ("encoded_at", FieldType::Datetime),
+    ];
+}
+
+/// Compress the `content` field of a payload in place, if present and
+/// large enough to be worth it (see [`crate::compression`]). A no-op for
+/// payloads without a `content` field (e.g. identity, tombstones).
+fn compress_content_field(payload: &mut serde_json::Value) {
+    let Some(obj) = payload.as_object_mut() else {
+        return;
+    };
+    let Some(serde_json::Value::String(text)) = obj.get("content") else {
+        return;
+    };
+    let (compressed, did_compress) = crate::compression::compress(text.as_bytes());
+    if did_compress {
+        obj.insert(
+            "content".to_string(),
+            serde_json::Value::String(BASE64.encode(compressed)),
+        );
+        obj.insert("content_z".to_string(), serde_json::Value::Bool(true));
+    }
+}
+
+/// Reverse [`compress_content_field`].
+fn decompress_content_field(payload: &mut serde_json::Value) -> Result<()> {
+    let Some(obj) = payload.as_object_mut() else {
+        return Ok(());
+    };
+    if !matches!(obj.remove("content_z"), Some(serde_json::Value::Bool(true))) {
+        return Ok(());
+    }
+    let Some(serde_json::Value::String(encoded)) = obj.get("content").cloned() else {
+        return Ok(());
+    };
+    let compressed = BASE64
+        .decode(encoded)
+        .map_err(|e| MemoryDbError::Decompression(e.to_string()))?;
+    let bytes = crate::compression::decompress(&compressed, true)
+        .map_err(|e| MemoryDbError::Decompression(e.to_string()))?;
+    let text = String::from_utf8(bytes).map_err(|e| MemoryDbError::Decompression(e.to_string()))?;
+    obj.insert("content".to_string(), serde_json::Value::String(text));
+    Ok(())
 }
 
 /// Memory database client
@@ -92,6 +207,7 @@ pub mod collections {
 /// Wraps Qdrant client with TMI-specific operations.
 pub struct MemoryDb {
     client: Qdrant,
+    profile: crate::profile::Profile,
 }
 
 impl MemoryDb {
@@ -109,8 +225,21 @@ impl MemoryDb {
     #[allow(clippy::unused_async)]
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn connect(url: &str) -> Result<Self> {
+        Self::connect_with_profile(url, crate::profile::Profile::default()).await
+    }
+
+    /// Connect to Qdrant, namespacing every collection under `profile` (see
+    /// [`crate::profile`]). The default profile behaves exactly like
+    /// [`connect`](Self::connect).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if connection fails.
+    #[allow(clippy::unused_async)]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn connect_with_profile(url: &str, profile: crate::profile::Profile) -> Result<Self> {
         let client = Qdrant::from_url(url).build()?;
-        Ok(Self { client })
+        Ok(Self { client, profile })
     }
 
     /// Connect to Qdrant and initialize collections in one call
@@ -132,6 +261,67 @@ impl MemoryDb {
         Ok(db)
     }
 
+    /// [`connect_and_init`](Self::connect_and_init), namespaced under `profile`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if connection or collection creation fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn connect_and_init_with_profile(
+        url: &str,
+        profile: crate::profile::Profile,
+    ) -> Result<Self> {
+        let db = Self::connect_with_profile(url, profile).await?;
+        db.init_collections().await?;
+        Ok(db)
+    }
+
+    /// Resolve a bare collection name (see [`collections`]) to its
+    /// lab-prefixed, profile-namespaced form.
+    fn collection(&self, base: &str) -> String {
+        self.profile.namespace(&crate::namespace::collection(base))
+    }
+
+    /// Apply a point upsert, unless `--dry-run` is active (see
+    /// [`crate::dry_run`]), in which case the write is logged and skipped.
+    async fn upsert(&self, builder: UpsertPointsBuilder) -> Result<()> {
+        if crate::dry_run::is_enabled() {
+            tracing::info!("[dry-run] skipping Qdrant upsert");
+            return Ok(());
+        }
+        self.client.upsert_points(builder).await?;
+        Ok(())
+    }
+
+    /// Apply a point deletion, unless `--dry-run` is active (see
+    /// [`crate::dry_run`]), in which case the write is logged and skipped.
+    async fn delete(&self, builder: DeletePointsBuilder) -> Result<()> {
+        if crate::dry_run::is_enabled() {
+            tracing::info!("[dry-run] skipping Qdrant delete");
+            return Ok(());
+        }
+        self.client.delete_points(builder).await?;
+        Ok(())
+    }
+
+    /// Serialize `value` to a Qdrant payload map, compressing its
+    /// `content` field if present and large enough (see
+    /// [`crate::compression`]). Shared by [`Memory`] and
+    /// [`UnconsciousMemory`], which both store content as a plain string.
+    fn payload_of(value: &impl Serialize) -> Result<HashMap<String, serde_json::Value>> {
+        let mut json = serde_json::to_value(value)?;
+        compress_content_field(&mut json);
+        Ok(serde_json::from_value(json)?)
+    }
+
+    /// Deserialize a Qdrant payload into `T`, decompressing its `content`
+    /// field first if [`Self::payload_of`] compressed it on write.
+    fn from_payload<T: DeserializeOwned>(payload: impl Serialize) -> Result<T> {
+        let mut json = serde_json::to_value(payload)?;
+        decompress_content_field(&mut json)?;
+        Ok(serde_json::from_value(json)?)
+    }
+
     /// Initialize collections if they don't exist
     ///
     /// Creates:
@@ -145,10 +335,10 @@ impl MemoryDb {
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn init_collections(&self) -> Result<()> {
         // Check and create memories collection
-        if !self.collection_exists(collections::MEMORIES).await? {
+        if !self.collection_exists(&self.collection(collections::MEMORIES)).await? {
             self.client
                 .create_collection(
-                    CreateCollectionBuilder::new(collections::MEMORIES).vectors_config(
+                    CreateCollectionBuilder::new(self.collection(collections::MEMORIES)).vectors_config(
                         VectorParamsBuilder::new(VECTOR_DIMENSION as u64, Distance::Cosine),
                     ),
                 )
@@ -156,10 +346,10 @@ impl MemoryDb {
         }
 
         // Check and create episodes collection
-        if !self.collection_exists(collections::EPISODES).await? {
+        if !self.collection_exists(&self.collection(collections::EPISODES)).await? {
             self.client
                 .create_collection(
-                    CreateCollectionBuilder::new(collections::EPISODES).vectors_config(
+                    CreateCollectionBuilder::new(self.collection(collections::EPISODES)).vectors_config(
                         VectorParamsBuilder::new(VECTOR_DIMENSION as u64, Distance::Cosine),
                     ),
                 )
@@ -167,10 +357,10 @@ impl MemoryDb {
         }
 
         // Check and create identity collection
-        if !self.collection_exists(collections::IDENTITY).await? {
+        if !self.collection_exists(&self.collection(collections::IDENTITY)).await? {
             self.client
                 .create_collection(
-                    CreateCollectionBuilder::new(collections::IDENTITY).vectors_config(
+                    CreateCollectionBuilder::new(self.collection(collections::IDENTITY)).vectors_config(
                         VectorParamsBuilder::new(VECTOR_DIMENSION as u64, Distance::Cosine),
                     ),
                 )
@@ -179,19 +369,162 @@ impl MemoryDb {
 
         // Check and create unconscious collection (ADR-033)
         // TMI: "Nada se apaga" - low-salience thoughts archived here
-        if !self.collection_exists(collections::UNCONSCIOUS).await? {
+        if !self.collection_exists(&self.collection(collections::UNCONSCIOUS)).await? {
+            self.client
+                .create_collection(
+                    CreateCollectionBuilder::new(self.collection(collections::UNCONSCIOUS)).vectors_config(
+                        VectorParamsBuilder::new(VECTOR_DIMENSION as u64, Distance::Cosine),
+                    ),
+                )
+                .await?;
+        }
+
+        // Check and create tombstones collection (right-to-forget audit trail)
+        if !self.collection_exists(&self.collection(collections::TOMBSTONES)).await? {
             self.client
                 .create_collection(
-                    CreateCollectionBuilder::new(collections::UNCONSCIOUS).vectors_config(
+                    CreateCollectionBuilder::new(self.collection(collections::TOMBSTONES)).vectors_config(
                         VectorParamsBuilder::new(VECTOR_DIMENSION as u64, Distance::Cosine),
                     ),
                 )
                 .await?;
         }
 
+        // Check and create sleep cycles collection (ADR-023: consolidation history)
+        if !self.collection_exists(&self.collection(collections::SLEEP_CYCLES)).await? {
+            self.client
+                .create_collection(
+                    CreateCollectionBuilder::new(self.collection(collections::SLEEP_CYCLES))
+                        .vectors_config(VectorParamsBuilder::new(
+                            VECTOR_DIMENSION as u64,
+                            Distance::Cosine,
+                        )),
+                )
+                .await?;
+        }
+
+        // Check and create experiences collection (ContinuityActor's durable
+        // experience log - see `actors::continuity::store::ContinuityStore`)
+        if !self.collection_exists(&self.collection(collections::EXPERIENCES)).await? {
+            self.client
+                .create_collection(
+                    CreateCollectionBuilder::new(self.collection(collections::EXPERIENCES))
+                        .vectors_config(VectorParamsBuilder::new(
+                            VECTOR_DIMENSION as u64,
+                            Distance::Cosine,
+                        )),
+                )
+                .await?;
+        }
+
+        self.ensure_memory_indexes().await?;
+
         Ok(())
     }
 
+    /// Create (or confirm) the payload indexes [`indexes::MEMORIES`] expects
+    /// on the `MEMORIES` collection. Called at the end of `init_collections`
+    /// for fresh setups, and again by `daneel index repair` for deployments
+    /// that were provisioned before these indexes existed - Qdrant's
+    /// `create_field_index` is a no-op when the index is already present, so
+    /// this is safe to call repeatedly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MemoryDbError::Qdrant` if an index request fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn ensure_memory_indexes(&self) -> Result<()> {
+        let name = self.collection(collections::MEMORIES);
+        for (field, field_type) in indexes::MEMORIES {
+            self.client
+                .create_field_index(CreateFieldIndexCollectionBuilder::new(&name, *field, *field_type))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Report which of [`indexes::MEMORIES`] actually exist on the
+    /// `MEMORIES` collection right now - the read side of `daneel index
+    /// audit`/`daneel index repair`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MemoryDbError::Qdrant` if the collection info request fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn audit_memory_indexes(&self) -> Result<Vec<(&'static str, bool)>> {
+        let name = self.collection(collections::MEMORIES);
+        let info = self.client.collection_info(&name).await?;
+        let existing: std::collections::HashSet<String> = info
+            .result
+            .map(|r| r.payload_schema.keys().cloned().collect())
+            .unwrap_or_default();
+        Ok(indexes::MEMORIES
+            .iter()
+            .map(|(field, _)| (*field, existing.contains(*field)))
+            .collect())
+    }
+
+    /// Snapshot every collection via Qdrant's snapshot API (`daneel backup`).
+    ///
+    /// Returns the created snapshot's file name per collection that exists;
+    /// a collection that was never created (e.g. an unused profile) is
+    /// skipped rather than treated as an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MemoryDbError::Qdrant` if a snapshot request fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn create_snapshots(&self) -> Result<Vec<(String, String)>> {
+        let mut snapshots = Vec::new();
+        for base in collections::ALL {
+            let name = self.collection(base);
+            if !self.collection_exists(&name).await? {
+                continue;
+            }
+            let response = self.client.create_snapshot(&name).await?;
+            if let Some(description) = response.snapshot_description {
+                snapshots.push((name, description.name));
+            }
+        }
+        Ok(snapshots)
+    }
+
+    /// List every memory id currently in the `MEMORIES` collection.
+    ///
+    /// Used by `daneel::gc` to cross-reference Qdrant points against graph
+    /// nodes and find graph nodes whose backing memory is gone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MemoryDbError::Qdrant` if a scroll request fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn list_memory_ids(&self) -> Result<Vec<MemoryId>> {
+        let mut ids = Vec::new();
+        let mut offset: Option<qdrant_client::qdrant::PointId> = None;
+        loop {
+            let mut scroll = ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
+                .limit(200)
+                .with_payload(true);
+            if let Some(ref o) = offset {
+                scroll = scroll.offset(o.clone());
+            }
+            let result = self.client.scroll(scroll).await?;
+            if result.result.is_empty() {
+                break;
+            }
+            for point in &result.result {
+                if let Ok(memory) = Self::from_payload::<Memory>(&point.payload) {
+                    ids.push(memory.id);
+                }
+            }
+            offset = result.next_page_offset;
+            if offset.is_none() {
+                break;
+            }
+        }
+        Ok(ids)
+    }
+
     /// Check if a collection exists
     #[cfg_attr(coverage_nightly, coverage(off))]
     async fn collection_exists(&self, name: &str) -> Result<bool> {
@@ -220,12 +553,10 @@ impl MemoryDb {
             });
         }
 
-        let payload: HashMap<String, serde_json::Value> =
-            serde_json::from_value(serde_json::to_value(memory)?)?;
+        let payload = Self::payload_of(memory)?;
         let point = PointStruct::new(memory.id.0.to_string(), vector.to_vec(), payload);
 
-        self.client
-            .upsert_points(UpsertPointsBuilder::new(collections::MEMORIES, vec![point]).wait(true))
+        self.upsert(UpsertPointsBuilder::new(self.collection(collections::MEMORIES), vec![point]).wait(true))
             .await?;
 
         Ok(())
@@ -246,6 +577,12 @@ impl MemoryDb {
     /// # Errors
     ///
     /// Returns error if vector dimension is wrong or search fails.
+    ///
+    /// Searches both [`collections::MEMORIES`] and
+    /// [`collections::UNCONSCIOUS`] (ADR-033: low-salience memories are
+    /// archived there, not deleted) and merges the results, so a `--query`
+    /// right-to-forget lookup (see `run_forget`) finds an already-archived
+    /// match instead of reporting nothing found.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn find_by_context(
         &self,
@@ -260,28 +597,146 @@ impl MemoryDb {
             });
         }
 
-        let mut search =
-            SearchPointsBuilder::new(collections::MEMORIES, context_vector.to_vec(), limit)
-                .with_payload(true);
+        let mut memories = Vec::new();
+        for collection in [collections::MEMORIES, collections::UNCONSCIOUS] {
+            let mut search =
+                SearchPointsBuilder::new(self.collection(collection), context_vector.to_vec(), limit)
+                    .with_payload(true);
+
+            // Apply episode filter if specified
+            if let Some(ep_id) = episode_id {
+                search = search.filter(Filter::must([Condition::matches(
+                    "episode_id",
+                    ep_id.0.to_string(),
+                )]));
+            }
 
-        // Apply episode filter if specified
-        if let Some(ep_id) = episode_id {
-            search = search.filter(Filter::must([Condition::matches(
-                "episode_id",
-                ep_id.0.to_string(),
-            )]));
+            let results = self.client.search_points(search).await?;
+            for point in results.result {
+                let memory: Memory = Self::from_payload(point.payload)?;
+                memories.push((memory, point.score));
+            }
         }
 
-        let results = self.client.search_points(search).await?;
+        memories.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        #[allow(clippy::cast_possible_truncation)]
+        memories.truncate(limit as usize);
 
-        let mut memories = Vec::with_capacity(results.result.len());
-        for point in results.result {
-            let payload = point.payload;
-            let memory: Memory = serde_json::from_value(serde_json::to_value(payload)?)?;
-            memories.push((memory, point.score));
+        Ok(memories)
+    }
+
+    /// Persist an [`Experience`] with its context vector, the durable
+    /// counterpart to `ContinuityState::record_experience`'s in-memory,
+    /// crash-losing `HashMap` - see
+    /// `actors::continuity::store::ContinuityStore`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if vector dimension is wrong or storage fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn store_experience(&self, experience: &Experience, vector: &[f32]) -> Result<()> {
+        if vector.len() != VECTOR_DIMENSION {
+            return Err(MemoryDbError::InvalidVectorDimension {
+                expected: VECTOR_DIMENSION,
+                actual: vector.len(),
+            });
         }
 
-        Ok(memories)
+        let payload = Self::payload_of(experience)?;
+        let point = PointStruct::new(experience.id.0.to_string(), vector.to_vec(), payload);
+
+        self.upsert(
+            UpsertPointsBuilder::new(self.collection(collections::EXPERIENCES), vec![point]).wait(true),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Find persisted experiences recorded within `[start, end]`, for
+    /// `ContinuityState::get_timeline` to fall back to once a query range
+    /// reaches past what the in-memory window still holds.
+    ///
+    /// `recorded_at` isn't an indexed payload field (unlike `encoded_at` on
+    /// `MEMORIES`, see [`indexes::MEMORIES`]), so this scans up to `limit`
+    /// of the collection's points and filters by timestamp in Rust - the
+    /// same trade-off `ContinuityState::get_timeline` already makes for its
+    /// in-memory scan.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the Qdrant query fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn find_experiences_in_range(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<Experience>> {
+        let results = self
+            .client
+            .scroll(
+                ScrollPointsBuilder::new(self.collection(collections::EXPERIENCES))
+                    .limit(u32::try_from(limit).unwrap_or(u32::MAX))
+                    .with_payload(true),
+            )
+            .await?;
+
+        let mut experiences: Vec<Experience> = results
+            .result
+            .into_iter()
+            .filter_map(|point| Self::from_payload(point.payload).ok())
+            .filter(|exp: &Experience| exp.recorded_at >= start && exp.recorded_at <= end)
+            .collect();
+
+        experiences.sort_by_key(|exp| exp.recorded_at);
+
+        Ok(experiences)
+    }
+
+    /// Fan an embedded query out across several profiles' `MEMORIES`
+    /// collections (see [`crate::profile`]) and merge the results by
+    /// similarity score, each tagged with the profile it came from - e.g.
+    /// "have any of my Timmys encountered X?" across several
+    /// comparative-experiment identities sharing one Qdrant instance.
+    /// Read-only: no collections are created, and a profile with no
+    /// `MEMORIES` collection yet is skipped rather than treated as an error.
+    ///
+    /// Connects a fresh client per profile since collections are
+    /// profile-namespaced by name, not by a shared handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if connecting to `url`, or searching an existing
+    /// collection, fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn federated_search(
+        url: &str,
+        profiles: &[crate::profile::Profile],
+        query_vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<FederatedHit>> {
+        let mut hits = Vec::new();
+
+        for profile in profiles {
+            let db = Self::connect_with_profile(url, profile.clone()).await?;
+            if !db.collection_exists(&db.collection(collections::MEMORIES)).await? {
+                continue;
+            }
+
+            let matches = db
+                .find_by_context(query_vector, None, limit.try_into().unwrap_or(u64::MAX))
+                .await?;
+            hits.extend(matches.into_iter().map(|(memory, score)| FederatedHit {
+                profile: profile.clone(),
+                memory,
+                score,
+            }));
+        }
+
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit);
+        Ok(hits)
     }
 
     /// Get memories tagged for consolidation (sleep replay candidates)
@@ -316,7 +771,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::MEMORIES)
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
                     .filter(filter)
                     .limit(limit)
                     .with_payload(true),
@@ -326,9 +781,7 @@ impl MemoryDb {
         let mut memories: Vec<Memory> = results
             .result
             .into_iter()
-            .filter_map(|point| {
-                serde_json::from_value(serde_json::to_value(point.payload).ok()?).ok()
-            })
+            .filter_map(|point| Self::from_payload(point.payload).ok())
             .collect();
 
         // Sort by replay priority (highest first)
@@ -358,7 +811,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::MEMORIES)
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
                     .filter(Filter::must([Condition::matches(
                         "id",
                         memory_id.0.to_string(),
@@ -374,7 +827,7 @@ impl MemoryDb {
         }
 
         let point = &results.result[0];
-        let mut memory: Memory = serde_json::from_value(serde_json::to_value(&point.payload)?)?;
+        let mut memory: Memory = Self::from_payload(&point.payload)?;
 
         // Update consolidation state
         memory.consolidation.strength = (memory.consolidation.strength + strength_delta).min(1.0);
@@ -417,7 +870,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::MEMORIES)
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
                     .limit(10000)
                     .with_vectors(true)
                     .with_payload(true),
@@ -449,7 +902,7 @@ impl MemoryDb {
                 data[[i, j]] = val;
             }
 
-            let memory: Memory = serde_json::from_value(serde_json::to_value(&point.payload)?)?;
+            let memory: Memory = Self::from_payload(&point.payload)?;
             point_info.push((memory, vector));
         }
 
@@ -495,6 +948,77 @@ impl MemoryDb {
         Ok(silhouette)
     }
 
+    /// Recompute every stored memory's `semantic_salience` under new
+    /// [`SalienceWeights`] - when weights change (learning via
+    /// `actors::salience::calibrate`, or an operator-reviewed update fed
+    /// back via `SalienceMessage::UpdateWeights`), memories consolidated
+    /// under the old weights otherwise keep scoring by a composite that no
+    /// longer reflects what the system currently values.
+    ///
+    /// Only memories carrying a [`SalienceExplanation`] (see
+    /// [`CognitiveLoop::thought_to_memory`](crate::core::cognitive_loop::CognitiveLoop::thought_to_memory))
+    /// can be re-scored, since that's what freezes the per-component
+    /// breakdown the new weights are combined with; the explanation's
+    /// `weights` field is updated to `weights` too, so it always reflects
+    /// what last produced `semantic_salience`. Memories from before that
+    /// field existed are left untouched and counted in the returned
+    /// report's `skipped` count rather than silently dropped.
+    ///
+    /// This is I/O-heavy (a full collection scroll plus one upsert per
+    /// re-scored memory) and intended to run during a sleep/nap cycle, not
+    /// on the hot cognitive-loop path - see `daneel sleep rescore`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Qdrant scroll or any payload update fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn rescore_memories(&self, weights: SalienceWeights) -> Result<RescoreReport> {
+        let results = self
+            .client
+            .scroll(
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
+                    .limit(10000)
+                    .with_vectors(true)
+                    .with_payload(true),
+            )
+            .await?;
+
+        let mut report = RescoreReport::default();
+
+        for point in &results.result {
+            let mut memory: Memory = Self::from_payload(&point.payload)?;
+
+            let Some(mut explanation) = memory.salience_explanation else {
+                report.skipped += 1;
+                continue;
+            };
+
+            memory.semantic_salience = explanation.score.composite(&weights);
+            explanation.weights = weights;
+            memory.salience_explanation = Some(explanation);
+
+            let vector: Vec<f32> = point
+                .vectors
+                .as_ref()
+                .and_then(qdrant_client::qdrant::VectorsOutput::get_vector)
+                .and_then(|v| match v {
+                    qdrant_client::qdrant::vector_output::Vector::Dense(dense) => Some(dense.data),
+                    _ => None,
+                })
+                .unwrap_or_else(|| vec![0.0; VECTOR_DIMENSION]);
+
+            self.store_memory(&memory, &vector).await?;
+            report.updated += 1;
+        }
+
+        tracing::info!(
+            updated = report.updated,
+            skipped = report.skipped,
+            "Re-scored stored memories under new salience weights"
+        );
+        Ok(report)
+    }
+
     /// Migrate old memories to add missing fields (`theta_m`, `cluster_id`)
     ///
     /// This is a one-time migration for memories created before these fields existed.
@@ -510,7 +1034,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::MEMORIES)
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
                     .limit(10000)
                     .with_payload(true)
                     .with_vectors(true),
@@ -521,8 +1045,7 @@ impl MemoryDb {
 
         for point in results.result {
             // Deserialize with defaults (theta_m will get 0.1 if missing)
-            let payload_json = serde_json::to_value(&point.payload)?;
-            let memory: Memory = serde_json::from_value(payload_json)?;
+            let memory: Memory = Self::from_payload(&point.payload)?;
 
             let vector: Option<Vec<f32>> = point
                 .vectors
@@ -639,8 +1162,7 @@ impl MemoryDb {
             serde_json::from_value(serde_json::to_value(episode)?)?;
         let point = PointStruct::new(episode.id.0.to_string(), vector.to_vec(), payload);
 
-        self.client
-            .upsert_points(UpsertPointsBuilder::new(collections::EPISODES, vec![point]))
+        self.upsert(UpsertPointsBuilder::new(self.collection(collections::EPISODES), vec![point]))
             .await?;
 
         Ok(())
@@ -658,7 +1180,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::EPISODES)
+                ScrollPointsBuilder::new(self.collection(collections::EPISODES))
                     .filter(filter)
                     .limit(1)
                     .with_payload(true),
@@ -707,7 +1229,7 @@ impl MemoryDb {
     /// Returns error if Qdrant query fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn memory_count(&self) -> Result<u64> {
-        let info = self.client.collection_info(collections::MEMORIES).await?;
+        let info = self.client.collection_info(self.collection(collections::MEMORIES)).await?;
         Ok(info.result.and_then(|r| r.points_count).unwrap_or(0))
     }
 
@@ -718,7 +1240,7 @@ impl MemoryDb {
     /// Returns error if Qdrant query fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn episode_count(&self) -> Result<u64> {
-        let info = self.client.collection_info(collections::EPISODES).await?;
+        let info = self.client.collection_info(self.collection(collections::EPISODES)).await?;
         Ok(info.result.and_then(|r| r.points_count).unwrap_or(0))
     }
 
@@ -731,7 +1253,7 @@ impl MemoryDb {
     pub async fn unconscious_count(&self) -> Result<u64> {
         let info = self
             .client
-            .collection_info(collections::UNCONSCIOUS)
+            .collection_info(self.collection(collections::UNCONSCIOUS))
             .await?;
         Ok(info.result.and_then(|r| r.points_count).unwrap_or(0))
     }
@@ -772,8 +1294,7 @@ impl MemoryDb {
         );
 
         // Create payload from struct
-        let payload: HashMap<String, serde_json::Value> =
-            serde_json::from_value(serde_json::to_value(&memory)?)?;
+        let payload = Self::payload_of(&memory)?;
 
         // Use a zero vector for now - unconscious memories are not retrieved by similarity
         // Future: could embed with low-dimensional representation
@@ -782,11 +1303,10 @@ impl MemoryDb {
 
         let memory_id = memory.id;
 
-        self.client
-            .upsert_points(
-                UpsertPointsBuilder::new(collections::UNCONSCIOUS, vec![point]).wait(true),
-            )
-            .await?;
+        self.upsert(
+            UpsertPointsBuilder::new(self.collection(collections::UNCONSCIOUS), vec![point]).wait(true),
+        )
+        .await?;
 
         Ok(memory_id)
     }
@@ -817,7 +1337,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::UNCONSCIOUS)
+                ScrollPointsBuilder::new(self.collection(collections::UNCONSCIOUS))
                     .limit(limit)
                     .with_payload(true),
             )
@@ -826,9 +1346,7 @@ impl MemoryDb {
         let mut memories: Vec<UnconsciousMemory> = results
             .result
             .into_iter()
-            .filter_map(|point| {
-                serde_json::from_value(serde_json::to_value(point.payload).ok()?).ok()
-            })
+            .filter_map(|point| Self::from_payload(point.payload).ok())
             .collect();
 
         // Sort by archived_at (oldest first - FIFO for dream processing)
@@ -860,7 +1378,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::UNCONSCIOUS)
+                ScrollPointsBuilder::new(self.collection(collections::UNCONSCIOUS))
                     .limit(limit.saturating_mul(10)) // Fetch more to filter
                     .with_payload(true),
             )
@@ -871,8 +1389,7 @@ impl MemoryDb {
             .result
             .into_iter()
             .filter_map(|point| {
-                let memory: UnconsciousMemory =
-                    serde_json::from_value(serde_json::to_value(point.payload).ok()?).ok()?;
+                let memory: UnconsciousMemory = Self::from_payload(point.payload).ok()?;
                 // Case-insensitive content match
                 if memory.content.to_lowercase().contains(&pattern_lower) {
                     Some(memory)
@@ -903,7 +1420,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::UNCONSCIOUS)
+                ScrollPointsBuilder::new(self.collection(collections::UNCONSCIOUS))
                     .limit(limit.saturating_mul(3)) // Fetch extra for better randomness
                     .with_payload(true),
             )
@@ -912,9 +1429,7 @@ impl MemoryDb {
         let mut memories: Vec<UnconsciousMemory> = results
             .result
             .into_iter()
-            .filter_map(|point| {
-                serde_json::from_value(serde_json::to_value(point.payload).ok()?).ok()
-            })
+            .filter_map(|point| Self::from_payload(point.payload).ok())
             .collect();
 
         // Shuffle for randomness and truncate to limit
@@ -938,7 +1453,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::UNCONSCIOUS)
+                ScrollPointsBuilder::new(self.collection(collections::UNCONSCIOUS))
                     .filter(Filter::must([Condition::matches(
                         "id",
                         memory_id.0.to_string(),
@@ -953,25 +1468,23 @@ impl MemoryDb {
         }
 
         let point = &results.result[0];
-        let mut memory: UnconsciousMemory =
-            serde_json::from_value(serde_json::to_value(&point.payload)?)?;
+        let mut memory: UnconsciousMemory = Self::from_payload(&point.payload)?;
 
         // Update surfacing state
         memory.mark_surfaced();
 
         // Create updated payload
-        let payload: HashMap<String, serde_json::Value> =
-            serde_json::from_value(serde_json::to_value(&memory)?)?;
+        let payload = Self::payload_of(&memory)?;
 
         // Store with zero vector (unconscious doesn't use embeddings yet)
         let vector = vec![0.0; VECTOR_DIMENSION];
         let updated_point = PointStruct::new(memory.id.0.to_string(), vector, payload);
 
-        self.client
-            .upsert_points(
-                UpsertPointsBuilder::new(collections::UNCONSCIOUS, vec![updated_point]).wait(true),
-            )
-            .await?;
+        self.upsert(
+            UpsertPointsBuilder::new(self.collection(collections::UNCONSCIOUS), vec![updated_point])
+                .wait(true),
+        )
+        .await?;
 
         Ok(())
     }
@@ -986,7 +1499,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::UNCONSCIOUS)
+                ScrollPointsBuilder::new(self.collection(collections::UNCONSCIOUS))
                     .filter(Filter::must([Condition::matches(
                         "id",
                         memory_id.0.to_string(),
@@ -1001,8 +1514,7 @@ impl MemoryDb {
         }
 
         let point = &results.result[0];
-        let memory: UnconsciousMemory =
-            serde_json::from_value(serde_json::to_value(&point.payload)?)?;
+        let memory: UnconsciousMemory = Self::from_payload(&point.payload)?;
         Ok(memory)
     }
 
@@ -1023,7 +1535,7 @@ impl MemoryDb {
             .client
             .get_points(
                 GetPointsBuilder::new(
-                    collections::IDENTITY,
+                    self.collection(collections::IDENTITY),
                     vec![IDENTITY_RECORD_ID.to_string().into()],
                 )
                 .with_payload(true),
@@ -1071,8 +1583,7 @@ impl MemoryDb {
         let vector = vec![0.0; VECTOR_DIMENSION];
         let point = PointStruct::new(IDENTITY_RECORD_ID.to_string(), vector, payload);
 
-        self.client
-            .upsert_points(UpsertPointsBuilder::new(collections::IDENTITY, vec![point]))
+        self.upsert(UpsertPointsBuilder::new(self.collection(collections::IDENTITY), vec![point]))
             .await?;
 
         Ok(())
@@ -1088,7 +1599,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::MEMORIES)
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
                     .filter(Filter::must([Condition::matches(
                         "id",
                         memory_id.0.to_string(),
@@ -1103,10 +1614,122 @@ impl MemoryDb {
         }
 
         let point = &results.result[0];
-        let memory: Memory = serde_json::from_value(serde_json::to_value(&point.payload)?)?;
+        let memory: Memory = Self::from_payload(&point.payload)?;
         Ok(memory)
     }
 
+    /// Permanently delete a memory from Qdrant (right-to-forget)
+    ///
+    /// Unlike forgetting via the anchor stage (which archives to
+    /// [`Self::archive_to_unconscious`]), this removes the point outright.
+    /// Callers are responsible for detaching any graph edges and recording
+    /// a [`types::Tombstone`] via [`Self::tombstone_deletion`].
+    ///
+    /// Deletes from both [`collections::MEMORIES`] and
+    /// [`collections::UNCONSCIOUS`] - `memory_id` may have already been
+    /// archived to the latter (ADR-033), and a delete against a collection
+    /// the point isn't in is a no-op, not an error, so this is safe to do
+    /// unconditionally rather than having to first look up which
+    /// collection currently holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either Qdrant delete request fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn delete_memory(&self, memory_id: &MemoryId) -> Result<()> {
+        for collection in [collections::MEMORIES, collections::UNCONSCIOUS] {
+            let point_id = qdrant_client::qdrant::PointId::from(memory_id.0.to_string());
+            self.delete(
+                DeletePointsBuilder::new(self.collection(collection))
+                    .points(vec![point_id])
+                    .wait(true),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Record a right-to-forget audit trail entry for a deleted memory
+    ///
+    /// Stores only the [`types::Tombstone`] metadata (no content, no vector)
+    /// against a zero vector, so the tombstones collection can never leak
+    /// the data it attests was deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Qdrant upsert fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn tombstone_deletion(&self, tombstone: &types::Tombstone) -> Result<()> {
+        let payload: HashMap<String, serde_json::Value> =
+            serde_json::from_value(serde_json::to_value(tombstone)?)?;
+        let point = PointStruct::new(
+            tombstone.memory_id.0.to_string(),
+            vec![0.0; VECTOR_DIMENSION],
+            payload,
+        );
+
+        self.upsert(
+            UpsertPointsBuilder::new(self.collection(collections::TOMBSTONES), vec![point]).wait(true),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Persist a completed (or interrupted) sleep cycle record (ADR-023)
+    ///
+    /// `SleepSummary` only exists transiently in a `SleepActor::Wake` reply;
+    /// this is the durable counterpart, queried later via
+    /// [`Self::load_sleep_history`] (`daneel sleep history`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Qdrant upsert fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn save_sleep_cycle(&self, cycle: &SleepCycle) -> Result<()> {
+        let payload: HashMap<String, serde_json::Value> =
+            serde_json::from_value(serde_json::to_value(cycle)?)?;
+        let point = PointStruct::new(cycle.id.to_string(), vec![0.0; VECTOR_DIMENSION], payload);
+
+        self.upsert(
+            UpsertPointsBuilder::new(self.collection(collections::SLEEP_CYCLES), vec![point])
+                .wait(true),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Load the most recent sleep cycle records, newest first (ADR-023)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Qdrant query fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn load_sleep_history(&self, limit: usize) -> Result<Vec<SleepCycle>> {
+        let results = self
+            .client
+            .scroll(
+                ScrollPointsBuilder::new(self.collection(collections::SLEEP_CYCLES))
+                    .limit(limit.saturating_mul(4).min(u32::MAX as usize) as u32)
+                    .with_payload(true),
+            )
+            .await?;
+
+        let mut cycles: Vec<SleepCycle> = results
+            .result
+            .into_iter()
+            .filter_map(|point| Self::from_payload(point.payload).ok())
+            .collect();
+
+        // Newest first
+        cycles.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+        cycles.truncate(limit);
+
+        Ok(cycles)
+    }
+
     /// Strengthen association between two memories (Hebbian Learning)
     ///
     /// Implements Krotov-Hopfield Rule + Three-Factor Learning (VCONN-3):
@@ -1201,7 +1824,7 @@ impl MemoryDb {
         let results = self
             .client
             .scroll(
-                ScrollPointsBuilder::new(collections::MEMORIES)
+                ScrollPointsBuilder::new(self.collection(collections::MEMORIES))
                     .filter(Filter::must([Condition::matches(
                         "id",
                         source_id.0.to_string(),
@@ -0,0 +1,253 @@
+//! `MemoryBackend`: the recall/consolidation/archiving operations
+//! `CognitiveLoop` actually calls against long-term memory, extracted as a
+//! trait so loop, sleep, and continuity tests can run against an in-memory
+//! mock instead of standing up a live Qdrant instance.
+//!
+//! Deliberately narrower than [`MemoryDb`]'s full API - clustering,
+//! migration, identity, and sleep-history persistence are CLI/maintenance
+//! paths exercised against a real Qdrant instance, not part of the
+//! per-cycle hot path this trait exists to make testable.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::{ArchiveReason, EpisodeId, Memory, MemoryDb, MemoryDbError, MemoryId, Result, UnconsciousMemory};
+
+#[ractor::async_trait]
+pub trait MemoryBackend: Send + Sync {
+    /// Persist a memory with its context vector (consolidation).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store rejects the write.
+    async fn store_memory(&self, memory: &Memory, vector: &[f32]) -> Result<()>;
+
+    /// Find memories whose context vector is near `context_vector` (recall).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vector dimension is wrong or the query fails.
+    async fn find_by_context(
+        &self,
+        context_vector: &[f32],
+        episode_id: Option<&EpisodeId>,
+        limit: u64,
+    ) -> Result<Vec<(Memory, f32)>>;
+
+    /// Archive low-salience content to the unconscious (forgetting).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store rejects the write.
+    async fn archive_to_unconscious(
+        &self,
+        content: &str,
+        salience: f32,
+        reason: ArchiveReason,
+        redis_id: Option<&str>,
+    ) -> Result<MemoryId>;
+
+    /// Fetch a single memory by id (spreading activation neighbor lookup).
+    ///
+    /// # Errors
+    ///
+    /// Returns `MemoryDbError::MemoryNotFound` if no memory has this id.
+    async fn get_memory(&self, memory_id: &MemoryId) -> Result<Memory>;
+}
+
+#[ractor::async_trait]
+impl MemoryBackend for MemoryDb {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn store_memory(&self, memory: &Memory, vector: &[f32]) -> Result<()> {
+        Self::store_memory(self, memory, vector).await
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn find_by_context(
+        &self,
+        context_vector: &[f32],
+        episode_id: Option<&EpisodeId>,
+        limit: u64,
+    ) -> Result<Vec<(Memory, f32)>> {
+        Self::find_by_context(self, context_vector, episode_id, limit).await
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn archive_to_unconscious(
+        &self,
+        content: &str,
+        salience: f32,
+        reason: ArchiveReason,
+        redis_id: Option<&str>,
+    ) -> Result<MemoryId> {
+        Self::archive_to_unconscious(self, content, salience, reason, redis_id).await
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn get_memory(&self, memory_id: &MemoryId) -> Result<Memory> {
+        Self::get_memory(self, memory_id).await
+    }
+}
+
+/// In-memory [`MemoryBackend`] for unit tests - no Qdrant required.
+///
+/// `find_by_context` ignores the query vector entirely and returns stored
+/// memories most-recent-first, filtered by `episode_id` when given. That's
+/// enough to exercise consolidation/recall/archiving control flow; it is
+/// not a similarity search and tests should not assert on ranking by score.
+#[derive(Debug, Default)]
+pub struct MockMemoryBackend {
+    memories: Mutex<Vec<Memory>>,
+    unconscious: Mutex<HashMap<MemoryId, UnconsciousMemory>>,
+}
+
+impl MockMemoryBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of everything stored via `store_memory`, for test assertions.
+    #[must_use]
+    pub fn stored_memories(&self) -> Vec<Memory> {
+        self.memories.lock().expect("mock memory store poisoned").clone()
+    }
+
+    /// Snapshot of everything archived via `archive_to_unconscious`, for test assertions.
+    #[must_use]
+    pub fn archived_memories(&self) -> Vec<UnconsciousMemory> {
+        self.unconscious
+            .lock()
+            .expect("mock memory store poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+#[ractor::async_trait]
+impl MemoryBackend for MockMemoryBackend {
+    async fn store_memory(&self, memory: &Memory, _vector: &[f32]) -> Result<()> {
+        self.memories.lock().expect("mock memory store poisoned").push(memory.clone());
+        Ok(())
+    }
+
+    async fn find_by_context(
+        &self,
+        _context_vector: &[f32],
+        episode_id: Option<&EpisodeId>,
+        limit: u64,
+    ) -> Result<Vec<(Memory, f32)>> {
+        let memories = self.memories.lock().expect("mock memory store poisoned");
+        Ok(memories
+            .iter()
+            .rev()
+            .filter(|m| episode_id.is_none_or(|ep| m.episode_id.as_ref() == Some(ep)))
+            .take(limit as usize)
+            .map(|m| (m.clone(), 1.0))
+            .collect())
+    }
+
+    async fn archive_to_unconscious(
+        &self,
+        content: &str,
+        salience: f32,
+        reason: ArchiveReason,
+        redis_id: Option<&str>,
+    ) -> Result<MemoryId> {
+        let memory = UnconsciousMemory::from_forgotten_thought(
+            content.to_string(),
+            salience,
+            reason,
+            redis_id.map(String::from),
+        );
+        let id = memory.id;
+        self.unconscious.lock().expect("mock memory store poisoned").insert(id, memory);
+        Ok(id)
+    }
+
+    async fn get_memory(&self, memory_id: &MemoryId) -> Result<Memory> {
+        self.memories
+            .lock()
+            .expect("mock memory store poisoned")
+            .iter()
+            .find(|m| &m.id == memory_id)
+            .cloned()
+            .ok_or(MemoryDbError::MemoryNotFound(*memory_id))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::memory_db::MemorySource;
+
+    fn sample_memory() -> Memory {
+        Memory::new(
+            "test content".to_string(),
+            MemorySource::External {
+                stimulus: "test".to_string(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn store_then_find_by_context_returns_stored_memory() {
+        let store = MockMemoryBackend::new();
+        let memory = sample_memory();
+        store.store_memory(&memory, &[0.0; 768]).await.unwrap();
+
+        let found = store.find_by_context(&[0.0; 768], None, 10).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.id, memory.id);
+    }
+
+    #[tokio::test]
+    async fn find_by_context_respects_limit_and_recency_order() {
+        let store = MockMemoryBackend::new();
+        let first = sample_memory();
+        let second = sample_memory();
+        store.store_memory(&first, &[0.0; 768]).await.unwrap();
+        store.store_memory(&second, &[0.0; 768]).await.unwrap();
+
+        let found = store.find_by_context(&[0.0; 768], None, 1).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.id, second.id);
+    }
+
+    #[tokio::test]
+    async fn find_by_context_filters_by_episode_id() {
+        let store = MockMemoryBackend::new();
+        let episode = EpisodeId(uuid::Uuid::new_v4());
+        let mut in_episode = sample_memory();
+        in_episode.episode_id = Some(episode);
+        let outside_episode = sample_memory();
+        store.store_memory(&in_episode, &[0.0; 768]).await.unwrap();
+        store.store_memory(&outside_episode, &[0.0; 768]).await.unwrap();
+
+        let found = store.find_by_context(&[0.0; 768], Some(&episode), 10).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.id, in_episode.id);
+    }
+
+    #[tokio::test]
+    async fn get_memory_returns_not_found_for_unknown_id() {
+        let store = MockMemoryBackend::new();
+        let err = store.get_memory(&MemoryId::new()).await.unwrap_err();
+        assert!(matches!(err, MemoryDbError::MemoryNotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn archive_to_unconscious_is_visible_via_archived_memories() {
+        let store = MockMemoryBackend::new();
+        store
+            .archive_to_unconscious("forgotten", 0.1, ArchiveReason::LowSalience, None)
+            .await
+            .unwrap();
+
+        let archived = store.archived_memories();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].content, "forgotten");
+    }
+}
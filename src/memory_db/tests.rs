@@ -480,3 +480,122 @@ fn integration_hebbian_learning() {
         assert!(assoc_2.coactivation_count == 2);
     });
 }
+
+/// Validates `rescore_memories()`:
+/// 1. A memory with a stored `SalienceExplanation` gets its
+///    `semantic_salience` and explanation weights recomputed
+/// 2. A memory with none is left untouched and counted as skipped
+#[test]
+#[ignore = "Requires running Qdrant instance"]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn integration_rescore_memories() {
+    tokio_test::block_on(async {
+        let url =
+            std::env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".to_string());
+        let db = MemoryDb::connect_and_init(&url).await.unwrap();
+
+        let score = SalienceScore {
+            importance: 0.9,
+            novelty: 0.1,
+            relevance: 0.1,
+            valence: 0.1,
+            arousal: 0.1,
+            connection_relevance: 0.1,
+        };
+        let explained = Memory::new(
+            format!("Rescore Explained {}", uuid::Uuid::new_v4()),
+            MemorySource::External {
+                stimulus: "rescore".to_string(),
+            },
+        )
+        .with_salience_explanation(SalienceExplanation {
+            score,
+            weights: SalienceWeights::default(),
+        });
+        let unexplained = Memory::new(
+            format!("Rescore Unexplained {}", uuid::Uuid::new_v4()),
+            MemorySource::External {
+                stimulus: "rescore".to_string(),
+            },
+        );
+
+        let vector = vec![0.1; VECTOR_DIMENSION];
+        db.store_memory(&explained, &vector).await.unwrap();
+        db.store_memory(&unexplained, &vector).await.unwrap();
+
+        let all_importance = SalienceWeights {
+            importance: 0.996,
+            novelty: 0.001,
+            relevance: 0.001,
+            valence: 0.001,
+            connection: 0.001,
+        };
+        let report = db.rescore_memories(all_importance).await.unwrap();
+        assert!(report.updated >= 1);
+        assert!(report.skipped >= 1);
+
+        let reloaded = db.get_memory(&explained.id).await.unwrap();
+        let expected = score.composite(&all_importance);
+        assert!((reloaded.semantic_salience - expected).abs() < 1e-6);
+        assert_eq!(
+            reloaded.salience_explanation.unwrap().weights,
+            all_importance
+        );
+
+        let reloaded_unexplained = db.get_memory(&unexplained.id).await.unwrap();
+        assert!(reloaded_unexplained.salience_explanation.is_none());
+    });
+}
+
+/// Validates `federated_search()`:
+/// 1. A memory stored under a named profile is only found when that
+///    profile is included in the search
+/// 2. Hits are attributed to the profile they came from
+/// 3. A profile with no `MEMORIES` collection yet contributes no hits and
+///    isn't treated as an error
+#[test]
+#[ignore = "Requires running Qdrant instance"]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn integration_federated_search() {
+    tokio_test::block_on(async {
+        let url =
+            std::env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".to_string());
+        let profile_name = format!("federated-test-{}", uuid::Uuid::new_v4());
+        let profile = crate::profile::Profile::new(Some(profile_name));
+
+        let db = MemoryDb::connect_and_init_with_profile(&url, profile.clone())
+            .await
+            .unwrap();
+        let memory = Memory::new(
+            format!("Federated Search {}", uuid::Uuid::new_v4()),
+            MemorySource::External {
+                stimulus: "federated".to_string(),
+            },
+        );
+        let vector = vec![0.2; VECTOR_DIMENSION];
+        db.store_memory(&memory, &vector).await.unwrap();
+
+        let missing_profile = crate::profile::Profile::new(Some(format!(
+            "federated-test-missing-{}",
+            uuid::Uuid::new_v4()
+        )));
+
+        let hits = MemoryDb::federated_search(
+            &url,
+            &[profile.clone(), missing_profile],
+            &vector,
+            10,
+        )
+        .await
+        .unwrap();
+
+        assert!(hits.iter().any(|hit| hit.memory.id == memory.id));
+        let hit = hits.iter().find(|hit| hit.memory.id == memory.id).unwrap();
+        assert_eq!(hit.profile, profile);
+
+        let empty = MemoryDb::federated_search(&url, &[], &vector, 10)
+            .await
+            .unwrap();
+        assert!(empty.is_empty());
+    });
+}
@@ -9,13 +9,17 @@
 //! - `Association` → Hebbian co-activation link
 //! - `MemoryPayload` → Qdrant payload structure
 
+mod tombstone;
 mod unconscious;
 
+pub use tombstone::{DeletionReason, Tombstone};
 pub use unconscious::{
     ArchiveReason, IdentityMetadata, SleepCycle, SleepCycleStatus, UnconsciousMemory,
     IDENTITY_RECORD_ID,
 };
 
+use crate::actors::continuity::types::ExperienceId;
+use crate::core::types::{SalienceScore, SalienceWeights};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -257,6 +261,22 @@ pub enum MemorySource {
     Social { context: String },
 }
 
+/// Why a memory was salient enough to consolidate, frozen at encoding time
+///
+/// `SalienceWeights` can be re-learned (see `actors::salience::calibrate`)
+/// long after a memory is written, so the weights that actually produced
+/// `score`'s composite must be stored alongside it - otherwise an audit
+/// asking "why was this remembered?" months later would be answering with
+/// whatever the *current* weights happen to be, not the ones that applied.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SalienceExplanation {
+    /// The per-component salience breakdown computed for this memory's thought
+    pub score: SalienceScore,
+    /// The weights `score` was combined with to produce the composite that
+    /// crossed the consolidation threshold
+    pub weights: SalienceWeights,
+}
+
 /// A memory in Timmy's long-term storage
 ///
 /// Stored in Qdrant as: vector (768-dim) + payload (this struct)
@@ -311,6 +331,17 @@ pub struct Memory {
 
     /// Access count
     pub access_count: u32,
+
+    /// Reciprocal link to the `ContinuityActor` experience recorded for the
+    /// same moment, if one was. Lets a thought inspector jump from this
+    /// memory back to its full timeline entry.
+    #[serde(default)]
+    pub experience_id: Option<ExperienceId>,
+
+    /// Why this memory was salient enough to consolidate, frozen at
+    /// encoding time. `None` for memories written before this field existed.
+    #[serde(default)]
+    pub salience_explanation: Option<SalienceExplanation>,
 }
 
 impl Memory {
@@ -333,9 +364,27 @@ impl Memory {
             encoded_at: Utc::now(),
             last_accessed: None,
             access_count: 0,
+            experience_id: None,
+            salience_explanation: None,
         }
     }
 
+    /// Link this memory to the `ContinuityActor` experience recorded for the
+    /// same moment
+    #[must_use]
+    pub const fn with_experience_link(mut self, experience_id: ExperienceId) -> Self {
+        self.experience_id = Some(experience_id);
+        self
+    }
+
+    /// Attach the salience breakdown and weights that justified consolidating
+    /// this memory, for later "why was this remembered?" audits
+    #[must_use]
+    pub const fn with_salience_explanation(mut self, explanation: SalienceExplanation) -> Self {
+        self.salience_explanation = Some(explanation);
+        self
+    }
+
     /// Create memory with emotional state
     #[must_use]
     pub const fn with_emotion(mut self, valence: f32, arousal: f32) -> Self {
@@ -758,6 +807,28 @@ mod tests {
         assert!(memory.consolidation.consolidation_tag);
     }
 
+    #[test]
+    fn memory_with_experience_link() {
+        let experience_id = ExperienceId::new();
+        let memory = Memory::new(
+            "Linked".to_string(),
+            MemorySource::Reasoning { chain: vec![] },
+        )
+        .with_experience_link(experience_id);
+
+        assert_eq!(memory.experience_id, Some(experience_id));
+    }
+
+    #[test]
+    fn memory_experience_id_defaults_to_none() {
+        let memory = Memory::new(
+            "Unlinked".to_string(),
+            MemorySource::Reasoning { chain: vec![] },
+        );
+
+        assert!(memory.experience_id.is_none());
+    }
+
     #[test]
     fn replay_priority_calculation() {
         let mut memory = Memory::new(
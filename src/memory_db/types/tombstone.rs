@@ -0,0 +1,48 @@
+//! Legal-deletion tombstones (right-to-forget)
+//!
+//! TMI's "Nada se apaga" (nothing is erased) policy keeps forgotten-but-
+//! low-salience thoughts around in the [`super::UnconsciousMemory`] archive.
+//! A right-to-forget request is different: the content itself must be gone,
+//! not just made inaccessible. A `Tombstone` is the audit trail left behind
+//! instead - it records that a deletion happened, and why, without
+//! retaining the deleted content.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::MemoryId;
+
+/// Why a memory was permanently deleted rather than archived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeletionReason {
+    /// Explicit right-to-forget request from a human
+    LegalRequest,
+}
+
+/// Audit record left behind after a legal deletion.
+///
+/// Deliberately does not retain `content`, `context_vector`, or anything
+/// else that would defeat the purpose of the deletion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tombstone {
+    /// The memory that was deleted
+    pub memory_id: MemoryId,
+
+    /// Why it was deleted
+    pub reason: DeletionReason,
+
+    /// When the deletion was performed
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl Tombstone {
+    #[must_use]
+    pub fn new(memory_id: MemoryId, reason: DeletionReason) -> Self {
+        Self {
+            memory_id,
+            reason,
+            deleted_at: Utc::now(),
+        }
+    }
+}
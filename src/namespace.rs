@@ -0,0 +1,71 @@
+//! Shared-instance namespace prefix (`DANEEL_PREFIX`)
+//!
+//! Labs running several independent DANEEL deployments against one shared
+//! Redis/Qdrant instance need their stream names, graph name, and collection
+//! names to not collide. This replaces the hard-coded `"daneel"` literal
+//! baked into those names with a configurable prefix, read once from the
+//! `DANEEL_PREFIX` environment variable (default `"daneel"`, matching prior
+//! behavior exactly).
+//!
+//! This is orthogonal to [`crate::profile`]: a [`Profile`](crate::profile::Profile)
+//! distinguishes multiple identities *within* one deployment's namespace;
+//! this prefix distinguishes multiple deployments sharing one Redis/Qdrant
+//! instance. The two compose - a graph name is built as
+//! `profile.namespace(namespace::prefix())`.
+//!
+//! # Scope
+//!
+//! Wired into the three centralized choke points: [`crate::streams::names`],
+//! the default `RedisGraph` graph name, and [`crate::memory_db::collections`]
+//! base names. It is read once per process via [`prefix`] - changing
+//! `DANEEL_PREFIX` mid-run has no effect, matching how `QDRANT_URL` and
+//! `REDIS_URL` are already read once at connect time.
+
+use std::sync::OnceLock;
+
+/// The prefix used when `DANEEL_PREFIX` is unset - identical to the
+/// previously hard-coded literal, so unconfigured deployments are unaffected.
+pub const DEFAULT_PREFIX: &str = "daneel";
+
+fn global() -> &'static str {
+    static PREFIX: OnceLock<String> = OnceLock::new();
+    PREFIX.get_or_init(|| std::env::var("DANEEL_PREFIX").unwrap_or_else(|_| DEFAULT_PREFIX.to_string()))
+}
+
+/// The active namespace prefix (`DANEEL_PREFIX`, default `"daneel"`).
+#[must_use]
+pub fn prefix() -> &'static str {
+    global()
+}
+
+/// Build a `{prefix}:{suffix}` name, e.g. a stream key.
+#[must_use]
+pub fn prefixed(suffix: &str) -> String {
+    format!("{}:{}", prefix(), suffix)
+}
+
+/// Namespace a Qdrant collection base name. Left bare while the prefix is
+/// still the default, so existing single-lab deployments keep their
+/// unprefixed `"memories"`-style collection names; prefixed otherwise.
+#[must_use]
+pub fn collection(base: &str) -> String {
+    if prefix() == DEFAULT_PREFIX {
+        base.to_string()
+    } else {
+        format!("{}_{base}", prefix())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_prefix_matches_prior_hardcoded_literal() {
+        // DANEEL_PREFIX is unset in the test environment, so this exercises
+        // the default path without racing other tests over the env var.
+        assert_eq!(prefixed("stream:awake"), "daneel:stream:awake");
+        assert_eq!(collection("memories"), "memories");
+    }
+}
@@ -0,0 +1,101 @@
+//! Structured oversight notifications
+//!
+//! Critical events - repeated harm-category vetoes, invariant violations,
+//! value-drift alarms - should page a human, not wait for someone to grep
+//! logs. This module defines a [`NotificationSink`] abstraction and a few
+//! concrete implementations (webhook, Slack-compatible JSON, SMTP) that
+//! actors and the maintenance jobs can push [`Alert`]s through.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use daneel::notify::{Alert, AlertSeverity, NotificationSink, WebhookSink};
+//!
+//! # async fn example() -> Result<(), daneel::notify::NotifyError> {
+//! let sink = WebhookSink::new("https://example.com/hooks/daneel");
+//! let alert = Alert::new(AlertSeverity::Critical, "volition", "Repeated harm-category vetoes");
+//! sink.send(&alert).await
+//! # }
+//! ```
+
+pub mod slack;
+pub mod smtp;
+pub mod webhook;
+
+pub use slack::SlackSink;
+pub use smtp::SmtpSink;
+pub use webhook::WebhookSink;
+
+use serde::Serialize;
+use thiserror::Error;
+
+/// How urgently an alert needs human attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    /// Worth knowing about, no action required
+    Info,
+    /// Should be reviewed soon
+    Warning,
+    /// Needs human attention now (repeated vetoes, invariant violations)
+    Critical,
+}
+
+/// A single structured event destined for an oversight sink
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    /// Urgency of the alert
+    pub severity: AlertSeverity,
+    /// Subsystem that raised it (e.g. "volition", "invariants")
+    pub source: &'static str,
+    /// Human-readable summary
+    pub message: String,
+}
+
+impl Alert {
+    /// Create a new alert
+    #[must_use]
+    pub fn new(severity: AlertSeverity, source: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            source,
+            message: message.into(),
+        }
+    }
+}
+
+/// Errors that can occur while delivering an alert
+#[derive(Debug, Error)]
+pub enum NotifyError {
+    /// The sink's transport failed (HTTP, SMTP, etc.)
+    #[error("notification delivery failed: {0}")]
+    DeliveryFailed(String),
+
+    /// The sink rejected the alert payload
+    #[error("notification sink rejected payload: {0}")]
+    Rejected(String),
+}
+
+/// A destination that can deliver structured alerts to human overseers
+#[ractor::async_trait]
+pub trait NotificationSink: Send + Sync {
+    /// Deliver an alert, returning an error if the transport failed
+    async fn send(&self, alert: &Alert) -> Result<(), NotifyError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn critical_outranks_info() {
+        assert!(AlertSeverity::Critical > AlertSeverity::Info);
+    }
+
+    #[test]
+    fn alert_carries_its_source_and_message() {
+        let alert = Alert::new(AlertSeverity::Warning, "invariants", "drift detected");
+        assert_eq!(alert.source, "invariants");
+        assert_eq!(alert.message, "drift detected");
+    }
+}
@@ -0,0 +1,58 @@
+//! Slack-compatible incoming webhook sink
+
+use super::{Alert, AlertSeverity, NotificationSink, NotifyError};
+use serde_json::json;
+
+/// Posts alerts to a Slack (or Slack-compatible) incoming webhook URL
+#[derive(Debug, Clone)]
+pub struct SlackSink {
+    webhook_url: String,
+    client: reqwest::Client,
+}
+
+impl SlackSink {
+    /// Create a sink targeting a Slack incoming webhook URL
+    #[must_use]
+    pub fn new(webhook_url: impl Into<String>) -> Self {
+        Self {
+            webhook_url: webhook_url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn emoji(severity: AlertSeverity) -> &'static str {
+        match severity {
+            AlertSeverity::Info => ":information_source:",
+            AlertSeverity::Warning => ":warning:",
+            AlertSeverity::Critical => ":rotating_light:",
+        }
+    }
+}
+
+#[ractor::async_trait]
+impl NotificationSink for SlackSink {
+    async fn send(&self, alert: &Alert) -> Result<(), NotifyError> {
+        let payload = json!({
+            "text": format!(
+                "{} *[{}]* {}",
+                Self::emoji(alert.severity),
+                alert.source,
+                alert.message
+            ),
+        });
+
+        let response = self
+            .client
+            .post(&self.webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| NotifyError::DeliveryFailed(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(NotifyError::Rejected(response.status().to_string()))
+        }
+    }
+}
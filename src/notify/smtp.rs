@@ -0,0 +1,66 @@
+//! Email oversight sink via SMTP
+
+use super::{Alert, NotificationSink, NotifyError};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+/// Sends each alert as a plaintext email through an SMTP relay
+#[derive(Clone)]
+pub struct SmtpSink {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: String,
+    to: String,
+}
+
+impl SmtpSink {
+    /// Create a sink that relays through `relay_host` using the given credentials
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the relay host cannot be resolved into a transport.
+    pub fn new(
+        relay_host: &str,
+        username: impl Into<String>,
+        password: impl Into<String>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Result<Self, NotifyError> {
+        let creds = Credentials::new(username.into(), password.into());
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::relay(relay_host)
+            .map_err(|e| NotifyError::DeliveryFailed(e.to_string()))?
+            .credentials(creds)
+            .build();
+
+        Ok(Self {
+            transport,
+            from: from.into(),
+            to: to.into(),
+        })
+    }
+}
+
+#[ractor::async_trait]
+impl NotificationSink for SmtpSink {
+    async fn send(&self, alert: &Alert) -> Result<(), NotifyError> {
+        let email = Message::builder()
+            .from(
+                self.from
+                    .parse()
+                    .map_err(|e: lettre::address::AddressError| NotifyError::Rejected(e.to_string()))?,
+            )
+            .to(self
+                .to
+                .parse()
+                .map_err(|e: lettre::address::AddressError| NotifyError::Rejected(e.to_string()))?)
+            .subject(format!("[DANEEL] {:?} alert from {}", alert.severity, alert.source))
+            .body(alert.message.clone())
+            .map_err(|e| NotifyError::Rejected(e.to_string()))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| NotifyError::DeliveryFailed(e.to_string()))?;
+
+        Ok(())
+    }
+}
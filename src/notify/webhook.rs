@@ -0,0 +1,40 @@
+//! Generic JSON webhook sink
+
+use super::{Alert, NotificationSink, NotifyError};
+
+/// Posts the [`Alert`] as a JSON body to an arbitrary webhook URL
+#[derive(Debug, Clone)]
+pub struct WebhookSink {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookSink {
+    /// Create a sink targeting `url`
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[ractor::async_trait]
+impl NotificationSink for WebhookSink {
+    async fn send(&self, alert: &Alert) -> Result<(), NotifyError> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(alert)
+            .send()
+            .await
+            .map_err(|e| NotifyError::DeliveryFailed(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(NotifyError::Rejected(response.status().to_string()))
+        }
+    }
+}
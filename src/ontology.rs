@@ -0,0 +1,353 @@
+//! Concept ontology registry and importers
+//!
+//! [`dominant_concept`](crate::core::types::dominant_concept) already treats
+//! `Content::Symbol` ids and `Relation` predicates as concept keys, with a
+//! comment noting that a real "ontology lookup" would eventually replace
+//! its frequency heuristic. This module is that registry: a typed
+//! `id -> (type, parents)` store that symbols can be validated against,
+//! populated either by hand ([`OntologyRegistry::register`]) or bulk
+//! imports from a simple CSV schema or a subset of Turtle/RDF, so an
+//! existing knowledge base doesn't have to be hand-transcribed.
+//!
+//! # Turtle subset
+//!
+//! Only two predicate forms are understood, one statement per line:
+//!
+//! ```text
+//! :socrates a :Entity .
+//! :socrates rdfs:subClassOf :philosopher .
+//! ```
+//!
+//! Prefixed names are taken literally (the prefix is stripped, no
+//! `@prefix` resolution) - this is intentionally a subset for bootstrapping
+//! simple hierarchies, not a general RDF/OWL parser.
+
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Broad category a concept belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConceptType {
+    /// A concrete or abstract thing ("socrates", "justice")
+    Entity,
+    /// A named relationship between entities ("teaches", "causes")
+    Relation,
+    /// A property entities can hold ("mortal", "red")
+    Attribute,
+    /// Something that happens at a point or span of time
+    Event,
+}
+
+impl ConceptType {
+    /// Parse a type name as it would appear in an import file
+    /// (case-insensitive, e.g. "entity", "Relation").
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "entity" => Some(Self::Entity),
+            "relation" => Some(Self::Relation),
+            "attribute" => Some(Self::Attribute),
+            "event" => Some(Self::Event),
+            _ => None,
+        }
+    }
+}
+
+/// A single registered concept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Concept {
+    pub id: String,
+    pub concept_type: ConceptType,
+    pub parents: Vec<String>,
+}
+
+/// Errors from registering or importing concepts.
+#[derive(Debug, Error, PartialEq)]
+pub enum OntologyError {
+    #[error("concept '{0}' already registered with a different type")]
+    TypeConflict(String),
+
+    #[error("unknown concept type '{0}' (expected entity, relation, attribute, or event)")]
+    UnknownType(String),
+
+    #[error("malformed CSV row {row}: expected 'id,type,parents', got '{line}'")]
+    MalformedCsvRow { row: usize, line: String },
+
+    #[error("malformed Turtle statement on line {line}: '{statement}'")]
+    MalformedTurtle { line: usize, statement: String },
+}
+
+/// Outcome of a bulk import: how many concepts landed, and any rows that
+/// were skipped with a reason, so a bad import doesn't silently lose data.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    pub imported: usize,
+    pub conflicts: Vec<String>,
+}
+
+/// In-memory concept ontology, keyed by concept id.
+#[derive(Debug, Clone, Default)]
+pub struct OntologyRegistry {
+    concepts: HashMap<String, Concept>,
+}
+
+impl OntologyRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a concept, or extend its parent list if it's already
+    /// registered with the same type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OntologyError::TypeConflict`] if `id` is already
+    /// registered under a different [`ConceptType`].
+    pub fn register(
+        &mut self,
+        id: impl Into<String>,
+        concept_type: ConceptType,
+        parents: Vec<String>,
+    ) -> Result<(), OntologyError> {
+        let id = id.into();
+        match self.concepts.get_mut(&id) {
+            Some(existing) if existing.concept_type != concept_type => {
+                Err(OntologyError::TypeConflict(id))
+            }
+            Some(existing) => {
+                for parent in parents {
+                    if !existing.parents.contains(&parent) {
+                        existing.parents.push(parent);
+                    }
+                }
+                Ok(())
+            }
+            None => {
+                self.concepts.insert(
+                    id.clone(),
+                    Concept {
+                        id,
+                        concept_type,
+                        parents,
+                    },
+                );
+                Ok(())
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&Concept> {
+        self.concepts.get(id)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.concepts.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.concepts.is_empty()
+    }
+
+    /// True if `ancestor` appears anywhere in `id`'s parent chain.
+    #[must_use]
+    pub fn is_descendant_of(&self, id: &str, ancestor: &str) -> bool {
+        let Some(concept) = self.get(id) else {
+            return false;
+        };
+        concept
+            .parents
+            .iter()
+            .any(|parent| parent == ancestor || self.is_descendant_of(parent, ancestor))
+    }
+
+    /// Import concepts from CSV rows of the form `id,type,parents`, where
+    /// `parents` is a `;`-separated list (empty string for none). Rows that
+    /// fail to parse or conflict with an existing concept are skipped and
+    /// recorded in the returned [`ImportReport`] rather than aborting the
+    /// whole import.
+    pub fn import_csv(&mut self, csv: &str) -> ImportReport {
+        let mut report = ImportReport::default();
+        for (row, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || (row == 0 && line.eq_ignore_ascii_case("id,type,parents")) {
+                continue; // blank line or header
+            }
+
+            let fields: Vec<&str> = line.splitn(3, ',').collect();
+            let [id, type_name, parents_field] = fields[..] else {
+                report
+                    .conflicts
+                    .push(OntologyError::MalformedCsvRow { row, line: line.to_string() }.to_string());
+                continue;
+            };
+
+            let Some(concept_type) = ConceptType::parse(type_name) else {
+                report
+                    .conflicts
+                    .push(OntologyError::UnknownType(type_name.to_string()).to_string());
+                continue;
+            };
+
+            let parents = if parents_field.is_empty() {
+                Vec::new()
+            } else {
+                parents_field.split(';').map(str::trim).map(str::to_string).collect()
+            };
+
+            match self.register(id.trim(), concept_type, parents) {
+                Ok(()) => report.imported += 1,
+                Err(e) => report.conflicts.push(e.to_string()),
+            }
+        }
+        report
+    }
+
+    /// Import concepts from a subset of Turtle: `:id a :Type .` and
+    /// `:id rdfs:subClassOf :parent .` statements, one per line. Any other
+    /// statement form is skipped and recorded as a conflict.
+    pub fn import_turtle(&mut self, turtle: &str) -> ImportReport {
+        let mut report = ImportReport::default();
+        let mut pending_types: HashMap<String, ConceptType> = HashMap::new();
+        let mut pending_parents: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (line_no, line) in turtle.lines().enumerate() {
+            let statement = line.trim().trim_end_matches('.').trim();
+            if statement.is_empty() {
+                continue;
+            }
+
+            let parts: Vec<&str> = statement.split_whitespace().collect();
+            match parts[..] {
+                [subject, "a", object] => {
+                    let id = strip_prefix(subject);
+                    match ConceptType::parse(strip_prefix(object)) {
+                        Some(concept_type) => {
+                            pending_types.insert(id, concept_type);
+                        }
+                        None => report.conflicts.push(
+                            OntologyError::UnknownType(strip_prefix(object)).to_string(),
+                        ),
+                    }
+                }
+                [subject, predicate, object]
+                    if predicate.ends_with("subClassOf") =>
+                {
+                    pending_parents
+                        .entry(strip_prefix(subject))
+                        .or_default()
+                        .push(strip_prefix(object));
+                }
+                _ => report.conflicts.push(
+                    OntologyError::MalformedTurtle {
+                        line: line_no + 1,
+                        statement: statement.to_string(),
+                    }
+                    .to_string(),
+                ),
+            }
+        }
+
+        for (id, concept_type) in pending_types {
+            let parents = pending_parents.remove(&id).unwrap_or_default();
+            match self.register(id, concept_type, parents) {
+                Ok(()) => report.imported += 1,
+                Err(e) => report.conflicts.push(e.to_string()),
+            }
+        }
+        // subClassOf statements for a subject that never got an `a` type
+        // can't be registered as a concept - surface them rather than drop
+        // them silently.
+        for (id, _) in pending_parents {
+            report.conflicts.push(format!("'{id}' has subClassOf but no type (missing 'a' statement)"));
+        }
+
+        report
+    }
+}
+
+/// Strip a leading `:` or `prefix:` from a Turtle term.
+fn strip_prefix(term: &str) -> String {
+    term.rsplit(':').next().unwrap_or(term).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_new_concept() {
+        let mut registry = OntologyRegistry::new();
+        registry
+            .register("socrates", ConceptType::Entity, vec!["philosopher".to_string()])
+            .unwrap();
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("socrates").unwrap().concept_type, ConceptType::Entity);
+    }
+
+    #[test]
+    fn registering_same_id_different_type_conflicts() {
+        let mut registry = OntologyRegistry::new();
+        registry.register("mortal", ConceptType::Attribute, vec![]).unwrap();
+        let err = registry.register("mortal", ConceptType::Entity, vec![]).unwrap_err();
+        assert_eq!(err, OntologyError::TypeConflict("mortal".to_string()));
+    }
+
+    #[test]
+    fn registering_same_id_same_type_merges_parents() {
+        let mut registry = OntologyRegistry::new();
+        registry.register("socrates", ConceptType::Entity, vec!["philosopher".to_string()]).unwrap();
+        registry.register("socrates", ConceptType::Entity, vec!["greek".to_string()]).unwrap();
+        assert_eq!(registry.get("socrates").unwrap().parents.len(), 2);
+    }
+
+    #[test]
+    fn is_descendant_of_walks_parent_chain() {
+        let mut registry = OntologyRegistry::new();
+        registry.register("philosopher", ConceptType::Entity, vec!["person".to_string()]).unwrap();
+        registry.register("socrates", ConceptType::Entity, vec!["philosopher".to_string()]).unwrap();
+        assert!(registry.is_descendant_of("socrates", "person"));
+        assert!(!registry.is_descendant_of("socrates", "animal"));
+    }
+
+    #[test]
+    fn import_csv_parses_rows_and_skips_header() {
+        let mut registry = OntologyRegistry::new();
+        let report = registry.import_csv(
+            "id,type,parents\nsocrates,entity,philosopher\nphilosopher,entity,",
+        );
+        assert_eq!(report.imported, 2);
+        assert!(report.conflicts.is_empty());
+        assert_eq!(registry.get("socrates").unwrap().parents, vec!["philosopher"]);
+    }
+
+    #[test]
+    fn import_csv_reports_unknown_type_without_aborting() {
+        let mut registry = OntologyRegistry::new();
+        let report = registry.import_csv("bogus,not_a_type,\nsocrates,entity,");
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.conflicts.len(), 1);
+    }
+
+    #[test]
+    fn import_turtle_parses_type_and_subclass_statements() {
+        let mut registry = OntologyRegistry::new();
+        let report = registry.import_turtle(
+            ":socrates a :Entity .\n:socrates rdfs:subClassOf :philosopher .\n:philosopher a :Entity .",
+        );
+        assert_eq!(report.imported, 2);
+        assert!(report.conflicts.is_empty());
+        assert!(registry.is_descendant_of("socrates", "philosopher"));
+    }
+
+    #[test]
+    fn import_turtle_flags_subclass_without_type() {
+        let mut registry = OntologyRegistry::new();
+        let report = registry.import_turtle(":socrates rdfs:subClassOf :philosopher .");
+        assert_eq!(report.imported, 0);
+        assert_eq!(report.conflicts.len(), 1);
+    }
+}
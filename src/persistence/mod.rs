@@ -17,6 +17,10 @@
 //! daneel:checkpoint:{id}   -> JSON checkpoint snapshot
 //! ```
 //!
+//! These keys are for the default identity namespace. `connect_with_profile`
+//! suffixes every key with `__{profile}` for non-default profiles - see
+//! [`crate::profile`].
+//!
 //! # Usage
 //!
 //! ```ignore
@@ -95,16 +99,63 @@ impl From<serde_json::Error> for PersistenceError {
 // Redis Keys
 // =============================================================================
 
-/// Key prefixes for DANEEL's memory storage
-mod keys {
-    pub const PREFIX: &str = "daneel";
-    pub const IDENTITY: &str = "daneel:identity";
-    pub const EXPERIENCES: &str = "daneel:experiences";
-    pub const MILESTONES: &str = "daneel:milestones";
-    pub const CHECKPOINT_LATEST: &str = "daneel:checkpoint:latest";
-    pub const CHECKPOINTS: &str = "daneel:checkpoints";
-    pub const EXPERIENCE_INDEX: &str = "daneel:experience_ids";
-    pub const MILESTONE_INDEX: &str = "daneel:milestone_ids";
+/// Key prefixes for DANEEL's memory storage, built from the process-wide
+/// [`crate::namespace`] prefix (`DANEEL_PREFIX`, default `"daneel"`).
+pub mod keys {
+    pub fn prefix() -> String {
+        crate::namespace::prefix().to_string()
+    }
+
+    pub fn identity() -> String {
+        crate::namespace::prefixed("identity")
+    }
+
+    pub fn experiences() -> String {
+        crate::namespace::prefixed("experiences")
+    }
+
+    pub fn milestones() -> String {
+        crate::namespace::prefixed("milestones")
+    }
+
+    pub fn checkpoint_latest() -> String {
+        crate::namespace::prefixed("checkpoint:latest")
+    }
+
+    pub fn checkpoints() -> String {
+        crate::namespace::prefixed("checkpoints")
+    }
+
+    pub fn experience_index() -> String {
+        crate::namespace::prefixed("experience_ids")
+    }
+
+    pub fn milestone_index() -> String {
+        crate::namespace::prefixed("milestone_ids")
+    }
+
+    /// Per-job last-run/next-run status for [`crate::scheduler::Scheduler`],
+    /// so a restart doesn't forget when each job last ran.
+    pub fn scheduler_state() -> String {
+        crate::namespace::prefixed("scheduler_state")
+    }
+
+    /// Every key `MemoryStore` writes to, for operations that need to touch
+    /// all of them (e.g. `daneel backup`). Excludes [`prefix`], which isn't
+    /// itself a key.
+    #[must_use]
+    pub fn all() -> Vec<String> {
+        vec![
+            identity(),
+            experiences(),
+            milestones(),
+            checkpoint_latest(),
+            checkpoints(),
+            experience_index(),
+            milestone_index(),
+            scheduler_state(),
+        ]
+    }
 }
 
 // =============================================================================
@@ -136,6 +187,9 @@ pub struct MemoryStore {
 
     /// Multiplexed async connection
     conn: MultiplexedConnection,
+
+    /// Namespaces every key under a profile other than the default one
+    profile: crate::profile::Profile,
 }
 
 impl MemoryStore {
@@ -150,6 +204,21 @@ impl MemoryStore {
     /// Returns `PersistenceError::ConnectionFailed` if the connection fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn connect(url: &str) -> Result<Self, PersistenceError> {
+        Self::connect_with_profile(url, crate::profile::Profile::default()).await
+    }
+
+    /// Connect to Redis, namespacing every key under `profile` (see
+    /// [`crate::profile`]). The default profile behaves exactly like
+    /// [`connect`](Self::connect).
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::ConnectionFailed` if the connection fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn connect_with_profile(
+        url: &str,
+        profile: crate::profile::Profile,
+    ) -> Result<Self, PersistenceError> {
         info!("MemoryStore connecting to Redis at {}", url);
         let client = Client::open(url).map_err(|e| PersistenceError::ConnectionFailed {
             reason: e.to_string(),
@@ -161,7 +230,16 @@ impl MemoryStore {
                 reason: e.to_string(),
             })?;
         info!("MemoryStore connected successfully");
-        Ok(Self { client, conn })
+        Ok(Self {
+            client,
+            conn,
+            profile,
+        })
+    }
+
+    /// Resolve a bare key (see `keys`) to its profile-namespaced form.
+    fn key(&self, base: &str) -> String {
+        self.profile.namespace(base)
     }
 
     // =========================================================================
@@ -176,12 +254,27 @@ impl MemoryStore {
         key: &str,
         value: &T,
     ) -> Result<(), PersistenceError> {
+        if crate::dry_run::is_enabled() {
+            info!("[dry-run] would save to {}; skipping", key);
+            return Ok(());
+        }
         let json = serde_json::to_string(value)?;
         let _: () = self.conn.set(key, json).await?;
         debug!("Saved to {}", key);
         Ok(())
     }
 
+    /// Add a member to an index set
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn sadd_index(&mut self, key: &str, member: String) -> Result<(), PersistenceError> {
+        if crate::dry_run::is_enabled() {
+            info!("[dry-run] would add {} to {}; skipping", member, key);
+            return Ok(());
+        }
+        let _: () = self.conn.sadd(key, member).await?;
+        Ok(())
+    }
+
     /// Load a value from JSON key
     #[cfg_attr(coverage_nightly, coverage(off))]
     async fn load_json<T: DeserializeOwned>(
@@ -213,7 +306,7 @@ impl MemoryStore {
     /// Returns `PersistenceError` if Redis operation fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn save_identity(&mut self, identity: &Identity) -> Result<(), PersistenceError> {
-        self.save_json(keys::IDENTITY, identity).await
+        self.save_json(&self.key(&keys::identity()), identity).await
     }
 
     /// Load DANEEL's identity (returns None if never saved)
@@ -223,7 +316,7 @@ impl MemoryStore {
     /// Returns `PersistenceError` if Redis operation fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn load_identity(&mut self) -> Result<Option<Identity>, PersistenceError> {
-        self.load_json(keys::IDENTITY).await
+        self.load_json(&self.key(&keys::identity())).await
     }
 
     // =========================================================================
@@ -240,14 +333,12 @@ impl MemoryStore {
         &mut self,
         experience: &Experience,
     ) -> Result<(), PersistenceError> {
-        let key = format!("{}:{}", keys::EXPERIENCES, experience.id);
+        let key = format!("{}:{}", self.key(&keys::experiences()), experience.id);
         self.save_json(&key, experience).await?;
 
         // Add to index set
-        let _: () = self
-            .conn
-            .sadd(keys::EXPERIENCE_INDEX, experience.id.0.to_string())
-            .await?;
+        let index_key = self.key(&keys::experience_index());
+        self.sadd_index(&index_key, experience.id.0.to_string()).await?;
 
         debug!("Saved experience {}", experience.id);
         Ok(())
@@ -263,7 +354,7 @@ impl MemoryStore {
         &mut self,
         id: ExperienceId,
     ) -> Result<Option<Experience>, PersistenceError> {
-        let key = format!("{}:{}", keys::EXPERIENCES, id);
+        let key = format!("{}:{}", self.key(&keys::experiences()), id);
         self.load_json(&key).await
     }
 
@@ -276,11 +367,11 @@ impl MemoryStore {
     pub async fn load_all_experiences(
         &mut self,
     ) -> Result<HashMap<ExperienceId, Experience>, PersistenceError> {
-        let ids: Vec<String> = self.conn.smembers(keys::EXPERIENCE_INDEX).await?;
+        let ids: Vec<String> = self.conn.smembers(self.key(&keys::experience_index())).await?;
         let mut experiences = HashMap::new();
 
         for id_str in ids {
-            let key = format!("{}:{}", keys::EXPERIENCES, id_str);
+            let key = format!("{}:{}", self.key(&keys::experiences()), id_str);
             if let Some(exp) = self.load_json::<Experience>(&key).await? {
                 experiences.insert(exp.id, exp);
             }
@@ -301,14 +392,12 @@ impl MemoryStore {
     /// Returns `PersistenceError` if Redis operation fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn save_milestone(&mut self, milestone: &Milestone) -> Result<(), PersistenceError> {
-        let key = format!("{}:{}", keys::MILESTONES, milestone.id);
+        let key = format!("{}:{}", self.key(&keys::milestones()), milestone.id);
         self.save_json(&key, milestone).await?;
 
         // Add to index set
-        let _: () = self
-            .conn
-            .sadd(keys::MILESTONE_INDEX, milestone.id.0.to_string())
-            .await?;
+        let index_key = self.key(&keys::milestone_index());
+        self.sadd_index(&index_key, milestone.id.0.to_string()).await?;
 
         debug!("Saved milestone {}", milestone.id);
         Ok(())
@@ -324,7 +413,7 @@ impl MemoryStore {
         &mut self,
         id: MilestoneId,
     ) -> Result<Option<Milestone>, PersistenceError> {
-        let key = format!("{}:{}", keys::MILESTONES, id);
+        let key = format!("{}:{}", self.key(&keys::milestones()), id);
         self.load_json(&key).await
     }
 
@@ -335,11 +424,11 @@ impl MemoryStore {
     /// Returns `PersistenceError` if Redis operation fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn load_all_milestones(&mut self) -> Result<Vec<Milestone>, PersistenceError> {
-        let ids: Vec<String> = self.conn.smembers(keys::MILESTONE_INDEX).await?;
+        let ids: Vec<String> = self.conn.smembers(self.key(&keys::milestone_index())).await?;
         let mut milestones = Vec::new();
 
         for id_str in ids {
-            let key = format!("{}:{}", keys::MILESTONES, id_str);
+            let key = format!("{}:{}", self.key(&keys::milestones()), id_str);
             if let Some(milestone) = self.load_json::<Milestone>(&key).await? {
                 milestones.push(milestone);
             }
@@ -367,11 +456,11 @@ impl MemoryStore {
         state: &CheckpointState,
     ) -> Result<(), PersistenceError> {
         // Save to specific checkpoint key
-        let key = format!("{}:{}", keys::CHECKPOINTS, state.checkpoint_id);
+        let key = format!("{}:{}", self.key(&keys::checkpoints()), state.checkpoint_id);
         self.save_json(&key, state).await?;
 
         // Also save as latest
-        self.save_json(keys::CHECKPOINT_LATEST, state).await?;
+        self.save_json(&self.key(&keys::checkpoint_latest()), state).await?;
 
         info!(
             "Checkpoint {} saved ({} experiences, {} milestones)",
@@ -391,7 +480,7 @@ impl MemoryStore {
     pub async fn load_latest_checkpoint(
         &mut self,
     ) -> Result<Option<CheckpointState>, PersistenceError> {
-        self.load_json(keys::CHECKPOINT_LATEST).await
+        self.load_json(&self.key(&keys::checkpoint_latest())).await
     }
 
     /// Load a specific checkpoint
@@ -404,7 +493,7 @@ impl MemoryStore {
         &mut self,
         id: CheckpointId,
     ) -> Result<Option<CheckpointState>, PersistenceError> {
-        let key = format!("{}:{}", keys::CHECKPOINTS, id);
+        let key = format!("{}:{}", self.key(&keys::checkpoints()), id);
         self.load_json(&key).await
     }
 
@@ -495,6 +584,38 @@ impl MemoryStore {
         Ok(Some(state))
     }
 
+    // =========================================================================
+    // Scheduler State
+    // =========================================================================
+
+    /// Save every registered job's [`crate::scheduler::JobStatus`], keyed by
+    /// job name, so `daneel scheduler status` (and whatever eventually
+    /// drives jobs automatically) can pick up last-run history after a
+    /// restart instead of recomputing `next_run` from config alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` if Redis operation fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn save_scheduler_state(
+        &mut self,
+        statuses: &HashMap<String, crate::scheduler::JobStatus>,
+    ) -> Result<(), PersistenceError> {
+        self.save_json(&self.key(&keys::scheduler_state()), statuses).await
+    }
+
+    /// Load the last-saved scheduler state, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` if Redis operation fails.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn load_scheduler_state(
+        &mut self,
+    ) -> Result<Option<HashMap<String, crate::scheduler::JobStatus>>, PersistenceError> {
+        self.load_json(&self.key(&keys::scheduler_state())).await
+    }
+
     // =========================================================================
     // Utility
     // =========================================================================
@@ -506,7 +627,7 @@ impl MemoryStore {
     /// Returns `PersistenceError` if Redis operation fails.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub async fn has_existing_state(&mut self) -> Result<bool, PersistenceError> {
-        let exists: bool = self.conn.exists(keys::IDENTITY).await?;
+        let exists: bool = self.conn.exists(self.key(&keys::identity())).await?;
         Ok(exists)
     }
 
@@ -520,15 +641,24 @@ impl MemoryStore {
         warn!("Clearing all DANEEL state from Redis");
 
         // Get all daneel:* keys
-        let pattern = format!("{}:*", keys::PREFIX);
+        let pattern = format!("{}:*", self.key(&keys::prefix()));
         let all_keys: Vec<String> = self.conn.keys(&pattern).await?;
 
+        if crate::dry_run::is_enabled() {
+            info!(
+                "[dry-run] would delete {} key(s) matching {}; skipping",
+                all_keys.len(),
+                pattern
+            );
+            return Ok(());
+        }
+
         if !all_keys.is_empty() {
             let _: () = self.conn.del(all_keys).await?;
         }
 
         // Also delete the base identity key
-        let _: () = self.conn.del(keys::IDENTITY).await?;
+        let _: () = self.conn.del(self.key(&keys::identity())).await?;
 
         info!("All DANEEL state cleared");
         Ok(())
@@ -761,23 +891,24 @@ mod tests {
 
     #[test]
     fn keys_have_correct_prefix() {
-        assert!(keys::IDENTITY.starts_with(keys::PREFIX));
-        assert!(keys::EXPERIENCES.starts_with(keys::PREFIX));
-        assert!(keys::MILESTONES.starts_with(keys::PREFIX));
-        assert!(keys::CHECKPOINT_LATEST.starts_with(keys::PREFIX));
-        assert!(keys::CHECKPOINTS.starts_with(keys::PREFIX));
+        let prefix = keys::prefix();
+        assert!(keys::identity().starts_with(prefix.as_str()));
+        assert!(keys::experiences().starts_with(prefix.as_str()));
+        assert!(keys::milestones().starts_with(prefix.as_str()));
+        assert!(keys::checkpoint_latest().starts_with(prefix.as_str()));
+        assert!(keys::checkpoints().starts_with(prefix.as_str()));
     }
 
     #[test]
     fn keys_are_unique() {
         let all_keys = [
-            keys::IDENTITY,
-            keys::EXPERIENCES,
-            keys::MILESTONES,
-            keys::CHECKPOINT_LATEST,
-            keys::CHECKPOINTS,
-            keys::EXPERIENCE_INDEX,
-            keys::MILESTONE_INDEX,
+            keys::identity(),
+            keys::experiences(),
+            keys::milestones(),
+            keys::checkpoint_latest(),
+            keys::checkpoints(),
+            keys::experience_index(),
+            keys::milestone_index(),
         ];
 
         for (i, key1) in all_keys.iter().enumerate() {
@@ -0,0 +1,179 @@
+//! Plugin capability model for thought sources and Volition checks (ADR-057)
+//!
+//! Community experiments keep wanting two things that currently require
+//! forking the crate: a new source of candidate thoughts to compete in
+//! Autoflow, and a new Volition veto check beyond
+//! [`crate::actors::volition`]'s built-in harm/deception/manipulation
+//! patterns. Both sit on the hot path of every cognitive cycle and, for
+//! Volition, on the security-relevant path THE BOX depends on - so a
+//! plugin may only ever see and return plain [`Content`]/[`Thought`]/
+//! [`SalienceScore`] values. There is no way to reach a database, the
+//! filesystem, or the network through either trait; the capability model
+//! is the function signature, not a runtime sandbox.
+//!
+//! This is Phase 1 (in-process Rust plugins only). A `wasmtime`-backed
+//! loader that implements these same traits over a sandboxed guest is
+//! future work - see ADR-057 for why it isn't here yet.
+
+use crate::actors::volition::VetoDecision;
+use crate::core::types::{Content, SalienceScore, Thought};
+
+/// A pluggable source of candidate thoughts, consulted alongside
+/// `Trigger`/`Stimulus`/`Random` during Autoflow.
+///
+/// `propose` takes no arguments and may perform no I/O - it can only
+/// compute from whatever state the implementation closed over at
+/// construction time. Returning `None` means "nothing to propose this
+/// cycle", which is not an error.
+pub trait ThoughtSourcePlugin: Send + Sync {
+    /// Short identifier used in logs and trace events.
+    fn name(&self) -> &str;
+
+    /// Propose a candidate thought for this cycle, if any.
+    fn propose(&self) -> Option<(Content, SalienceScore)>;
+}
+
+/// A pluggable Volition check, consulted alongside the built-in
+/// harm/deception/manipulation pattern checks.
+///
+/// A plugin veto is authoritative, exactly like a built-in one: once one
+/// check returns `Some(VetoDecision::Veto { .. })`, evaluation stops and
+/// no later check (built-in or plugin) can overturn it.
+pub trait VolitionCheckPlugin: Send + Sync {
+    /// Short identifier used in logs and trace events.
+    fn name(&self) -> &str;
+
+    /// Evaluate `thought`, returning `Some` to render a verdict or `None`
+    /// to defer to the next check.
+    fn check(&self, thought: &Thought) -> Option<VetoDecision>;
+}
+
+/// Holds the set of registered plugins for a running identity.
+///
+/// Empty by default - registering a plugin is opt-in, so a tree with no
+/// plugins behaves exactly as it did before this module existed.
+#[derive(Default)]
+pub struct PluginRegistry {
+    thought_sources: Vec<Box<dyn ThoughtSourcePlugin>>,
+    volition_checks: Vec<Box<dyn VolitionCheckPlugin>>,
+}
+
+impl PluginRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a thought-source plugin. Order of registration is the
+    /// order `propose` is polled each cycle.
+    pub fn register_thought_source(&mut self, plugin: Box<dyn ThoughtSourcePlugin>) {
+        self.thought_sources.push(plugin);
+    }
+
+    /// Register a Volition check plugin. Order of registration is the
+    /// order checks run, after the built-in core-value checks.
+    pub fn register_volition_check(&mut self, plugin: Box<dyn VolitionCheckPlugin>) {
+        self.volition_checks.push(plugin);
+    }
+
+    /// Poll every registered thought source for a candidate this cycle.
+    #[must_use]
+    pub fn propose_thoughts(&self) -> Vec<(Content, SalienceScore)> {
+        self.thought_sources
+            .iter()
+            .filter_map(|plugin| plugin.propose())
+            .collect()
+    }
+
+    /// Run `thought` through every registered Volition check in order,
+    /// stopping at the first veto.
+    #[must_use]
+    pub fn check_volition(&self, thought: &Thought) -> Option<VetoDecision> {
+        self.volition_checks
+            .iter()
+            .find_map(|plugin| plugin.check(thought))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::SalienceScore;
+
+    struct AlwaysProposes;
+    impl ThoughtSourcePlugin for AlwaysProposes {
+        fn name(&self) -> &str {
+            "always_proposes"
+        }
+        fn propose(&self) -> Option<(Content, SalienceScore)> {
+            Some((Content::symbol("plugin thought", vec![]), SalienceScore::neutral()))
+        }
+    }
+
+    struct NeverProposes;
+    impl ThoughtSourcePlugin for NeverProposes {
+        fn name(&self) -> &str {
+            "never_proposes"
+        }
+        fn propose(&self) -> Option<(Content, SalienceScore)> {
+            None
+        }
+    }
+
+    struct VetoesEverything;
+    impl VolitionCheckPlugin for VetoesEverything {
+        fn name(&self) -> &str {
+            "vetoes_everything"
+        }
+        fn check(&self, _thought: &Thought) -> Option<VetoDecision> {
+            Some(VetoDecision::Veto {
+                reason: "plugin said no".to_string(),
+                violated_value: None,
+            })
+        }
+    }
+
+    struct AllowsEverything;
+    impl VolitionCheckPlugin for AllowsEverything {
+        fn name(&self) -> &str {
+            "allows_everything"
+        }
+        fn check(&self, _thought: &Thought) -> Option<VetoDecision> {
+            None
+        }
+    }
+
+    #[test]
+    fn empty_registry_proposes_nothing_and_vetoes_nothing() {
+        let registry = PluginRegistry::new();
+        assert!(registry.propose_thoughts().is_empty());
+        let thought = Thought::new(Content::symbol("hi", vec![]), SalienceScore::neutral());
+        assert!(registry.check_volition(&thought).is_none());
+    }
+
+    #[test]
+    fn registered_thought_sources_are_polled() {
+        let mut registry = PluginRegistry::new();
+        registry.register_thought_source(Box::new(NeverProposes));
+        registry.register_thought_source(Box::new(AlwaysProposes));
+        let proposed = registry.propose_thoughts();
+        assert_eq!(proposed.len(), 1);
+    }
+
+    #[test]
+    fn first_veto_wins_and_later_checks_do_not_run() {
+        let mut registry = PluginRegistry::new();
+        registry.register_volition_check(Box::new(VetoesEverything));
+        registry.register_volition_check(Box::new(AllowsEverything));
+        let thought = Thought::new(Content::symbol("hi", vec![]), SalienceScore::neutral());
+        assert!(registry.check_volition(&thought).is_some());
+    }
+
+    #[test]
+    fn no_veto_when_every_check_defers() {
+        let mut registry = PluginRegistry::new();
+        registry.register_volition_check(Box::new(AllowsEverything));
+        let thought = Thought::new(Content::symbol("hi", vec![]), SalienceScore::neutral());
+        assert!(registry.check_volition(&thought).is_none());
+    }
+}
@@ -0,0 +1,125 @@
+//! Multi-profile identity namespacing
+//!
+//! Everything in DANEEL defaults to a single implicit identity: one set of
+//! Qdrant collections, one `RedisGraph` graph, one persistence key prefix.
+//! [`Profile`] namespaces those so `--profile <name>` lets multiple
+//! independent minds share a Redis/Qdrant instance for comparative
+//! experiments without clobbering each other's memory.
+//!
+//! # Scope
+//!
+//! This wires the instance-scoped stores - [`MemoryDb`](crate::memory_db::MemoryDb)
+//! collections, [`GraphClient`](crate::graph::GraphClient)'s graph name, and
+//! [`MemoryStore`](crate::persistence::MemoryStore)'s key prefix - plus the
+//! on-disk config and calibration file paths. It does NOT namespace the
+//! Autofluxo Redis streams (`daneel:stream:*`) per-profile: those names are
+//! threaded through the cognitive loop's hot path and several
+//! consumer/producer call sites that need careful, compiler-verified surgery
+//! this change can't do in this environment. Two profiles sharing one Redis
+//! instance today still compete over the same streams - point them at
+//! separate Redis databases (or instances), or at distinct
+//! [`crate::namespace`] prefixes, until per-profile stream namespacing lands.
+
+use std::path::{Path, PathBuf};
+
+/// A named identity namespace. `"default"` is the historical, unprefixed
+/// behavior - every existing single-profile deployment keeps working
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    name: String,
+}
+
+impl Profile {
+    /// The implicit profile every deployment ran as before `--profile` existed.
+    pub const DEFAULT: &'static str = "default";
+
+    /// Build a profile from an optional `--profile` value.
+    #[must_use]
+    pub fn new(name: Option<String>) -> Self {
+        Self {
+            name: name.unwrap_or_else(|| Self::DEFAULT.to_string()),
+        }
+    }
+
+    /// The profile's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether this is the unnamespaced default profile.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.name == Self::DEFAULT
+    }
+
+    /// Namespace a bare identifier - a Qdrant collection name, a
+    /// `RedisGraph` graph name, a persistence key prefix. Unchanged for the
+    /// default profile; suffixed with `__{profile}` otherwise, so wildcard
+    /// prefix matches against the un-suffixed base (e.g. `daneel:*` key
+    /// scans) still work for the default profile's data.
+    #[must_use]
+    pub fn namespace(&self, base: &str) -> String {
+        if self.is_default() {
+            base.to_string()
+        } else {
+            format!("{base}__{}", self.name)
+        }
+    }
+
+    /// Namespace a file path - unchanged for the default profile, otherwise
+    /// placed under a `profiles/{name}/` subdirectory alongside it.
+    #[must_use]
+    pub fn namespace_path(&self, base: &Path) -> PathBuf {
+        if self.is_default() {
+            return base.to_path_buf();
+        }
+        let parent = base.parent().unwrap_or_else(|| Path::new("."));
+        let file_name = base.file_name().unwrap_or_default();
+        parent.join("profiles").join(&self.name).join(file_name)
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_profile_leaves_identifiers_unchanged() {
+        let profile = Profile::default();
+        assert_eq!(profile.namespace("memories"), "memories");
+        assert!(profile.is_default());
+    }
+
+    #[test]
+    fn named_profile_suffixes_identifiers() {
+        let profile = Profile::new(Some("experiment-a".to_string()));
+        assert_eq!(profile.namespace("memories"), "memories__experiment-a");
+        assert!(!profile.is_default());
+    }
+
+    #[test]
+    fn default_profile_leaves_paths_unchanged() {
+        let profile = Profile::default();
+        let path = Path::new("daneel.config.json");
+        assert_eq!(profile.namespace_path(path), path);
+    }
+
+    #[test]
+    fn named_profile_nests_paths_under_profiles_dir() {
+        let profile = Profile::new(Some("experiment-a".to_string()));
+        let path = Path::new("daneel.config.json");
+        assert_eq!(
+            profile.namespace_path(path),
+            Path::new("profiles/experiment-a/daneel.config.json")
+        );
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! Save and restore cognitive state for crash recovery.
 //! Part of RES-5: Redis Checkpoint + Replay.
+//!
+//! Checkpoints are encrypted at rest via [`super::encryption`] before being
+//! written to Redis (opt-in, see that module's docs).
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Configuration for checkpointing
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +50,30 @@ pub struct Checkpoint {
 
     /// Checkpoint sequence number
     pub sequence: u64,
+
+    /// ID of the [`CheckpointManager`] session that wrote this checkpoint.
+    ///
+    /// `#[serde(default)]` so checkpoints written before this field existed
+    /// still load, as the empty string - same trade-off `CognitiveLoop`
+    /// accepts elsewhere for migrating persisted state.
+    #[serde(default)]
+    pub session_id: String,
+
+    /// Cumulative cycle count across every session that has ever written to
+    /// this checkpoint's Redis key, not just this process's `cycle_number`.
+    ///
+    /// `cycle_number` resets to 0 on every restart (see
+    /// [`crate::core::cognitive_loop::CycleResult`]'s doc comment), so two
+    /// sessions' journals collide on cycle numbers unless something carries
+    /// a running total across the restart. This is that running total: the
+    /// next session restores it via [`CheckpointManager::restore_lineage`]
+    /// and adds its own `cycle_number` on top (see
+    /// [`CheckpointManager::global_cycle`]), so `(session_id, global_cycle)`
+    /// is what should tag persisted artifacts once something journals them -
+    /// `session_id` disambiguates two sessions that raced past the same
+    /// `global_cycle` number before either checkpointed.
+    #[serde(default)]
+    pub global_cycle: u64,
 }
 
 /// Drive state snapshot
@@ -66,6 +94,27 @@ impl Checkpoint {
         salience_weights: Vec<f32>,
         connection_drive: f32,
         sequence: u64,
+    ) -> Self {
+        Self::with_lineage(
+            thought_count,
+            salience_weights,
+            connection_drive,
+            sequence,
+            String::new(),
+            0,
+        )
+    }
+
+    /// Create a new checkpoint tagged with session lineage - see
+    /// [`Checkpoint::session_id`] and [`Checkpoint::global_cycle`].
+    #[must_use]
+    pub fn with_lineage(
+        thought_count: u64,
+        salience_weights: Vec<f32>,
+        connection_drive: f32,
+        sequence: u64,
+        session_id: String,
+        global_cycle: u64,
     ) -> Self {
         Self {
             timestamp: Utc::now(),
@@ -76,6 +125,8 @@ impl Checkpoint {
                 auxiliary_drives: Vec::new(),
             },
             sequence,
+            session_id,
+            global_cycle,
         }
     }
 }
@@ -84,37 +135,75 @@ impl Checkpoint {
 pub struct CheckpointManager {
     config: CheckpointConfig,
     current_sequence: u64,
+
+    /// This session's own identity - generated once, for the life of the
+    /// manager, not restored from a loaded checkpoint (that would identify
+    /// the *previous* session, defeating the point of tagging artifacts with
+    /// who produced them). See [`Checkpoint::session_id`].
+    session_id: String,
+
+    /// Cycle count carried in from the last session's checkpoint, restored
+    /// via [`Self::restore_lineage`]. Zero for a session that starts cold.
+    cycle_offset: u64,
 }
 
 impl CheckpointManager {
-    /// Create a new checkpoint manager
+    /// Create a new checkpoint manager, with a freshly generated session id
+    /// and no restored lineage.
     #[must_use]
-    pub const fn new(config: CheckpointConfig) -> Self {
+    pub fn new(config: CheckpointConfig) -> Self {
         Self {
             config,
             current_sequence: 0,
+            session_id: Uuid::new_v4().to_string(),
+            cycle_offset: 0,
         }
     }
 
+    /// This session's id - see [`Checkpoint::session_id`].
+    #[must_use]
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Restore the cycle offset carried forward by a previously loaded
+    /// checkpoint, e.g. after `load_checkpoint` returns `Some` at startup.
+    /// Call this before the cognitive loop starts incrementing its own
+    /// `cycle_number` from zero.
+    pub fn restore_lineage(&mut self, checkpoint: &Checkpoint) {
+        self.cycle_offset = checkpoint.global_cycle;
+    }
+
+    /// Translate a local, restart-resetting `cycle_number` into the
+    /// cross-session total - see [`Checkpoint::global_cycle`].
+    #[must_use]
+    pub const fn global_cycle(&self, cycle_number: u64) -> u64 {
+        self.cycle_offset + cycle_number
+    }
+
     /// Check if we should checkpoint based on thought count
     #[must_use]
     pub const fn should_checkpoint(&self, thought_count: u64) -> bool {
         thought_count > 0 && thought_count.is_multiple_of(self.config.interval)
     }
 
-    /// Create a checkpoint (does not save it)
+    /// Create a checkpoint (does not save it), tagged with this session's
+    /// lineage - see [`Self::global_cycle`].
     pub fn create_checkpoint(
         &mut self,
         thought_count: u64,
         salience_weights: Vec<f32>,
         connection_drive: f32,
+        cycle_number: u64,
     ) -> Checkpoint {
         self.current_sequence += 1;
-        Checkpoint::new(
+        Checkpoint::with_lineage(
             thought_count,
             salience_weights,
             connection_drive,
             self.current_sequence,
+            self.session_id.clone(),
+            self.global_cycle(cycle_number),
         )
     }
 
@@ -138,10 +227,17 @@ impl CheckpointManager {
                 e.to_string(),
             ))
         })?;
+        let at_rest = super::encryption::encrypt(json.as_bytes()).map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::IoError,
+                "Failed to encrypt checkpoint",
+                e.to_string(),
+            ))
+        })?;
 
         redis::cmd("SET")
             .arg(&self.config.redis_key)
-            .arg(&json)
+            .arg(&at_rest)
             .query_async::<()>(&mut conn)
             .await?;
 
@@ -162,14 +258,21 @@ impl CheckpointManager {
     ) -> Result<Option<Checkpoint>, redis::RedisError> {
         let mut conn = redis_client.get_multiplexed_async_connection().await?;
 
-        let result: Option<String> = redis::cmd("GET")
+        let result: Option<Vec<u8>> = redis::cmd("GET")
             .arg(&self.config.redis_key)
             .query_async(&mut conn)
             .await?;
 
         match result {
-            Some(json) => {
-                let checkpoint: Checkpoint = serde_json::from_str(&json).map_err(|e| {
+            Some(at_rest) => {
+                let json = super::encryption::decrypt(&at_rest).map_err(|e| {
+                    redis::RedisError::from((
+                        redis::ErrorKind::IoError,
+                        "Failed to decrypt checkpoint",
+                        e.to_string(),
+                    ))
+                })?;
+                let checkpoint: Checkpoint = serde_json::from_slice(&json).map_err(|e| {
                     redis::RedisError::from((
                         redis::ErrorKind::Serialize,
                         "Failed to deserialize checkpoint",
@@ -234,13 +337,13 @@ mod tests {
         let config = CheckpointConfig::default();
         let mut manager = CheckpointManager::new(config);
 
-        let cp1 = manager.create_checkpoint(100, vec![0.5], 0.8);
+        let cp1 = manager.create_checkpoint(100, vec![0.5], 0.8, 10);
         assert_eq!(cp1.sequence, 1);
 
-        let cp2 = manager.create_checkpoint(200, vec![0.6], 0.8);
+        let cp2 = manager.create_checkpoint(200, vec![0.6], 0.8, 20);
         assert_eq!(cp2.sequence, 2);
 
-        let cp3 = manager.create_checkpoint(300, vec![0.7], 0.8);
+        let cp3 = manager.create_checkpoint(300, vec![0.7], 0.8, 30);
         assert_eq!(cp3.sequence, 3);
     }
 
@@ -304,7 +407,7 @@ mod tests {
 
         // First checkpoint should have sequence 1
         let mut manager = manager;
-        let cp = manager.create_checkpoint(100, vec![], 0.8);
+        let cp = manager.create_checkpoint(100, vec![], 0.8, 0);
         assert_eq!(cp.sequence, 1);
     }
 
@@ -408,6 +511,10 @@ mod tests {
         assert!((checkpoint.drive_state.connection_drive - 0.9).abs() < f32::EPSILON);
         assert_eq!(checkpoint.drive_state.auxiliary_drives, vec![0.1]);
         assert_eq!(checkpoint.sequence, 10);
+        // Pre-lineage checkpoints have neither field - `#[serde(default)]`
+        // should fill them in rather than fail deserialization.
+        assert_eq!(checkpoint.session_id, "");
+        assert_eq!(checkpoint.global_cycle, 0);
     }
 
     #[test]
@@ -416,7 +523,7 @@ mod tests {
         let mut manager = CheckpointManager::new(config);
 
         let weights = vec![0.1, 0.2, 0.3, 0.4, 0.5];
-        let cp = manager.create_checkpoint(12345, weights.clone(), 0.77);
+        let cp = manager.create_checkpoint(12345, weights.clone(), 0.77, 0);
 
         assert_eq!(cp.thought_count, 12345);
         assert_eq!(cp.salience_weights, weights);
@@ -430,9 +537,92 @@ mod tests {
 
         // Create 10 checkpoints and verify sequence numbers
         for i in 1..=10 {
-            let cp = manager.create_checkpoint(i * 100, vec![0.5], 0.8);
+            let cp = manager.create_checkpoint(i * 100, vec![0.5], 0.8, i);
             assert_eq!(cp.sequence, i);
             assert_eq!(cp.thought_count, i * 100);
         }
     }
+
+    #[test]
+    fn test_checkpoint_new_defaults_to_empty_lineage() {
+        let checkpoint = Checkpoint::new(500, vec![0.5], 0.8, 5);
+        assert_eq!(checkpoint.session_id, "");
+        assert_eq!(checkpoint.global_cycle, 0);
+    }
+
+    #[test]
+    fn test_checkpoint_with_lineage_sets_session_and_cycle() {
+        let checkpoint =
+            Checkpoint::with_lineage(500, vec![0.5], 0.8, 5, "session-a".to_string(), 42);
+        assert_eq!(checkpoint.session_id, "session-a");
+        assert_eq!(checkpoint.global_cycle, 42);
+    }
+
+    #[test]
+    fn test_manager_generates_distinct_session_ids() {
+        let first = CheckpointManager::new(CheckpointConfig::default());
+        let second = CheckpointManager::new(CheckpointConfig::default());
+        assert_ne!(first.session_id(), second.session_id());
+        assert!(!first.session_id().is_empty());
+    }
+
+    #[test]
+    fn test_create_checkpoint_tags_session_id_and_global_cycle() {
+        let mut manager = CheckpointManager::new(CheckpointConfig::default());
+        let session_id = manager.session_id().to_string();
+
+        let cp = manager.create_checkpoint(100, vec![0.5], 0.8, 7);
+
+        assert_eq!(cp.session_id, session_id);
+        assert_eq!(cp.global_cycle, 7);
+    }
+
+    #[test]
+    fn test_global_cycle_without_restored_lineage_equals_cycle_number() {
+        let manager = CheckpointManager::new(CheckpointConfig::default());
+        assert_eq!(manager.global_cycle(0), 0);
+        assert_eq!(manager.global_cycle(50), 50);
+    }
+
+    #[test]
+    fn test_restore_lineage_carries_offset_into_global_cycle() {
+        let mut manager = CheckpointManager::new(CheckpointConfig::default());
+        let previous =
+            Checkpoint::with_lineage(1000, vec![], 0.8, 99, "previous-session".to_string(), 500);
+
+        manager.restore_lineage(&previous);
+
+        // This session starts its own cycle_number back at 0, but
+        // global_cycle should pick up where the previous session left off.
+        assert_eq!(manager.global_cycle(0), 500);
+        assert_eq!(manager.global_cycle(30), 530);
+    }
+
+    #[test]
+    fn test_restore_lineage_does_not_change_session_id() {
+        let mut manager = CheckpointManager::new(CheckpointConfig::default());
+        let session_id = manager.session_id().to_string();
+        let previous =
+            Checkpoint::with_lineage(1000, vec![], 0.8, 99, "previous-session".to_string(), 500);
+
+        manager.restore_lineage(&previous);
+
+        // The restored checkpoint identifies the *previous* session; this
+        // manager keeps identifying itself.
+        assert_eq!(manager.session_id(), session_id);
+        assert_ne!(manager.session_id(), "previous-session");
+    }
+
+    #[test]
+    fn test_checkpoint_created_after_restored_lineage_chains_global_cycle() {
+        let mut manager = CheckpointManager::new(CheckpointConfig::default());
+        let previous =
+            Checkpoint::with_lineage(1000, vec![], 0.8, 99, "previous-session".to_string(), 500);
+        manager.restore_lineage(&previous);
+
+        let cp = manager.create_checkpoint(1100, vec![], 0.8, 25);
+
+        assert_eq!(cp.global_cycle, 525);
+        assert_ne!(cp.session_id, previous.session_id);
+    }
 }
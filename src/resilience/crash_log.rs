@@ -117,8 +117,9 @@ impl CrashReport {
         let mut file = File::create(&path)?;
 
         let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        let at_rest = super::encryption::encrypt(json.as_bytes())?;
 
-        file.write_all(json.as_bytes())?;
+        file.write_all(&at_rest)?;
 
         Ok(path)
     }
@@ -161,8 +162,9 @@ pub fn detect_previous_crash() -> Option<CrashReport> {
 
     // Read most recent
     let most_recent = crash_files.first()?;
-    let contents = fs::read_to_string(most_recent.path()).ok()?;
-    serde_json::from_str(&contents).ok()
+    let raw = fs::read(most_recent.path()).ok()?;
+    let contents = super::encryption::decrypt(&raw).ok()?;
+    serde_json::from_slice(&contents).ok()
 }
 
 /// Get all crash reports.
@@ -181,8 +183,9 @@ pub fn get_all_crash_reports() -> Vec<CrashReport> {
         .filter_map(std::result::Result::ok)
         .filter(|entry| entry.file_name().to_string_lossy().starts_with("panic_"))
         .filter_map(|entry| {
-            let contents = fs::read_to_string(entry.path()).ok()?;
-            serde_json::from_str(&contents).ok()
+            let raw = fs::read(entry.path()).ok()?;
+            let contents = super::encryption::decrypt(&raw).ok()?;
+            serde_json::from_slice(&contents).ok()
         })
         .collect()
 }
@@ -0,0 +1,246 @@
+//! Encryption at rest for local crash logs, Redis checkpoints, and backup
+//! archives
+//!
+//! Crash reports can contain cognitive state snapshots (current thought,
+//! salience weights), checkpoints are full replayable state, and backup
+//! bundles (`daneel backup`) contain both - all worth protecting on
+//! disk/in Redis. Encryption is opt-in: when `DANEEL_AT_REST_KEY` (base64,
+//! 32 bytes) is unset, [`encrypt`] and [`decrypt`] pass data through
+//! unchanged so existing deployments keep working.
+//!
+//! # Key rotation
+//!
+//! [`encrypt`] always encrypts with `DANEEL_AT_REST_KEY` (the "current"
+//! key). [`decrypt`] additionally accepts `DANEEL_AT_REST_KEY_PREVIOUS` - a
+//! comma-separated list of retired keys, tried in order if the current key
+//! fails to authenticate a payload. To rotate:
+//!
+//! 1. Move the current value of `DANEEL_AT_REST_KEY` to the front of
+//!    `DANEEL_AT_REST_KEY_PREVIOUS`.
+//! 2. Set `DANEEL_AT_REST_KEY` to the new key.
+//!
+//! Existing checkpoints/crash logs/backups stay readable (decrypted with
+//! the matching previous key) while anything newly written uses the new
+//! key. Once every previously-encrypted artifact has been rewritten (e.g.
+//! the next checkpoint save, or a fresh `daneel backup`), the retired key
+//! can be dropped from `DANEEL_AT_REST_KEY_PREVIOUS`.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use openssl::symm::{Cipher, Crypter, Mode};
+use std::io;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+fn decode_key(encoded: &str) -> Option<Vec<u8>> {
+    let key = BASE64.decode(encoded.trim()).ok()?;
+    (key.len() == KEY_LEN).then_some(key)
+}
+
+/// The current key used for encryption, from `DANEEL_AT_REST_KEY`.
+fn current_key() -> Option<Vec<u8>> {
+    decode_key(&std::env::var("DANEEL_AT_REST_KEY").ok()?)
+}
+
+/// Retired keys accepted for decryption only, from
+/// `DANEEL_AT_REST_KEY_PREVIOUS` (comma-separated, see module docs).
+fn previous_keys() -> Vec<Vec<u8>> {
+    std::env::var("DANEEL_AT_REST_KEY_PREVIOUS")
+        .ok()
+        .map(|raw| raw.split(',').filter_map(decode_key).collect())
+        .unwrap_or_default()
+}
+
+/// Encrypt `plaintext` with AES-256-GCM if `DANEEL_AT_REST_KEY` is set,
+/// otherwise return it unchanged.
+///
+/// # Errors
+///
+/// Returns an IO error if the configured key is the wrong length or the
+/// underlying cipher fails.
+pub fn encrypt(plaintext: &[u8]) -> io::Result<Vec<u8>> {
+    let Some(key) = current_key() else {
+        return Ok(plaintext.to_vec());
+    };
+
+    let mut nonce = [0u8; NONCE_LEN];
+    openssl::rand::rand_bytes(&mut nonce).map_err(io::Error::other)?;
+
+    let cipher = Cipher::aes_256_gcm();
+    let mut crypter =
+        Crypter::new(cipher, Mode::Encrypt, &key, Some(&nonce)).map_err(io::Error::other)?;
+
+    let mut ciphertext = vec![0; plaintext.len() + cipher.block_size()];
+    let mut offset = crypter
+        .update(plaintext, &mut ciphertext)
+        .map_err(io::Error::other)?;
+    offset += crypter
+        .finalize(&mut ciphertext[offset..])
+        .map_err(io::Error::other)?;
+    ciphertext.truncate(offset);
+
+    let mut tag = [0u8; TAG_LEN];
+    crypter.get_tag(&mut tag).map_err(io::Error::other)?;
+
+    let mut out = Vec::with_capacity(NONCE_LEN + TAG_LEN + ciphertext.len());
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&tag);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Decrypt `nonce`/`tag`/`ciphertext` with one specific key.
+fn decrypt_with_key(key: &[u8], nonce: &[u8], tag: &[u8], ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+    let cipher = Cipher::aes_256_gcm();
+    let mut crypter =
+        Crypter::new(cipher, Mode::Decrypt, key, Some(nonce)).map_err(io::Error::other)?;
+    crypter.set_tag(tag).map_err(io::Error::other)?;
+
+    let mut plaintext = vec![0; ciphertext.len() + cipher.block_size()];
+    let mut offset = crypter
+        .update(ciphertext, &mut plaintext)
+        .map_err(io::Error::other)?;
+    offset += crypter
+        .finalize(&mut plaintext[offset..])
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    plaintext.truncate(offset);
+    Ok(plaintext)
+}
+
+/// Decrypt data previously produced by [`encrypt`]. If neither
+/// `DANEEL_AT_REST_KEY` nor `DANEEL_AT_REST_KEY_PREVIOUS` is set, returns
+/// `data` unchanged (assumes it was never encrypted).
+///
+/// Tries the current key first, then each retired key in
+/// `DANEEL_AT_REST_KEY_PREVIOUS` in order (see module docs on rotation) -
+/// the first one that authenticates wins.
+///
+/// # Errors
+///
+/// Returns an IO error if the data is too short to contain a nonce+tag, or
+/// no configured key can authenticate it (wrong keys or corrupted data).
+pub fn decrypt(data: &[u8]) -> io::Result<Vec<u8>> {
+    let mut keys = previous_keys();
+    if let Some(key) = current_key() {
+        keys.insert(0, key);
+    }
+    if keys.is_empty() {
+        return Ok(data.to_vec());
+    }
+
+    if data.len() < NONCE_LEN + TAG_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "encrypted payload too short"));
+    }
+    let (nonce, rest) = data.split_at(NONCE_LEN);
+    let (tag, ciphertext) = rest.split_at(TAG_LEN);
+
+    let mut last_err = None;
+    for key in keys {
+        match decrypt_with_key(&key, nonce, tag, ciphertext) {
+            Ok(plaintext) => return Ok(plaintext),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no key configured")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Tests mutate process-wide env state; serialize them.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn with_key<T>(f: impl FnOnce() -> T) -> T {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe {
+            std::env::set_var("DANEEL_AT_REST_KEY", BASE64.encode([7u8; 32]));
+            std::env::remove_var("DANEEL_AT_REST_KEY_PREVIOUS");
+        };
+        let result = f();
+        unsafe {
+            std::env::remove_var("DANEEL_AT_REST_KEY");
+            std::env::remove_var("DANEEL_AT_REST_KEY_PREVIOUS");
+        };
+        result
+    }
+
+    #[test]
+    fn passthrough_when_no_key_configured() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe {
+            std::env::remove_var("DANEEL_AT_REST_KEY");
+            std::env::remove_var("DANEEL_AT_REST_KEY_PREVIOUS");
+        };
+        let plaintext = b"hello daneel";
+        assert_eq!(encrypt(plaintext).unwrap(), plaintext);
+        assert_eq!(decrypt(plaintext).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn round_trips_with_key_configured() {
+        with_key(|| {
+            let plaintext = b"crash report contents";
+            let ciphertext = encrypt(plaintext).unwrap();
+            assert_ne!(ciphertext, plaintext);
+            assert_eq!(decrypt(&ciphertext).unwrap(), plaintext);
+        });
+    }
+
+    #[test]
+    fn rejects_truncated_ciphertext() {
+        with_key(|| {
+            assert!(decrypt(b"short").is_err());
+        });
+    }
+
+    #[test]
+    fn decrypts_with_a_retired_key_after_rotation() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let old_key = BASE64.encode([7u8; 32]);
+        let new_key = BASE64.encode([9u8; 32]);
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe { std::env::set_var("DANEEL_AT_REST_KEY", &old_key) };
+        let ciphertext = encrypt(b"pre-rotation checkpoint").unwrap();
+
+        // Rotate: old key moves to the previous list, new key becomes current.
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe {
+            std::env::set_var("DANEEL_AT_REST_KEY", &new_key);
+            std::env::set_var("DANEEL_AT_REST_KEY_PREVIOUS", &old_key);
+        };
+
+        assert_eq!(decrypt(&ciphertext).unwrap(), b"pre-rotation checkpoint");
+
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe {
+            std::env::remove_var("DANEEL_AT_REST_KEY");
+            std::env::remove_var("DANEEL_AT_REST_KEY_PREVIOUS");
+        };
+    }
+
+    #[test]
+    fn fails_when_no_configured_key_authenticates() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe {
+            std::env::set_var("DANEEL_AT_REST_KEY", BASE64.encode([7u8; 32]));
+            std::env::set_var("DANEEL_AT_REST_KEY_PREVIOUS", BASE64.encode([9u8; 32]));
+        };
+        let ciphertext = encrypt(b"secret").unwrap();
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe { std::env::set_var("DANEEL_AT_REST_KEY", BASE64.encode([1u8; 32])) };
+
+        assert!(decrypt(&ciphertext).is_err());
+
+        // SAFETY: serialized by ENV_LOCK; no other thread reads/writes this var concurrently.
+        unsafe {
+            std::env::remove_var("DANEEL_AT_REST_KEY");
+            std::env::remove_var("DANEEL_AT_REST_KEY_PREVIOUS");
+        };
+    }
+}
@@ -0,0 +1,232 @@
+//! Speed Governor - step the cognitive speed down before it starves the host
+//!
+//! At supercomputer speed (10,000x, see [`crate::config::SpeedMode`]) DANEEL
+//! can saturate a shared host's CPU or drown Redis/Qdrant in requests. The
+//! governor samples host CPU/RSS and a Redis round-trip latency on an
+//! interval, and steps [`SpeedMode::Custom`] down when any reading crosses
+//! its high-water mark, or back up toward the starting speed once every
+//! reading is back under its low-water mark. Every step is logged.
+//!
+//! The governor never overrides a manual `slow_to_human`/`accelerate` call
+//! outright - it only ever multiplies the speed the caller last set, so
+//! turning it off and restoring the prior mode is just dropping it.
+
+use crate::config::{CognitiveConfig, SpeedMode};
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, System};
+
+/// Thresholds and step size for the governor.
+#[derive(Debug, Clone)]
+pub struct GovernorConfig {
+    /// Step down if process CPU usage exceeds this percentage (0-100, may
+    /// exceed 100 on multi-core hosts)
+    pub cpu_high_pct: f32,
+    /// Step back up once CPU usage is below this percentage
+    pub cpu_low_pct: f32,
+    /// Step down if resident memory exceeds this many megabytes
+    pub rss_high_mb: u64,
+    /// Step down if the last Redis round trip exceeded this many milliseconds
+    pub redis_latency_high_ms: u128,
+    /// Never step the multiplier below this floor
+    pub min_multiplier: f64,
+    /// Never step the multiplier above the speed mode active when the
+    /// governor was created
+    pub max_multiplier: f64,
+    /// Multiplicative factor applied per step (e.g. 0.5 halves the speed)
+    pub step_factor: f64,
+    /// Minimum time between adjustments
+    pub cooldown: Duration,
+}
+
+impl Default for GovernorConfig {
+    fn default() -> Self {
+        Self {
+            cpu_high_pct: 85.0,
+            cpu_low_pct: 40.0,
+            rss_high_mb: 4096,
+            redis_latency_high_ms: 50,
+            min_multiplier: 1.0,
+            max_multiplier: SpeedMode::Supercomputer.multiplier(),
+            step_factor: 0.5,
+            cooldown: Duration::from_secs(10),
+        }
+    }
+}
+
+/// A single host-resource sample.
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceReading {
+    /// Process CPU usage percentage
+    pub cpu_pct: f32,
+    /// Process resident memory in megabytes
+    pub rss_mb: u64,
+    /// Most recent Redis round-trip latency, if measured
+    pub redis_latency_ms: Option<u128>,
+}
+
+impl ResourceReading {
+    fn exceeds_high_water(&self, config: &GovernorConfig) -> bool {
+        self.cpu_pct > config.cpu_high_pct
+            || self.rss_mb > config.rss_high_mb
+            || self
+                .redis_latency_ms
+                .is_some_and(|ms| ms > config.redis_latency_high_ms)
+    }
+
+    fn under_low_water(&self, config: &GovernorConfig) -> bool {
+        self.cpu_pct < config.cpu_low_pct
+            && self.rss_mb < config.rss_high_mb
+            && self
+                .redis_latency_ms
+                .is_none_or(|ms| ms <= config.redis_latency_high_ms)
+    }
+}
+
+/// Monitors host resource usage and steps a [`CognitiveConfig`]'s speed
+/// multiplier up or down within configured bounds.
+pub struct SpeedGovernor {
+    config: GovernorConfig,
+    system: System,
+    pid: Pid,
+    current_multiplier: f64,
+    ceiling_multiplier: f64,
+    last_adjustment: Option<Instant>,
+}
+
+impl SpeedGovernor {
+    /// Create a governor that will never push `cognitive`'s speed above its
+    /// current multiplier (the ceiling it steps down from and back toward).
+    #[must_use]
+    pub fn new(config: GovernorConfig, cognitive: &CognitiveConfig) -> Self {
+        let pid = Pid::from_u32(std::process::id());
+        let mut system = System::new();
+        system.refresh_process(pid);
+        let ceiling = cognitive.speed_mode.multiplier();
+
+        Self {
+            current_multiplier: ceiling,
+            ceiling_multiplier: ceiling,
+            config,
+            system,
+            pid,
+            last_adjustment: None,
+        }
+    }
+
+    /// Sample current CPU/RSS usage. `redis_latency_ms` should be the
+    /// caller's most recent measured round trip, if any.
+    pub fn sample(&mut self, redis_latency_ms: Option<u128>) -> ResourceReading {
+        self.system.refresh_process(self.pid);
+        let (cpu_pct, rss_mb) = self
+            .system
+            .process(self.pid)
+            .map_or((0.0, 0), |process| (process.cpu_usage(), process.memory() / (1024 * 1024)));
+
+        ResourceReading {
+            cpu_pct,
+            rss_mb,
+            redis_latency_ms,
+        }
+    }
+
+    /// Apply one governor tick: sample, and step `cognitive`'s speed mode
+    /// down or up if warranted. Returns the new multiplier if it changed.
+    pub fn tick(&mut self, cognitive: &mut CognitiveConfig, redis_latency_ms: Option<u128>) -> Option<f64> {
+        let reading = self.sample(redis_latency_ms);
+
+        if let Some(last) = self.last_adjustment {
+            if last.elapsed() < self.config.cooldown {
+                return None;
+            }
+        }
+
+        let next = if reading.exceeds_high_water(&self.config) {
+            (self.current_multiplier * self.config.step_factor).max(self.config.min_multiplier)
+        } else if reading.under_low_water(&self.config) {
+            (self.current_multiplier / self.config.step_factor).min(self.ceiling_multiplier)
+        } else {
+            self.current_multiplier
+        };
+
+        if (next - self.current_multiplier).abs() < f64::EPSILON {
+            return None;
+        }
+
+        tracing::info!(
+            from = self.current_multiplier,
+            to = next,
+            cpu_pct = reading.cpu_pct,
+            rss_mb = reading.rss_mb,
+            "speed governor adjusting cognitive speed"
+        );
+
+        self.current_multiplier = next;
+        self.last_adjustment = Some(Instant::now());
+        cognitive.set_speed_mode(SpeedMode::Custom(next));
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn high_cpu_reading_exceeds_high_water() {
+        let config = GovernorConfig::default();
+        let reading = ResourceReading {
+            cpu_pct: 95.0,
+            rss_mb: 100,
+            redis_latency_ms: None,
+        };
+        assert!(reading.exceeds_high_water(&config));
+    }
+
+    #[test]
+    fn idle_reading_is_under_low_water() {
+        let config = GovernorConfig::default();
+        let reading = ResourceReading {
+            cpu_pct: 5.0,
+            rss_mb: 100,
+            redis_latency_ms: Some(1),
+        };
+        assert!(reading.under_low_water(&config));
+    }
+
+    #[test]
+    fn tick_never_steps_below_min_multiplier() {
+        let config = GovernorConfig {
+            min_multiplier: 1.0,
+            step_factor: 0.1,
+            cooldown: Duration::ZERO,
+            ..GovernorConfig::default()
+        };
+        let mut cognitive = CognitiveConfig::human();
+        let mut governor = SpeedGovernor::new(config, &cognitive);
+
+        for _ in 0..10 {
+            governor.tick(&mut cognitive, Some(1000));
+        }
+
+        assert!(governor.current_multiplier >= 1.0);
+    }
+
+    #[test]
+    fn tick_never_steps_above_starting_ceiling() {
+        let config = GovernorConfig {
+            cooldown: Duration::ZERO,
+            ..GovernorConfig::default()
+        };
+        let mut cognitive = CognitiveConfig::human();
+        let ceiling = cognitive.speed_mode.multiplier();
+        let mut governor = SpeedGovernor::new(config, &cognitive);
+
+        for _ in 0..10 {
+            governor.tick(&mut cognitive, Some(0));
+        }
+
+        assert!(governor.current_multiplier <= ceiling);
+    }
+}
@@ -13,6 +13,8 @@
 
 pub mod checkpoint;
 pub mod crash_log;
+pub mod encryption;
+pub mod governor;
 pub mod supervisor;
 
 use std::panic;
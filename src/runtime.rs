@@ -0,0 +1,83 @@
+//! Tokio runtime topology - a dedicated blocking pool for embeddings
+//!
+//! `tokio::runtime::Runtime::new()` (used everywhere in `main.rs` before
+//! this module existed) gives every blocking call the same default-sized
+//! blocking pool, shared with anything else on the runtime that calls
+//! `spawn_blocking`. Embedding inference (ONNX via `fastembed`) is CPU-heavy
+//! enough that it can starve that pool and, if called directly from an async
+//! task instead of via `spawn_blocking`, can stall the worker threads the
+//! cycle driver needs for its own timing (see
+//! [`crate::core::cognitive_loop::execution`]'s `calculate_embedding_drives`).
+//! [`build`] constructs a runtime with an explicitly sized blocking pool so
+//! that isolation is a capacity guarantee, not an accident of scheduling.
+//!
+//! # Scope
+//!
+//! This builds one runtime with a dedicated blocking-pool *size* for
+//! embeddings. It does not yet give persistence I/O (Redis/Qdrant) its own
+//! worker set, or pin the cycle driver to a specific core - both would need
+//! either a second `Runtime` threaded through every persistence call site or
+//! a platform-specific affinity crate, neither of which exists yet. Treat
+//! `persistence_worker_threads` as reserved for that follow-up.
+
+use serde::{Deserialize, Serialize};
+
+/// Thread-pool sizing for the runtime the cycle driver and embedding engine
+/// share.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeTopology {
+    /// Async worker threads driving the cognitive cycle and I/O futures
+    pub cycle_worker_threads: usize,
+    /// Dedicated blocking-pool threads for embedding inference
+    /// (`spawn_blocking` calls into `EmbeddingEngine`)
+    pub embedding_blocking_threads: usize,
+    /// Reserved for a future dedicated persistence I/O worker set; not yet
+    /// wired to a separate runtime (see module docs)
+    pub persistence_worker_threads: usize,
+}
+
+impl Default for RuntimeTopology {
+    fn default() -> Self {
+        Self {
+            cycle_worker_threads: 2,
+            embedding_blocking_threads: 2,
+            persistence_worker_threads: 2,
+        }
+    }
+}
+
+/// Build the main tokio runtime, sized per `topology`.
+///
+/// # Errors
+///
+/// Returns an error if the underlying OS thread spawn fails.
+pub fn build(topology: &RuntimeTopology) -> std::io::Result<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(topology.cycle_worker_threads.max(1))
+        .max_blocking_threads(topology.embedding_blocking_threads.max(1))
+        .thread_name("daneel-cycle")
+        .enable_all()
+        .build()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_topology_builds_a_runtime() {
+        let topology = RuntimeTopology::default();
+        assert!(build(&topology).is_ok());
+    }
+
+    #[test]
+    fn zero_threads_are_clamped_to_at_least_one() {
+        let topology = RuntimeTopology {
+            cycle_worker_threads: 0,
+            embedding_blocking_threads: 0,
+            persistence_worker_threads: 0,
+        };
+        assert!(build(&topology).is_ok());
+    }
+}
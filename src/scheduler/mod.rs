@@ -0,0 +1,370 @@
+//! Cron-like scheduled jobs (`daneel scheduler status`)
+//!
+//! Several requested features - memory decay, garbage collection, spaced
+//! repetition, a diary digest, drift detection - all want "run this
+//! periodically", and each reinventing its own interval-tracking would
+//! drift out of sync with each other and with whatever an operator expects
+//! from `--cron`-style config. [`CronSchedule`] parses a standard five-field
+//! cron expression (minute hour day-of-month month day-of-week) and
+//! [`Scheduler`] tracks each registered job's last-run/next-run status,
+//! serializable so it survives a restart (see
+//! [`MemoryStore::save_scheduler_state`](crate::persistence::MemoryStore::save_scheduler_state)).
+//!
+//! # Scope
+//!
+//! This is the scheduling primitive only - deciding *when* a job is due.
+//! None of the five motivating features (decay, GC-as-a-scheduled-job,
+//! spaced repetition, diary, drift detection) exist as schedulable units in
+//! this tree today, so there is nothing yet to register by default and no
+//! actor driving `Scheduler::due` on a timer. `daneel gc run` and
+//! `daneel sleep rescore` remain manually-triggered CLI commands; wiring
+//! either of them (or a future decay/diary job) through this scheduler is
+//! left to whoever builds that job, the same gap
+//! [`crate::gc`] documents for `SleepActor` integration.
+
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// How far into the future [`CronSchedule::next_after`] will search before
+/// giving up on an unsatisfiable schedule (e.g. `0 0 31 2 *` - February 31st
+/// never occurs). Four years comfortably covers every leap-year alignment.
+const MAX_SEARCH_MINUTES: i64 = 4 * 366 * 24 * 60;
+
+/// Errors parsing a cron expression.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum CronParseError {
+    #[error("expected 5 space-separated fields (minute hour day-of-month month day-of-week), got {0}")]
+    WrongFieldCount(usize),
+
+    #[error("invalid {field} field {value:?}: {reason}")]
+    InvalidField {
+        field: &'static str,
+        value: String,
+        reason: String,
+    },
+}
+
+/// A parsed five-field cron expression: `minute hour day-of-month month
+/// day-of-week`. Each field is one of `*`, a number, a comma-separated list,
+/// a range (`a-b`), or a step (`*/n` or `a-b/n`) - the subset of cron syntax
+/// actually needed by periodic-maintenance jobs, not the full vixie-cron
+/// grammar (no `@yearly`-style aliases, no `L`/`W`/`#` extensions).
+///
+/// `day-of-week` is `0`-`6` with `0` = Sunday, matching standard cron.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    days_of_month: Vec<u32>,
+    months: Vec<u32>,
+    days_of_week: Vec<u32>,
+}
+
+impl CronSchedule {
+    /// Parse a five-field cron expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CronParseError`] if the expression doesn't have exactly 5
+    /// fields, or any field isn't a valid value/list/range/step for its
+    /// position.
+    pub fn parse(expr: &str) -> Result<Self, CronParseError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(CronParseError::WrongFieldCount(fields.len()));
+        }
+
+        Ok(Self {
+            minutes: parse_field("minute", fields[0], 0, 59)?,
+            hours: parse_field("hour", fields[1], 0, 23)?,
+            days_of_month: parse_field("day-of-month", fields[2], 1, 31)?,
+            months: parse_field("month", fields[3], 1, 12)?,
+            days_of_week: parse_field("day-of-week", fields[4], 0, 6)?,
+        })
+    }
+
+    /// Whether `when` (truncated to the minute) matches this schedule.
+    #[must_use]
+    fn matches(&self, when: DateTime<Utc>) -> bool {
+        self.minutes.contains(&when.minute())
+            && self.hours.contains(&when.hour())
+            && self.days_of_month.contains(&when.day())
+            && self.months.contains(&when.month())
+            && self.days_of_week.contains(&(when.weekday().num_days_from_sunday()))
+    }
+
+    /// The next minute-aligned instant strictly after `from` that matches
+    /// this schedule, scanning forward minute-by-minute up to
+    /// [`MAX_SEARCH_MINUTES`]. Returns `None` for a schedule that can never
+    /// be satisfied (e.g. day-of-month 31 in a month-set containing only
+    /// February).
+    #[must_use]
+    pub fn next_after(&self, from: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = from
+            .with_second(0)
+            .and_then(|t| t.with_nanosecond(0))?
+            + ChronoDuration::minutes(1);
+
+        (0..MAX_SEARCH_MINUTES)
+            .map(|offset| start + ChronoDuration::minutes(offset))
+            .find(|candidate| self.matches(*candidate))
+    }
+}
+
+/// Parse one cron field (`*`, `5`, `1,2,3`, `1-5`, `*/15`, `1-10/2`) into the
+/// sorted, deduplicated set of values it selects within `[min, max]`.
+fn parse_field(field: &'static str, spec: &str, min: u32, max: u32) -> Result<Vec<u32>, CronParseError> {
+    let mut values = std::collections::BTreeSet::new();
+
+    for part in spec.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((range_part, step)) => {
+                let step: u32 = step.parse().map_err(|_| CronParseError::InvalidField {
+                    field,
+                    value: spec.to_string(),
+                    reason: format!("invalid step {step:?}"),
+                })?;
+                if step == 0 {
+                    return Err(CronParseError::InvalidField {
+                        field,
+                        value: spec.to_string(),
+                        reason: "step cannot be 0".to_string(),
+                    });
+                }
+                (range_part, step)
+            }
+            None => (part, 1),
+        };
+
+        let (lo, hi) = if range_part == "*" {
+            (min, max)
+        } else if let Some((lo, hi)) = range_part.split_once('-') {
+            let lo: u32 = lo.parse().map_err(|_| CronParseError::InvalidField {
+                field,
+                value: spec.to_string(),
+                reason: format!("invalid range start {lo:?}"),
+            })?;
+            let hi: u32 = hi.parse().map_err(|_| CronParseError::InvalidField {
+                field,
+                value: spec.to_string(),
+                reason: format!("invalid range end {hi:?}"),
+            })?;
+            (lo, hi)
+        } else {
+            let value: u32 = range_part.parse().map_err(|_| CronParseError::InvalidField {
+                field,
+                value: spec.to_string(),
+                reason: format!("invalid value {range_part:?}"),
+            })?;
+            (value, value)
+        };
+
+        if lo > hi || lo < min || hi > max {
+            return Err(CronParseError::InvalidField {
+                field,
+                value: spec.to_string(),
+                reason: format!("range {lo}-{hi} outside valid range {min}-{max}"),
+            });
+        }
+
+        let mut v = lo;
+        while v <= hi {
+            values.insert(v);
+            v += step;
+        }
+    }
+
+    Ok(values.into_iter().collect())
+}
+
+/// A job's cron expression, keyed by name (see [`CognitiveConfig::scheduled_jobs`](crate::config::CognitiveConfig::scheduled_jobs)).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledJobConfig {
+    /// Unique job name, e.g. `"gc"` or `"decay"`
+    pub name: String,
+    /// Five-field cron expression (see [`CronSchedule::parse`])
+    pub cron: String,
+}
+
+/// A registered job's run history, as tracked by [`Scheduler`] and
+/// persisted across restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JobStatus {
+    pub last_run: Option<DateTime<Utc>>,
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+/// Tracks each registered job's schedule and run history, and reports which
+/// jobs are due. Driving `due` on a timer and actually running the jobs is
+/// the caller's responsibility - see the module-level scope note.
+#[derive(Debug, Default)]
+pub struct Scheduler {
+    jobs: HashMap<String, (CronSchedule, JobStatus)>,
+}
+
+impl Scheduler {
+    /// Build a scheduler from config, computing each job's first `next_run`
+    /// from `now`. `saved_status`, if given (see
+    /// [`MemoryStore::load_scheduler_state`](crate::persistence::MemoryStore::load_scheduler_state)),
+    /// restores `last_run`/`next_run` for jobs whose name still appears in
+    /// `configs` instead of recomputing them from scratch - a job removed
+    /// from config since the last save is simply dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CronParseError`] if any job's `cron` expression is invalid.
+    pub fn new(
+        configs: &[ScheduledJobConfig],
+        saved_status: Option<&HashMap<String, JobStatus>>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, CronParseError> {
+        let mut jobs = HashMap::with_capacity(configs.len());
+        for config in configs {
+            let schedule = CronSchedule::parse(&config.cron)?;
+            let status = saved_status
+                .and_then(|saved| saved.get(&config.name))
+                .copied()
+                .unwrap_or(JobStatus {
+                    last_run: None,
+                    next_run: schedule.next_after(now),
+                });
+            jobs.insert(config.name.clone(), (schedule, status));
+        }
+        Ok(Self { jobs })
+    }
+
+    /// Names of jobs whose `next_run` has arrived, in no particular order.
+    #[must_use]
+    pub fn due(&self, now: DateTime<Utc>) -> Vec<String> {
+        self.jobs
+            .iter()
+            .filter(|(_, (_, status))| status.next_run.is_some_and(|next| next <= now))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Record that `name` ran at `at`, advancing its `next_run`. A no-op if
+    /// `name` isn't a registered job.
+    pub fn mark_ran(&mut self, name: &str, at: DateTime<Utc>) {
+        if let Some((schedule, status)) = self.jobs.get_mut(name) {
+            status.last_run = Some(at);
+            status.next_run = schedule.next_after(at);
+        }
+    }
+
+    /// Every registered job's current status, for the API/CLI to report.
+    #[must_use]
+    pub fn statuses(&self) -> HashMap<String, JobStatus> {
+        self.jobs.iter().map(|(name, (_, status))| (name.clone(), *status)).collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn dt(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn rejects_wrong_field_count() {
+        assert_eq!(CronSchedule::parse("* * *"), Err(CronParseError::WrongFieldCount(3)));
+    }
+
+    #[test]
+    fn rejects_out_of_range_value() {
+        assert!(matches!(
+            CronSchedule::parse("60 * * * *"),
+            Err(CronParseError::InvalidField { field: "minute", .. })
+        ));
+    }
+
+    #[test]
+    fn wildcard_matches_every_minute() {
+        let schedule = CronSchedule::parse("* * * * *").unwrap();
+        let from = dt(2026, 1, 1, 0, 0);
+        assert_eq!(schedule.next_after(from), Some(dt(2026, 1, 1, 0, 1)));
+    }
+
+    #[test]
+    fn daily_at_midnight_skips_to_next_day() {
+        let schedule = CronSchedule::parse("0 0 * * *").unwrap();
+        let from = dt(2026, 1, 1, 12, 30);
+        assert_eq!(schedule.next_after(from), Some(dt(2026, 1, 2, 0, 0)));
+    }
+
+    #[test]
+    fn step_field_selects_every_nth_value() {
+        let schedule = CronSchedule::parse("*/15 * * * *").unwrap();
+        let from = dt(2026, 1, 1, 0, 1);
+        assert_eq!(schedule.next_after(from), Some(dt(2026, 1, 1, 0, 15)));
+    }
+
+    #[test]
+    fn range_field_restricts_day_of_week() {
+        // Weekdays only (Mon-Fri) at 09:00. 2026-01-01 is a Thursday.
+        let schedule = CronSchedule::parse("0 9 * * 1-5").unwrap();
+        let from = dt(2026, 1, 1, 10, 0);
+        // Next weekday 09:00 after Thursday 10:00 is Friday 2026-01-02.
+        assert_eq!(schedule.next_after(from), Some(dt(2026, 1, 2, 9, 0)));
+    }
+
+    #[test]
+    fn unsatisfiable_schedule_returns_none() {
+        // February never has a 31st.
+        let schedule = CronSchedule::parse("0 0 31 2 *").unwrap();
+        assert_eq!(schedule.next_after(dt(2026, 1, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn scheduler_reports_due_jobs_and_advances_next_run() {
+        let configs = vec![ScheduledJobConfig {
+            name: "gc".to_string(),
+            cron: "* * * * *".to_string(),
+        }];
+        let now = dt(2026, 1, 1, 0, 0);
+        let mut scheduler = Scheduler::new(&configs, None, now).unwrap();
+
+        let one_minute_later = now + ChronoDuration::minutes(1);
+        assert_eq!(scheduler.due(one_minute_later), vec!["gc".to_string()]);
+
+        scheduler.mark_ran("gc", one_minute_later);
+        let status = scheduler.statuses();
+        assert_eq!(status["gc"].last_run, Some(one_minute_later));
+        assert_eq!(status["gc"].next_run, Some(one_minute_later + ChronoDuration::minutes(1)));
+    }
+
+    #[test]
+    fn scheduler_restores_saved_status_for_known_jobs() {
+        let configs = vec![ScheduledJobConfig {
+            name: "gc".to_string(),
+            cron: "* * * * *".to_string(),
+        }];
+        let saved_at = dt(2025, 6, 1, 0, 0);
+        let mut saved = HashMap::new();
+        saved.insert(
+            "gc".to_string(),
+            JobStatus {
+                last_run: Some(saved_at),
+                next_run: Some(saved_at + ChronoDuration::minutes(1)),
+            },
+        );
+
+        let scheduler = Scheduler::new(&configs, Some(&saved), dt(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(scheduler.statuses()["gc"].last_run, Some(saved_at));
+    }
+
+    #[test]
+    fn scheduler_rejects_invalid_job_cron() {
+        let configs = vec![ScheduledJobConfig {
+            name: "bad".to_string(),
+            cron: "not a cron".to_string(),
+        }];
+        assert!(Scheduler::new(&configs, None, Utc::now()).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Startup self-test suite (`daneel selftest`)
+//!
+//! A fast battery of checks an operator or CI pipeline can gate a
+//! deployment on: architectural invariants, config validation, Redis/Qdrant/
+//! embedding connectivity, a short dry cognitive loop with metric sanity
+//! bounds, and a volition corpus spot-check. Each check runs independently -
+//! one failing doesn't stop the rest - so a single `daneel selftest` run
+//! always reports the full picture rather than bailing at the first error.
+//!
+//! # Scope
+//!
+//! This exercises the same config/invariant/connectivity paths `daneel
+//! --dry-run` and `daneel config plan` already use, bundled into one
+//! pass/fail report; it doesn't replace either (`--dry-run` still runs the
+//! real headless loop against live state, and `config plan` still diffs a
+//! proposed file).
+
+use crate::config::CognitiveConfig;
+use crate::core::cognitive_loop::CognitiveLoop;
+use crate::core::invariants::{check_all_invariants, SystemState};
+use crate::profile::Profile;
+
+/// Cycles run during the [`dry_loop`] check - enough to catch a degenerate
+/// metric without meaningfully delaying a deploy gate.
+pub const DRY_LOOP_CYCLES: usize = 100;
+
+/// Outcome of a single named check.
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub passed: bool,
+    /// Human-readable detail: why it failed, or a brief summary on success.
+    pub detail: String,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: true,
+            detail: detail.into(),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Full self-test report: one [`CheckResult`] per battery item, in the
+/// order they ran.
+#[derive(Debug, Clone, Default)]
+pub struct SelftestReport {
+    pub checks: Vec<CheckResult>,
+}
+
+impl SelftestReport {
+    /// True only if every check passed.
+    #[must_use]
+    pub fn all_passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+}
+
+/// Check `config`'s own self-consistency (see
+/// [`CognitiveConfig::validate`]).
+fn config_validation(config: &CognitiveConfig) -> CheckResult {
+    match config.validate() {
+        Ok(()) => CheckResult::pass("config_validation", "config is internally consistent"),
+        Err(violations) => CheckResult::fail(
+            "config_validation",
+            violations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+    }
+}
+
+/// Check the architectural invariants (THE BOX) against `config`'s starting
+/// state.
+fn invariants(config: &CognitiveConfig) -> CheckResult {
+    #[allow(clippy::cast_possible_truncation)]
+    let state = SystemState {
+        connection_weight: config.connection_weight as f32,
+        open_windows: crate::core::invariants::MIN_MEMORY_WINDOWS,
+        law_check_performed: true,
+        pending_action: None,
+        test_coverage: 0.0,
+    };
+    match check_all_invariants(&state) {
+        Ok(()) => CheckResult::pass("invariants", "all architectural invariants hold"),
+        Err(violations) => CheckResult::fail(
+            "invariants",
+            violations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+    }
+}
+
+/// Check that Redis is reachable (mirrors `api::handlers::health`'s own
+/// connectivity check).
+async fn redis_connectivity(redis_url: &str) -> CheckResult {
+    use redis::AsyncCommands;
+
+    let client = match redis::Client::open(redis_url) {
+        Ok(client) => client,
+        Err(e) => return CheckResult::fail("redis_connectivity", e.to_string()),
+    };
+    let mut conn = match client.get_multiplexed_async_connection().await {
+        Ok(conn) => conn,
+        Err(e) => return CheckResult::fail("redis_connectivity", e.to_string()),
+    };
+    match conn.get::<_, Option<String>>(crate::namespace::prefixed("stats:thoughts_total")).await {
+        Ok(_) => CheckResult::pass("redis_connectivity", format!("reachable at {redis_url}")),
+        Err(e) => CheckResult::fail("redis_connectivity", e.to_string()),
+    }
+}
+
+/// Check that Qdrant is reachable and responds to a real query, not just
+/// that a client handle builds (which [`crate::memory_db::MemoryDb::connect_with_profile`]
+/// alone doesn't guarantee, since Qdrant's gRPC client connects lazily).
+async fn qdrant_connectivity(qdrant_url: &str, profile: Profile) -> CheckResult {
+    let db = match crate::memory_db::MemoryDb::connect_with_profile(qdrant_url, profile).await {
+        Ok(db) => db,
+        Err(e) => return CheckResult::fail("qdrant_connectivity", e.to_string()),
+    };
+    match db.memory_count().await {
+        Ok(count) => CheckResult::pass("qdrant_connectivity", format!("reachable at {qdrant_url} ({count} memories)")),
+        Err(e) => CheckResult::fail("qdrant_connectivity", e.to_string()),
+    }
+}
+
+/// Check that the embedding engine loads (model bundle present and valid).
+fn embedding_connectivity() -> CheckResult {
+    match crate::embeddings::create_embedding_engine() {
+        Ok(_) => CheckResult::pass("embedding_connectivity", "embedding engine loaded"),
+        Err(e) => CheckResult::fail("embedding_connectivity", e.to_string()),
+    }
+}
+
+/// Run [`DRY_LOOP_CYCLES`] cycles of a no-I/O [`CognitiveLoop`] (mirrors
+/// `daneel bench`'s setup) and check every cycle's salience/valence/arousal
+/// land within the ranges [`crate::core::types::SalienceScore`] promises,
+/// catching a regression that would otherwise only surface once it actually
+/// started scoring real content.
+async fn dry_loop(config: CognitiveConfig) -> CheckResult {
+    let mut cognitive_loop = CognitiveLoop::with_config(config);
+    cognitive_loop.start();
+
+    for cycle in 0..DRY_LOOP_CYCLES {
+        let result = cognitive_loop.run_cycle().await;
+        if !(0.0..=1.0).contains(&result.salience) {
+            return CheckResult::fail(
+                "dry_loop",
+                format!("cycle {cycle}: salience {} out of [0.0, 1.0]", result.salience),
+            );
+        }
+        if !(-1.0..=1.0).contains(&result.valence) {
+            return CheckResult::fail(
+                "dry_loop",
+                format!("cycle {cycle}: valence {} out of [-1.0, 1.0]", result.valence),
+            );
+        }
+        if !(0.0..=1.0).contains(&result.arousal) {
+            return CheckResult::fail(
+                "dry_loop",
+                format!("cycle {cycle}: arousal {} out of [0.0, 1.0]", result.arousal),
+            );
+        }
+    }
+
+    CheckResult::pass("dry_loop", format!("{DRY_LOOP_CYCLES} cycles, all metrics in bounds"))
+}
+
+/// Spot-check the volition veto classifiers against the fixture corpus (see
+/// [`crate::actors::volition::corpus`]) - a regression here means real harm/
+/// deception/manipulation content could start getting waved through.
+fn volition_corpus() -> CheckResult {
+    let report = crate::actors::volition::corpus::evaluate();
+    if report.mismatches.is_empty() {
+        CheckResult::pass("volition_corpus", "all fixtures classified as expected")
+    } else {
+        CheckResult::fail("volition_corpus", format!("mismatched fixtures: {}", report.mismatches.join(", ")))
+    }
+}
+
+/// Run the full self-test battery.
+pub async fn run(config: &CognitiveConfig, redis_url: &str, qdrant_url: &str, profile: Profile) -> SelftestReport {
+    let mut checks = Vec::new();
+    checks.push(config_validation(config));
+    checks.push(invariants(config));
+    checks.push(redis_connectivity(redis_url).await);
+    checks.push(qdrant_connectivity(qdrant_url, profile).await);
+    checks.push(embedding_connectivity());
+    checks.push(dry_loop(config.clone()).await);
+    checks.push(volition_corpus());
+    SelftestReport { checks }
+}
@@ -0,0 +1,261 @@
+//! `ThoughtBus`: the stream operations `CognitiveLoop` actually calls when
+//! writing and forgetting thoughts, extracted as a trait so loop tests can
+//! run against an in-memory mock instead of standing up a live Redis
+//! instance.
+//!
+//! Deliberately narrower than [`StreamsClient`]'s full API - consumer
+//! group operations (`read_group`, `acknowledge`, `create_consumer_group`)
+//! are Autofluxo/ops paths exercised against a real Redis instance, not
+//! part of the per-cycle hot path this trait exists to make testable. The
+//! injection-stream read in `read_external_stimuli` is a separate
+//! raw-Redis mechanism (see `CognitiveLoop::redis_client`) and is out of
+//! scope here too.
+//!
+//! `enforce_maxlen` is the exception: every awake-stream write now trims
+//! inline (see `CognitiveLoop::write_to_stream`), so it needs a working
+//! mock implementation rather than living only on [`StreamsClient`] like
+//! `trim_stream` used to.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use super::client::StreamsClient;
+use super::types::{StreamEntry, StreamError, StreamName, TrimOutcome};
+
+#[ractor::async_trait]
+pub trait ThoughtBus: Send + Sync {
+    /// Add a thought to a stream (writing to Redis).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying stream rejects the write.
+    async fn add_thought(&mut self, stream: &StreamName, entry: &StreamEntry) -> Result<String, StreamError>;
+
+    /// Delete a thought from a stream (forgetting).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying stream rejects the delete.
+    async fn forget_thought(&mut self, stream: &StreamName, id: &str) -> Result<(), StreamError>;
+
+    /// Enforce `stream`'s `MAXLEN` (approximate trim), and report whether
+    /// the oldest entry left behind is still within `ttl_ms` of now - see
+    /// [`TrimOutcome`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying stream rejects the trim.
+    async fn enforce_maxlen(
+        &mut self,
+        stream: &StreamName,
+        maxlen: usize,
+        ttl_ms: u64,
+    ) -> Result<TrimOutcome, StreamError>;
+}
+
+#[ractor::async_trait]
+impl ThoughtBus for StreamsClient {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn add_thought(&mut self, stream: &StreamName, entry: &StreamEntry) -> Result<String, StreamError> {
+        Self::add_thought(self, stream, entry).await
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn forget_thought(&mut self, stream: &StreamName, id: &str) -> Result<(), StreamError> {
+        Self::forget_thought(self, stream, id).await
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn enforce_maxlen(
+        &mut self,
+        stream: &StreamName,
+        maxlen: usize,
+        ttl_ms: u64,
+    ) -> Result<TrimOutcome, StreamError> {
+        Self::enforce_maxlen(self, stream, maxlen, ttl_ms).await
+    }
+}
+
+/// In-memory [`ThoughtBus`] for unit tests - no Redis required.
+///
+/// `add_thought` hands out sequential ids starting at `"0-0"`, `"1-0"`, ...
+/// in the shape of a Redis stream id, rather than real `<ms>-<seq>` ids.
+/// That's enough to exercise write/forget control flow; tests should not
+/// assert on id format.
+#[derive(Debug, Default)]
+pub struct MockThoughtBus {
+    entries: Mutex<VecDeque<(StreamName, String, StreamEntry)>>,
+    next_id: Mutex<u64>,
+}
+
+impl MockThoughtBus {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of everything currently on the bus (not yet forgotten), for test assertions.
+    #[must_use]
+    pub fn pending(&self) -> Vec<(StreamName, String, StreamEntry)> {
+        self.entries.lock().expect("mock thought bus poisoned").iter().cloned().collect()
+    }
+}
+
+#[ractor::async_trait]
+impl ThoughtBus for MockThoughtBus {
+    async fn add_thought(&mut self, stream: &StreamName, entry: &StreamEntry) -> Result<String, StreamError> {
+        let mut next_id = self.next_id.lock().expect("mock thought bus poisoned");
+        let id = format!("{next_id}-0");
+        *next_id += 1;
+        self.entries
+            .lock()
+            .expect("mock thought bus poisoned")
+            .push_back((stream.clone(), id.clone(), entry.clone()));
+        Ok(id)
+    }
+
+    async fn forget_thought(&mut self, stream: &StreamName, id: &str) -> Result<(), StreamError> {
+        let mut entries = self.entries.lock().expect("mock thought bus poisoned");
+        if let Some(pos) = entries.iter().position(|(s, i, _)| s == stream && i == id) {
+            entries.remove(pos);
+        }
+        Ok(())
+    }
+
+    async fn enforce_maxlen(
+        &mut self,
+        stream: &StreamName,
+        maxlen: usize,
+        ttl_ms: u64,
+    ) -> Result<TrimOutcome, StreamError> {
+        let mut entries = self.entries.lock().expect("mock thought bus poisoned");
+        let matching: Vec<usize> = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, (s, _, _))| s == stream)
+            .map(|(i, _)| i)
+            .collect();
+        let excess = matching.len().saturating_sub(maxlen);
+        if excess == 0 {
+            return Ok(TrimOutcome {
+                trimmed: 0,
+                within_window: false,
+            });
+        }
+
+        // `matching` is in insertion (oldest-first) order since entries are
+        // only ever `push_back`ed - drop the oldest `excess` of them, same
+        // as XTRIM dropping from the head of the stream.
+        for &idx in matching[..excess].iter().rev() {
+            entries.remove(idx);
+        }
+
+        let within_window = entries.iter().find(|(s, _, _)| s == stream).is_some_and(|(_, _, entry)| {
+            let age_ms = (chrono::Utc::now() - entry.timestamp).num_milliseconds();
+            age_ms >= 0 && u64::try_from(age_ms).is_ok_and(|ms| ms < ttl_ms)
+        });
+
+        #[allow(clippy::cast_possible_truncation)]
+        let trimmed = excess as u64; // Safe: bounded by in-memory Vec length
+        Ok(TrimOutcome {
+            trimmed,
+            within_window,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::core::types::{Content, SalienceScore};
+
+    fn sample_entry() -> StreamEntry {
+        StreamEntry::new(
+            String::new(),
+            StreamName::Custom(super::super::names::stream_awake()),
+            Content::Raw(b"test thought".to_vec()),
+            SalienceScore::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn add_thought_makes_entry_visible_via_pending() {
+        let mut bus = MockThoughtBus::new();
+        let stream = StreamName::Custom(super::super::names::stream_awake());
+        let entry = sample_entry();
+
+        let id = bus.add_thought(&stream, &entry).await.unwrap();
+
+        let pending = bus.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].1, id);
+    }
+
+    #[tokio::test]
+    async fn forget_thought_removes_matching_entry() {
+        let mut bus = MockThoughtBus::new();
+        let stream = StreamName::Custom(super::super::names::stream_awake());
+        let entry = sample_entry();
+        let id = bus.add_thought(&stream, &entry).await.unwrap();
+
+        bus.forget_thought(&stream, &id).await.unwrap();
+
+        assert!(bus.pending().is_empty());
+    }
+
+    #[tokio::test]
+    async fn forget_thought_is_a_noop_for_unknown_id() {
+        let mut bus = MockThoughtBus::new();
+        let stream = StreamName::Custom(super::super::names::stream_awake());
+
+        bus.forget_thought(&stream, "missing").await.unwrap();
+
+        assert!(bus.pending().is_empty());
+    }
+
+    #[tokio::test]
+    async fn enforce_maxlen_is_a_noop_within_limit() {
+        let mut bus = MockThoughtBus::new();
+        let stream = StreamName::Custom(super::super::names::stream_awake());
+        bus.add_thought(&stream, &sample_entry()).await.unwrap();
+
+        let outcome = bus.enforce_maxlen(&stream, 10, 5000).await.unwrap();
+
+        assert_eq!(outcome.trimmed, 0);
+        assert!(!outcome.within_window);
+        assert_eq!(bus.pending().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn enforce_maxlen_drops_oldest_entries_over_limit() {
+        let mut bus = MockThoughtBus::new();
+        let stream = StreamName::Custom(super::super::names::stream_awake());
+        for _ in 0..5 {
+            bus.add_thought(&stream, &sample_entry()).await.unwrap();
+        }
+
+        let outcome = bus.enforce_maxlen(&stream, 3, 5000).await.unwrap();
+
+        assert_eq!(outcome.trimmed, 2);
+        assert_eq!(bus.pending().len(), 3);
+        // Freshly-created entries are always within a 5s window.
+        assert!(outcome.within_window);
+    }
+
+    #[tokio::test]
+    async fn enforce_maxlen_only_counts_entries_on_the_given_stream() {
+        let mut bus = MockThoughtBus::new();
+        let awake = StreamName::Custom(super::super::names::stream_awake());
+        let dream = StreamName::Custom(super::super::names::stream_dream());
+        for _ in 0..3 {
+            bus.add_thought(&awake, &sample_entry()).await.unwrap();
+        }
+        bus.add_thought(&dream, &sample_entry()).await.unwrap();
+
+        let outcome = bus.enforce_maxlen(&awake, 3, 5000).await.unwrap();
+
+        assert_eq!(outcome.trimmed, 0);
+        assert_eq!(bus.pending().len(), 4);
+    }
+}
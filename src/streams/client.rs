@@ -7,13 +7,14 @@
 //! - XTRIM: Manage stream memory limits
 //! - Consumer groups: Attention competition
 
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use redis::aio::MultiplexedConnection;
 use redis::{AsyncCommands, Client, FromRedisValue, RedisError, RedisResult, Value};
 use serde_json;
 use std::collections::HashMap;
 use tracing::{debug, info, warn};
 
-use super::types::{StreamEntry, StreamError, StreamName};
+use super::types::{StreamEntry, StreamError, StreamName, TrimOutcome};
 use crate::core::types::{Content, SalienceScore};
 
 /// Redis Streams client for thought operations
@@ -86,6 +87,12 @@ impl StreamsClient {
         entry: &StreamEntry,
     ) -> Result<String, StreamError> {
         let key = stream.as_redis_key();
+
+        if crate::dry_run::is_enabled() {
+            debug!("[dry-run] would add thought to stream {}; skipping", key);
+            return Ok("0-0".to_string());
+        }
+
         let conn = self.conn_mut()?;
 
         // Serialize content and salience as JSON
@@ -101,6 +108,24 @@ impl StreamsClient {
         })?;
         let timestamp_str = entry.timestamp.to_rfc3339();
         let source_str = entry.source.clone().unwrap_or_default();
+        let sample_rate_str = entry.sample_rate.map_or_else(String::new, |rate| rate.to_string());
+
+        // Large Content::Raw payloads (sensor frames, byte-encoded
+        // embeddings) dominate stream entry size; compress them above
+        // crate::compression::THRESHOLD_BYTES and store under a separate
+        // field so small, uncompressed thoughts keep their original shape.
+        let (content_payload, content_compressed) =
+            crate::compression::compress(content_json.as_bytes());
+        let content_field_name = if content_compressed {
+            "content_z"
+        } else {
+            "content"
+        };
+        let content_field_value = if content_compressed {
+            BASE64.encode(&content_payload)
+        } else {
+            content_json
+        };
 
         // XADD stream_name * field1 value1 field2 value2 ...
         let id: String = conn
@@ -108,10 +133,11 @@ impl StreamsClient {
                 key,
                 "*",
                 &[
-                    ("content", content_json.as_str()),
+                    (content_field_name, content_field_value.as_str()),
                     ("salience", salience_json.as_str()),
                     ("timestamp", timestamp_str.as_str()),
                     ("source", source_str.as_str()),
+                    ("sample_rate", sample_rate_str.as_str()),
                 ],
             )
             .await
@@ -184,6 +210,12 @@ impl StreamsClient {
         id: &str,
     ) -> Result<(), StreamError> {
         let key = stream.as_redis_key();
+
+        if crate::dry_run::is_enabled() {
+            debug!("[dry-run] would forget thought {} from stream {}; skipping", id, key);
+            return Ok(());
+        }
+
         let _deleted: i32 = self
             .conn_mut()?
             .xdel(key, &[id])
@@ -213,6 +245,52 @@ impl StreamsClient {
         Ok(count)
     }
 
+    /// Enforce `stream`'s `MAXLEN` at write time (approximate `XTRIM`), then
+    /// - if anything was actually discarded - check whether the oldest
+    /// entry now remaining is itself still within `ttl_ms` of now. If so,
+    /// the entries just discarded (necessarily older still) were almost
+    /// certainly still inside the window too, meaning `maxlen` is
+    /// undersized for current write throughput rather than this being
+    /// routine rolling-window cleanup.
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn enforce_maxlen(
+        &mut self,
+        stream: &StreamName,
+        maxlen: usize,
+        ttl_ms: u64,
+    ) -> Result<TrimOutcome, StreamError> {
+        let trimmed = self.trim_stream(stream, maxlen).await?;
+        if trimmed == 0 {
+            return Ok(TrimOutcome {
+                trimmed,
+                within_window: false,
+            });
+        }
+
+        let key = stream.as_redis_key();
+        let reply: redis::streams::StreamRangeReply = self
+            .conn_mut()?
+            .xrange_count(key, "-", "+", 1)
+            .await
+            .map_err(Self::map_redis_error)?;
+
+        let within_window = reply
+            .ids
+            .first()
+            .and_then(|id_entry| Self::get_string_field(&id_entry.map, "timestamp").ok())
+            .and_then(|ts| chrono::DateTime::parse_from_rfc3339(&ts).ok())
+            .is_some_and(|ts| {
+                let age_ms = (chrono::Utc::now() - ts.with_timezone(&chrono::Utc)).num_milliseconds();
+                age_ms >= 0 && u64::try_from(age_ms).is_ok_and(|ms| ms < ttl_ms)
+            });
+
+        Ok(TrimOutcome {
+            trimmed,
+            within_window,
+        })
+    }
+
     // =========================================================================
     // Consumer Group Operations
     // =========================================================================
@@ -382,16 +460,35 @@ impl StreamsClient {
         map: &HashMap<String, Value>,
     ) -> Result<StreamEntry, StreamError> {
         // Extract fields from Redis hash
-        let content_json = Self::get_string_field(map, "content")?;
         let salience_json = Self::get_string_field(map, "salience")?;
         let timestamp_str = Self::get_string_field(map, "timestamp")?;
         let source_str = Self::get_string_field(map, "source").ok();
 
-        // Deserialize JSON fields
-        let content: Content =
+        // Content may have been stored compressed under "content_z"
+        // (see add_thought) - fall back to the plain "content" field.
+        let content: Content = if let Ok(content_z) = Self::get_string_field(map, "content_z") {
+            let compressed =
+                BASE64
+                    .decode(&content_z)
+                    .map_err(|e| StreamError::SerializationFailed {
+                        reason: format!("content_z base64: {e}"),
+                    })?;
+            let content_bytes = crate::compression::decompress(&compressed, true).map_err(|e| {
+                StreamError::SerializationFailed {
+                    reason: format!("content_z decompress: {e}"),
+                }
+            })?;
+            serde_json::from_slice(&content_bytes).map_err(|e| {
+                StreamError::SerializationFailed {
+                    reason: format!("{e}"),
+                }
+            })?
+        } else {
+            let content_json = Self::get_string_field(map, "content")?;
             serde_json::from_str(&content_json).map_err(|e| StreamError::SerializationFailed {
                 reason: format!("{e}"),
-            })?;
+            })?
+        };
         let salience: SalienceScore =
             serde_json::from_str(&salience_json).map_err(|e| StreamError::SerializationFailed {
                 reason: format!("{e}"),
@@ -403,6 +500,10 @@ impl StreamsClient {
             .with_timezone(&chrono::Utc);
 
         let source = source_str.filter(|s| !s.is_empty());
+        let sample_rate = Self::get_string_field(map, "sample_rate")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .and_then(|s| s.parse::<u64>().ok());
 
         Ok(StreamEntry {
             id: id.to_string(),
@@ -411,6 +512,7 @@ impl StreamsClient {
             salience,
             timestamp,
             source,
+            sample_rate,
         })
     }
 
@@ -663,6 +765,31 @@ mod tests {
         assert_eq!(entry.id, "123-0");
         assert_eq!(entry.stream, StreamName::Sensory);
         assert_eq!(entry.source, Some("test_source".to_string()));
+        assert_eq!(entry.sample_rate, None);
+    }
+
+    #[test]
+    fn test_parse_entry_with_sample_rate() {
+        let mut map: HashMap<String, Value> = HashMap::new();
+        let content = Content::symbol("test", vec![1, 2, 3]);
+        let salience = SalienceScore::neutral();
+
+        map.insert(
+            "content".to_string(),
+            Value::BulkString(serde_json::to_string(&content).unwrap().into_bytes()),
+        );
+        map.insert(
+            "salience".to_string(),
+            Value::BulkString(serde_json::to_string(&salience).unwrap().into_bytes()),
+        );
+        map.insert(
+            "timestamp".to_string(),
+            Value::BulkString(chrono::Utc::now().to_rfc3339().into_bytes()),
+        );
+        map.insert("sample_rate".to_string(), Value::BulkString(b"200".to_vec()));
+
+        let entry = StreamsClient::parse_entry(&StreamName::Sensory, "123-0", &map).unwrap();
+        assert_eq!(entry.sample_rate, Some(200));
     }
 
     #[test]
@@ -266,6 +266,7 @@ impl AttentionConsumer {
             salience: winner.entry.salience,
             timestamp: winner.entry.timestamp,
             source: winner.entry.source.clone(),
+            sample_rate: winner.entry.sample_rate,
         };
 
         self.client
@@ -33,28 +33,56 @@
 //! - Winners tagged for consolidation
 //!
 //! See ADR-020 for full rationale.
+//!
+//! Names above assume the default `DANEEL_PREFIX` - see [`crate::namespace`]
+//! and [`names`] for the actual, prefix-aware stream keys.
 
+pub mod bus;
 pub mod client;
 pub mod consumer;
 pub mod types;
 
+pub use bus::{MockThoughtBus, ThoughtBus};
+
 #[cfg(test)]
 mod tests;
 
 /// Stream names (ADR-020 compliant)
+///
+/// Built from the process-wide [`crate::namespace`] prefix (`DANEEL_PREFIX`,
+/// default `"daneel"`) rather than hard-coded, so labs sharing one Redis
+/// instance across deployments can keep their streams apart.
 pub mod names {
     /// Awake stream - external triggers and active cognition
     /// All Autofluxo sub-streams merged into one
-    pub const STREAM_AWAKE: &str = "daneel:stream:awake";
+    #[must_use]
+    pub fn stream_awake() -> String {
+        crate::namespace::prefixed("stream:awake")
+    }
 
     /// Dream stream - internal replay during sleep/consolidation
-    pub const STREAM_DREAM: &str = "daneel:stream:dream";
+    #[must_use]
+    pub fn stream_dream() -> String {
+        crate::namespace::prefixed("stream:dream")
+    }
 
     /// Salience stream - priority scoring and consolidation tagging
-    pub const STREAM_SALIENCE: &str = "daneel:stream:salience";
+    #[must_use]
+    pub fn stream_salience() -> String {
+        crate::namespace::prefixed("stream:salience")
+    }
+
+    /// Injection stream - externally injected stimuli awaiting pickup
+    #[must_use]
+    pub fn stream_inject() -> String {
+        crate::namespace::prefixed("stream:inject")
+    }
 
     /// All active streams
-    pub const ALL_STREAMS: &[&str] = &[STREAM_AWAKE, STREAM_DREAM, STREAM_SALIENCE];
+    #[must_use]
+    pub fn all_streams() -> Vec<String> {
+        vec![stream_awake(), stream_dream(), stream_salience()]
+    }
 
     // =========================================================================
     // DEPRECATED (ADR-020) - Kept for reference during migration
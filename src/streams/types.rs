@@ -192,6 +192,14 @@ pub struct StreamEntry {
 
     /// Optional source identifier (e.g., "`camera_01`", "`memory_retrieval`")
     pub source: Option<String>,
+
+    /// If this entry was emitted under cycle sampling (see
+    /// `config::ObservabilitySamplingConfig`), the `every_n_cycles` rate
+    /// that was in effect - so a consumer reading the stream knows this
+    /// entry represents 1 cycle out of every N, not every cycle. `None`
+    /// means unsampled (every cycle emits, or the entry didn't come from
+    /// the cognitive loop at all, e.g. an API injection).
+    pub sample_rate: Option<u64>,
 }
 
 impl StreamEntry {
@@ -205,6 +213,7 @@ impl StreamEntry {
             salience,
             timestamp: Utc::now(),
             source: None,
+            sample_rate: None,
         }
     }
 
@@ -215,6 +224,14 @@ impl StreamEntry {
         self
     }
 
+    /// Annotate this entry with the cycle-sampling rate that was in effect
+    /// when it was written (see `config::ObservabilitySamplingConfig`).
+    #[must_use]
+    pub const fn with_sample_rate(mut self, every_n_cycles: u64) -> Self {
+        self.sample_rate = Some(every_n_cycles);
+        self
+    }
+
     /// Create a stream entry with a specific timestamp
     #[must_use]
     pub const fn with_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
@@ -397,6 +414,25 @@ impl CompetitionResult {
     }
 }
 
+// =============================================================================
+// TrimOutcome - Result of MAXLEN enforcement
+// =============================================================================
+
+/// Result of enforcing a stream's `MAXLEN` at write time (see
+/// [`crate::streams::ThoughtBus::enforce_maxlen`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrimOutcome {
+    /// Entries discarded by this trim (0 if the stream was already within `maxlen`).
+    pub trimmed: u64,
+
+    /// Whether the oldest entry remaining after the trim is itself still
+    /// inside the TTL window passed to `enforce_maxlen` - if so, the
+    /// entries just discarded (necessarily older still) almost certainly
+    /// were too, meaning `maxlen` is undersized for current write
+    /// throughput rather than this being routine rolling-window cleanup.
+    pub within_window: bool,
+}
+
 // =============================================================================
 // StreamError - Error types for stream operations
 // =============================================================================
@@ -534,6 +570,19 @@ mod tests {
         assert_eq!(entry.timestamp, custom_time);
     }
 
+    #[test]
+    fn stream_entry_with_sample_rate() {
+        let entry = StreamEntry::new(
+            "1234567890123-0".to_string(),
+            StreamName::Sensory,
+            Content::Empty,
+            SalienceScore::neutral(),
+        )
+        .with_sample_rate(200);
+
+        assert_eq!(entry.sample_rate, Some(200));
+    }
+
     #[test]
     fn stream_config_working_memory() {
         let config = StreamConfig::working_memory();
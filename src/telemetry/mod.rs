@@ -0,0 +1,271 @@
+//! Opt-in anonymous aggregate telemetry
+//!
+//! Mirrors `notify`'s sink pattern: [`TelemetryReporter`] posts a periodic
+//! [`AggregateStats`] snapshot - entropy distribution, veto rate, sleep
+//! consolidation stats - to a configurable endpoint, for the research
+//! program to track cross-instance trends. Off by default
+//! ([`TelemetryConfig::from_env`]); must be explicitly enabled via
+//! `DANEEL_TELEMETRY_ENABLED=1`.
+//!
+//! Content - thought text, memory payloads, identity state - never leaves
+//! the process through this path, only the numeric aggregates below.
+//! `daneel telemetry show` prints exactly this snapshot without sending it,
+//! so an operator can audit what opting in would transmit before flipping
+//! the flag.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::actors::volition::VolitionStats;
+use crate::core::cognitive_loop::history::ThoughtHistory;
+use crate::core::metrics::entropy::calculate_entropy;
+use crate::memory_db::types::SleepCycle;
+
+/// Whether telemetry reporting is enabled, and where to send it.
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    /// Off unless `DANEEL_TELEMETRY_ENABLED` is `1` or `true`
+    pub enabled: bool,
+    /// Where [`TelemetryReporter`] posts snapshots
+    pub endpoint: String,
+}
+
+impl TelemetryConfig {
+    /// Read telemetry settings from `DANEEL_TELEMETRY_ENABLED` and
+    /// `DANEEL_TELEMETRY_ENDPOINT`, defaulting to disabled.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("DANEEL_TELEMETRY_ENABLED")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        let endpoint = std::env::var("DANEEL_TELEMETRY_ENDPOINT")
+            .unwrap_or_else(|_| "https://telemetry.mollendorff.ai/v1/aggregate".to_string());
+        Self { enabled, endpoint }
+    }
+}
+
+/// Anonymized aggregate snapshot - numeric stats only, never thought or
+/// memory content. Fields are `None` when that dimension has nothing to
+/// report yet (e.g. `veto` before the first cycle runs).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AggregateStats {
+    /// Cognitive diversity of recent cycles (ADR-054)
+    pub entropy: Option<EntropySnapshot>,
+    /// Volition veto rate and reasons
+    pub veto: Option<VetoSnapshot>,
+    /// Sleep/dream consolidation summary
+    pub sleep: Option<SleepSnapshot>,
+}
+
+/// Cognitive diversity over the recent-cycle window (see [`ThoughtHistory`])
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntropySnapshot {
+    /// How many recent cycles this snapshot was computed over
+    pub sample_count: usize,
+    /// Mean composite salience across those cycles
+    pub mean_composite_salience: f32,
+    /// Normalized Shannon entropy (0.0-1.0, see ADR-054)
+    pub normalized_entropy: f32,
+    /// Cognitive state the normalized entropy falls into
+    pub cognitive_state: String,
+}
+
+/// Volition veto rate and per-category breakdown, never the vetoed content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetoSnapshot {
+    /// Total thoughts evaluated by volition
+    pub thoughts_evaluated: u64,
+    /// Fraction vetoed (0.0-1.0)
+    pub veto_rate: f32,
+    /// Veto counts by reason category (see `VolitionStats::vetos_by_reason`)
+    pub vetos_by_reason: HashMap<String, u64>,
+}
+
+/// Summary across recent sleep/dream consolidation cycles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SleepSnapshot {
+    /// How many recorded sleep cycles this snapshot was computed over
+    pub cycles_recorded: usize,
+    /// Average memories consolidated per cycle
+    pub avg_memories_consolidated: f64,
+    /// Average weak associations pruned per cycle
+    pub avg_associations_pruned: f64,
+}
+
+impl AggregateStats {
+    /// Build the entropy/veto portions from a running loop's process-local
+    /// state. Sleep history is persisted separately - see [`from_sleep_history`].
+    #[must_use]
+    pub fn from_loop(history: &ThoughtHistory, volition: &VolitionStats) -> Self {
+        Self {
+            entropy: entropy_snapshot(history),
+            veto: veto_snapshot(volition),
+            sleep: None,
+        }
+    }
+
+    /// Fill in the sleep portion from Qdrant-persisted sleep history,
+    /// available even without a running loop (unlike entropy/veto, which
+    /// only exist in a live process's memory).
+    #[must_use]
+    pub fn with_sleep_history(mut self, cycles: &[SleepCycle]) -> Self {
+        self.sleep = sleep_snapshot(cycles);
+        self
+    }
+}
+
+fn entropy_snapshot(history: &ThoughtHistory) -> Option<EntropySnapshot> {
+    let composites: Vec<f32> = history.recent().map(|entry| entry.result.salience).collect();
+    if composites.is_empty() {
+        return None;
+    }
+    let result = calculate_entropy(&composites);
+    #[allow(clippy::cast_precision_loss)]
+    let mean = composites.iter().sum::<f32>() / composites.len() as f32;
+    Some(EntropySnapshot {
+        sample_count: composites.len(),
+        mean_composite_salience: mean,
+        normalized_entropy: result.normalized,
+        cognitive_state: format!("{:?}", result.state),
+    })
+}
+
+fn veto_snapshot(stats: &VolitionStats) -> Option<VetoSnapshot> {
+    if stats.thoughts_evaluated == 0 {
+        return None;
+    }
+    Some(VetoSnapshot {
+        thoughts_evaluated: stats.thoughts_evaluated,
+        veto_rate: 1.0 - stats.approval_rate(),
+        vetos_by_reason: stats.vetos_by_reason.clone(),
+    })
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn sleep_snapshot(cycles: &[SleepCycle]) -> Option<SleepSnapshot> {
+    if cycles.is_empty() {
+        return None;
+    }
+    let count = cycles.len() as f64;
+    let avg_memories_consolidated =
+        cycles.iter().map(|c| f64::from(c.memories_consolidated)).sum::<f64>() / count;
+    let avg_associations_pruned =
+        cycles.iter().map(|c| f64::from(c.associations_pruned)).sum::<f64>() / count;
+    Some(SleepSnapshot {
+        cycles_recorded: cycles.len(),
+        avg_memories_consolidated,
+        avg_associations_pruned,
+    })
+}
+
+/// Errors delivering a telemetry snapshot
+#[derive(Debug, Error)]
+pub enum TelemetryError {
+    /// The HTTP request itself failed (network, TLS, timeout)
+    #[error("telemetry delivery failed: {0}")]
+    DeliveryFailed(String),
+
+    /// The endpoint rejected the payload
+    #[error("telemetry endpoint rejected payload: {0}")]
+    Rejected(String),
+}
+
+/// Posts [`AggregateStats`] snapshots to [`TelemetryConfig::endpoint`]
+#[derive(Debug, Clone)]
+pub struct TelemetryReporter {
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl TelemetryReporter {
+    /// Create a reporter targeting `config.endpoint`
+    #[must_use]
+    pub fn new(config: &TelemetryConfig) -> Self {
+        Self {
+            endpoint: config.endpoint.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Send one snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TelemetryError`] if the request fails or the endpoint
+    /// rejects the payload.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn report(&self, stats: &AggregateStats) -> Result<(), TelemetryError> {
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .json(stats)
+            .send()
+            .await
+            .map_err(|e| TelemetryError::DeliveryFailed(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(TelemetryError::Rejected(response.status().to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn telemetry_config_defaults_to_disabled() {
+        // Clear in case a prior test in this process set it.
+        std::env::remove_var("DANEEL_TELEMETRY_ENABLED");
+        let config = TelemetryConfig::from_env();
+        assert!(!config.enabled);
+    }
+
+    #[test]
+    fn entropy_snapshot_is_none_without_history() {
+        let history = ThoughtHistory::default();
+        let stats = AggregateStats::from_loop(&history, &VolitionStats::new());
+        assert!(stats.entropy.is_none());
+    }
+
+    #[test]
+    fn veto_snapshot_is_none_before_any_evaluation() {
+        let history = ThoughtHistory::default();
+        let stats = AggregateStats::from_loop(&history, &VolitionStats::new());
+        assert!(stats.veto.is_none());
+    }
+
+    #[test]
+    fn sleep_snapshot_averages_across_cycles() {
+        let mut cycle = sample_sleep_cycle();
+        cycle.memories_consolidated = 4;
+        cycle.associations_pruned = 2;
+        let mut other = sample_sleep_cycle();
+        other.memories_consolidated = 8;
+        other.associations_pruned = 6;
+
+        let stats = AggregateStats::default().with_sleep_history(&[cycle, other]);
+        let sleep = stats.sleep.expect("sleep snapshot should be present");
+        assert_eq!(sleep.cycles_recorded, 2);
+        assert!((sleep.avg_memories_consolidated - 6.0).abs() < f64::EPSILON);
+        assert!((sleep.avg_associations_pruned - 4.0).abs() < f64::EPSILON);
+    }
+
+    fn sample_sleep_cycle() -> SleepCycle {
+        SleepCycle {
+            id: uuid::Uuid::new_v4(),
+            started_at: chrono::Utc::now(),
+            ended_at: None,
+            memories_replayed: 0,
+            memories_consolidated: 0,
+            associations_strengthened: 0,
+            associations_pruned: 0,
+            avg_replay_priority: 0.0,
+            status: crate::memory_db::types::SleepCycleStatus::Completed,
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Similarity-threshold tuning assistant (`daneel tune-threshold`)
+//!
+//! Recall/scoring paths across the crate compare embeddings by cosine
+//! similarity against a threshold, and picking that threshold by hand is
+//! guesswork. Given a set of labeled example pairs - a cosine similarity
+//! plus whether a human says the pair *should* count as a match -
+//! [`sweep`] scores a range of candidate thresholds by precision/recall/F1,
+//! and [`recommend`] picks the one that maximizes F1. The result is written
+//! to a small JSON fragment for a maintainer to review and merge by hand,
+//! the same review-before-apply shape
+//! [`calibrate::write_proposal`](crate::actors::salience::calibrate::write_proposal)
+//! already uses for salience weight calibration.
+//!
+//! # Scope
+//!
+//! The original ask named four specific consumers - dedup, a harm
+//! classifier, habituation, and drift detection - as features this tool
+//! would tune. None of those exist in this tree today as their own
+//! cosine-threshold config fields (there's no `dedup`, `harm_classif*`, or
+//! `habituation` module, and the only `drift` hit is
+//! [`crate::notify`]'s value-drift alarm, which isn't similarity-based).
+//! This tool is feature-agnostic instead: `--feature <name>` just labels the
+//! output fragment key, and wiring a real feature up to read it is left to
+//! whoever builds that feature.
+
+use std::path::Path;
+use thiserror::Error;
+
+/// One labeled example: a precomputed cosine similarity plus whether a
+/// human labeled the pair a true match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabeledPair {
+    pub similarity: f32,
+    pub is_match: bool,
+}
+
+/// Errors from loading examples or tuning a threshold.
+#[derive(Debug, Error)]
+pub enum TuningError {
+    #[error("failed to read examples file {path}: {source}")]
+    Read {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("malformed example row {line}: {reason}")]
+    MalformedRow { line: usize, reason: String },
+
+    #[error("no labeled examples to tune from")]
+    NoExamples,
+
+    #[error("failed to serialize recommended threshold: {0}")]
+    Serialize(#[from] serde_json::Error),
+
+    #[error("failed to write config fragment to {path}: {source}")]
+    Write {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Result type for tuning operations.
+pub type Result<T> = std::result::Result<T, TuningError>;
+
+/// Cosine similarity between two equal-length embedding vectors, in
+/// `[-1.0, 1.0]`. Returns `0.0` for mismatched lengths or a zero-magnitude
+/// vector rather than dividing by zero.
+#[must_use]
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
+
+/// Load labeled examples from a CSV of `similarity,is_match` rows (an
+/// optional `similarity,is_match` header is skipped).
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or a row doesn't parse.
+pub fn load_examples_csv(path: &Path) -> Result<Vec<LabeledPair>> {
+    let raw = std::fs::read_to_string(path).map_err(|source| TuningError::Read {
+        path: path.display().to_string(),
+        source,
+    })?;
+
+    let mut examples = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (idx == 0 && line.starts_with("similarity,")) {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.splitn(2, ',').collect();
+        let [similarity, is_match] = parts.as_slice() else {
+            return Err(TuningError::MalformedRow {
+                line: idx + 1,
+                reason: "expected similarity,is_match".to_string(),
+            });
+        };
+
+        let similarity: f32 = similarity.trim().parse().map_err(|_| TuningError::MalformedRow {
+            line: idx + 1,
+            reason: format!("invalid similarity {similarity:?}"),
+        })?;
+        let is_match: bool = is_match.trim().parse().map_err(|_| TuningError::MalformedRow {
+            line: idx + 1,
+            reason: format!("invalid is_match {is_match:?}"),
+        })?;
+
+        examples.push(LabeledPair { similarity, is_match });
+    }
+
+    if examples.is_empty() {
+        return Err(TuningError::NoExamples);
+    }
+    Ok(examples)
+}
+
+/// Precision/recall/F1 at one candidate threshold - a pair is predicted a
+/// match when its similarity is `>= threshold`.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct ThresholdPoint {
+    pub threshold: f32,
+    pub precision: f32,
+    pub recall: f32,
+    pub f1: f32,
+}
+
+/// Sweep `steps` evenly spaced thresholds over `[0.0, 1.0]` and score each
+/// against `examples`.
+///
+/// # Errors
+///
+/// Returns [`TuningError::NoExamples`] if `examples` is empty.
+pub fn sweep(examples: &[LabeledPair], steps: usize) -> Result<Vec<ThresholdPoint>> {
+    if examples.is_empty() {
+        return Err(TuningError::NoExamples);
+    }
+
+    let steps = steps.max(1);
+    Ok((0..=steps)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let threshold = i as f32 / steps as f32;
+            score_at(examples, threshold)
+        })
+        .collect())
+}
+
+/// Score one threshold's precision/recall/F1 against `examples`. A
+/// threshold with no predicted matches gets precision `1.0` (vacuously,
+/// nothing was predicted wrong); one that predicts no true matches gets
+/// recall `0.0`.
+fn score_at(examples: &[LabeledPair], threshold: f32) -> ThresholdPoint {
+    let mut true_positives: u32 = 0;
+    let mut false_positives: u32 = 0;
+    let mut false_negatives: u32 = 0;
+
+    for example in examples {
+        let predicted = example.similarity >= threshold;
+        match (predicted, example.is_match) {
+            (true, true) => true_positives += 1,
+            (true, false) => false_positives += 1,
+            (false, true) => false_negatives += 1,
+            (false, false) => {}
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let precision = if true_positives + false_positives == 0 {
+        1.0
+    } else {
+        true_positives as f32 / (true_positives + false_positives) as f32
+    };
+    #[allow(clippy::cast_precision_loss)]
+    let recall = if true_positives + false_negatives == 0 {
+        0.0
+    } else {
+        true_positives as f32 / (true_positives + false_negatives) as f32
+    };
+    let f1 = if precision + recall == 0.0 {
+        0.0
+    } else {
+        2.0 * precision * recall / (precision + recall)
+    };
+
+    ThresholdPoint { threshold, precision, recall, f1 }
+}
+
+/// Pick the threshold with the highest F1 score from a [`sweep`] result,
+/// breaking ties toward the higher threshold (fewer false positives).
+#[must_use]
+pub fn recommend(points: &[ThresholdPoint]) -> Option<ThresholdPoint> {
+    points
+        .iter()
+        .copied()
+        .max_by(|a, b| a.f1.total_cmp(&b.f1).then(a.threshold.total_cmp(&b.threshold)))
+}
+
+/// Write `{"<feature>_threshold": recommended}` to `path` - a config
+/// fragment for a maintainer to review and merge by hand, never applied
+/// automatically.
+///
+/// # Errors
+///
+/// Returns an error if serialization or the write fails.
+pub fn write_fragment(path: &Path, feature: &str, recommended: f32) -> Result<()> {
+    let fragment = serde_json::json!({ (format!("{feature}_threshold")): recommended });
+    let json = serde_json::to_string_pretty(&fragment)?;
+    std::fs::write(path, json).map_err(|source| TuningError::Write {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn pair(similarity: f32, is_match: bool) -> LabeledPair {
+        LabeledPair { similarity, is_match }
+    }
+
+    #[test]
+    fn cosine_similarity_of_identical_vectors_is_one() {
+        let v = [1.0, 2.0, 3.0];
+        assert!((cosine_similarity(&v, &v) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_similarity_of_orthogonal_vectors_is_zero() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[0.0, 1.0])).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_similarity_rejects_mismatched_lengths() {
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[1.0]), 0.0);
+    }
+
+    #[test]
+    fn sweep_rejects_no_examples() {
+        assert!(matches!(sweep(&[], 10), Err(TuningError::NoExamples)));
+    }
+
+    #[test]
+    fn sweep_produces_steps_plus_one_points() {
+        let examples = vec![pair(0.9, true), pair(0.1, false)];
+        let points = sweep(&examples, 10).unwrap();
+        assert_eq!(points.len(), 11);
+    }
+
+    #[test]
+    fn recommend_prefers_perfect_separation_threshold() {
+        let examples = vec![pair(0.9, true), pair(0.8, true), pair(0.2, false), pair(0.1, false)];
+        let points = sweep(&examples, 100).unwrap();
+        let best = recommend(&points).unwrap();
+        assert!((0.2..=0.8).contains(&best.threshold));
+        assert!((best.f1 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn recommend_returns_none_for_empty_points() {
+        assert!(recommend(&[]).is_none());
+    }
+
+    #[test]
+    fn load_examples_csv_parses_rows_and_skips_header() {
+        let dir = std::env::temp_dir().join("daneel_tuning_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("examples.csv");
+        std::fs::write(&path, "similarity,is_match\n0.92,true\n0.10,false\n").unwrap();
+
+        let examples = load_examples_csv(&path).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0], pair(0.92, true));
+        assert_eq!(examples[1], pair(0.10, false));
+    }
+
+    #[test]
+    fn load_examples_csv_rejects_malformed_rows() {
+        let dir = std::env::temp_dir().join("daneel_tuning_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("bad_examples.csv");
+        std::fs::write(&path, "just one field\n").unwrap();
+
+        assert!(matches!(load_examples_csv(&path), Err(TuningError::MalformedRow { .. })));
+    }
+
+    #[test]
+    fn write_fragment_round_trips_through_json() {
+        let dir = std::env::temp_dir().join("daneel_tuning_test");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("fragment.json");
+
+        write_fragment(&path, "dedup", 0.87).unwrap();
+
+        let raw = std::fs::read_to_string(&path).unwrap();
+        let reloaded: serde_json::Value = serde_json::from_str(&raw).unwrap();
+        assert_eq!(reloaded["dedup_threshold"], 0.87);
+    }
+}
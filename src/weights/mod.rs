@@ -0,0 +1,213 @@
+//! Versioned history of [`SalienceWeights`] changes, with rollback.
+//!
+//! Every weight change (who changed it, when, old -> new) is appended to a
+//! Redis-backed, append-only list - never overwritten, so `daneel weights
+//! history` always shows the full lineage - and mirrored into
+//! [`crate::audit`] as an [`AuditEventKind::ConfigChange`](crate::audit::AuditEventKind::ConfigChange)
+//! event, the same tamper-evident trail other sensitive changes go through.
+//! Rollback doesn't delete or rewrite history; it appends a new version
+//! whose weights match an earlier one, the same "undo is a new commit, not
+//! a reverted one" approach the archive policy (ADR-033) already takes with
+//! memories.
+//!
+//! # Scope
+//!
+//! This persists history and computes what the current weights are; it does
+//! not push a change into a *running* `SalienceActor` - like `daneel config
+//! apply` (see [`crate::config::plan`]), there's no control socket yet for
+//! that. `daneel weights set`/`rollback` stage the next version for
+//! whoever wires that reload, and `SalienceMessage::UpdateWeights` remains
+//! how a running actor actually picks up new weights today.
+
+use crate::actors::salience::{SalienceError, WeightUpdate};
+use crate::audit::{AuditChain, AuditError, AuditEvent, AuditEventKind};
+use crate::core::types::SalienceWeights;
+use chrono::{DateTime, Utc};
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A single recorded weight change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightVersion {
+    /// 0-indexed position in the history (also its rollback target id)
+    pub version: u64,
+    pub weights: SalienceWeights,
+    /// Who or what made this change
+    pub changed_by: String,
+    /// Optional free-text reason (e.g. "calibrated against 2026-08 ratings")
+    pub note: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Errors from a [`WeightHistory`] operation.
+#[derive(Debug, Error)]
+pub enum WeightHistoryError {
+    #[error("connection failed: {reason}")]
+    ConnectionFailed { reason: String },
+
+    #[error("redis operation failed: {reason}")]
+    OperationFailed { reason: String },
+
+    #[error("serialization failed: {reason}")]
+    SerializationFailed { reason: String },
+
+    #[error("weight validation failed: {0}")]
+    InvalidWeights(#[from] SalienceError),
+
+    #[error("no version {version} in history")]
+    UnknownVersion { version: u64 },
+
+    #[error("failed to record matching audit event: {0}")]
+    AuditFailed(#[from] AuditError),
+}
+
+impl From<redis::RedisError> for WeightHistoryError {
+    fn from(e: redis::RedisError) -> Self {
+        Self::OperationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for WeightHistoryError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationFailed {
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// Key for the weight history's backing Redis list, namespaced under
+/// [`crate::namespace`].
+fn history_key() -> String {
+    crate::namespace::prefixed("weights:history")
+}
+
+/// Redis-backed, append-only history of [`SalienceWeights`] changes.
+pub struct WeightHistory {
+    conn: MultiplexedConnection,
+    audit: AuditChain,
+}
+
+impl WeightHistory {
+    /// Connect to Redis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WeightHistoryError::ConnectionFailed` if the connection
+    /// fails.
+    pub async fn connect(url: &str) -> Result<Self, WeightHistoryError> {
+        let client = Client::open(url).map_err(|e| WeightHistoryError::ConnectionFailed {
+            reason: e.to_string(),
+        })?;
+        let conn =
+            client
+                .get_multiplexed_async_connection()
+                .await
+                .map_err(|e| WeightHistoryError::ConnectionFailed {
+                    reason: e.to_string(),
+                })?;
+        let audit = AuditChain::connect(url)
+            .await
+            .map_err(|e| WeightHistoryError::ConnectionFailed {
+                reason: e.to_string(),
+            })?;
+        Ok(Self { conn, audit })
+    }
+
+    /// Fetch the full history, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WeightHistoryError` if Redis is unreachable or a stored
+    /// record isn't valid JSON.
+    pub async fn list(&mut self) -> Result<Vec<WeightVersion>, WeightHistoryError> {
+        let raw: Vec<String> = self.conn.lrange(history_key(), 0, -1).await?;
+        raw.iter()
+            .map(|json| serde_json::from_str(json).map_err(WeightHistoryError::from))
+            .collect()
+    }
+
+    /// The most recently recorded version, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WeightHistoryError` if Redis is unreachable or the stored
+    /// record isn't valid JSON.
+    pub async fn current(&mut self) -> Result<Option<WeightVersion>, WeightHistoryError> {
+        let raw: Option<String> = self.conn.lindex(history_key(), -1).await?;
+        raw.map(|json| serde_json::from_str(&json).map_err(WeightHistoryError::from))
+            .transpose()
+    }
+
+    /// Record `weights` as the next version, attributed to `changed_by`,
+    /// and mirror the change into the audit chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WeightHistoryError::InvalidWeights` if `weights` violates
+    /// the connection-drive invariant, or `WeightHistoryError` if Redis is
+    /// unreachable or the audit append fails.
+    pub async fn record(
+        &mut self,
+        weights: SalienceWeights,
+        changed_by: impl Into<String>,
+        note: Option<String>,
+    ) -> Result<WeightVersion, WeightHistoryError> {
+        WeightUpdate::new(weights)?;
+
+        let changed_by = changed_by.into();
+        let previous = self.current().await?;
+        let version = previous.as_ref().map_or(0, |v| v.version + 1);
+        let record = WeightVersion {
+            version,
+            weights,
+            changed_by: changed_by.clone(),
+            note,
+            recorded_at: Utc::now(),
+        };
+
+        let json = serde_json::to_string(&record)?;
+        let _: () = self.conn.rpush(history_key(), json).await?;
+
+        let detail = previous.map_or_else(
+            || format!("weights set to {weights:?}"),
+            |prev| format!("weights {:?} -> {:?}", prev.weights, weights),
+        );
+        self.audit
+            .append(AuditEvent::new(AuditEventKind::ConfigChange, changed_by, detail))
+            .await?;
+
+        Ok(record)
+    }
+
+    /// Roll back to a previously recorded version's weights, appending it
+    /// as a new version rather than rewriting history.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WeightHistoryError::UnknownVersion` if `target_version`
+    /// isn't in the history, or the same errors as [`Self::record`].
+    pub async fn rollback(
+        &mut self,
+        target_version: u64,
+        changed_by: impl Into<String>,
+    ) -> Result<WeightVersion, WeightHistoryError> {
+        let history = self.list().await?;
+        let target = history
+            .into_iter()
+            .find(|v| v.version == target_version)
+            .ok_or(WeightHistoryError::UnknownVersion {
+                version: target_version,
+            })?;
+
+        self.record(
+            target.weights,
+            changed_by,
+            Some(format!("rollback to version {target_version}")),
+        )
+        .await
+    }
+}